@@ -1,5 +1,7 @@
 #include "Test.h"
 
+#include <cstdlib>
+
 const int Test::COUNTDOWN[] = { 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0 };
 const int* Test::COUNTDOWN_PTR = Test::COUNTDOWN;
 
@@ -21,6 +23,24 @@ Test::Test(double foo)
   , m_double(foo)
 {}
 
+Sequence* Sequence::create(int len) {
+  Sequence* seq = static_cast<Sequence*>(
+      malloc(sizeof(Sequence) + len * sizeof(int)));
+  seq->len = len;
+  for (int i = 0; i < len; ++i) {
+    seq->values[i] = i;
+  }
+  return seq;
+}
+
+void Sequence::destroy(Sequence* seq) {
+  free(seq);
+}
+
+void store_float(IntOrFloat* u, float value) {
+  u->as_float = value;
+}
+
 namespace bitfields {
 
 bool
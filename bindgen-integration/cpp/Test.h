@@ -15,6 +15,25 @@ public:
   static const int* countdown();
 };
 
+struct Sequence {
+  int len;
+  int values[];
+
+  /// Returns a sequence holding `0..len`, to be freed with `destroy`.
+  static Sequence* create(int len);
+  static void destroy(Sequence* seq);
+};
+
+union IntOrFloat {
+  int as_int;
+  float as_float;
+  // Named like the accessor for a mutable reference to `as_int`.
+  int as_int_mut;
+};
+
+/// Stores `value` in the `as_float` variant of `u`.
+void store_float(IntOrFloat* u, float value);
+
 namespace testing {
 
 typedef Test TypeAlias;
@@ -23,6 +42,13 @@ typedef Test TypeAlias;
 
 typedef testing::TypeAlias TypeAlias;
 
+enum Color {
+  COLOR_RED = -1,
+  COLOR_GREEN = 3,
+  COLOR_BLUE = 7,
+  COLOR_DEFAULT = COLOR_GREEN,
+};
+
 namespace bitfields {
 
 struct First {
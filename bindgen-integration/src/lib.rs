@@ -51,6 +51,21 @@ fn test_overload() {
     assert_eq!(test.m_double, 5.0);
 }
 
+#[test]
+fn test_incomplete_array_field() {
+    unsafe {
+        let seq = bindings::Sequence::create(4);
+        let len = (*seq).len as usize;
+        assert_eq!((*seq).values.as_slice(len), &[0, 1, 2, 3]);
+
+        *(*seq).values.get_unchecked_mut(3) = 42;
+        assert_eq!(*(*seq).values.get_unchecked(3), 42);
+        assert_eq!((*seq).values.as_mut_slice(len)[3], 42);
+
+        bindings::Sequence::destroy(seq);
+    }
+}
+
 #[test]
 fn test_bitfields_first() {
     let mut first: bindings::bitfields::First = unsafe {
@@ -80,6 +95,14 @@ fn test_bitfields_second() {
     assert!(unsafe {
         second.assert(1337, true)
     });
+
+    // Negative values need to be sign-extended when read back.
+    second.set_thirty_one_bits(-1337);
+    assert!(unsafe {
+        second.assert(-1337, true)
+    });
+    assert_eq!(second.thirty_one_bits(), -1337);
+    assert!(second.one_bit());
 }
 
 #[test]
@@ -101,3 +124,31 @@ fn test_bitfields_third() {
                      bindings::bitfields::ItemKind::ITEM_KIND_TRES)
     });
 }
+
+#[test]
+fn test_union_variants() {
+    let mut u: bindings::IntOrFloat = unsafe { mem::zeroed() };
+    unsafe {
+        bindings::store_float(&mut u, 1.0);
+        assert_eq!(*u.as_float(), 1.0);
+        assert_eq!(*u.as_int(), 0x3f800000);
+
+        // `as_int_mut` is taken by the variant of that name.
+        *u.as_int_mut_() = 0x40000000;
+        assert_eq!(*u.as_float(), 2.0);
+        assert_eq!(*u.as_int_mut(), 0x40000000);
+    }
+}
+
+#[test]
+fn test_enum_conversions() {
+    use bindings::Color;
+
+    for color in &[Color::COLOR_RED, Color::COLOR_GREEN, Color::COLOR_BLUE] {
+        assert_eq!(Color::from_raw(i32::from(*color)), Some(*color));
+    }
+
+    // Values shared by several enumerators map to the first one.
+    assert_eq!(Color::from_raw(3), Some(Color::COLOR_GREEN));
+    assert_eq!(Color::from_raw(4), None);
+}
@@ -10,9 +10,15 @@ mod codegen {
 
         quasi_codegen::expand(&src, &dst).unwrap();
         println!("cargo:rerun-if-changed=src/codegen/mod.rs");
+        println!("cargo:rerun-if-changed=src/codegen/bitfield_unit.rs");
+        println!("cargo:rerun-if-changed=src/codegen/bitfield_unit_tests.rs");
+        println!("cargo:rerun-if-changed=src/codegen/constants.rs");
         println!("cargo:rerun-if-changed=src/codegen/error.rs");
         println!("cargo:rerun-if-changed=src/codegen/helpers.rs");
+        println!("cargo:rerun-if-changed=src/codegen/lints.rs");
+        println!("cargo:rerun-if-changed=src/codegen/sort.rs");
         println!("cargo:rerun-if-changed=src/codegen/struct_layout.rs");
+        println!("cargo:rerun-if-changed=src/codegen/visibility.rs");
     }
 }
 
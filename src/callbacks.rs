@@ -18,9 +18,26 @@ pub trait ParseCallbacks: fmt::Debug + UnwindSafe {
         None
     }
 
-    /// This function should return whether, given the a given enum variant
-    /// name, and value, returns whether this enum variant will forcibly be a
-    /// constant.
+    /// The integer kind of the parameters and the result of the function
+    /// generated for an arithmetic function-like macro, given its name, or
+    /// `None` for `u32`. See `Builder::emit_macro_fns`.
+    fn int_macro_fn(&self, _name: &str) -> Option<IntKind> {
+        None
+    }
+
+    /// The paths of the marker traits to implement for the type with the given
+    /// name, like `::my::Ops`, in addition to those from
+    /// `Builder::add_impl_for`. Only called for the types we generate.
+    fn implemented_traits(&self, _name: &str) -> Vec<String> {
+        vec![]
+    }
+
+    /// What to do with the given variant of the enum with the given name,
+    /// which is `None` for unnamed enums, or `None` to generate it like the
+    /// rest of the enum.
+    ///
+    /// A variant can be hidden, or made a constant even if the enum is
+    /// rustified, which comes in handy for the variants aliasing another one.
     fn enum_variant_behavior(&self,
                              _enum_name: Option<&str>,
                              _variant_name: &str,
@@ -12,6 +12,8 @@ use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::io;
+use std::iter;
 use std::os::raw::{c_char, c_int, c_uint, c_ulong};
 
 /// A cursor into the Clang AST, pointing to an AST node.
@@ -48,6 +50,11 @@ impl Cursor {
         unsafe { clang_isDeclaration(self.kind()) != 0 }
     }
 
+    /// Is this cursor's referent an expression?
+    pub fn is_expression(&self) -> bool {
+        unsafe { clang_isExpression(self.kind()) != 0 }
+    }
+
     /// Get the null cursor, which has no referent.
     pub fn null() -> Self {
         Cursor {
@@ -70,7 +77,7 @@ impl Cursor {
 
     /// Get the mangled name of this cursor's referent.
     pub fn mangling(&self) -> String {
-        if clang_Cursor_getMangling::is_loaded() {
+        if Capabilities::detect().mangling {
             unsafe { cxstring_into_string(clang_Cursor_getMangling(self.x)) }
         } else {
             self.spelling()
@@ -139,6 +146,10 @@ impl Cursor {
     /// NOTE: This may not return `Some` for partial template specializations,
     /// see #193 and #194.
     pub fn num_template_args(&self) -> Option<u32> {
+        if !Capabilities::detect().template_arguments {
+            return None;
+        }
+
         // XXX: `clang_Type_getNumTemplateArguments` is sort of reliable, while
         // `clang_Cursor_getNumTemplateArguments` is totally unreliable.
         // Therefore, try former first, and only fallback to the latter if we
@@ -166,6 +177,24 @@ impl Cursor {
             })
     }
 
+    /// Get the value of the `index`th template argument of this template
+    /// specialization, if it's an integral one, like the `16` in `Buf<16>`.
+    pub fn template_arg_value(&self, index: u32) -> Option<u64> {
+        if !Capabilities::detect().template_argument_values {
+            return None;
+        }
+
+        let kind =
+            unsafe { clang_Cursor_getTemplateArgumentKind(self.x, index) };
+        if kind != CXTemplateArgumentKind_Integral {
+            return None;
+        }
+
+        Some(unsafe {
+            clang_Cursor_getTemplateArgumentUnsignedValue(self.x, index)
+        } as u64)
+    }
+
     /// Get a cursor pointing to this referent's containing translation unit.
     ///
     /// Note that we shouldn't create a `TranslationUnit` struct here, because
@@ -426,10 +455,27 @@ impl Cursor {
 
     /// Is the referent an inlined function?
     pub fn is_inlined_function(&self) -> bool {
-        clang_Cursor_isFunctionInlined::is_loaded() &&
+        Capabilities::detect().inline_functions &&
         unsafe { clang_Cursor_isFunctionInlined(self.x) != 0 }
     }
 
+    /// Is the referent a copy or move constructor written by the user, that
+    /// is, one that isn't defaulted?
+    pub fn is_user_provided_copy_or_move_constructor(&self) -> bool {
+        Capabilities::detect().special_members &&
+        unsafe {
+            (clang_CXXConstructor_isCopyConstructor(self.x) != 0 ||
+             clang_CXXConstructor_isMoveConstructor(self.x) != 0) &&
+            clang_CXXMethod_isDefaulted(self.x) == 0
+        }
+    }
+
+    /// Is the referent a function-like macro, like `#define FOO(x) (x)`?
+    pub fn is_macro_function_like(&self) -> bool {
+        Capabilities::detect().macro_functions &&
+        unsafe { clang_Cursor_isMacroFunctionLike(self.x) != 0 }
+    }
+
     /// Get the width of this cursor's referent bit field, or `None` if the
     /// referent is not a bit field.
     pub fn bit_width(&self) -> Option<u32> {
@@ -495,7 +541,7 @@ impl Cursor {
 
     /// Get the visibility of this cursor's referent.
     pub fn visibility(&self) -> CXVisibilityKind {
-        if clang_getCursorVisibility::is_loaded() {
+        if Capabilities::detect().visibility {
             unsafe { clang_getCursorVisibility(self.x) }
         } else {
             CXVisibility_Default
@@ -547,13 +593,13 @@ impl Cursor {
     /// Is this cursor's referent a field declaration that is marked as
     /// `mutable`?
     pub fn is_mutable_field(&self) -> bool {
-        clang_CXXField_isMutable::is_loaded() &&
+        Capabilities::detect().mutable_fields &&
         unsafe { clang_CXXField_isMutable(self.x) != 0 }
     }
 
     /// Get the offset of the field represented by the Cursor.
     pub fn offset_of_field(&self) -> Result<usize, LayoutError> {
-        if !clang_Cursor_getOffsetOfField::is_loaded() {
+        if !Capabilities::detect().field_offsets {
             return Err(LayoutError::from(-1));
         }
 
@@ -614,6 +660,16 @@ extern "C" fn visit_children<Visitor>(cur: CXCursor,
     (*func)(child)
 }
 
+extern "C" fn visit_inclusion(file: CXFile,
+                              _stack: *mut CXSourceLocation,
+                              _stack_len: c_uint,
+                              data: CXClientData) {
+    let files: &mut Vec<File> = unsafe { mem::transmute(data) };
+    files.push(File {
+        x: file,
+    });
+}
+
 impl PartialEq for Cursor {
     fn eq(&self, other: &Cursor) -> bool {
         unsafe { clang_equalCursors(self.x, other.x) == 1 }
@@ -789,6 +845,10 @@ impl Type {
     /// Get the number of template arguments this type has, or `None` if it is
     /// not some kind of template.
     pub fn num_template_args(&self) -> Option<u32> {
+        if !Capabilities::detect().template_arguments {
+            return None;
+        }
+
         let n = unsafe { clang_Type_getNumTemplateArguments(self.x) };
         if n >= 0 {
             Some(n as u32)
@@ -888,7 +948,7 @@ impl Type {
     pub fn named(&self) -> Type {
         unsafe {
             Type {
-                x: if clang_Type_getNamedType::is_loaded() {
+                x: if Capabilities::detect().named_types {
                     clang_Type_getNamedType(self.x)
                 } else {
                     self.x
@@ -1146,6 +1206,22 @@ impl File {
         }
         Some(unsafe { cxstring_into_string(clang_getFileName(self.x)) })
     }
+
+    /// Get an identifier for this source file that doesn't depend on the path
+    /// it was reached through, unlike its name: `a/../b.h`, `./b.h` and `b.h`
+    /// all have the same one.
+    pub fn unique_id(&self) -> Option<[u64; 3]> {
+        if self.x.is_null() {
+            return None;
+        }
+        unsafe {
+            let mut id: CXFileUniqueID = mem::zeroed();
+            if clang_getFileUniqueID(self.x, &mut id) != 0 {
+                return None;
+            }
+            Some([id.data[0] as u64, id.data[1] as u64, id.data[2] as u64])
+        }
+    }
 }
 
 fn cxstring_into_string(s: CXString) -> String {
@@ -1249,6 +1325,22 @@ impl TranslationUnit {
         }
     }
 
+    /// Save this translation unit to `file`, in a form suitable to be used as
+    /// a precompiled header.
+    pub fn save(&self, file: &str) -> Result<(), CXSaveError> {
+        let fname = CString::new(file).unwrap();
+        let ret = unsafe {
+            clang_saveTranslationUnit(self.x,
+                                      fname.as_ptr(),
+                                      clang_defaultSaveOptions(self.x))
+        };
+        if ret == CXSaveError_None {
+            Ok(())
+        } else {
+            Err(ret)
+        }
+    }
+
     /// Get the Clang diagnostic information associated with this translation
     /// unit.
     pub fn diags(&self) -> Vec<Diagnostic> {
@@ -1273,11 +1365,40 @@ impl TranslationUnit {
         }
     }
 
+    /// Get the most specific cursor at the given source location.
+    pub fn cursor_at(&self, location: &SourceLocation) -> Cursor {
+        unsafe {
+            Cursor {
+                x: clang_getCursor(self.x, location.x),
+            }
+        }
+    }
+
     /// Is this the null translation unit?
     pub fn is_null(&self) -> bool {
         self.x.is_null()
     }
 
+    /// Get the files clang read to parse this translation unit: the main
+    /// file, and every file it (transitively) includes.
+    pub fn included_files(&self) -> Vec<File> {
+        let mut files = vec![];
+        unsafe {
+            clang_getInclusions(self.x,
+                                visit_inclusion,
+                                mem::transmute(&mut files));
+        }
+        files
+    }
+
+    /// Is the given file one of the system headers?
+    pub fn is_system_header(&self, file: &File) -> bool {
+        unsafe {
+            let location = clang_getLocationForOffset(self.x, file.x, 0);
+            clang_Location_isInSystemHeader(location) != 0
+        }
+    }
+
     /// Invoke Clang's lexer on this translation unit and get the stream of
     /// tokens that come out.
     pub fn tokens(&self, cursor: &Cursor) -> Option<Vec<Token>> {
@@ -1395,6 +1516,21 @@ impl Diagnostic {
     pub fn severity(&self) -> CXDiagnosticSeverity {
         unsafe { clang_getDiagnosticSeverity(self.x) }
     }
+
+    /// Get the text of this diagnostic message, without the location or any
+    /// other decoration.
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_getDiagnosticSpelling(self.x)) }
+    }
+
+    /// Get the location this diagnostic message refers to.
+    pub fn location(&self) -> SourceLocation {
+        unsafe {
+            SourceLocation {
+                x: clang_getDiagnosticLocation(self.x),
+            }
+        }
+    }
 }
 
 impl Drop for Diagnostic {
@@ -1564,13 +1700,11 @@ pub fn ast_dump(c: &Cursor, depth: isize) -> CXChildVisitResult {
 
         print_indent(depth,
                      format!(" {}spelling = \"{}\"", prefix, ty.spelling()));
-        let num_template_args =
-            unsafe { clang_Type_getNumTemplateArguments(ty.x) };
-        if num_template_args >= 0 {
+        if let Some(num) = ty.num_template_args() {
             print_indent(depth,
                          format!(" {}number-of-template-args = {}",
                                  prefix,
-                                 num_template_args));
+                                 num));
         }
         if let Some(num) = ty.num_elements() {
             print_indent(depth,
@@ -1641,11 +1775,221 @@ pub fn ast_dump(c: &Cursor, depth: isize) -> CXChildVisitResult {
     CXChildVisit_Continue
 }
 
+/// Pretty-print the cursor tree rooted at `c` to `out`, for debugging
+/// purposes.
+///
+/// Each cursor gets a line with its kind, spelling, type and location,
+/// indented by its depth in the tree. The cursor it references, if any, is
+/// printed right below it, but only one level deep: neither its children nor
+/// its own references are followed, so cycles through references can't send
+/// us into an infinite loop.
+pub fn dump_cursor_tree<W>(out: &mut W,
+                           c: &Cursor,
+                           depth: usize)
+                           -> io::Result<()>
+    where W: io::Write,
+{
+    fn describe(c: &Cursor) -> String {
+        let ty = c.cur_type();
+        format!("{} \"{}\" : {} \"{}\" @ {}",
+                kind_to_str(c.kind()),
+                c.spelling(),
+                type_to_str(ty.kind()),
+                ty.spelling(),
+                c.location())
+    }
+
+    let indent: String = iter::repeat("    ").take(depth).collect();
+    try!(writeln!(out, "{}{}", indent, describe(c)));
+
+    if let Some(refd) = c.referenced() {
+        if refd != *c {
+            try!(writeln!(out, "{}  -> {}", indent, describe(&refd)));
+        }
+    }
+
+    let mut result = Ok(());
+    c.visit(|child| {
+        result = dump_cursor_tree(out, &child, depth + 1);
+        if result.is_ok() {
+            CXChildVisit_Continue
+        } else {
+            CXChildVisit_Break
+        }
+    });
+    result
+}
+
 /// Try to extract the clang version to a string
 pub fn extract_clang_version() -> String {
     unsafe { cxstring_into_string(clang_getClangVersion()) }
 }
 
+/// The parts of the libclang API that not every libclang version we support
+/// provides.
+///
+/// We check for them at runtime, and fall back to a less precise behavior
+/// when they're missing, instead of failing to link or panicking.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Whether we can evaluate constant expressions, with
+    /// `clang_Cursor_Evaluate`.
+    pub evaluate: bool,
+    /// Whether we can iterate over the template arguments of a type, with
+    /// `clang_Type_getNumTemplateArguments` and friends.
+    pub template_arguments: bool,
+    /// Whether we can get the values of integral template arguments, with
+    /// `clang_Cursor_getTemplateArgumentUnsignedValue` and friends.
+    pub template_argument_values: bool,
+    /// Whether we can get the mangled name of a symbol, with
+    /// `clang_Cursor_getMangling`.
+    pub mangling: bool,
+    /// Whether we can get the offset of a field, with
+    /// `clang_Cursor_getOffsetOfField`.
+    pub field_offsets: bool,
+    /// Whether we can get the visibility of a symbol, with
+    /// `clang_getCursorVisibility`.
+    pub visibility: bool,
+    /// Whether we can look through elaborated types, with
+    /// `clang_Type_getNamedType`.
+    pub named_types: bool,
+    /// Whether we can tell inline functions apart, with
+    /// `clang_Cursor_isFunctionInlined`.
+    pub inline_functions: bool,
+    /// Whether we can tell mutable fields apart, with
+    /// `clang_CXXField_isMutable`.
+    pub mutable_fields: bool,
+    /// Whether we can tell function-like macros apart, with
+    /// `clang_Cursor_isMacroFunctionLike`.
+    pub macro_functions: bool,
+    /// Whether we can tell user-provided copy and move constructors apart,
+    /// with `clang_CXXConstructor_isCopyConstructor` and friends.
+    pub special_members: bool,
+}
+
+#[cfg(test)]
+thread_local! {
+    static MOCKED_CAPABILITIES: ::std::cell::Cell<Option<Capabilities>> =
+        ::std::cell::Cell::new(None);
+}
+
+/// Run `f` pretending the loaded libclang only has the given capabilities.
+#[cfg(test)]
+pub fn with_mocked_capabilities<F, R>(capabilities: Capabilities, f: F) -> R
+    where F: FnOnce() -> R,
+{
+    let old = MOCKED_CAPABILITIES.with(|c| c.get());
+    MOCKED_CAPABILITIES.with(|c| c.set(Some(capabilities)));
+    let result = f();
+    MOCKED_CAPABILITIES.with(|c| c.set(old));
+    result
+}
+
+impl Capabilities {
+    /// Check which capabilities the loaded libclang has.
+    ///
+    /// This is called for lots of cursors and types, so the loaded libclang
+    /// is only probed once, on the first call.
+    pub fn detect() -> Self {
+        #[cfg(test)]
+        {
+            if let Some(mocked) = MOCKED_CAPABILITIES.with(|c| c.get()) {
+                return mocked;
+            }
+        }
+
+        lazy_static! {
+            static ref DETECTED: Capabilities = Capabilities::probe();
+        }
+        *DETECTED
+    }
+
+    fn probe() -> Self {
+        let template_arguments =
+            clang_Type_getNumTemplateArguments::is_loaded() &&
+            clang_Cursor_getNumTemplateArguments::is_loaded() &&
+            clang_Type_getTemplateArgumentAsType::is_loaded();
+        let template_argument_values =
+            clang_Cursor_getTemplateArgumentKind::is_loaded() &&
+            clang_Cursor_getTemplateArgumentUnsignedValue::is_loaded();
+
+        let special_members =
+            clang_CXXConstructor_isCopyConstructor::is_loaded() &&
+            clang_CXXConstructor_isMoveConstructor::is_loaded() &&
+            clang_CXXMethod_isDefaulted::is_loaded();
+
+        Capabilities {
+            evaluate: clang_Cursor_Evaluate::is_loaded(),
+            template_arguments: template_arguments,
+            template_argument_values: template_argument_values,
+            mangling: clang_Cursor_getMangling::is_loaded(),
+            field_offsets: clang_Cursor_getOffsetOfField::is_loaded(),
+            visibility: clang_getCursorVisibility::is_loaded(),
+            named_types: clang_Type_getNamedType::is_loaded(),
+            inline_functions: clang_Cursor_isFunctionInlined::is_loaded(),
+            mutable_fields: clang_CXXField_isMutable::is_loaded(),
+            macro_functions: clang_Cursor_isMacroFunctionLike::is_loaded(),
+            special_members: special_members,
+        }
+    }
+
+    fn all(&self) -> [(&'static str, bool, &'static str); 11] {
+        [("evaluate",
+          self.evaluate,
+          "constant expressions won't be evaluated"),
+         ("template-arguments",
+          self.template_arguments,
+          "template instantiations will be opaque"),
+         ("template-argument-values",
+          self.template_argument_values,
+          "instantiations with const generic arguments will be opaque"),
+         ("mangling",
+          self.mangling,
+          "symbols will be linked by their unmangled name"),
+         ("field-offsets",
+          self.field_offsets,
+          "field offsets will be computed by bindgen"),
+         ("visibility",
+          self.visibility,
+          "hidden symbols will be generated too"),
+         ("named-types",
+          self.named_types,
+          "elaborated types won't be looked through"),
+         ("inline-functions",
+          self.inline_functions,
+          "inline functions will be generated too"),
+         ("mutable-fields",
+          self.mutable_fields,
+          "mutable fields won't be detected"),
+         ("macro-functions",
+          self.macro_functions,
+          "function-like macros will be ignored"),
+         ("special-members",
+          self.special_members,
+          "classes with a user-provided copy constructor won't be returned \
+           through an out-pointer")]
+    }
+
+    /// The names of the available capabilities.
+    pub fn active(&self) -> Vec<&'static str> {
+        self.all()
+            .iter()
+            .filter(|&&(_, present, _)| present)
+            .map(|&(name, _, _)| name)
+            .collect()
+    }
+
+    /// The names of the missing capabilities, along with what we do without
+    /// them.
+    pub fn missing(&self) -> Vec<(&'static str, &'static str)> {
+        self.all()
+            .iter()
+            .filter(|&&(_, present, _)| !present)
+            .map(|&(name, _, fallback)| (name, fallback))
+            .collect()
+    }
+}
+
 /// A wrapper for the result of evaluating an expression.
 #[derive(Debug)]
 pub struct EvalResult {
@@ -1655,7 +1999,7 @@ pub struct EvalResult {
 impl EvalResult {
     /// Evaluate `cursor` and return the result.
     pub fn new(cursor: Cursor) -> Option<Self> {
-        if !clang_Cursor_Evaluate::is_loaded() {
+        if !Capabilities::detect().evaluate {
             return None;
         }
 
@@ -1728,3 +2072,96 @@ impl Drop for EvalResult {
         unsafe { clang_EvalResult_dispose(self.x) };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn none() -> Capabilities {
+        Capabilities {
+            evaluate: false,
+            template_arguments: false,
+            template_argument_values: false,
+            mangling: false,
+            field_offsets: false,
+            visibility: false,
+            named_types: false,
+            inline_functions: false,
+            mutable_fields: false,
+            macro_functions: false,
+            special_members: false,
+        }
+    }
+
+    /// Parse `source` as C++, and call `f` with the first variable declared in
+    /// it.
+    fn with_first_var<F>(source: &str, f: F)
+        where F: FnOnce(Cursor),
+    {
+        ::ensure_libclang_is_loaded();
+        let index = Index::new(false, false);
+        let unsaved = [UnsavedFile::new("capabilities.hpp", source)];
+        let args = ["-x".to_owned(),
+                    "c++".to_owned(),
+                    "-std=c++11".to_owned()];
+        let tu = TranslationUnit::parse(&index,
+                                        "capabilities.hpp",
+                                        &args,
+                                        &unsaved,
+                                        CXTranslationUnit_None)
+            .expect("Should parse");
+
+        let mut var = None;
+        tu.cursor().visit(|c| if c.kind() == CXCursor_VarDecl {
+            var = Some(c);
+            CXChildVisit_Break
+        } else {
+            CXChildVisit_Continue
+        });
+        f(var.expect("Should have a variable"));
+    }
+
+    #[test]
+    fn probe_reports_every_capability_once() {
+        let version = ::clang_version();
+        assert!(version.full.contains("clang"), "{}", version.full);
+
+        let capabilities = ::clang_capabilities();
+        assert_eq!(capabilities.active().len() + capabilities.missing().len(),
+                   11);
+
+        let missing = none().missing();
+        assert_eq!(missing.len(), 11);
+        assert!(missing.contains(&("evaluate",
+                                   "constant expressions won't be \
+                                    evaluated")));
+    }
+
+    #[test]
+    fn without_evaluate_nothing_is_evaluated() {
+        with_first_var("constexpr int x = 1 + 2;", |var| {
+            if Capabilities::detect().evaluate {
+                assert_eq!(var.evaluate().and_then(|r| r.as_int()), Some(3));
+            }
+
+            with_mocked_capabilities(none(), || {
+                assert!(var.evaluate().is_none());
+            });
+        });
+    }
+
+    #[test]
+    fn without_template_arguments_instantiations_have_none() {
+        with_first_var("template<typename T> struct A { T t; }; A<int> a;",
+                       |var| {
+            if Capabilities::detect().template_arguments {
+                assert_eq!(var.cur_type().num_template_args(), Some(1));
+            }
+
+            with_mocked_capabilities(none(), || {
+                assert_eq!(var.cur_type().num_template_args(), None);
+                assert!(var.cur_type().template_args().is_none());
+            });
+        });
+    }
+}
@@ -0,0 +1,135 @@
+//! Gathering every generated constant in a single module.
+//!
+//! See `Builder::combined_constants_module`. The module re-exports the
+//! constants instead of moving them, so that they keep referring to their
+//! types the same way, wherever they were generated.
+
+use aster;
+use aster::ident::ToIdent;
+use std::collections::HashMap;
+use syntax::ast;
+use syntax::codemap::DUMMY_SP;
+use syntax::print::pprust;
+use syntax::ptr::P;
+
+/// The name of the module holding all the constants.
+pub const CONSTANTS_MODULE_NAME: &'static str = "constants";
+
+/// A constant we found, and how to refer to it from the constants module.
+struct Constant {
+    /// The path to the constant, from the constants module.
+    path: Vec<ast::Ident>,
+    /// The `cfg` attributes of the constant and of the modules containing it.
+    cfgs: Vec<ast::Attribute>,
+    /// The visibility of the constant.
+    vis: ast::Visibility,
+}
+
+/// Build the module re-exporting all the constants in `items` and in the
+/// modules they contain, if there's any.
+///
+/// Constants with the same name, from different modules, get a numeric
+/// suffix, in the order they appear, like overloaded functions do. The ones
+/// that only differ in their `cfg` attributes, like the versions of a constant
+/// for different targets, keep their name.
+pub fn combine(items: &[P<ast::Item>],
+               vis: ast::Visibility)
+               -> Option<P<ast::Item>> {
+    let mut constants = vec![];
+    let mut path = vec!["super".to_ident()];
+    collect(items, &mut path, &mut vec![], &mut constants);
+    if constants.is_empty() {
+        return None;
+    }
+
+    // The `cfg` attributes each name is taken under.
+    let mut names: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+    let uses = constants.into_iter()
+        .map(|constant| {
+            let name = (&*constant.path.last().unwrap().name.as_str())
+                .to_owned();
+            let cfgs: Vec<_> =
+                constant.cfgs.iter().map(pprust::attr_to_string).collect();
+            let mut unique_name = name.clone();
+            let mut suffix = 0;
+            while names.get(&unique_name).map_or(false, |taken| {
+                taken.iter().any(|other| clash(other, &cfgs))
+            }) {
+                suffix += 1;
+                unique_name = format!("{}{}", name, suffix);
+            }
+            names.entry(unique_name.clone()).or_insert(vec![]).push(cfgs);
+
+            let path = aster::AstBuilder::new()
+                .item()
+                .with_attrs(constant.cfgs)
+                .use_()
+                .ids(constant.path)
+                .build();
+            let item = if unique_name == name {
+                path.build()
+            } else {
+                path.as_(&*unique_name)
+            };
+            let vis = constant.vis;
+            item.map(move |mut item| {
+                item.vis = vis;
+                item
+            })
+        })
+        .collect();
+
+    let module = ast::ItemKind::Mod(ast::Mod {
+        inner: DUMMY_SP,
+        items: uses,
+    });
+    Some(aster::AstBuilder::new()
+        .item()
+        .build_item_kind(CONSTANTS_MODULE_NAME, module)
+        .map(|mut item| {
+            item.vis = vis;
+            item
+        }))
+}
+
+/// Whether two constants with the given `cfg` attributes can be generated
+/// together, so they can't have the same name.
+fn clash(cfgs: &[String], other_cfgs: &[String]) -> bool {
+    cfgs.is_empty() || other_cfgs.is_empty() || cfgs == other_cfgs
+}
+
+fn collect(items: &[P<ast::Item>],
+           path: &mut Vec<ast::Ident>,
+           cfgs: &mut Vec<ast::Attribute>,
+           constants: &mut Vec<Constant>) {
+    for item in items {
+        let item_cfgs: Vec<_> = item.attrs
+            .iter()
+            .filter(|attr| attr.check_name("cfg"))
+            .cloned()
+            .collect();
+
+        match item.node {
+            ast::ItemKind::Const(..) => {
+                let mut constant_path = path.clone();
+                constant_path.push(item.ident);
+                let mut constant_cfgs = cfgs.clone();
+                constant_cfgs.extend(item_cfgs);
+                constants.push(Constant {
+                    path: constant_path,
+                    cfgs: constant_cfgs,
+                    vis: item.vis.clone(),
+                });
+            }
+            ast::ItemKind::Mod(ref module) => {
+                let cfgs_len = cfgs.len();
+                path.push(item.ident);
+                cfgs.extend(item_cfgs);
+                collect(&module.items, path, cfgs, constants);
+                cfgs.truncate(cfgs_len);
+                path.pop();
+            }
+            _ => {}
+        }
+    }
+}
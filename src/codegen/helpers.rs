@@ -22,6 +22,17 @@ pub mod attributes {
         aster::AstBuilder::new().attr().list("repr").words(which_ones).build()
     }
 
+    pub fn repr_align(align: usize) -> ast::Attribute {
+        aster::AstBuilder::new()
+            .attr()
+            .list("repr")
+            .word("C")
+            .list("align")
+            .word(&*align.to_string())
+            .build()
+            .build()
+    }
+
     pub fn derives(which_ones: &[&str]) -> ast::Attribute {
         aster::AstBuilder::new().attr().list("derive").words(which_ones).build()
     }
@@ -30,6 +41,22 @@ pub mod attributes {
         aster::AstBuilder::new().attr().word("inline")
     }
 
+    pub fn cold() -> ast::Attribute {
+        aster::AstBuilder::new().attr().word("cold")
+    }
+
+    pub fn non_exhaustive() -> ast::Attribute {
+        aster::AstBuilder::new().attr().word("non_exhaustive")
+    }
+
+    pub fn doc_hidden() -> ast::Attribute {
+        aster::AstBuilder::new().attr().list("doc").words(&["hidden"]).build()
+    }
+
+    pub fn thread_local() -> ast::Attribute {
+        aster::AstBuilder::new().attr().word("thread_local")
+    }
+
     pub fn doc(comment: &str) -> ast::Attribute {
         aster::AstBuilder::new().attr().doc(comment)
     }
@@ -37,6 +64,17 @@ pub mod attributes {
     pub fn link_name(name: &str) -> ast::Attribute {
         aster::AstBuilder::new().attr().name_value("link_name").str(name)
     }
+
+    pub fn link_dylib(lib: &str) -> ast::Attribute {
+        aster::AstBuilder::new()
+            .attr()
+            .list("link")
+            .name_value("name")
+            .str(lib)
+            .name_value("kind")
+            .str("dylib")
+            .build()
+    }
 }
 
 /// Generates a proper type for a field or type with a given `Layout`, that is,
@@ -80,6 +118,7 @@ impl BlobTyBuilder {
 
 pub mod ast_ty {
     use aster;
+    use codegen::helper_type;
     use ir::context::BindgenContext;
     use ir::function::FunctionSig;
     use ir::ty::FloatKind;
@@ -87,6 +126,19 @@ pub mod ast_ty {
     use syntax::ptr::P;
 
     pub fn raw_type(ctx: &BindgenContext, name: &str) -> P<ast::Ty> {
+        if ctx.options().use_libc {
+            return libc_type(ctx, name);
+        }
+
+        // `c_void` doesn't come from the ctypes prefix along with `no_std`.
+        if name == "c_void" && ctx.options().no_std {
+            if ctx.options().core_ffi_c_void {
+                return quote_ty!(ctx.ext_cx(), ::core::ffi::c_void);
+            }
+            ctx.generated_c_void_stub();
+            return helper_type(ctx, "c_void", vec![]);
+        }
+
         let ident = ctx.rust_ident_raw(&name);
         match ctx.options().ctypes_prefix {
             Some(ref prefix) => {
@@ -97,6 +149,11 @@ pub mod ast_ty {
         }
     }
 
+    pub fn libc_type(ctx: &BindgenContext, name: &str) -> P<ast::Ty> {
+        let ident = ctx.rust_ident_raw(&name);
+        quote_ty!(ctx.ext_cx(), ::libc::$ident)
+    }
+
     pub fn float_kind_rust_type(ctx: &BindgenContext,
                                 fk: FloatKind)
                                 -> P<ast::Ty> {
@@ -159,12 +216,20 @@ pub mod ast_ty {
     }
 
     pub fn float_expr(ctx: &BindgenContext,
-                      f: f64)
+                      f: f64,
+                      is_f32: bool)
                       -> Result<P<ast::Expr>, ()> {
         use aster::symbol::ToSymbol;
 
         if f.is_finite() {
-            let mut string = f.to_string();
+            // Rust prints the shortest representation that parses back to the
+            // same value, but we need to narrow `float`s first, otherwise we'd
+            // print all the digits of their widened value.
+            let mut string = if is_f32 {
+                (f as f32).to_string()
+            } else {
+                f.to_string()
+            };
 
             // So it gets properly recognised as a floating point constant.
             if !string.contains('.') {
@@ -176,15 +241,16 @@ pub mod ast_ty {
         }
 
         let prefix = ctx.trait_prefix();
+        let float_ty = ctx.rust_ident_raw(if is_f32 { "f32" } else { "f64" });
         if f.is_nan() {
-            return Ok(quote_expr!(ctx.ext_cx(), ::$prefix::f64::NAN));
+            return Ok(quote_expr!(ctx.ext_cx(), ::$prefix::$float_ty::NAN));
         }
 
         if f.is_infinite() {
             return Ok(if f.is_sign_positive() {
-                quote_expr!(ctx.ext_cx(), ::$prefix::f64::INFINITY)
+                quote_expr!(ctx.ext_cx(), ::$prefix::$float_ty::INFINITY)
             } else {
-                quote_expr!(ctx.ext_cx(), ::$prefix::f64::NEG_INFINITY)
+                quote_expr!(ctx.ext_cx(), ::$prefix::$float_ty::NEG_INFINITY)
             });
         }
 
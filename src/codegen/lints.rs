@@ -0,0 +1,248 @@
+//! Allowing the naming lints only on the generated items that need it.
+//!
+//! The names of the generated items come from C and C++, so they often don't
+//! follow the Rust naming conventions. Instead of allowing the naming lints
+//! for the whole file, this pass finds the items whose names (or whose
+//! fields', variants' or methods' names) would trigger them, and attaches an
+//! `#[allow(...)]` attribute to just those.
+//!
+//! The same checks are used to compute which lints to allow for the whole
+//! file, when asked to.
+
+use super::helpers::attributes;
+use Visibility;
+use syntax::ast;
+use syntax::fold::{self, Folder};
+use syntax::ptr::P;
+use syntax::util::small_vector::SmallVector;
+use syntax::visit::{self, Visitor};
+
+const NON_CAMEL_CASE_TYPES: &'static str = "non_camel_case_types";
+const NON_SNAKE_CASE: &'static str = "non_snake_case";
+const NON_UPPER_CASE_GLOBALS: &'static str = "non_upper_case_globals";
+const DEAD_CODE: &'static str = "dead_code";
+
+/// All the lints we may allow for the whole file, in the order we list them.
+const FILE_LINTS: &'static [&'static str] = &[NON_CAMEL_CASE_TYPES,
+                                             NON_SNAKE_CASE,
+                                             NON_UPPER_CASE_GLOBALS,
+                                             DEAD_CODE];
+
+/// Attach `#[allow(...)]` attributes for the naming lints to the items that
+/// would trigger them.
+pub fn allow_where_needed(items: Vec<P<ast::Item>>) -> Vec<P<ast::Item>> {
+    items.into_iter()
+        .flat_map(|item| LintAllower.fold_item(item).into_iter())
+        .collect()
+}
+
+fn is_camel_case(name: &str) -> bool {
+    let name = name.trim_matches('_');
+    name.chars().next().map_or(true, |c| !c.is_lowercase()) &&
+    !name.contains('_')
+}
+
+fn is_snake_case(name: &str) -> bool {
+    let name = name.trim_matches('_');
+    !name.contains("__") && !name.chars().any(|c| c.is_uppercase())
+}
+
+fn is_upper_case(name: &str) -> bool {
+    !name.chars().any(|c| c.is_lowercase())
+}
+
+fn ident_str(ident: &ast::Ident) -> String {
+    ident.name.as_str().to_string()
+}
+
+/// Add `lint` to `lints` unless it's already there.
+fn add(lints: &mut Vec<&'static str>, lint: &'static str) {
+    if !lints.contains(&lint) {
+        lints.push(lint);
+    }
+}
+
+fn push_allow(attrs: &mut Vec<ast::Attribute>, lints: &[&'static str]) {
+    if !lints.is_empty() {
+        attrs.push(attributes::allow(lints));
+    }
+}
+
+/// Compute the naming lints the given item triggers, including the ones
+/// triggered by its fields or variants.
+fn item_lints(item: &ast::Item) -> Vec<&'static str> {
+    let mut lints = vec![];
+    let name = ident_str(&item.ident);
+    match item.node {
+        ast::ItemKind::Struct(ref data, _) |
+        ast::ItemKind::Union(ref data, _) => {
+            if !is_camel_case(&name) {
+                add(&mut lints, NON_CAMEL_CASE_TYPES);
+            }
+            let bad_field = data.fields().iter().any(|field| {
+                field.ident
+                    .map_or(false, |ident| !is_snake_case(&ident_str(&ident)))
+            });
+            if bad_field {
+                add(&mut lints, NON_SNAKE_CASE);
+            }
+        }
+        ast::ItemKind::Enum(ref def, _) => {
+            let bad_variant = def.variants.iter().any(|variant| {
+                !is_camel_case(&ident_str(&variant.node.name))
+            });
+            if !is_camel_case(&name) || bad_variant {
+                add(&mut lints, NON_CAMEL_CASE_TYPES);
+            }
+        }
+        ast::ItemKind::Ty(..) => {
+            if !is_camel_case(&name) {
+                add(&mut lints, NON_CAMEL_CASE_TYPES);
+            }
+        }
+        ast::ItemKind::Fn(..) |
+        ast::ItemKind::Mod(..) => {
+            if !is_snake_case(&name) {
+                add(&mut lints, NON_SNAKE_CASE);
+            }
+        }
+        ast::ItemKind::Const(..) |
+        ast::ItemKind::Static(..) => {
+            if !is_upper_case(&name) {
+                add(&mut lints, NON_UPPER_CASE_GLOBALS);
+            }
+        }
+        _ => {}
+    }
+    lints
+}
+
+/// Compute the naming lints the given item of an inherent impl triggers.
+fn impl_item_lints(item: &ast::ImplItem) -> Vec<&'static str> {
+    let mut lints = vec![];
+    let name = ident_str(&item.ident);
+    match item.node {
+        ast::ImplItemKind::Method(..) => {
+            if !is_snake_case(&name) {
+                add(&mut lints, NON_SNAKE_CASE);
+            }
+        }
+        ast::ImplItemKind::Const(..) => {
+            if !is_upper_case(&name) {
+                add(&mut lints, NON_UPPER_CASE_GLOBALS);
+            }
+        }
+        _ => {}
+    }
+    lints
+}
+
+/// Compute the lints the given items trigger, to allow them for the whole
+/// file with a `#![allow(...)]` attribute.
+///
+/// Items that aren't `pub` may be unused, so `dead_code` is included when
+/// they aren't generated as such.
+pub fn triggered_by(items: &[P<ast::Item>],
+                    visibility: Visibility)
+                    -> Vec<&'static str> {
+    let mut collector = LintCollector {
+        lints: vec![],
+    };
+    for item in items {
+        collector.visit_item(item);
+    }
+    if visibility != Visibility::Public {
+        add(&mut collector.lints, DEAD_CODE);
+    }
+
+    FILE_LINTS.iter()
+        .cloned()
+        .filter(|lint| collector.lints.contains(lint))
+        .collect()
+}
+
+struct LintCollector {
+    lints: Vec<&'static str>,
+}
+
+impl<'a> Visitor<'a> for LintCollector {
+    fn visit_item(&mut self, item: &'a ast::Item) {
+        for lint in item_lints(item) {
+            add(&mut self.lints, lint);
+        }
+
+        if let ast::ItemKind::Impl(_, _, _, None, _, ref items) = item.node {
+            for impl_item in items {
+                for lint in impl_item_lints(impl_item) {
+                    add(&mut self.lints, lint);
+                }
+            }
+        }
+
+        visit::walk_item(self, item);
+    }
+
+    fn visit_foreign_item(&mut self, item: &'a ast::ForeignItem) {
+        if let Some(lint) = foreign_item_lint(item) {
+            add(&mut self.lints, lint);
+        }
+    }
+
+    fn visit_mac(&mut self, _: &'a ast::Mac) {
+        // The layout test assertions don't contain any items either.
+    }
+}
+
+/// Compute the naming lint the given foreign item triggers, if any.
+fn foreign_item_lint(item: &ast::ForeignItem) -> Option<&'static str> {
+    let name = ident_str(&item.ident);
+    match item.node {
+        ast::ForeignItemKind::Fn(..) if !is_snake_case(&name) => {
+            Some(NON_SNAKE_CASE)
+        }
+        ast::ForeignItemKind::Static(..) if !is_upper_case(&name) => {
+            Some(NON_UPPER_CASE_GLOBALS)
+        }
+        _ => None,
+    }
+}
+
+struct LintAllower;
+
+impl Folder for LintAllower {
+    fn fold_item(&mut self, item: P<ast::Item>) -> SmallVector<P<ast::Item>> {
+        let item = item.map(|mut item| {
+            let lints = item_lints(&item);
+            push_allow(&mut item.attrs, &lints);
+
+            // The names of the items of trait impls are given by the trait,
+            // so only the inherent impls matter.
+            if let ast::ItemKind::Impl(_, _, _, None, _, ref mut items) =
+                item.node {
+                for impl_item in items.iter_mut() {
+                    let lints = impl_item_lints(impl_item);
+                    push_allow(&mut impl_item.attrs, &lints);
+                }
+            }
+
+            item
+        });
+        fold::noop_fold_item(item, self)
+    }
+
+    fn fold_foreign_item(&mut self,
+                         item: ast::ForeignItem)
+                         -> ast::ForeignItem {
+        let mut item = fold::noop_fold_foreign_item(item, self);
+        if let Some(lint) = foreign_item_lint(&item) {
+            item.attrs.push(attributes::allow(&[lint]));
+        }
+        item
+    }
+
+    fn fold_mac(&mut self, mac: ast::Mac) -> ast::Mac {
+        // The only macros we generate are the layout test assertions, which
+        // don't contain any items.
+        mac
+    }
+}
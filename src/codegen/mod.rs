@@ -1,29 +1,40 @@
+mod constants;
 mod error;
 mod helpers;
+pub mod lints;
+mod sort;
 mod struct_layout;
+mod visibility;
 
 use self::helpers::{BlobTyBuilder, attributes};
 use self::struct_layout::{StructLayoutTracker, bytes_from_bits_pow2};
 use self::struct_layout::{align_to, bytes_from_bits};
+use {CBoolType, DiagnosticSeverity, EnumVariation};
 use aster;
 
 use ir::annotations::FieldAccessorKind;
+use ir::comment;
 use ir::comp::{Base, CompInfo, CompKind, Field, Method, MethodKind};
 use ir::context::{BindgenContext, ItemId};
 use ir::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
 use ir::dot;
 use ir::enum_ty::{Enum, EnumVariant, EnumVariantValue};
-use ir::function::{Function, FunctionSig};
+use ir::function::{DllStorage, Function, FunctionSig};
 use ir::int::IntKind;
-use ir::item::{Item, ItemAncestors, ItemCanonicalName, ItemCanonicalPath,
-               ItemSet};
+use ir::interner::Name;
+use ir::item::{Item, ItemAncestors, ItemCanonicalName, ItemCanonicalPath};
+use ir::item_id_set::ItemIdSet;
 use ir::item_kind::ItemKind;
 use ir::layout::Layout;
+use ir::macro_fn::{BinaryOp, MacroArg, MacroExpr, MacroFnBody, UnaryOp};
 use ir::module::Module;
 use ir::objc::{ObjCInterface, ObjCMethod};
 use ir::template::{AsNamed, TemplateInstantiation};
-use ir::ty::{TemplateDeclaration, Type, TypeKind};
+use ir::ty::{FloatKind, RUST_DERIVE_IN_ARRAY_LIMIT, TemplateDeclaration, Type,
+             TypeKind};
 use ir::var::Var;
+use regex_set::last_matching_style;
+use targets;
 
 use std::borrow::Cow;
 use std::cell::Cell;
@@ -36,16 +47,25 @@ use std::ops;
 use syntax::abi::Abi;
 use syntax::ast;
 use syntax::codemap::{Span, respan};
+use syntax::parse;
 use syntax::ptr::P;
+use syntax::visit::{self, Visitor};
 
 fn root_import_depth(ctx: &BindgenContext, item: &Item) -> usize {
     if !ctx.options().enable_cxx_namespaces {
         return 0;
     }
 
-    item.ancestors(ctx)
+    let depth = item.ancestors(ctx)
         .filter(|id| ctx.resolve_item(*id).is_module())
-        .fold(1, |i, _| i + 1)
+        .fold(1, |i, _| i + 1);
+
+    // Header modules add a level, see `Builder::module_per_header`.
+    if ctx.header_module(item).is_some() {
+        depth + 1
+    } else {
+        depth
+    }
 }
 
 fn top_level_path(ctx: &BindgenContext, item: &Item) -> Vec<ast::Ident> {
@@ -62,6 +82,76 @@ fn top_level_path(ctx: &BindgenContext, item: &Item) -> Vec<ast::Ident> {
     path
 }
 
+/// Does any of the given items refer to something through the root module,
+/// like `root::foo::Bar`?
+///
+/// The nested modules that import the root module on their own don't count.
+fn uses_root(ctx: &BindgenContext, items: &[P<ast::Item>]) -> bool {
+    struct RootFinder {
+        root: ast::Name,
+        found: bool,
+    }
+
+    impl RootFinder {
+        fn is_root_import(&self, item: &ast::Item) -> bool {
+            match item.node {
+                ast::ItemKind::Use(ref view_path) => {
+                    match view_path.node {
+                        ast::ViewPathSimple(ident, _) => {
+                            ident.name == self.root
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl<'a> Visitor<'a> for RootFinder {
+        fn visit_item(&mut self, item: &'a ast::Item) {
+            // Modules that import the root module themselves don't need ours,
+            // but the ones that don't, like constified enum modules, refer to
+            // it through `self::super::root`.
+            if let ast::ItemKind::Mod(ref module) = item.node {
+                let imports_root = module.items
+                    .iter()
+                    .any(|item| self.is_root_import(item));
+                if imports_root {
+                    return;
+                }
+            }
+            visit::walk_item(self, item);
+        }
+
+        fn visit_path(&mut self, path: &'a ast::Path, _: ast::NodeId) {
+            let first = path.segments
+                .iter()
+                .map(|segment| segment.identifier.name.as_str())
+                .find(|name| &**name != "self" && &**name != "super");
+            if first.map_or(false, |name| *name == *self.root.as_str()) {
+                self.found = true;
+            }
+            visit::walk_path(self, path);
+        }
+
+        fn visit_mac(&mut self, _: &'a ast::Mac) {
+            // The only macros we generate are the layout test assertions,
+            // which only refer to the type they test by name.
+        }
+    }
+
+    let root = ctx.root_module().canonical_name(ctx);
+    let mut finder = RootFinder {
+        root: ctx.rust_ident(&root).name,
+        found: false,
+    };
+    for item in items {
+        finder.visit_item(item);
+    }
+    finder.found
+}
+
 fn root_import(ctx: &BindgenContext, module: &Item) -> P<ast::Item> {
     assert!(ctx.options().enable_cxx_namespaces, "Somebody messed it up");
     assert!(module.is_module());
@@ -82,6 +172,218 @@ fn root_import(ctx: &BindgenContext, module: &Item) -> P<ast::Item> {
     quote_item!(ctx.ext_cx(), #[allow(unused_imports)] $use_root).unwrap()
 }
 
+/// Refer to one of the generic helper types we prepend to the root module,
+/// like `__BindgenUnionField`, instantiated with `args`.
+fn helper_type(ctx: &BindgenContext,
+               name: &str,
+               args: Vec<P<ast::Ty>>)
+               -> P<ast::Ty> {
+    let prefix = if ctx.options().enable_cxx_namespaces {
+        vec![ctx.root_module().canonical_name(ctx)]
+    } else if ctx.options().module_per_header {
+        // Everything lives in a header module then.
+        vec![ctx.names().intern("self"), ctx.names().intern("super")]
+    } else {
+        vec![]
+    };
+
+    let builder = aster::AstBuilder::new().ty().path();
+    if prefix.is_empty() {
+        builder.segment(name).with_tys(args).build().build()
+    } else {
+        builder.ids(prefix).segment(name).with_tys(args).build().build()
+    }
+}
+
+/// The `#[cfg(...)]` attribute restricting the layout tests to the target the
+/// bindings were generated for, if one was given explicitly, or if the
+/// integer widths were fixed for the one we run on.
+fn layout_test_cfg(ctx: &BindgenContext) -> Option<ast::Attribute> {
+    let meta = match ctx.options().target_triple() {
+        Some(triple) => targets::layout_test_cfg(triple),
+        None if ctx.options().fixed_width_integers => {
+            targets::host_layout_test_cfg()
+        }
+        None => return None,
+    };
+    Some(aster::AstBuilder::new()
+        .attr()
+        .list("cfg")
+        .with_meta_item(meta)
+        .build())
+}
+
+/// The Rust primitive integer with the given signedness and size in bytes,
+/// see `Builder::fixed_width_integers`.
+fn fixed_width_int_type(is_signed: bool, size: usize) -> P<ast::Ty> {
+    let name = int_type_name(is_signed, size)
+        .unwrap_or_else(|| panic!("No fixed-width integer of {} bytes", size));
+    aster::ty::TyBuilder::new().id(name)
+}
+
+/// The `#[link(name = "...", kind = "dylib")]` attribute for the `extern`
+/// block of a function or variable imported from a DLL, if we were told which
+/// one.
+fn dylib_link(ctx: &BindgenContext,
+              storage: Option<DllStorage>)
+              -> Option<ast::Attribute> {
+    match (storage, ctx.options().dynamic_link_name.as_ref()) {
+        (Some(DllStorage::Import), Some(lib)) => {
+            Some(attributes::link_dylib(lib))
+        }
+        _ => None,
+    }
+}
+
+/// The `/// <file>:<line>` doc line pointing at where `item` was declared, if
+/// we were asked to emit those and we know where it comes from.
+fn source_annotation(ctx: &BindgenContext,
+                     item: &Item)
+                     -> Option<ast::Attribute> {
+    if !ctx.options().emit_source_annotations {
+        return None;
+    }
+    item.location().map(|location| {
+        attributes::doc(&format!("/// {}:{}", location.file, location.line))
+    })
+}
+
+/// The `///` doc lines noting which of the arguments and return value of a
+/// function are C++ references, lowered to raw pointers that are never null.
+fn reference_notes(ctx: &BindgenContext,
+                   signature: &FunctionSig)
+                   -> Vec<String> {
+    let reference_kind = |ty: ItemId| {
+        match *ctx.resolve_type(ty).canonical_type(ctx).kind() {
+            TypeKind::Reference(_, kind) => Some(kind),
+            _ => None,
+        }
+    };
+
+    let mut notes = vec![];
+    let names = utils::fnsig_argument_names(ctx, signature);
+    for (&(_, ty), name) in signature.argument_types().iter().zip(names) {
+        if let Some(kind) = reference_kind(ty) {
+            notes.push(format!("/// `{}`: {}.", name, kind.doc_note()));
+        }
+    }
+    if let Some(kind) = reference_kind(signature.return_type()) {
+        notes.push(format!("/// Returns a {}.", kind.doc_note()));
+    }
+    notes
+}
+
+/// The `///` doc lines listing the documented arguments of a function, with
+/// their doc comments, if any of them has one.
+fn argument_docs(ctx: &BindgenContext, signature: &FunctionSig) -> Vec<String> {
+    let names = utils::fnsig_argument_names(ctx, signature);
+    let docs = names.iter()
+        .zip(signature.argument_comments())
+        .filter_map(|(name, comment)| {
+            comment.as_ref().map(|raw| {
+                format!("/// * `{}`: {}", name, comment::text(raw))
+            })
+        })
+        .collect::<Vec<_>>();
+    if docs.is_empty() {
+        return docs;
+    }
+
+    let mut lines = vec!["/// # Arguments".to_owned(), "///".to_owned()];
+    lines.extend(docs);
+    lines
+}
+
+/// An opaque zero-sized struct standing for a type we don't know (or don't
+/// want to generate) the definition of, that can only be used behind pointers.
+///
+/// The private field prevents constructing it outside of the bindings, and
+/// it's neither `Copy` nor `Default`, since we don't know what copying or
+/// zeroing it would mean.
+fn forward_declaration(ctx: &BindgenContext, item: &Item) -> P<ast::Item> {
+    let mut attributes = vec![attributes::repr("C")];
+    if ctx.options().derive_debug {
+        attributes.push(attributes::derives(&["Debug"]));
+    }
+
+    let struct_name = item.canonical_name(ctx);
+    let struct_name = ctx.rust_ident_raw(&struct_name);
+    quote_item!(ctx.ext_cx(),
+                pub struct $struct_name {
+                    _unused: [u8; 0],
+                }
+               )
+        .unwrap()
+        .map(|mut forward_decl| {
+            forward_decl.attrs = attributes;
+            forward_decl
+        })
+}
+
+/// The `Send` and `Sync` implementations the user asked for the given struct
+/// or union, either through `Builder::send_type` and `Builder::sync_type`, or
+/// through annotations.
+fn thread_safety_impls(ctx: &BindgenContext,
+                       item: &Item,
+                       template_params: &[ItemId])
+                       -> Vec<P<ast::Item>> {
+    let generics = utils::template_generics(ctx, template_params);
+    let ty_for_impl =
+        utils::ty_for_impl(ctx, &item.canonical_name(ctx), template_params);
+
+    let mut impls = vec![];
+    for &(trait_, asserted) in &[("Send", item.is_send(ctx)),
+                                 ("Sync", item.is_sync(ctx))] {
+        if !asserted {
+            continue;
+        }
+
+        let comment = format!("/// The user asserted that this type is `{}`, \
+                               bindgen can't check it.",
+                              trait_);
+        let impl_ = aster::AstBuilder::new()
+            .item()
+            .with_attr(attributes::doc(&comment))
+            .impl_()
+            .unsafe_()
+            .trait_()
+            .id(trait_)
+            .build()
+            .with_generics(generics.clone())
+            .build_ty(ty_for_impl.clone());
+        impls.push(impl_);
+    }
+    impls
+}
+
+/// The impls of the traits the user wants implemented for the given type. See
+/// `Builder::add_impl_for`.
+fn user_trait_impls(ctx: &BindgenContext,
+                    item: &Item,
+                    template_params: &[ItemId])
+                    -> Vec<P<ast::Item>> {
+    let traits = ctx.traits_to_impl(&item.cached_canonical_path(ctx));
+    if traits.is_empty() {
+        return vec![];
+    }
+
+    let generics = utils::template_generics(ctx, template_params);
+    let ty_for_impl =
+        utils::ty_for_impl(ctx, &item.canonical_name(ctx), template_params);
+    traits.iter()
+        .map(|trait_| {
+            let mut builder = aster::AstBuilder::new().item().impl_().trait_();
+            if trait_.starts_with("::") {
+                builder = builder.global();
+            }
+            builder.ids(trait_.split("::").filter(|id| !id.is_empty()))
+                .build()
+                .with_generics(generics.clone())
+                .build_ty(ty_for_impl.clone())
+        })
+        .collect()
+}
+
 struct CodegenResult<'a> {
     items: Vec<P<ast::Item>>,
 
@@ -95,10 +397,13 @@ struct CodegenResult<'a> {
     /// Whether an incomplete array has been generated at least once.
     saw_incomplete_array: bool,
 
+    /// Whether a bitfield allocation unit has been generated at least once.
+    saw_bitfield_unit: bool,
+
     /// Whether Objective C types have been seen at least once.
     saw_objc: bool,
 
-    items_seen: HashSet<ItemId>,
+    items_seen: ItemIdSet,
     /// The set of generated function/var names, needed because in C/C++ is
     /// legal to do something like:
     ///
@@ -130,6 +435,7 @@ impl<'a> CodegenResult<'a> {
             items: vec![],
             saw_union: false,
             saw_incomplete_array: false,
+            saw_bitfield_unit: false,
             saw_objc: false,
             codegen_id: codegen_id,
             items_seen: Default::default(),
@@ -147,6 +453,10 @@ impl<'a> CodegenResult<'a> {
         self.saw_incomplete_array = true;
     }
 
+    fn saw_bitfield_unit(&mut self) {
+        self.saw_bitfield_unit = true;
+    }
+
     fn saw_objc(&mut self) {
         self.saw_objc = true;
     }
@@ -195,6 +505,7 @@ impl<'a> CodegenResult<'a> {
 
         self.saw_union |= new.saw_union;
         self.saw_incomplete_array |= new.saw_incomplete_array;
+        self.saw_bitfield_unit |= new.saw_bitfield_unit;
         self.saw_objc |= new.saw_objc;
 
         new.items
@@ -217,6 +528,7 @@ impl<'a> ops::DerefMut for CodegenResult<'a> {
 
 struct ForeignModBuilder {
     inner: ast::ForeignMod,
+    attrs: Vec<ast::Attribute>,
 }
 
 impl ForeignModBuilder {
@@ -226,9 +538,17 @@ impl ForeignModBuilder {
                 abi: abi,
                 items: vec![],
             },
+            attrs: vec![],
         }
     }
 
+    fn with_attrs<I>(mut self, attrs: I) -> Self
+        where I: IntoIterator<Item = ast::Attribute>,
+    {
+        self.attrs.extend(attrs.into_iter());
+        self
+    }
+
     fn with_foreign_item(mut self, item: ast::ForeignItem) -> Self {
         self.inner.items.push(item);
         self
@@ -249,7 +569,7 @@ impl ForeignModBuilder {
             id: ast::DUMMY_NODE_ID,
             node: ast::ItemKind::ForeignMod(self.inner),
             vis: ast::Visibility::Public,
-            attrs: vec![],
+            attrs: self.attrs,
             span: DUMMY_SP,
         })
     }
@@ -289,7 +609,7 @@ trait CodeGenerator {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   whitelisted_items: &ItemSet,
+                   whitelisted_items: &ItemIdSet,
                    extra: &Self::Extra);
 }
 
@@ -299,7 +619,7 @@ impl CodeGenerator for Item {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   whitelisted_items: &ItemSet,
+                   whitelisted_items: &ItemIdSet,
                    _extra: &()) {
         if self.is_hidden(ctx) || result.seen(self.id()) {
             debug!("<Item as CodeGenerator>::codegen: Ignoring hidden or seen: \
@@ -313,11 +633,14 @@ impl CodeGenerator for Item {
             // TODO(emilio, #453): Figure out what to do when this happens
             // legitimately, we could track the opaque stuff and disable the
             // assertion there I guess.
-            error!("Found non-whitelisted item in code generation: {:?}", self);
+            error!("{}: Found non-whitelisted item in code generation: {:?}",
+                   self.location_for_diagnostics(),
+                   self);
         }
 
         result.set_seen(self.id());
 
+        let start = result.len();
         match *self.kind() {
             ItemKind::Module(ref module) => {
                 module.codegen(ctx, result, whitelisted_items, self);
@@ -338,29 +661,243 @@ impl CodeGenerator for Item {
                 }
             }
         }
+
+        if !self.is_module() {
+            if self.is_marked_hidden(ctx) {
+                hide_from_docs(result, start);
+            }
+            wrap_in_cfg(ctx, self, result, start);
+        }
+    }
+}
+
+/// Mark the public items generated for a hidden item, starting at `start`, as
+/// `#[doc(hidden)]`. See `Builder::emit_hidden_items`.
+///
+/// They stay public, since the public items that aren't hidden may use them.
+fn hide_from_docs(result: &mut CodegenResult, start: usize) {
+    for generated in &mut result[start..] {
+        let new = generated.clone().map(|mut generated| {
+            if let ast::ItemKind::ForeignMod(ref mut foreign) =
+                generated.node {
+                for item in &mut foreign.items {
+                    if item.vis == ast::Visibility::Public {
+                        item.attrs.push(attributes::doc_hidden());
+                    }
+                }
+            } else if generated.vis == ast::Visibility::Public {
+                generated.attrs.push(attributes::doc_hidden());
+            }
+            generated
+        });
+        *generated = new;
     }
 }
 
+/// Add the `#[cfg]` attribute the item needs, if any, to the items generated
+/// for it, starting at `start`. See `Builder::wrap_matching_items_in_cfg`.
+///
+/// Items generated along the way for other items already got their own
+/// attribute, if they needed one, so those are left alone.
+fn wrap_in_cfg(ctx: &BindgenContext,
+               item: &Item,
+               result: &mut CodegenResult,
+               start: usize) {
+    let predicate = match ctx.item_cfg(item.id()) {
+        Some(predicate) => predicate,
+        None => return,
+    };
+
+    let meta = parse::parse_meta_from_source_str("<cfg>".into(),
+                                                 format!("cfg({})",
+                                                         predicate),
+                                                 ctx.ext_cx().parse_sess());
+    let meta = match meta {
+        Ok(meta) => meta,
+        Err(mut err) => {
+            err.cancel();
+            ctx.diagnostic(DiagnosticSeverity::Error,
+                           format!("{}: Invalid cfg predicate `{}`, not \
+                                     wrapping `{}` in it",
+                                    item.location_for_diagnostics(),
+                                    predicate,
+                                    item.canonical_name(ctx)));
+            return;
+        }
+    };
+
+    let attr = aster::AstBuilder::new().attr().build_meta_item(meta);
+    for generated in &mut result[start..] {
+        if generated.attrs.iter().any(|a| a.check_name("cfg")) {
+            continue;
+        }
+        let new = generated.clone().map(|mut generated| {
+            generated.attrs.push(attr.clone());
+            generated
+        });
+        *generated = new;
+    }
+}
+
+/// The children of `module` in the order we generate them.
+///
+/// The order in which libclang visits functions varies between versions, so
+/// the functions are sorted by their name without the overload suffix, and the
+/// overloads of a name by their declaration order, which is what the suffix
+/// is assigned from: `f`, `f1`, ..., `f10`, not `f`, `f1`, `f10`, `f2`. The
+/// rest of the items keep their place.
+fn children_in_emission_order(ctx: &BindgenContext,
+                              module: &Module)
+                              -> Vec<ItemId> {
+    let is_function = |id: &ItemId| ctx.resolve_item(*id).kind().is_function();
+
+    let mut overload_counts = HashMap::new();
+    let mut functions: Vec<(Name, usize, ItemId)> = module.children()
+        .iter()
+        .cloned()
+        .filter(&is_function)
+        .map(|id| {
+            let name = ctx.resolve_item(id).canonical_name(ctx);
+            let count = overload_counts.entry(name.clone()).or_insert(0);
+            let overload_index = *count;
+            *count += 1;
+            (name, overload_index, id)
+        })
+        .collect();
+    functions.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+
+    let mut functions = functions.into_iter().map(|(_, _, id)| id);
+    module.children()
+        .iter()
+        .map(|id| if is_function(id) {
+            functions.next().unwrap()
+        } else {
+            *id
+        })
+        .collect()
+}
+
+/// Move the items generated for `item`, starting at `start`, to the module of
+/// the header that declared it, if it goes to one. See
+/// `Builder::module_per_header`.
+fn move_to_header_module(ctx: &BindgenContext,
+                         item: &Item,
+                         result: &mut CodegenResult,
+                         start: usize,
+                         header_modules: &mut Vec<(String,
+                                                   Vec<P<ast::Item>>)>) {
+    let name = match ctx.header_module(item) {
+        Some(name) => name,
+        None => return,
+    };
+
+    let items = result.split_off(start);
+    if items.is_empty() {
+        return;
+    }
+
+    match header_modules.iter().position(|&(ref n, _)| n == name) {
+        Some(i) => header_modules[i].1.extend(items),
+        None => header_modules.push((name.to_owned(), items)),
+    }
+}
+
+/// Build the module holding the items of a header.
+fn header_module(ctx: &BindgenContext,
+                 name: &str,
+                 mut items: Vec<P<ast::Item>>)
+                 -> P<ast::Item> {
+    // Header modules are always right inside of the root module.
+    if ctx.options().enable_cxx_namespaces && uses_root(ctx, &items) {
+        let root = ctx.root_module().canonical_name(ctx);
+        let use_root = aster::AstBuilder::new()
+            .item()
+            .use_()
+            .ids(&["self", "super", "super", &*root])
+            .build()
+            .build();
+        items.insert(0,
+                     quote_item!(ctx.ext_cx(),
+                                 #[allow(unused_imports)] $use_root)
+                         .unwrap());
+    }
+
+    let module = ast::ItemKind::Mod(ast::Mod {
+        inner: ctx.span(),
+        items: items,
+    });
+    aster::AstBuilder::new()
+        .item()
+        .pub_()
+        .build_item_kind(name, module)
+}
+
 impl CodeGenerator for Module {
     type Extra = Item;
 
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   whitelisted_items: &ItemSet,
+                   whitelisted_items: &ItemIdSet,
                    item: &Item) {
         debug!("<Module as CodeGenerator>::codegen: item = {:?}", item);
 
         let codegen_self = |result: &mut CodegenResult,
                             found_any: &mut bool| {
-            for child in self.children() {
-                if whitelisted_items.contains(child) {
+            let mut header_modules = vec![];
+            for child in children_in_emission_order(ctx, self) {
+                if whitelisted_items.contains(&child) {
                     *found_any = true;
-                    ctx.resolve_item(*child)
-                        .codegen(ctx, result, whitelisted_items, &());
+                    let start = result.len();
+                    let child = ctx.resolve_item(child);
+                    child.codegen(ctx, result, whitelisted_items, &());
+                    move_to_header_module(ctx,
+                                          child,
+                                          result,
+                                          start,
+                                          &mut header_modules);
+                }
+            }
+
+            // The types we only need to forward declare go to the closest
+            // module, since their parent may not be generated at all.
+            for id in ctx.forward_declared_types() {
+                let module = id.ancestors(ctx)
+                    .skip(1)
+                    .find(|&ancestor| ctx.resolve_item(ancestor).is_module())
+                    .unwrap_or(ctx.root_module());
+                if module != item.id() || result.seen(id) {
+                    continue;
                 }
+
+                *found_any = true;
+                result.set_seen(id);
+                let start = result.len();
+                let forward_declared = ctx.resolve_item(id);
+                result.push(forward_declaration(ctx, forward_declared));
+                result.extend(thread_safety_impls(ctx, forward_declared, &[]));
+                result.extend(user_trait_impls(ctx, forward_declared, &[]));
+                wrap_in_cfg(ctx, forward_declared, result, start);
+                move_to_header_module(ctx,
+                                      forward_declared,
+                                      result,
+                                      start,
+                                      &mut header_modules);
             }
 
+            let mut reexports = vec![];
+            for (name, items) in header_modules {
+                reexports.push(aster::AstBuilder::new()
+                    .item()
+                    .pub_()
+                    .use_()
+                    .ids(&["self", &*name])
+                    .build()
+                    .glob());
+                result.push(header_module(ctx, &name, items));
+            }
+            result.extend(reexports);
+
             if item.id() == ctx.root_module() {
                 if result.saw_union && !ctx.options().unstable_rust {
                     utils::prepend_union_types(ctx, &mut *result);
@@ -371,6 +908,17 @@ impl CodeGenerator for Module {
                 if ctx.need_bindegen_complex_type() {
                     utils::prepend_complex_type(ctx, &mut *result);
                 }
+                if ctx.need_c_void_stub() {
+                    utils::prepend_c_void_stub(ctx, &mut *result);
+                }
+                if ctx.options().stl_blobs {
+                    utils::prepend_stl_blobs(ctx,
+                                             whitelisted_items,
+                                             &mut *result);
+                }
+                if result.saw_bitfield_unit {
+                    utils::prepend_bitfield_unit_type(ctx, &mut *result);
+                }
                 if result.saw_objc {
                     utils::prepend_objc_header(ctx, &mut *result);
                 }
@@ -378,14 +926,13 @@ impl CodeGenerator for Module {
         };
 
         if !ctx.options().enable_cxx_namespaces ||
-           (self.is_inline() && !ctx.options().conservative_inline_namespaces) {
+           item.is_inlined_namespace(ctx) {
             codegen_self(result, &mut false);
             return;
         }
 
         let mut found_any = false;
-        let inner_items = result.inner(|result| {
-            result.push(root_import(ctx, item));
+        let mut inner_items = result.inner(|result| {
             codegen_self(result, &mut found_any);
         });
 
@@ -394,16 +941,21 @@ impl CodeGenerator for Module {
             return;
         }
 
+        if uses_root(ctx, &inner_items) {
+            inner_items.insert(0, root_import(ctx, item));
+        }
+
         let module = ast::ItemKind::Mod(ast::Mod {
             inner: ctx.span(),
             items: inner_items,
         });
 
+        let is_root = item.id() == ctx.root_module();
         let name = item.canonical_name(ctx);
         let item_builder = aster::AstBuilder::new()
             .item()
             .pub_();
-        let item = if name == "root" {
+        let item = if is_root && !ctx.options().allow_lints_per_item {
             let attrs = &["non_snake_case",
                 "non_camel_case_types",
                 "non_upper_case_globals"];
@@ -413,7 +965,14 @@ impl CodeGenerator for Module {
             item_builder.build_item_kind(name, module)
         };
 
+        let flat_aliases = if is_root && ctx.options().emit_flat_aliases {
+            utils::flat_aliases(ctx, &item)
+        } else {
+            vec![]
+        };
+
         result.push(item);
+        result.extend(flat_aliases.into_iter());
     }
 }
 
@@ -422,9 +981,8 @@ impl CodeGenerator for Var {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   _whitelisted_items: &ItemSet,
+                   _whitelisted_items: &ItemIdSet,
                    item: &Item) {
-        use ir::var::VarType;
         debug!("<Var as CodeGenerator>::codegen: item = {:?}", item);
 
         let canonical_name = item.canonical_name(ctx);
@@ -434,78 +992,292 @@ impl CodeGenerator for Var {
         }
         result.saw_var(&canonical_name);
 
+        match var_constant(ctx, self) {
+            Some((ty, expr)) => {
+                let item = aster::AstBuilder::new()
+                    .item()
+                    .pub_()
+                    .const_(canonical_name)
+                    .expr()
+                    .build(expr)
+                    .build(ty);
+                result.push(item);
+                return;
+            }
+            // There's nothing to link against for the values we can't
+            // represent if they don't come from an actual symbol, like
+            // macros.
+            None if self.val().is_some() &&
+                    self.mangled_name().is_none() => return,
+            None => {}
+        }
+
+        let mut attrs = vec![];
+        if let Some(note) = self.visibility().doc_note() {
+            attrs.push(attributes::doc(note));
+        }
+        if self.is_thread_local() {
+            // Going through a plain static would access the variable of
+            // whichever thread initialized the bindings, if at all.
+            if !ctx.options().thread_local_statics {
+                let message = format!("{}: Skipping the thread-local \
+                                       variable `{}`, since it can only be \
+                                       accessed correctly with the unstable \
+                                       `#[thread_local]` attribute",
+                                      item.location_for_diagnostics(),
+                                      canonical_name);
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                return;
+            }
+            attrs.push(attributes::thread_local());
+        }
+        if let Some(mangled) = self.mangled_name() {
+            attrs.push(attributes::link_name(mangled));
+        } else if canonical_name != self.name() {
+            attrs.push(attributes::link_name(self.name()));
+        }
+
         let ty = self.ty().to_rust_ty_or_opaque(ctx, &());
+        let item = ast::ForeignItem {
+            ident: ctx.rust_ident_raw(&canonical_name),
+            attrs: attrs,
+            node: ast::ForeignItemKind::Static(ty, !self.is_const()),
+            id: ast::DUMMY_NODE_ID,
+            span: ctx.span(),
+            vis: ast::Visibility::Public,
+        };
 
-        if let Some(val) = self.val() {
-            let const_item = aster::AstBuilder::new()
-                .item()
-                .pub_()
-                .const_(canonical_name)
-                .expr();
-            let item = match *val {
-                VarType::Bool(val) => {
-                    const_item.build(helpers::ast_ty::bool_expr(val)).build(ty)
-                }
-                VarType::Int(val) => {
-                    const_item.build(helpers::ast_ty::int_expr(val)).build(ty)
-                }
-                VarType::String(ref bytes) => {
-                    // Account the trailing zero.
-                    //
-                    // TODO: Here we ignore the type we just made up, probably
-                    // we should refactor how the variable type and ty id work.
-                    let len = bytes.len() + 1;
-                    let ty = quote_ty!(ctx.ext_cx(), [u8; $len]);
-
-                    match String::from_utf8(bytes.clone()) {
-                        Ok(string) => {
-                            const_item.build(helpers::ast_ty::cstr_expr(string))
-                                .build(quote_ty!(ctx.ext_cx(), &'static $ty))
-                        }
-                        Err(..) => {
-                            const_item
-                                .build(helpers::ast_ty::byte_array_expr(bytes))
-                                .build(ty)
-                        }
+        let item = ForeignModBuilder::new(Abi::C)
+            .with_attrs(dylib_link(ctx, self.dll_storage()))
+            .with_foreign_item(item)
+            .build(ctx);
+        result.push(item);
+
+        if ctx.options().c_string_constants && !self.is_thread_local() {
+            let accessor = c_string_accessor(ctx, self, &canonical_name);
+            result.extend(accessor);
+        }
+    }
+}
+
+/// Build the safe accessor of the given `static`, if it's a C string, a
+/// pointer to one, or an array of pointers to them. See
+/// `Builder::c_string_constants`.
+///
+/// Only constant statics get one: C could change the others, or free what
+/// they point to, while Rust holds the `'static` reference, and a mutable
+/// buffer isn't necessarily NUL-terminated.
+fn c_string_accessor(ctx: &BindgenContext,
+                     var: &Var,
+                     name: &str)
+                     -> Option<P<ast::Item>> {
+    fn is_char(ctx: &BindgenContext, id: ItemId) -> bool {
+        match *ctx.resolve_type(id).canonical_type(ctx).kind() {
+            TypeKind::Int(IntKind::Char) |
+            TypeKind::Int(IntKind::UChar) => true,
+            _ => false,
+        }
+    }
+
+    fn is_char_pointer(ctx: &BindgenContext, id: ItemId) -> bool {
+        match *ctx.resolve_type(id).canonical_type(ctx).kind() {
+            TypeKind::Pointer(pointee) => is_char(ctx, pointee),
+            _ => false,
+        }
+    }
+
+    // There's no `CStr` in `core`.
+    if ctx.options().use_core {
+        return None;
+    }
+
+    // The array itself isn't const qualified in `const char foo[]`, its
+    // elements are.
+    let is_const = |id: ItemId| {
+        var.is_const() || ctx.resolve_type(id).is_const()
+    };
+
+    let static_ = ctx.rust_ident_raw(name);
+    let c_char = helpers::ast_ty::raw_type(ctx, "c_char");
+    let (suffix, ty, body) =
+        match *ctx.resolve_type(var.ty()).canonical_type(ctx).kind() {
+            TypeKind::Array(elem, _) if is_char(ctx, elem) &&
+                                        is_const(elem) => {
+                let ty = quote_ty!(ctx.ext_cx(), &'static ::std::ffi::CStr);
+                let body = quote_expr!(ctx.ext_cx(), unsafe {
+                    ::std::ffi::CStr::from_ptr($static_.as_ptr() as
+                                               *const $c_char)
+                });
+                ("cstr", ty, body)
+            }
+            TypeKind::Pointer(pointee) if is_char(ctx, pointee) &&
+                                          is_const(var.ty()) => {
+                let ty = quote_ty!(ctx.ext_cx(),
+                                   Option<&'static ::std::ffi::CStr>);
+                let body = quote_expr!(ctx.ext_cx(), unsafe {
+                    if $static_.is_null() {
+                        None
+                    } else {
+                        Some(::std::ffi::CStr::from_ptr($static_ as
+                                                        *const $c_char))
                     }
+                });
+                ("cstr", ty, body)
+            }
+            TypeKind::Array(elem, _) if is_char_pointer(ctx, elem) &&
+                                        is_const(elem) => {
+                let array = var.ty().to_rust_ty_or_opaque(ctx, &());
+                let ty = quote_ty!(ctx.ext_cx(), &'static $array);
+                let body = quote_expr!(ctx.ext_cx(), unsafe { &$static_ });
+                ("array", ty, body)
+            }
+            _ => return None,
+        };
+
+    // Statics and functions share a namespace, so the accessor can't be
+    // named like the static.
+    let accessor = ctx.rust_ident_raw(&format!("{}_{}", name, suffix));
+    quote_item!(ctx.ext_cx(),
+        #[inline]
+        pub fn $accessor() -> $ty {
+            $body
+        }
+    )
+}
+
+/// Get the Rust type and value of the constant to generate for the given
+/// variable, if it has a value we know how to represent.
+fn var_constant(ctx: &BindgenContext,
+                var: &Var)
+                -> Option<(P<ast::Ty>, P<ast::Expr>)> {
+    use ir::var::VarType;
+
+    let ty = var.ty().to_rust_ty_or_opaque(ctx, &());
+    let val = match var.val() {
+        Some(val) => val,
+        None => return None,
+    };
+    let canonical_kind = ctx.resolve_type(var.ty())
+        .safe_canonical_type(ctx)
+        .map(|ty| ty.kind());
+
+    Some(match *val {
+        VarType::Bool(val) => {
+            let expr = match ctx.options().c_bool_type {
+                CBoolType::Bool => helpers::ast_ty::bool_expr(val),
+                CBoolType::U8 => helpers::ast_ty::int_expr(val as i64),
+            };
+            (ty, expr)
+        }
+        VarType::Int(val) => {
+            match canonical_enum(ctx, var.ty()) {
+                Some((item, enum_)) => {
+                    let expr = match enum_value_expr(ctx, item, enum_, val) {
+                        Some(expr) => expr,
+                        None => return None,
+                    };
+                    (ty, expr)
                 }
-                VarType::Float(f) => {
-                    match helpers::ast_ty::float_expr(ctx, f) {
-                        Ok(expr) => {
-                            const_item.build(expr).build(ty)
-                        }
-                        Err(..) => return,
-                    }
+                None => {
+                    // Values in the upper half of the `u64` range come back
+                    // as negative numbers.
+                    let is_unsigned = match canonical_kind {
+                        Some(&TypeKind::Int(kind)) => !kind.is_signed(),
+                        _ => false,
+                    };
+                    let expr = if is_unsigned && val < 0 {
+                        aster::AstBuilder::new().expr().uint(val as u64)
+                    } else {
+                        helpers::ast_ty::int_expr(val)
+                    };
+                    (ty, expr)
                 }
-                VarType::Char(c) => {
-                    const_item
-                        .build(aster::AstBuilder::new().expr().lit().byte(c))
-                        .build(ty)
+            }
+        }
+        VarType::String(ref bytes) => {
+            // Account the trailing zero.
+            //
+            // TODO: Here we ignore the type we just made up, probably
+            // we should refactor how the variable type and ty id work.
+            let len = bytes.len() + 1;
+            let ty = quote_ty!(ctx.ext_cx(), [u8; $len]);
+
+            match String::from_utf8(bytes.clone()) {
+                Ok(string) => {
+                    (quote_ty!(ctx.ext_cx(), &'static $ty),
+                     helpers::ast_ty::cstr_expr(string))
                 }
+                Err(..) => (ty, helpers::ast_ty::byte_array_expr(bytes)),
+            }
+        }
+        VarType::Float(f) => {
+            let is_f32 = match canonical_kind {
+                Some(&TypeKind::Float(FloatKind::Float)) => true,
+                _ => false,
             };
-
-            result.push(item);
-        } else {
-            let mut attrs = vec![];
-            if let Some(mangled) = self.mangled_name() {
-                attrs.push(attributes::link_name(mangled));
-            } else if canonical_name != self.name() {
-                attrs.push(attributes::link_name(self.name()));
+            match helpers::ast_ty::float_expr(ctx, f, is_f32) {
+                Ok(expr) => (ty, expr),
+                Err(..) => return None,
             }
+        }
+        VarType::Char(c) => {
+            (ty, aster::AstBuilder::new().expr().lit().byte(c))
+        }
+    })
+}
 
-            let item = ast::ForeignItem {
-                ident: ctx.rust_ident_raw(&canonical_name),
-                attrs: attrs,
-                node: ast::ForeignItemKind::Static(ty, !self.is_const()),
-                id: ast::DUMMY_NODE_ID,
-                span: ctx.span(),
-                vis: ast::Visibility::Public,
-            };
+/// Look through the aliases of the given type, and return the enum it
+/// resolves to, if any.
+fn canonical_enum<'a>(ctx: &'a BindgenContext,
+                      mut id: ItemId)
+                      -> Option<(&'a Item, &'a Enum)> {
+    loop {
+        let item = ctx.resolve_item(id);
+        let ty = match item.kind().as_type() {
+            Some(ty) => ty,
+            None => return None,
+        };
 
-            let item = ForeignModBuilder::new(Abi::C)
-                .with_foreign_item(item)
-                .build(ctx);
-            result.push(item);
+        match *ty.kind() {
+            TypeKind::Enum(ref enum_) => return Some((item, enum_)),
+            TypeKind::Alias(inner) |
+            TypeKind::ResolvedTypeRef(inner) => id = inner,
+            _ => return None,
+        }
+    }
+}
+
+/// Build the expression for the value `val` of the given enum, or `None` if
+/// it doesn't correspond to any of the variants of a Rust enum.
+fn enum_value_expr(ctx: &BindgenContext,
+                   item: &Item,
+                   enum_: &Enum,
+                   val: i64)
+                   -> Option<P<ast::Expr>> {
+    let path = item.namespace_aware_canonical_path(ctx);
+    match enum_style(ctx, enum_, item) {
+        EnumVariation::Consts |
+        EnumVariation::ModuleConsts => Some(helpers::ast_ty::int_expr(val)),
+        EnumVariation::Bitfield => {
+            let ctor = aster::AstBuilder::new().expr().path().ids(path).build();
+            let val = helpers::ast_ty::int_expr(val);
+            Some(quote_expr!(ctx.ext_cx(), $ctor($val)))
+        }
+        EnumVariation::Rust => {
+            let variant = enum_.variants().iter().find(|v| {
+                !v.hidden() &&
+                match v.val() {
+                    EnumVariantValue::Signed(v) => v == val,
+                    EnumVariantValue::Unsigned(v) => v as i64 == val,
+                }
+            });
+
+            variant.map(|variant| {
+                let mut path = path;
+                let name = ctx.rust_mangle(variant.name()).into_owned();
+                path.push(ctx.names().intern_string(name));
+                aster::AstBuilder::new().expr().path().ids(path).build()
+            })
         }
     }
 }
@@ -516,7 +1288,7 @@ impl CodeGenerator for Type {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   whitelisted_items: &ItemSet,
+                   whitelisted_items: &ItemIdSet,
                    item: &Item) {
         debug!("<Type as CodeGenerator>::codegen: item = {:?}", item);
 
@@ -527,6 +1299,7 @@ impl CodeGenerator for Type {
             TypeKind::Float(..) |
             TypeKind::Complex(..) |
             TypeKind::Array(..) |
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Pointer(..) |
             TypeKind::BlockPointer |
             TypeKind::Reference(..) |
@@ -570,6 +1343,14 @@ impl CodeGenerator for Type {
                 let inner_rust_type = if item.is_opaque(ctx) {
                     used_template_params = None;
                     self.to_opaque(ctx, item)
+                } else if let TypeKind::Function(ref sig) =
+                    *ctx.resolve_type_through_type_refs(inner).kind() {
+                    // This is a typedef of a function type, not of a pointer
+                    // to one, so there's nothing that could be null, and no
+                    // `Option` around it. Pointers to it add that.
+                    sig.try_to_rust_ty(ctx, &())
+                        .expect("function signature to Rust type conversion \
+                                 is infallible")
                 } else {
                     // Its possible that we have better layout information than
                     // the inner type does, so fall back to an opaque blob based
@@ -592,14 +1373,32 @@ impl CodeGenerator for Type {
                     let inner_canon_type = inner_item.expect_type()
                         .canonical_type(ctx);
                     if inner_canon_type.is_invalid_named_type() {
-                        warn!("Item contained invalid named type, skipping: \
-                              {:?}, {:?}",
+                        warn!("{}: Item contained invalid named type, \
+                              skipping: {:?}, {:?}",
+                              item.location_for_diagnostics(),
                               item,
                               inner_item);
                         return;
                     }
                 }
 
+                if ctx.is_new_type_alias(item.id()) {
+                    if used_template_params.is_none() {
+                        new_type_alias(ctx,
+                                       result,
+                                       item,
+                                       &name,
+                                       inner_rust_type);
+                        return;
+                    }
+                    let message = format!("{}: Can't generate the templated \
+                                           alias {} as a new type, generating \
+                                           a type alias instead",
+                                          item.location_for_diagnostics(),
+                                          name);
+                    ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                }
+
                 let rust_name = ctx.rust_ident(&name);
                 let mut typedef = aster::AstBuilder::new().item().pub_();
 
@@ -608,6 +1407,9 @@ impl CodeGenerator for Type {
                         typedef = typedef.attr().doc(comment);
                     }
                 }
+                if let Some(annotation) = source_annotation(ctx, item) {
+                    typedef = typedef.with_attr(annotation);
+                }
 
                 // We prefer using `pub use` over `pub type` because of:
                 // https://github.com/rust-lang/rust/issues/26264
@@ -627,7 +1429,16 @@ impl CodeGenerator for Type {
                 };
 
                 let typedef = if let Some(mut p) = simple_enum_path {
-                    for ident in top_level_path(ctx, item).into_iter().rev() {
+                    // Paths into header modules are already relative to this
+                    // module.
+                    let first = p.segments[0].identifier.name.as_str();
+                    let is_relative = &*first == "self";
+                    let prefix = if is_relative {
+                        vec![]
+                    } else {
+                        top_level_path(ctx, item)
+                    };
+                    for ident in prefix.into_iter().rev() {
                         p.segments.insert(0,
                                           ast::PathSegment {
                                               identifier: ident,
@@ -643,8 +1454,9 @@ impl CodeGenerator for Type {
                                 template_param.as_named(ctx, &()) {
                                 let template_param = ctx.resolve_type(id);
                                 if template_param.is_invalid_named_type() {
-                                    warn!("Item contained invalid template \
-                                           parameter: {:?}",
+                                    warn!("{}: Item contained invalid \
+                                           template parameter: {:?}",
+                                          item.location_for_diagnostics(),
                                           item);
                                     return;
                                 }
@@ -662,7 +1474,9 @@ impl CodeGenerator for Type {
                 ei.codegen(ctx, result, whitelisted_items, item)
             }
             TypeKind::ObjCId | TypeKind::ObjCSel => {
-                result.saw_objc();
+                if ctx.options().enable_objc {
+                    result.saw_objc();
+                }
             }
             TypeKind::ObjCInterface(ref interface) => {
                 interface.codegen(ctx, result, whitelisted_items, item)
@@ -674,6 +1488,117 @@ impl CodeGenerator for Type {
     }
 }
 
+/// Generate the alias `item` as a new type wrapping `inner`, instead of a type
+/// alias. See `Builder::new_type_alias`.
+fn new_type_alias(ctx: &BindgenContext,
+                  result: &mut CodegenResult,
+                  item: &Item,
+                  name: &str,
+                  inner: P<ast::Ty>) {
+    let mut attributes = vec![];
+    if ctx.options().generate_comments {
+        if let Some(comment) = item.comment() {
+            attributes.push(attributes::doc(comment));
+        }
+    }
+    attributes.extend(source_annotation(ctx, item));
+
+    attributes.push(attributes::repr("transparent"));
+
+    let mut derives = vec![];
+    if item.can_derive_debug(ctx, ()) {
+        derives.push("Debug");
+    }
+
+    if item.can_derive_default(ctx, ()) {
+        derives.push("Default");
+    }
+
+    let is_copy = item.can_derive_copy(ctx, ());
+    if is_copy {
+        derives.push("Copy");
+    }
+
+    if !derives.is_empty() {
+        attributes.push(attributes::derives(&derives));
+    }
+
+    let rust_name = ctx.rust_ident(name);
+    let new_type = quote_item!(ctx.ext_cx(),
+        pub struct $rust_name(pub $inner);
+    )
+        .unwrap()
+        .map(|mut new_type| {
+            new_type.attrs = attributes;
+            new_type
+        });
+    result.push(new_type);
+
+    if is_copy {
+        // Like for structs, deriving `Clone` doesn't work for big arrays.
+        let clone_impl = quote_item!(ctx.ext_cx(),
+            impl Clone for $rust_name {
+                fn clone(&self) -> Self { *self }
+            }
+        )
+            .unwrap();
+        result.push(clone_impl);
+    }
+
+    if ctx.new_type_alias_derefs(item.id()) {
+        let prefix = ctx.trait_prefix();
+        let deref_impl = quote_item!(ctx.ext_cx(),
+            impl ::$prefix::ops::Deref for $rust_name {
+                type Target = $inner;
+                #[inline]
+                fn deref(&self) -> &$inner {
+                    &self.0
+                }
+            }
+        )
+            .unwrap();
+        result.push(deref_impl);
+
+        let deref_mut_impl = quote_item!(ctx.ext_cx(),
+            impl ::$prefix::ops::DerefMut for $rust_name {
+                #[inline]
+                fn deref_mut(&mut self) -> &mut $inner {
+                    &mut self.0
+                }
+            }
+        )
+            .unwrap();
+        result.push(deref_mut_impl);
+    }
+
+    // The new type is a transparent wrapper of `inner`, so converting is
+    // always fine.
+    if ctx.options().newtype_conversions {
+        let prefix = ctx.trait_prefix();
+        let from_inner_impl = quote_item!(ctx.ext_cx(),
+            impl ::$prefix::convert::From<$inner> for $rust_name {
+                #[inline]
+                fn from(inner: $inner) -> Self {
+                    $rust_name(inner)
+                }
+            }
+        )
+            .unwrap();
+        result.push(from_inner_impl);
+
+        let into_inner_impl = quote_item!(ctx.ext_cx(),
+            impl ::$prefix::convert::From<$rust_name> for $inner {
+                #[inline]
+                fn from(new_type: $rust_name) -> Self {
+                    new_type.0
+                }
+            }
+        )
+            .unwrap();
+        result.push(into_inner_impl);
+    }
+}
+
 struct Vtable<'a> {
     item_id: ItemId,
     #[allow(dead_code)]
@@ -701,7 +1626,7 @@ impl<'a> CodeGenerator for Vtable<'a> {
     fn codegen<'b>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'b>,
-                   _whitelisted_items: &ItemSet,
+                   _whitelisted_items: &ItemIdSet,
                    item: &Item) {
         assert_eq!(item.id(), self.item_id);
         // For now, generate an empty struct, later we should generate function
@@ -723,8 +1648,9 @@ impl<'a> CodeGenerator for Vtable<'a> {
 }
 
 impl<'a> ItemCanonicalName for Vtable<'a> {
-    fn canonical_name(&self, ctx: &BindgenContext) -> String {
-        format!("{}__bindgen_vtable", self.item_id.canonical_name(ctx))
+    fn canonical_name(&self, ctx: &BindgenContext) -> Name {
+        ctx.names().intern_string(format!("{}__bindgen_vtable",
+                                          self.item_id.canonical_name(ctx)))
     }
 }
 
@@ -754,6 +1680,7 @@ impl<'a> Bitfield<'a> {
     fn codegen_fields(self,
                       ctx: &BindgenContext,
                       parent: &CompInfo,
+                      result: &mut CodegenResult,
                       fields: &mut Vec<ast::StructField>,
                       methods: &mut Vec<ast::ImplItem>)
                       -> Layout {
@@ -763,9 +1690,13 @@ impl<'a> Bitfield<'a> {
         // FIXME(emilio): There are some differences between Microsoft and the
         // Itanium ABI, but we'll ignore those and stick to Itanium for now.
         //
-        // Also, we need to handle packed bitfields and stuff.
-        // TODO(emilio): Take into account C++'s wide bitfields, and
-        // packing, sigh.
+        // TODO(emilio): Take into account C++'s wide bitfields, sigh.
+        //
+        // The bitfields of packed structs don't have allocation units at all:
+        // they're laid out one right after the other, straddling the
+        // boundaries of their types if needed, so they all go in a single
+        // byte-aligned unit.
+        let packed = parent.packed();
         let mut total_size_in_bits = 0;
         let mut max_align = 0;
         let mut unfilled_bits_in_last_unit = 0;
@@ -774,8 +1705,13 @@ impl<'a> Bitfield<'a> {
         let mut last_field_name = format!("_bitfield_{}", self.index);
         let mut last_field_align = 0;
 
-        // (name, mask, width, bitfield's type, bitfield's layout)
-        let mut bitfields: Vec<(&str, usize, usize, ast::Ty, Layout)> = vec![];
+        // (name, offset, width, bitfield's type, bitfield's layout, value)
+        let mut bitfields: Vec<(&str,
+                                usize,
+                                usize,
+                                ast::Ty,
+                                Layout,
+                                BitfieldValue)> = vec![];
 
         for field in self.fields {
             let width = field.bitfield().unwrap() as usize;
@@ -787,11 +1723,15 @@ impl<'a> Bitfield<'a> {
             let field_align = field_ty_layout.align;
 
             if field_size_in_bits != 0 &&
-               (width == 0 || width > unfilled_bits_in_last_unit) {
+               (width == 0 || (!packed && width > unfilled_bits_in_last_unit)) {
                 // We've finished a physical field, so flush it and its bitfields.
-                field_size_in_bits = align_to(field_size_in_bits, field_align);
+                if !packed {
+                    field_size_in_bits = align_to(field_size_in_bits,
+                                                  field_align);
+                }
                 fields.push(flush_bitfields(ctx,
                                             parent,
+                                            result,
                                             field_size_in_bits,
                                             last_field_align,
                                             &last_field_name,
@@ -808,13 +1748,17 @@ impl<'a> Bitfield<'a> {
                 last_field_align = 0;
             }
 
+            // Anonymous bitfields are only padding: they take up room in the
+            // unit below, but don't get any accessor.
             if let Some(name) = field.name() {
                 let field_item_ty = field_item.to_rust_ty_or_opaque(ctx, &());
+                let value = BitfieldValue::of(ctx, field_item);
                 bitfields.push((name,
                                 field_size_in_bits,
                                 width,
                                 field_item_ty.unwrap(),
-                                field_ty_layout));
+                                field_ty_layout,
+                                value));
             }
 
             field_size_in_bits += width;
@@ -834,6 +1778,7 @@ impl<'a> Bitfield<'a> {
             // Flush the last physical field and its bitfields.
             fields.push(flush_bitfields(ctx,
                                         parent,
+                                        result,
                                         field_size_in_bits,
                                         last_field_align,
                                         &last_field_name,
@@ -841,8 +1786,55 @@ impl<'a> Bitfield<'a> {
                                         methods));
         }
 
+        if packed {
+            max_align = 1;
+        }
         Layout::new(bytes_from_bits(total_size_in_bits), max_align)
     }
+
+    /// Like `codegen_fields`, but for the bitfields of a union. These all
+    /// start at the beginning of the union, so each of them gets a unit of
+    /// its own, as big as its type, which is also what the union is at least
+    /// as big as.
+    fn codegen_union_fields(self,
+                            ctx: &BindgenContext,
+                            parent: &CompInfo,
+                            result: &mut CodegenResult,
+                            fields: &mut Vec<ast::StructField>,
+                            methods: &mut Vec<ast::ImplItem>) {
+        for field in self.fields {
+            // Anonymous bitfields are only padding, which means nothing in a
+            // union.
+            let name = match field.name() {
+                Some(name) => name,
+                None => continue,
+            };
+            let width = field.bitfield().unwrap() as usize;
+            let field_item = ctx.resolve_item(field.ty());
+            let field_ty_layout = field_item.kind()
+                .expect_type()
+                .layout(ctx)
+                .expect("Bitfield without layout? Gah!");
+            let field_item_ty = field_item.to_rust_ty_or_opaque(ctx, &());
+            let value = BitfieldValue::of(ctx, field_item);
+
+            *self.index += 1;
+            let field_name = format!("_bitfield_{}", self.index);
+            fields.push(flush_bitfields(ctx,
+                                        parent,
+                                        result,
+                                        field_ty_layout.size * 8,
+                                        field_ty_layout.align * 8,
+                                        &field_name,
+                                        Some((name,
+                                              0,
+                                              width,
+                                              field_item_ty.unwrap(),
+                                              field_ty_layout,
+                                              value)),
+                                        methods));
+        }
+    }
 }
 
 fn parent_has_method(ctx: &BindgenContext,
@@ -891,87 +1883,231 @@ fn bitfield_setter_name(ctx: &BindgenContext,
     ctx.ext_cx().ident_of(&setter)
 }
 
+/// How the raw bits of a bitfield are turned into its value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BitfieldValue {
+    /// Zero-extended and transmuted into the bitfield's type.
+    Unsigned,
+    /// Sign-extended and transmuted into the bitfield's type.
+    Signed,
+    /// Compared against zero, since not every bit pattern is a valid `bool`.
+    Bool,
+}
+
+impl BitfieldValue {
+    fn of(ctx: &BindgenContext, ty: &Item) -> Self {
+        match *ty.expect_type().canonical_type(ctx).kind() {
+            TypeKind::Int(IntKind::Bool) if
+                ctx.options().c_bool_type == CBoolType::Bool => {
+                BitfieldValue::Bool
+            }
+            TypeKind::Int(ik) if ik.is_signed() => BitfieldValue::Signed,
+            _ => BitfieldValue::Unsigned,
+        }
+    }
+}
+
 /// A physical field (which is a word or byte or ...) has many logical bitfields
 /// contained within it, but not all bitfields are in the same physical field of
 /// a struct. This function creates a single physical field and flushes all the
 /// accessors for the logical `bitfields` within that physical field to the
 /// outgoing `methods`.
+///
+/// The physical field is a `__BindgenBitfieldUnit`, which does all the bit
+/// twiddling, so the accessors only need to deal with converting from and to
+/// the bitfield's type. In a union, reading the unit is unsafe, and so are the
+/// accessors.
 fn flush_bitfields<'a, I>(ctx: &BindgenContext,
                           parent: &CompInfo,
+                          result: &mut CodegenResult,
                           field_size_in_bits: usize,
                           field_align: usize,
                           field_name: &str,
                           bitfields: I,
                           methods: &mut Vec<ast::ImplItem>) -> ast::StructField
-    where I: IntoIterator<Item = (&'a str, usize, usize, ast::Ty, Layout)>
+    where I: IntoIterator<Item = (&'a str,
+                                  usize,
+                                  usize,
+                                  ast::Ty,
+                                  Layout,
+                                  BitfieldValue)>
 {
     use aster::struct_field::StructFieldBuilder;
 
-    let field_layout = Layout::new(bytes_from_bits_pow2(field_size_in_bits),
-                                   bytes_from_bits_pow2(field_align));
-    let field_ty = BlobTyBuilder::new(field_layout).build();
+    let field_layout = if parent.packed() {
+        Layout::new(bytes_from_bits(field_size_in_bits), 1)
+    } else {
+        Layout::new(bytes_from_bits_pow2(field_size_in_bits),
+                    bytes_from_bits_pow2(field_align))
+    };
+
+    let align_ty = match field_layout.align {
+        8 => quote_ty!(ctx.ext_cx(), u64),
+        4 => quote_ty!(ctx.ext_cx(), u32),
+        2 => quote_ty!(ctx.ext_cx(), u16),
+        1 => quote_ty!(ctx.ext_cx(), u8),
+        _ => panic!("physical field containing bitfields should be aligned \
+                     to 8, 4, 2, or 1 bytes")
+    };
+    let storage_ty = aster::AstBuilder::new()
+        .ty()
+        .array(field_layout.size)
+        .u8();
+
+    result.saw_bitfield_unit();
+    let field_ty = helper_type(ctx,
+                               "__BindgenBitfieldUnit",
+                               vec![storage_ty, align_ty]);
+
+    let is_union = parent.kind() == CompKind::Union;
+    let is_union_field = is_union && !ctx.options().unstable_rust;
+    let field_ty = if is_union_field {
+        helper_type(ctx, "__BindgenUnionField", vec![field_ty])
+    } else {
+        field_ty
+    };
 
     let field = StructFieldBuilder::named(field_name)
         .pub_()
-        .build_ty(field_ty.clone());
+        .build_ty(field_ty);
 
-    for (name, offset, width, bitfield_ty, bitfield_layout) in bitfields {
+    for (name, offset, width, bitfield_ty, bitfield_layout, value)
+            in bitfields {
         let prefix = ctx.trait_prefix();
         let getter_name = bitfield_getter_name(ctx, parent, name);
         let setter_name = bitfield_setter_name(ctx, parent, name);
         let field_ident = ctx.ext_cx().ident_of(field_name);
+        let bitfield_int_ty = BlobTyBuilder::new(bitfield_layout).build();
+
+        // `width` is at most 64, since the unit is at most 8 bytes.
+        let width = width as u8;
 
-        let field_int_ty = match field_layout.size {
-            8 => quote_ty!(ctx.ext_cx(), u64),
-            4 => quote_ty!(ctx.ext_cx(), u32),
-            2 => quote_ty!(ctx.ext_cx(), u16),
-            1 => quote_ty!(ctx.ext_cx(), u8),
-            _ => panic!("physical field containing bitfields should be sized \
-                         8, 4, 2, or 1 bytes")
+        // The unit of a wrapper union doesn't take any room itself.
+        let (unit, unit_mut) = if is_union_field {
+            (quote_expr!(ctx.ext_cx(), self.$field_ident.as_ref()),
+             quote_expr!(ctx.ext_cx(), self.$field_ident.as_mut()))
+        } else {
+            (quote_expr!(ctx.ext_cx(), self.$field_ident),
+             quote_expr!(ctx.ext_cx(), self.$field_ident))
         };
-        let bitfield_int_ty = BlobTyBuilder::new(bitfield_layout).build();
 
-        let mask: usize = ((1usize << width) - 1usize) << offset;
+        // The unit hands back the raw bits, so sign-extend them if needed.
+        let shift = 64 - width as usize;
+        let getter = match (is_union, value) {
+            (false, BitfieldValue::Bool) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width);
+                            val != 0
+                        }
+                    }
+                )
+            }
+            (false, BitfieldValue::Signed) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width) as i64;
+                            let val = (val << $shift) >> $shift;
+                            unsafe {
+                                ::$prefix::mem::transmute(
+                                    val as $bitfield_int_ty)
+                            }
+                        }
+                    }
+                )
+            }
+            (false, BitfieldValue::Unsigned) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width);
+                            unsafe {
+                                ::$prefix::mem::transmute(
+                                    val as $bitfield_int_ty)
+                            }
+                        }
+                    }
+                )
+            }
+            (true, BitfieldValue::Bool) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub unsafe fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width);
+                            val != 0
+                        }
+                    }
+                )
+            }
+            (true, BitfieldValue::Signed) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub unsafe fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width) as i64;
+                            let val = (val << $shift) >> $shift;
+                            ::$prefix::mem::transmute(val as $bitfield_int_ty)
+                        }
+                    }
+                )
+            }
+            (true, BitfieldValue::Unsigned) => {
+                quote_item!(
+                    ctx.ext_cx(),
+                    impl XxxIgnored {
+                        #[inline]
+                        pub unsafe fn $getter_name(&self) -> $bitfield_ty {
+                            let val = $unit.get($offset, $width);
+                            ::$prefix::mem::transmute(val as $bitfield_int_ty)
+                        }
+                    }
+                )
+            }
+        };
 
-        let impl_item = quote_item!(
-            ctx.ext_cx(),
-            impl XxxIgnored {
-                #[inline]
-                pub fn $getter_name(&self) -> $bitfield_ty {
-                    let mask = $mask as $field_int_ty;
-                    let field_val: $field_int_ty = unsafe {
-                        ::$prefix::mem::transmute(self.$field_ident)
-                    };
-                    let val = (field_val & mask) >> $offset;
-                    unsafe {
-                        ::$prefix::mem::transmute(val as $bitfield_int_ty)
+        let setter = if is_union {
+            quote_item!(
+                ctx.ext_cx(),
+                impl XxxIgnored {
+                    #[inline]
+                    pub unsafe fn $setter_name(&mut self, val: $bitfield_ty) {
+                        let val = val as $bitfield_int_ty as u64;
+                        $unit_mut.set($offset, $width, val)
                     }
                 }
-
-                #[inline]
-                pub fn $setter_name(&mut self, val: $bitfield_ty) {
-                    let mask = $mask as $field_int_ty;
-                    let val = val as $bitfield_int_ty as $field_int_ty;
-
-                    let mut field_val: $field_int_ty = unsafe {
-                        ::$prefix::mem::transmute(self.$field_ident)
-                    };
-                    field_val &= !mask;
-                    field_val |= (val << $offset) & mask;
-
-                    self.$field_ident = unsafe {
-                        ::$prefix::mem::transmute(field_val)
-                    };
+            )
+        } else {
+            quote_item!(
+                ctx.ext_cx(),
+                impl XxxIgnored {
+                    #[inline]
+                    pub fn $setter_name(&mut self, val: $bitfield_ty) {
+                        let val = val as $bitfield_int_ty as u64;
+                        $unit_mut.set($offset, $width, val)
+                    }
                 }
-            }
-        ).unwrap();
-
-        match impl_item.unwrap().node {
-            ast::ItemKind::Impl(_, _, _, _, _, items) => {
-                methods.extend(items.into_iter());
-            },
-            _ => unreachable!(),
+            )
         };
+
+        for impl_item in vec![getter, setter] {
+            match impl_item.unwrap().unwrap().node {
+                ast::ItemKind::Impl(_, _, _, _, _, items) => {
+                    methods.extend(items.into_iter());
+                },
+                _ => unreachable!(),
+            };
+        }
     }
 
     field
@@ -983,12 +2119,19 @@ impl CodeGenerator for TemplateInstantiation {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   _whitelisted_items: &ItemSet,
+                   _whitelisted_items: &ItemIdSet,
                    item: &Item) {
         // Although uses of instantiations don't need code generation, and are
         // just converted to rust types in fields, vars, etc, we take this
         // opportunity to generate tests for their layout here.
 
+        //
+        // Standard library blobs get their own layout tests alongside them.
+        if !ctx.options().layout_tests ||
+           utils::stl_blob_name(ctx, item).is_some() {
+            return;
+        }
+
         let layout = item.kind().expect_type().layout(ctx);
 
         if let Some(layout) = layout {
@@ -1017,7 +2160,11 @@ impl CodeGenerator for TemplateInstantiation {
                     assert_eq!($align_of_expr, $align,
                                concat!("Alignment of template specialization: ", stringify!($ident)));
                 })
-                .unwrap();
+                .unwrap()
+                .map(|mut item| {
+                    item.attrs.extend(layout_test_cfg(ctx));
+                    item
+                });
 
             result.push(item);
         }
@@ -1030,7 +2177,7 @@ impl CodeGenerator for CompInfo {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   whitelisted_items: &ItemSet,
+                   whitelisted_items: &ItemIdSet,
                    item: &Item) {
         use aster::struct_field::StructFieldBuilder;
 
@@ -1047,42 +2194,70 @@ impl CodeGenerator for CompInfo {
         // generate tuple struct if struct or union is a forward declaration,
         // skip for now if template parameters are needed.
         if self.is_forward_declaration() && used_template_params.is_none() {
-            let struct_name = item.canonical_name(ctx);
-            let struct_name = ctx.rust_ident_raw(&struct_name);
-            let tuple_struct = quote_item!(ctx.ext_cx(),
-                                           #[repr(C)]
-                                           #[derive(Debug, Copy, Clone)]
-                                           pub struct $struct_name([u8; 0]);
-                                          )
-                .unwrap();
-            result.push(tuple_struct);
+            result.push(forward_declaration(ctx, item));
+            result.extend(thread_safety_impls(ctx, item, &[]));
+            result.extend(user_trait_impls(ctx, item, &[]));
             return;
         }
 
         let mut attributes = vec![];
         let mut needs_clone_impl = false;
         let mut needs_default_impl = false;
+        let mut needs_debug_impl = false;
+        let mut needs_union_debug_impl = false;
         if ctx.options().generate_comments {
             if let Some(comment) = item.comment() {
                 attributes.push(attributes::doc(comment));
             }
         }
+        attributes.extend(source_annotation(ctx, item));
+
+        let is_union = self.kind() == CompKind::Union;
+
+        // There's no primitive aligned to more than 8 bytes to use as the
+        // storage of a union emitted as a struct, so align the struct itself.
+        let over_aligned_union = if is_union && !ctx.options().unstable_rust {
+            item.kind()
+                .expect_type()
+                .layout(ctx)
+                .and_then(|l| if l.align > 8 { Some(l.align) } else { None })
+        } else {
+            None
+        };
+
         if self.packed() {
             attributes.push(attributes::repr_list(&["C", "packed"]));
+        } else if let Some(align) = over_aligned_union {
+            attributes.push(attributes::repr_align(align));
         } else {
             attributes.push(attributes::repr("C"));
         }
 
-        let is_union = self.kind() == CompKind::Union;
         let mut derives = vec![];
-        if item.can_derive_debug(ctx, ()) {
-            derives.push("Debug");
+        if is_union && ctx.options().impl_debug_for_unions {
+            // Unions can't derive `Debug` in general, so we always implement
+            // it by hand for them, to get the same output in every case.
+            needs_union_debug_impl = item.can_derive_debug(ctx, ());
+        } else if item.can_derive_debug(ctx, ()) {
+            // `#[derive(Debug)]` would take references to the possibly
+            // unaligned fields of a packed struct, so we implement it by hand
+            // instead, copying the fields out first.
+            let packed = self.packed() && !is_union &&
+                         used_template_params.is_none();
+            if packed || self.needs_debug_impl_via_slices(ctx) {
+                needs_debug_impl = true;
+            } else {
+                derives.push("Debug");
+            }
         }
 
         if item.can_derive_default(ctx, ()) {
             derives.push("Default");
         } else {
-            needs_default_impl = ctx.options().derive_default;
+            // References are never null, so zeroing them isn't an option.
+            needs_default_impl = ctx.options().derive_default &&
+                                 ctx.options().impl_default_via_zeroed &&
+                                 !self.has_reference_fields(ctx);
         }
 
         if item.can_derive_copy(ctx, ()) &&
@@ -1152,6 +2327,14 @@ impl CodeGenerator for CompInfo {
             fields.push(vtable_field);
         }
 
+        // Where clang placed our first member, to detect the bases that got
+        // the empty base optimization.
+        let first_member_offset = self.fields()
+            .first()
+            .and_then(|field| field.offset());
+
+        // The bases we generate a field for, for the `Debug` implementation.
+        let mut base_field_names = vec![];
         for (i, base) in self.base_members().iter().enumerate() {
             // Virtual bases are already taken into account by the vtable
             // pointer.
@@ -1169,6 +2352,12 @@ impl CodeGenerator for CompInfo {
                 continue;
             }
 
+            // Nor the ones that share their storage with our first member.
+            if struct_layout.base_is_empty_optimized(base_ty,
+                                                     first_member_offset) {
+                continue;
+            }
+
             let inner = base.ty.to_rust_ty_or_opaque(ctx, &());
             let field_name = if i == 0 {
                 "_base".into()
@@ -1177,6 +2366,7 @@ impl CodeGenerator for CompInfo {
             };
 
             struct_layout.saw_base(base_ty);
+            base_field_names.push(field_name.clone());
 
             let field = StructFieldBuilder::named(field_name)
                 .pub_()
@@ -1192,6 +2382,10 @@ impl CodeGenerator for CompInfo {
         let mut current_bitfield_width = None;
         let mut current_bitfield_layout: Option<Layout> = None;
         let mut current_bitfield_fields = vec![];
+        // The offset the current bitfield batch must start at, if it follows
+        // a zero-width bitfield.
+        let mut current_bitfield_offset = None;
+        let mut saw_zero_width_bitfield = false;
         let mut bitfield_count = 0;
         let struct_fields = self.fields();
         let fields_should_be_private = item.annotations()
@@ -1201,6 +2395,14 @@ impl CodeGenerator for CompInfo {
             .accessor_kind()
             .unwrap_or(FieldAccessorKind::None);
 
+        // The accessors for the variants of a union emitted as a struct are
+        // named after them, so the ones for mutable references must not be
+        // named like any variant.
+        let mut variant_accessor_names: HashSet<String> = struct_fields.iter()
+            .filter_map(|field| field.name())
+            .map(|name| ctx.rust_mangle(name).into_owned())
+            .collect();
+
         let mut methods = vec![];
         let mut anonymous_field_count = 0;
         for field in struct_fields {
@@ -1211,6 +2413,16 @@ impl CodeGenerator for CompInfo {
 
             let field_ty = ctx.resolve_type(field.ty());
 
+            if is_union && field.bitfield().is_some() {
+                Bitfield::new(&mut bitfield_count, vec![field])
+                    .codegen_union_fields(ctx,
+                                          self,
+                                          result,
+                                          &mut fields,
+                                          &mut methods);
+                continue;
+            }
+
             // Try to catch a bitfield contination early.
             if let (Some(ref mut bitfield_width), Some(width)) =
                 (current_bitfield_width, field.bitfield()) {
@@ -1219,7 +2431,11 @@ impl CodeGenerator for CompInfo {
                        *bitfield_width,
                        width,
                        layout);
-                if *bitfield_width + width <= (layout.size * 8) as u32 {
+                // The bitfields of packed structs can straddle the units of
+                // their types, so only zero-width ones end a batch there.
+                if width != 0 &&
+                   (self.packed() ||
+                    *bitfield_width + width <= (layout.size * 8) as u32) {
                     *bitfield_width += width;
                     current_bitfield_fields.push(field);
                     continue;
@@ -1231,9 +2447,18 @@ impl CodeGenerator for CompInfo {
                 debug_assert!(!current_bitfield_fields.is_empty());
                 let bitfield_fields =
                     mem::replace(&mut current_bitfield_fields, vec![]);
+                if let Some(padding_field) =
+                    struct_layout.pad_bitfield_batch(
+                        current_bitfield_offset.take()) {
+                    fields.push(padding_field);
+                }
                 let bitfield_layout = Bitfield::new(&mut bitfield_count,
                                                     bitfield_fields)
-                    .codegen_fields(ctx, self, &mut fields, &mut methods);
+                    .codegen_fields(ctx,
+                                    self,
+                                    result,
+                                    &mut fields,
+                                    &mut methods);
                 struct_layout.saw_bitfield_batch(bitfield_layout);
 
                 current_bitfield_width = None;
@@ -1242,34 +2467,49 @@ impl CodeGenerator for CompInfo {
             debug_assert!(current_bitfield_fields.is_empty());
 
             if let Some(width) = field.bitfield() {
+                // A zero-width bitfield has no storage, it only forces the
+                // next bitfield to start at a new allocation unit. Clang has
+                // already computed where that is, so we pad up to it.
+                if width == 0 {
+                    saw_zero_width_bitfield = true;
+                    continue;
+                }
+
                 let layout = field_ty.layout(ctx)
                     .expect("Bitfield type without layout?");
+                if mem::replace(&mut saw_zero_width_bitfield, false) {
+                    current_bitfield_offset = field.offset();
+                }
                 current_bitfield_width = Some(width);
                 current_bitfield_layout = Some(layout);
                 current_bitfield_fields.push(field);
                 continue;
             }
 
-            let ty = field.ty().to_rust_ty_or_opaque(ctx, &());
+            // Regular fields are already padded to the offset clang gives us.
+            saw_zero_width_bitfield = false;
+
+            let ty = match *field_ty.canonical_type(ctx).kind() {
+                TypeKind::Reference(inner, _) if
+                    ctx.options().references_are_nonnull => {
+                    let inner = inner.to_rust_ty_or_opaque(ctx, &());
+                    let prefix = ctx.trait_prefix();
+                    quote_ty!(ctx.ext_cx(), ::$prefix::ptr::NonNull<$inner>)
+                }
+                _ => field.ty().to_rust_ty_or_opaque(ctx, &()),
+            };
+            let variant_ty = ty.clone();
 
             // NB: In unstable rust we use proper `union` types.
             let ty = if is_union && !ctx.options().unstable_rust {
-                if ctx.options().enable_cxx_namespaces {
-                    quote_ty!(ctx.ext_cx(), root::__BindgenUnionField<$ty>)
-                } else {
-                    quote_ty!(ctx.ext_cx(), __BindgenUnionField<$ty>)
-                }
+                helper_type(ctx, "__BindgenUnionField", vec![ty])
             } else if let Some(item) =
                 field_ty.is_incomplete_array(ctx) {
                 result.saw_incomplete_array();
 
                 let inner = item.to_rust_ty_or_opaque(ctx, &());
 
-                if ctx.options().enable_cxx_namespaces {
-                    quote_ty!(ctx.ext_cx(), root::__IncompleteArrayField<$inner>)
-                } else {
-                    quote_ty!(ctx.ext_cx(), __IncompleteArrayField<$inner>)
-                }
+                helper_type(ctx, "__IncompleteArrayField", vec![inner])
             } else {
                 ty
             };
@@ -1314,6 +2554,40 @@ impl CodeGenerator for CompInfo {
 
             fields.push(field);
 
+            // Provide a way to read the variants of the union without going
+            // through `__BindgenUnionField` by hand.
+            if is_union && !ctx.options().unstable_rust {
+                let variant_name = ctx.rust_ident_raw(&field_name);
+                let mut mutable_variant_name = format!("{}_mut", field_name);
+                while !variant_accessor_names
+                    .insert(mutable_variant_name.clone()) {
+                    mutable_variant_name.push('_');
+                }
+                let mutable_variant_name =
+                    ctx.rust_ident_raw(&mutable_variant_name);
+                let variant_accessors = quote_item!(ctx.ext_cx(),
+                    impl X {
+                        #[inline]
+                        pub unsafe fn $variant_name(&self) -> &$variant_ty {
+                            self.$variant_name.as_ref()
+                        }
+
+                        #[inline]
+                        pub unsafe fn $mutable_variant_name(&mut self)
+                            -> &mut $variant_ty {
+                            self.$variant_name.as_mut()
+                        }
+                    }
+                );
+
+                match variant_accessors.unwrap().node {
+                    ast::ItemKind::Impl(_, _, _, _, _, ref items) => {
+                        methods.extend(items.clone())
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
             // TODO: Factor the following code out, please!
             if accessor_kind == FieldAccessorKind::None {
                 continue;
@@ -1386,9 +2660,13 @@ impl CodeGenerator for CompInfo {
             debug_assert!(!current_bitfield_fields.is_empty());
             let bitfield_fields = mem::replace(&mut current_bitfield_fields,
                                                vec![]);
+            if let Some(padding_field) =
+                struct_layout.pad_bitfield_batch(current_bitfield_offset.take()) {
+                fields.push(padding_field);
+            }
             let bitfield_layout = Bitfield::new(&mut bitfield_count,
                                                 bitfield_fields)
-                .codegen_fields(ctx, self, &mut fields, &mut methods);
+                .codegen_fields(ctx, self, result, &mut fields, &mut methods);
             struct_layout.saw_bitfield_batch(bitfield_layout);
         }
         debug_assert!(current_bitfield_fields.is_empty());
@@ -1420,7 +2698,10 @@ impl CodeGenerator for CompInfo {
                     fields.push(field);
                 }
                 None => {
-                    warn!("Opaque type without layout! Expect dragons!");
+                    let message = format!("{}: Opaque type without layout! \
+                                           Expect dragons!",
+                                          item.location_for_diagnostics());
+                    ctx.diagnostic(DiagnosticSeverity::Warning, message);
                 }
             }
         } else if !is_union && !self.is_unsized(ctx) {
@@ -1458,18 +2739,9 @@ impl CodeGenerator for CompInfo {
             }
         }
 
-        let mut generics = aster::AstBuilder::new().generics();
-
-        if let Some(ref params) = used_template_params {
-            for ty in params.iter() {
-                let param = ctx.resolve_type(*ty);
-                let name = param.name().unwrap();
-                let ident = ctx.rust_ident(name);
-                generics = generics.ty_param_id(ident);
-            }
-        }
-
-        let generics = generics.build();
+        let template_params = used_template_params.as_ref()
+            .map_or(&[][..], |params| &params[..]);
+        let generics = utils::template_generics(ctx, template_params);
 
         let rust_struct = builder.with_generics(generics.clone())
             .with_fields(fields)
@@ -1490,16 +2762,39 @@ impl CodeGenerator for CompInfo {
         // affect layout, so we're bad and pray to the gods for avoid sending
         // all the tests to shit when parsing things like max_align_t.
         if self.found_unknown_attr() {
-            warn!("Type {} has an unkown attribute that may affect layout",
-                  canonical_name);
+            let message = format!("{}: Type {} has an unkown attribute that \
+                                   may affect layout",
+                                  item.location_for_diagnostics(),
+                                  canonical_name);
+            ctx.diagnostic(DiagnosticSeverity::Warning, message);
         }
 
+        let mut associated_constants = vec![];
         if used_template_params.is_none() {
             for var in self.inner_vars() {
-                ctx.resolve_item(*var)
-                    .codegen(ctx, result, whitelisted_items, &());
+                let var = ctx.resolve_item(*var);
+                if ctx.options().associated_constants {
+                    if let Some(constant) =
+                        associated_constant(ctx, result, var) {
+                        associated_constants.push(constant);
+                        continue;
+                    }
+                }
+                var.codegen(ctx, result, whitelisted_items, &());
             }
 
+            let mut layout =
+                if ctx.options().layout_tests { layout } else { None };
+            if layout.is_some() && item.annotations().no_layout_test() {
+                // Warn so that the skipped tests don't get forgotten.
+                let message = format!("{}: Skipping the layout test of `{}`, \
+                                       as requested by its `no-layout-test` \
+                                       annotation",
+                                      item.location_for_diagnostics(),
+                                      canonical_name);
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                layout = None;
+            }
             if let Some(layout) = layout {
                 let fn_name = format!("bindgen_test_layout_{}", canonical_name);
                 let fn_name = ctx.rust_ident_raw(&fn_name);
@@ -1568,7 +2863,11 @@ impl CodeGenerator for CompInfo {
                         $check_struct_align
                         $check_field_offset
                     })
-                    .unwrap();
+                    .unwrap()
+                    .map(|mut item| {
+                        item.attrs.extend(layout_test_cfg(ctx));
+                        item
+                    });
                 result.push(item);
             }
 
@@ -1603,13 +2902,8 @@ impl CodeGenerator for CompInfo {
 
         // NB: We can't use to_rust_ty here since for opaque types this tries to
         // use the specialization knowledge to generate a blob field.
-        let ty_for_impl = aster::AstBuilder::new()
-            .ty()
-            .path()
-            .segment(&canonical_name)
-            .with_generics(generics.clone())
-            .build()
-            .build();
+        let ty_for_impl =
+            utils::ty_for_impl(ctx, &canonical_name, template_params);
 
         if needs_clone_impl {
             let impl_ = quote_item!(ctx.ext_cx(),
@@ -1623,11 +2917,20 @@ impl CodeGenerator for CompInfo {
                 _ => unreachable!(),
             };
 
-            let clone_impl = aster::AstBuilder::new()
+            let mut trait_ = aster::AstBuilder::new()
                 .item()
                 .impl_()
-                .trait_()
-                .id("Clone")
+                .trait_();
+
+            // The prelude isn't necessarily there without libstd.
+            let trait_path = if ctx.options().no_std {
+                trait_ = trait_.global();
+                vec!["core", "clone", "Clone"]
+            } else {
+                vec!["Clone"]
+            };
+
+            let clone_impl = trait_.ids(trait_path)
                 .build()
                 .with_generics(generics.clone())
                 .with_items(impl_)
@@ -1636,6 +2939,151 @@ impl CodeGenerator for CompInfo {
             result.push(clone_impl);
         }
 
+        if needs_debug_impl {
+            let prefix = ctx.trait_prefix();
+            let struct_name = canonical_name.to_string();
+            let mut stmts = vec![];
+            stmts.push(quote_stmt!(ctx.ext_cx(),
+                                   let mut builder =
+                                       f.debug_struct($struct_name))
+                .unwrap());
+
+            for name in &base_field_names {
+                let ident = ctx.rust_ident_raw(name);
+                let expr = if self.packed() {
+                    quote_expr!(ctx.ext_cx(),
+                                builder.field($name, &{ self.$ident }))
+                } else {
+                    quote_expr!(ctx.ext_cx(),
+                                builder.field($name, &self.$ident))
+                };
+                stmts.push(ast::Stmt {
+                    id: ast::DUMMY_NODE_ID,
+                    node: ast::StmtKind::Semi(expr),
+                    span: ctx.span(),
+                });
+            }
+
+            for field in self.fields() {
+                let raw_name = match field.name() {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let name = ctx.rust_mangle(raw_name).into_owned();
+                let ident = ctx.rust_ident_raw(&name);
+
+                let field_ty = ctx.resolve_type(field.ty()).canonical_type(ctx);
+                let expr = match *field_ty.kind() {
+                    // Bitfields are printed through their getters, which copy
+                    // them out of their unit anyway.
+                    _ if field.bitfield().is_some() => {
+                        let getter = bitfield_getter_name(ctx, self, raw_name);
+                        quote_expr!(ctx.ext_cx(),
+                                    builder.field($name, &self.$getter()))
+                    }
+                    // The block copies the field into an aligned temporary.
+                    _ if self.packed() => {
+                        quote_expr!(ctx.ext_cx(),
+                                    builder.field($name, &{ self.$ident }))
+                    }
+                    TypeKind::Array(_, len) if len >
+                                               RUST_DERIVE_IN_ARRAY_LIMIT => {
+                        quote_expr!(ctx.ext_cx(),
+                                    builder.field($name, &&self.$ident[..]))
+                    }
+                    _ => {
+                        quote_expr!(ctx.ext_cx(),
+                                    builder.field($name, &self.$ident))
+                    }
+                };
+                stmts.push(ast::Stmt {
+                    id: ast::DUMMY_NODE_ID,
+                    node: ast::StmtKind::Semi(expr),
+                    span: ctx.span(),
+                });
+            }
+
+            stmts.push(quote_stmt!(ctx.ext_cx(), builder.finish()).unwrap());
+
+            let body = P(ast::Block {
+                stmts: stmts,
+                id: ast::DUMMY_NODE_ID,
+                rules: ast::BlockCheckMode::Default,
+                span: ctx.span(),
+            });
+
+            let impl_ = quote_item!(ctx.ext_cx(),
+                impl X {
+                    fn fmt(&self, f: &mut ::$prefix::fmt::Formatter)
+                           -> ::$prefix::fmt::Result $body
+                }
+            );
+
+            let impl_ = match impl_.unwrap().node {
+                ast::ItemKind::Impl(_, _, _, _, _, ref items) => items.clone(),
+                _ => unreachable!(),
+            };
+
+            let debug_impl = aster::AstBuilder::new()
+                .item()
+                .impl_()
+                .trait_()
+                .global()
+                .id(prefix)
+                .id("fmt")
+                .id("Debug")
+                .build()
+                .with_generics(generics.clone())
+                .with_items(impl_)
+                .build_ty(ty_for_impl.clone());
+
+            result.push(debug_impl);
+        }
+
+        if needs_union_debug_impl {
+            let prefix = ctx.trait_prefix();
+
+            // There's no way to know which field is the active one, so print
+            // just the name and size.
+            let description = match layout {
+                Some(layout) => {
+                    format!("{} {{ union, size: {} }}",
+                            canonical_name,
+                            layout.size)
+                }
+                None => format!("{} {{ union }}", canonical_name),
+            };
+
+            let impl_ = quote_item!(ctx.ext_cx(),
+                impl X {
+                    fn fmt(&self, f: &mut ::$prefix::fmt::Formatter)
+                           -> ::$prefix::fmt::Result {
+                        f.write_str($description)
+                    }
+                }
+            );
+
+            let impl_ = match impl_.unwrap().node {
+                ast::ItemKind::Impl(_, _, _, _, _, ref items) => items.clone(),
+                _ => unreachable!(),
+            };
+
+            let debug_impl = aster::AstBuilder::new()
+                .item()
+                .impl_()
+                .trait_()
+                .global()
+                .id(prefix)
+                .id("fmt")
+                .id("Debug")
+                .build()
+                .with_generics(generics.clone())
+                .with_items(impl_)
+                .build_ty(ty_for_impl.clone());
+
+            result.push(debug_impl);
+        }
+
         if needs_default_impl {
             let prefix = ctx.trait_prefix();
             let impl_ = quote_item!(ctx.ext_cx(),
@@ -1662,25 +3110,70 @@ impl CodeGenerator for CompInfo {
             result.push(default_impl);
         }
 
-        if !methods.is_empty() {
+        result.extend(thread_safety_impls(ctx, item, template_params));
+        result.extend(user_trait_impls(ctx, item, template_params));
+
+        let mut impl_items = associated_constants;
+        impl_items.extend(methods);
+        if !impl_items.is_empty() {
             let methods = aster::AstBuilder::new()
                 .item()
                 .impl_()
                 .with_generics(generics)
-                .with_items(methods)
+                .with_items(impl_items)
                 .build_ty(ty_for_impl);
             result.push(methods);
         }
     }
 }
 
+/// Generate an associated constant for the given static member variable of a
+/// class, if it has a value we know how to represent.
+fn associated_constant(ctx: &BindgenContext,
+                       result: &mut CodegenResult,
+                       item: &Item)
+                       -> Option<ast::ImplItem> {
+    if item.is_hidden(ctx) || result.seen(item.id()) ||
+       !ctx.options().codegen_config.vars {
+        return None;
+    }
+
+    let var = match *item.kind() {
+        ItemKind::Var(ref var) => var,
+        _ => return None,
+    };
+
+    let canonical_name = item.canonical_name(ctx);
+    if result.seen_var(&canonical_name) {
+        return None;
+    }
+
+    let (ty, expr) = match var_constant(ctx, var) {
+        Some(constant) => constant,
+        None => return None,
+    };
+
+    result.set_seen(item.id());
+    result.saw_var(&canonical_name);
+
+    Some(ast::ImplItem {
+        id: ast::DUMMY_NODE_ID,
+        ident: ctx.rust_ident(var.name()),
+        vis: ast::Visibility::Public,
+        attrs: vec![],
+        node: ast::ImplItemKind::Const(ty, expr),
+        defaultness: ast::Defaultness::Final,
+        span: ctx.span(),
+    })
+}
+
 trait MethodCodegen {
     fn codegen_method<'a>(&self,
                           ctx: &BindgenContext,
                           methods: &mut Vec<ast::ImplItem>,
                           method_names: &mut HashMap<String, usize>,
                           result: &mut CodegenResult<'a>,
-                          whitelisted_items: &ItemSet,
+                          whitelisted_items: &ItemIdSet,
                           parent: &CompInfo);
 }
 
@@ -1690,7 +3183,7 @@ impl MethodCodegen for Method {
                           methods: &mut Vec<ast::ImplItem>,
                           method_names: &mut HashMap<String, usize>,
                           result: &mut CodegenResult<'a>,
-                          whitelisted_items: &ItemSet,
+                          whitelisted_items: &ItemIdSet,
                           _parent: &CompInfo) {
         if self.is_virtual() {
             return; // FIXME
@@ -1834,7 +3327,9 @@ impl MethodCodegen for Method {
         };
 
         let mut attrs = vec![];
-        attrs.push(attributes::inline());
+        if ctx.options().inline_wrappers {
+            attrs.push(attributes::inline());
+        }
 
         let item = ast::ImplItem {
             id: ast::DUMMY_NODE_ID,
@@ -1858,6 +3353,11 @@ enum EnumBuilder<'a> {
         aster: P<ast::Item>,
     },
     Consts { aster: P<ast::Item> },
+    ModuleConsts {
+        module_name: &'a str,
+        module_items: Vec<P<ast::Item>>,
+        aster: aster::item::ItemBuilder<aster::invoke::Identity>,
+    },
 }
 
 impl<'a> EnumBuilder<'a> {
@@ -1866,9 +3366,25 @@ impl<'a> EnumBuilder<'a> {
     fn new(aster: aster::item::ItemBuilder<aster::invoke::Identity>,
            name: &'a str,
            repr: P<ast::Ty>,
-           bitfield_like: bool,
-           constify: bool)
+           style: EnumVariation)
            -> Self {
+        if style == EnumVariation::ModuleConsts {
+            let repr = from_child_module(repr);
+            let type_alias = aster::AstBuilder::new()
+                .item()
+                .pub_()
+                .type_(CONSTIFIED_ENUM_MODULE_REPR_NAME)
+                .build_ty(repr);
+
+            return EnumBuilder::ModuleConsts {
+                module_name: name,
+                module_items: vec![type_alias],
+                aster: aster,
+            };
+        }
+
+        let bitfield_like = style == EnumVariation::Bitfield;
+        let constify = style == EnumVariation::Consts;
         if bitfield_like {
             EnumBuilder::Bitfield {
                 canonical_name: name,
@@ -1887,11 +3403,37 @@ impl<'a> EnumBuilder<'a> {
         }
     }
 
+    /// Add the hidden variant that makes a rustified enum non-exhaustive, with
+    /// the given value.
+    fn with_non_exhaustive_variant(self,
+                                   ctx: &BindgenContext,
+                                   val: EnumVariantValue)
+                                   -> Self {
+        let expr = match val {
+            EnumVariantValue::Signed(v) => helpers::ast_ty::int_expr(v),
+            EnumVariantValue::Unsigned(v) => {
+                aster::AstBuilder::new().expr().uint(v)
+            }
+        };
+
+        match self {
+            EnumBuilder::Rust(b) => {
+                EnumBuilder::Rust(b.with_variant_(ast::Variant_ {
+                    name: ctx.rust_ident("__Nonexhaustive"),
+                    attrs: vec![attributes::doc_hidden()],
+                    data: ast::VariantData::Unit(ast::DUMMY_NODE_ID),
+                    disr_expr: Some(expr),
+                }))
+            }
+            _ => unreachable!("Only rustified enums can be non-exhaustive"),
+        }
+    }
+
     /// Add a variant to this enum.
     fn with_variant<'b>(self,
                         ctx: &BindgenContext,
                         variant: &EnumVariant,
-                        mangling_prefix: Option<&String>,
+                        mangling_prefix: Option<&str>,
                         rust_ty: P<ast::Ty>,
                         result: &mut CodegenResult<'b>)
                         -> Self {
@@ -1902,11 +3444,23 @@ impl<'a> EnumBuilder<'a> {
             EnumVariantValue::Unsigned(v) => expr.uint(v),
         };
 
+        let mut attrs = vec![];
+        if ctx.options().generate_comments {
+            // Trailing comments, like `/**< ... */`, aren't valid outer doc
+            // comments as is.
+            let text = variant.comment().map(comment::text);
+            if let Some(text) = text {
+                if !comment::is_annotation_only(&text) {
+                    attrs.push(attributes::doc(&format!("/// {}", text)));
+                }
+            }
+        }
+
         match self {
             EnumBuilder::Rust(b) => {
                 EnumBuilder::Rust(b.with_variant_(ast::Variant_ {
                     name: ctx.rust_ident(&*variant_name),
-                    attrs: vec![],
+                    attrs: attrs,
                     data: ast::VariantData::Unit(ast::DUMMY_NODE_ID),
                     disr_expr: Some(expr),
                 }))
@@ -1922,6 +3476,7 @@ impl<'a> EnumBuilder<'a> {
                 let constant = aster::AstBuilder::new()
                     .item()
                     .pub_()
+                    .with_attrs(attrs)
                     .const_(&*constant_name)
                     .expr()
                     .call()
@@ -1944,6 +3499,7 @@ impl<'a> EnumBuilder<'a> {
                 let constant = aster::AstBuilder::new()
                     .item()
                     .pub_()
+                    .with_attrs(attrs)
                     .const_(&*constant_name)
                     .expr()
                     .build(expr)
@@ -1952,6 +3508,30 @@ impl<'a> EnumBuilder<'a> {
                 result.push(constant);
                 self
             }
+            EnumBuilder::ModuleConsts { module_name,
+                                        mut module_items,
+                                        aster } => {
+                // The constants live in the enum's own module, so there's no
+                // need to prefix them.
+                let ty = aster::AstBuilder::new()
+                    .ty()
+                    .id(CONSTIFIED_ENUM_MODULE_REPR_NAME);
+                let constant = aster::AstBuilder::new()
+                    .item()
+                    .pub_()
+                    .with_attrs(attrs)
+                    .const_(&*variant_name)
+                    .expr()
+                    .build(expr)
+                    .build(ty);
+
+                module_items.push(constant);
+                EnumBuilder::ModuleConsts {
+                    module_name: module_name,
+                    module_items: module_items,
+                    aster: aster,
+                }
+            }
         }
     }
 
@@ -1982,35 +3562,271 @@ impl<'a> EnumBuilder<'a> {
                 aster
             }
             EnumBuilder::Consts { aster, .. } => aster,
+            EnumBuilder::ModuleConsts { module_name, module_items, aster } => {
+                let module = ast::ItemKind::Mod(ast::Mod {
+                    inner: ctx.span(),
+                    items: module_items,
+                });
+
+                aster.build_item_kind(module_name, module)
+            }
+        }
+    }
+}
+
+/// The name of the integer type alias inside of a constified enum module.
+const CONSTIFIED_ENUM_MODULE_REPR_NAME: &'static str = "Type";
+
+/// Make a type that is valid in a module also valid inside a module nested
+/// in it, by resolving relative paths through `super`.
+fn from_child_module(ty: P<ast::Ty>) -> P<ast::Ty> {
+    const PRIMITIVES: &'static [&'static str] = &["bool", "char", "f32",
+                                                  "f64", "i8", "i16", "i32",
+                                                  "i64", "isize", "u8", "u16",
+                                                  "u32", "u64", "usize"];
+    ty.map(|mut ty| {
+        if let ast::TyKind::Path(None, ref mut path) = ty.node {
+            let is_primitive = path.segments.len() == 1 &&
+                               PRIMITIVES.iter().any(|p| {
+                &*path.segments[0].identifier.name.as_str() == *p
+            });
+            let is_relative = path.segments
+                .first()
+                .map_or(false, |segment| {
+                    &*segment.identifier.name.as_str() == "self"
+                });
+            if is_relative {
+                let super_ = ast::Ident::from_str("super");
+                path.segments.insert(1, ast::PathSegment::from(super_));
+            } else if !path.is_global() && !is_primitive {
+                let mut segments: Vec<_> = ["self", "super"]
+                    .iter()
+                    .map(|s| ast::PathSegment::from(ast::Ident::from_str(s)))
+                    .collect();
+                segments.extend(path.segments.drain(..));
+                path.segments = segments;
+            }
+        }
+        ty
+    })
+}
+
+/// How to generate the given enum, see `Builder::default_enum_style`.
+fn enum_style(ctx: &BindgenContext,
+              enum_: &Enum,
+              item: &Item)
+              -> EnumVariation {
+    enum_style_and_conflicts(ctx, enum_, item).0
+}
+
+/// The integer kind of the underlying type of the given enum, if known.
+fn enum_repr_kind(ctx: &BindgenContext, enum_: &Enum) -> Option<IntKind> {
+    enum_.repr().map(|repr| {
+        match *ctx.resolve_type(repr).canonical_type(ctx).kind() {
+            TypeKind::Int(int_kind) => int_kind,
+            _ => panic!("Unexpected type as enum repr"),
+        }
+    })
+}
+
+/// The name of the Rust integer type of the given signedness and size in
+/// bytes, if there's one.
+fn int_type_name(signed: bool, size: usize) -> Option<&'static str> {
+    Some(match (signed, size) {
+        (true, 1) => "i8",
+        (false, 1) => "u8",
+        (true, 2) => "i16",
+        (false, 2) => "u16",
+        (true, 4) => "i32",
+        (false, 4) => "u32",
+        (true, 8) => "i64",
+        (false, 8) => "u64",
+        _ => return None,
+    })
+}
+
+/// The Rust integer type the given enum is represented with. This is quiet
+/// about the enums it has to guess the type of, the enum's own codegen warns
+/// about those already.
+fn enum_underlying_type(ctx: &BindgenContext,
+                        enum_: &Enum,
+                        item: &Item)
+                        -> &'static str {
+    let repr = enum_repr_kind(ctx, enum_).unwrap_or(IntKind::Int);
+    let size = item.expect_type()
+        .layout(ctx)
+        .map(|l| l.size)
+        .or_else(|| repr.known_size())
+        .unwrap_or(0);
+    int_type_name(repr.is_signed(), size).unwrap_or("i32")
+}
+
+/// The path to the type of the given enum, which is the alias in the enum's
+/// module for the enums generated as such.
+fn enum_path_ty(ctx: &BindgenContext,
+                item: &Item,
+                style: EnumVariation)
+                -> P<ast::Ty> {
+    let mut path = item.namespace_aware_canonical_path(ctx);
+    if style == EnumVariation::ModuleConsts {
+        path.pop();
+        path.push(enum_module_name(ctx, item));
+        path.push(ctx.names().intern(CONSTIFIED_ENUM_MODULE_REPR_NAME));
+    }
+    aster::AstBuilder::new()
+        .ty()
+        .path()
+        .ids(path)
+        .build()
+}
+
+/// Like `enum_style`, but also returns whether regexes of several styles
+/// matched the enum.
+fn enum_style_and_conflicts(ctx: &BindgenContext,
+                            enum_: &Enum,
+                            item: &Item)
+                            -> (EnumVariation, bool) {
+    if item.annotations().constify_enum_module() {
+        return (EnumVariation::ModuleConsts, false);
+    }
+
+    // FIXME(emilio): These should probably use the path so it can
+    // disambiguate between namespaces, just like is_opaque etc.
+    let mut names = vec![item.canonical_name(ctx)];
+    if item.expect_type().name().is_none() {
+        names.extend(enum_.variants()
+            .iter()
+            .map(|v| ctx.names().intern(v.name())));
+    }
+
+    let options = ctx.options();
+    let sets = [(EnumVariation::Rust, &options.rustified_enums),
+                (EnumVariation::Bitfield, &options.bitfield_enums),
+                (EnumVariation::Consts, &options.constified_enums),
+                (EnumVariation::ModuleConsts,
+                 &options.constified_enum_modules)];
+    last_matching_style(&sets, &options.enum_style_order, &names)
+        .unwrap_or((options.default_enum_style, false))
+}
+
+/// Should this rustified enum be non-exhaustive?
+fn is_non_exhaustive_enum(ctx: &BindgenContext,
+                          enum_: &Enum,
+                          item: &Item)
+                          -> bool {
+    if item.annotations().non_exhaustive_enum() {
+        return true;
+    }
+
+    let name = item.canonical_name(ctx);
+    let is_unnamed = item.expect_type().name().is_none();
+    let set = &ctx.options().rustified_non_exhaustive_enums;
+    set.matches(&name) ||
+    (is_unnamed && enum_.variants().iter().any(|v| set.matches(&v.name())))
+}
+
+/// Pick the value of the hidden variant of a non-exhaustive enum: one past the
+/// values of its variants if it fits in the integer type of the given
+/// signedness and size in bytes, or one before them otherwise. Returns `None`
+/// if neither fits.
+fn non_exhaustive_sentinel(enum_: &Enum,
+                           signed: bool,
+                           size: usize)
+                           -> Option<EnumVariantValue> {
+    use std::{i64, u64};
+
+    if int_type_name(signed, size).is_none() {
+        return None;
+    }
+    let bits = size * 8;
+
+    if signed {
+        let values: Vec<_> = enum_.variants()
+            .iter()
+            .map(|v| match v.val() {
+                EnumVariantValue::Signed(v) => v,
+                EnumVariantValue::Unsigned(v) => v as i64,
+            })
+            .collect();
+        let (min, max) = match (values.iter().min(), values.iter().max()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => return None,
+        };
+
+        let max_value = i64::MAX >> (64 - bits);
+        let min_value = -max_value - 1;
+        if max < max_value {
+            Some(EnumVariantValue::Signed(max + 1))
+        } else if min > min_value {
+            Some(EnumVariantValue::Signed(min - 1))
+        } else {
+            None
+        }
+    } else {
+        let values: Vec<_> = enum_.variants()
+            .iter()
+            .map(|v| match v.val() {
+                EnumVariantValue::Signed(v) => v as u64,
+                EnumVariantValue::Unsigned(v) => v,
+            })
+            .collect();
+        let (min, max) = match (values.iter().min(), values.iter().max()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => return None,
+        };
+
+        let max_value = u64::MAX >> (64 - bits);
+        if max < max_value {
+            Some(EnumVariantValue::Unsigned(max + 1))
+        } else if min > 0 {
+            Some(EnumVariantValue::Unsigned(min - 1))
+        } else {
+            None
         }
     }
 }
 
+/// The name of the module holding the constants of a `ModuleConsts` enum.
+///
+/// This is usually the enum's canonical name, but modules and types share a
+/// namespace in Rust, so if another type in the same scope already has that
+/// name (think of `enum foo { ... }; typedef int foo;` in C), the module gets
+/// a suffix instead.
+fn enum_module_name(ctx: &BindgenContext, item: &Item) -> Name {
+    let name = item.canonical_name(ctx);
+    if ctx.is_shared_type_path(&item.canonical_path(ctx)) {
+        ctx.names().intern_string(format!("{}_consts", name))
+    } else {
+        name
+    }
+}
+
 impl CodeGenerator for Enum {
     type Extra = Item;
 
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   _whitelisted_items: &ItemSet,
+                   _whitelisted_items: &ItemIdSet,
                    item: &Item) {
         debug!("<Enum as CodeGenerator>::codegen: item = {:?}", item);
 
         let name = item.canonical_name(ctx);
         let enum_ty = item.expect_type();
+        // An unnamed enum declared in a `typedef` takes the name of the
+        // `typedef`, see `Item::anonymous_enum_typedef`.
+        let is_named = enum_ty.name().is_some() ||
+                       item.anonymous_enum_typedef(ctx).is_some();
         let layout = enum_ty.layout(ctx);
 
-        let repr = self.repr().map(|repr| ctx.resolve_type(repr));
-        let repr = match repr {
-            Some(repr) => {
-                match *repr.canonical_type(ctx).kind() {
-                    TypeKind::Int(int_kind) => int_kind,
-                    _ => panic!("Unexpected type as enum repr"),
-                }
-            }
+        let repr = match enum_repr_kind(ctx, self) {
+            Some(repr) => repr,
             None => {
-                warn!("Guessing type of enum! Forward declarations of enums \
-                      shouldn't be legal!");
+                let message = format!("{}: Guessing type of enum! Forward \
+                                       declarations of enums shouldn't be \
+                                       legal!",
+                                      item.location_for_diagnostics());
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
                 IntKind::Int
             }
         };
@@ -2020,42 +3836,54 @@ impl CodeGenerator for Enum {
             .or_else(|| repr.known_size())
             .unwrap_or(0);
 
-        let repr_name = match (signed, size) {
-            (true, 1) => "i8",
-            (false, 1) => "u8",
-            (true, 2) => "i16",
-            (false, 2) => "u16",
-            (true, 4) => "i32",
-            (false, 4) => "u32",
-            (true, 8) => "i64",
-            (false, 8) => "u64",
-            _ => {
-                warn!("invalid enum decl: signed: {}, size: {}", signed, size);
-                "i32"
+        let (signed, size, repr_name) = match int_type_name(signed, size) {
+            Some(name) => (signed, size, name),
+            None => {
+                let message = format!("{}: invalid enum decl: signed: {}, \
+                                       size: {}",
+                                      item.location_for_diagnostics(),
+                                      signed,
+                                      size);
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                (true, 4, "i32")
             }
         };
 
         let mut builder = aster::AstBuilder::new().item().pub_();
 
-        // FIXME(emilio): These should probably use the path so it can
-        // disambiguate between namespaces, just like is_opaque etc.
-        let is_bitfield = {
-            ctx.options().bitfield_enums.matches(&name) ||
-            (enum_ty.name().is_none() &&
-             self.variants()
-                .iter()
-                .any(|v| ctx.options().bitfield_enums.matches(&v.name())))
-        };
+        let (style, conflicting) = enum_style_and_conflicts(ctx, self, item);
+        if conflicting {
+            let message = format!("{}: `{}` matches the regexes of several \
+                                   enum styles, using the one given last: \
+                                   {:?}",
+                                  item.location_for_diagnostics(),
+                                  name,
+                                  style);
+            ctx.diagnostic(DiagnosticSeverity::Warning, message);
+        }
 
-        let is_constified_enum = {
-            ctx.options().constified_enums.matches(&name) ||
-            (enum_ty.name().is_none() &&
-             self.variants()
-                .iter()
-                .any(|v| ctx.options().constified_enums.matches(&v.name())))
+        let name = if style == EnumVariation::ModuleConsts {
+            let module_name = enum_module_name(ctx, item);
+            if module_name != name {
+                let message = format!("{}: A type named `{}` already \
+                                       exists, putting the enum constants in \
+                                       module `{}` instead",
+                                      item.location_for_diagnostics(),
+                                      name,
+                                      module_name);
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
+            }
+            module_name
+        } else {
+            name
         };
 
-        let is_rust_enum = !is_bitfield && !is_constified_enum;
+        let is_bitfield = style == EnumVariation::Bitfield;
+        let is_constified_enum = style == EnumVariation::Consts ||
+                                 style == EnumVariation::ModuleConsts;
+        let is_rust_enum = style == EnumVariation::Rust;
+        let non_exhaustive = is_rust_enum && !self.variants().is_empty() &&
+                             is_non_exhaustive_enum(ctx, self, item);
 
         // FIXME: Rust forbids repr with empty enums. Remove this condition when
         // this is allowed.
@@ -2074,19 +3902,25 @@ impl CodeGenerator for Enum {
                 builder = builder.with_attr(attributes::doc(comment));
             }
         }
+        if let Some(annotation) = source_annotation(ctx, item) {
+            builder = builder.with_attr(annotation);
+        }
 
         if !is_constified_enum {
-            let derives = attributes::derives(&["Debug",
-                                                "Copy",
-                                                "Clone",
-                                                "PartialEq",
-                                                "Eq",
-                                                "Hash"]);
+            let mut derives = vec![];
+            if ctx.options().derive_debug {
+                derives.push("Debug");
+            }
+            derives.extend(&["Copy", "Clone", "PartialEq", "Eq", "Hash"]);
+
+            builder = builder.with_attr(attributes::derives(&derives));
+        }
 
-            builder = builder.with_attr(derives);
+        if non_exhaustive && ctx.options().non_exhaustive_attribute {
+            builder = builder.with_attr(attributes::non_exhaustive());
         }
 
-        fn add_constant<'a>(enum_: &Type,
+        fn add_constant<'a>(enum_is_named: bool,
                             // Only to avoid recomputing every time.
                             enum_canonical_name: &str,
                             // May be the same as "variant" if it's because the
@@ -2096,7 +3930,7 @@ impl CodeGenerator for Enum {
                             referenced_name: &str,
                             enum_rust_ty: P<ast::Ty>,
                             result: &mut CodegenResult<'a>) {
-            let constant_name = if enum_.name().is_some() {
+            let constant_name = if enum_is_named {
                 format!("{}_{}", enum_canonical_name, variant_name)
             } else {
                 variant_name.into()
@@ -2114,19 +3948,24 @@ impl CodeGenerator for Enum {
             result.push(constant);
         }
 
+        // The representation is a Rust integer type, not a C one, when we
+        // don't know the underlying type.
         let repr = self.repr()
             .and_then(|repr| repr.try_to_rust_ty_or_opaque(ctx, &()).ok())
-            .unwrap_or_else(|| helpers::ast_ty::raw_type(ctx, repr_name));
+            .unwrap_or_else(|| {
+                let ident = ctx.rust_ident_raw(repr_name);
+                quote_ty!(ctx.ext_cx(), $ident)
+            });
 
-        let mut builder = EnumBuilder::new(builder,
-                                           &name,
-                                           repr,
-                                           is_bitfield,
-                                           is_constified_enum);
+        let mut builder = EnumBuilder::new(builder, &name, repr, style);
 
         // A map where we keep a value -> variant relation.
         let mut seen_values = HashMap::<_, String>::new();
-        let enum_rust_ty = item.to_rust_ty_or_opaque(ctx, &());
+        // The variants of a rustified enum, in order, for the conversions.
+        let mut rust_variants = vec![];
+        // Not `to_rust_ty`, so that the constants keep the enum's type even
+        // when the rest of the bindings use its underlying type.
+        let enum_rust_ty = enum_path_ty(ctx, item, style);
         let is_toplevel = item.is_toplevel(ctx);
 
         // Used to mangle the constants we generate in the unnamed-enum case.
@@ -2137,10 +3976,10 @@ impl CodeGenerator for Enum {
         };
 
         let constant_mangling_prefix = if ctx.options().prepend_enum_name {
-            if enum_ty.name().is_none() {
-                parent_canonical_name.as_ref().map(|n| &*n)
+            if !is_named {
+                parent_canonical_name.as_ref().map(|n| &**n)
             } else {
-                Some(&name)
+                Some(&*name)
             }
         } else {
             None
@@ -2167,8 +4006,7 @@ impl CodeGenerator for Enum {
                 Entry::Occupied(ref entry) => {
                     if is_rust_enum {
                         let variant_name = ctx.rust_mangle(variant.name());
-                        let mangled_name = if is_toplevel ||
-                                              enum_ty.name().is_some() {
+                        let mangled_name = if is_toplevel || is_named {
                             variant_name
                         } else {
                             let parent_name = parent_canonical_name.as_ref()
@@ -2180,7 +4018,7 @@ impl CodeGenerator for Enum {
                         };
 
                         let existing_variant_name = entry.get();
-                        add_constant(enum_ty,
+                        add_constant(is_named,
                                      &name,
                                      &*mangled_name,
                                      existing_variant_name,
@@ -2206,7 +4044,7 @@ impl CodeGenerator for Enum {
                     // If it's an unnamed enum, or constification is enforced,
                     // we also generate a constant so it can be properly
                     // accessed.
-                    if (is_rust_enum && enum_ty.name().is_none()) ||
+                    if (is_rust_enum && !is_named) ||
                        variant.force_constification() {
                         let mangled_name = if is_toplevel {
                             variant_name.clone()
@@ -2219,7 +4057,7 @@ impl CodeGenerator for Enum {
                                                variant_name))
                         };
 
-                        add_constant(enum_ty,
+                        add_constant(is_named,
                                      &name,
                                      &mangled_name,
                                      &variant_name,
@@ -2227,14 +4065,132 @@ impl CodeGenerator for Enum {
                                      result);
                     }
 
+                    if is_rust_enum {
+                        rust_variants.push((variant.val(),
+                                            variant_name.clone().into_owned()));
+                    }
+
                     entry.insert(variant_name.into_owned());
                 }
             }
         }
 
+        let mut sentinel = None;
+        if non_exhaustive && !ctx.options().non_exhaustive_attribute {
+            match non_exhaustive_sentinel(self, signed, size) {
+                Some(val) => {
+                    builder = builder.with_non_exhaustive_variant(ctx, val);
+                    sentinel = Some(val);
+                }
+                None => {
+                    let message = format!("{}: Can't make enum `{}` \
+                                           non-exhaustive, its variants span \
+                                           all the values of `{}`",
+                                          item.location_for_diagnostics(),
+                                          name,
+                                          repr_name);
+                    ctx.diagnostic(DiagnosticSeverity::Error, message);
+                }
+            }
+        }
+
         let enum_ = builder.build(ctx, enum_rust_ty, result);
         result.push(enum_);
+
+        if !rust_variants.is_empty() &&
+           !item.annotations().disallow_conversions() {
+            enum_conversions(ctx,
+                             &name,
+                             repr_name,
+                             &rust_variants,
+                             sentinel,
+                             result);
+        }
+    }
+}
+
+/// Generate the conversions between a rustified enum and its underlying
+/// integer type: a `From` impl for the integer, and a `from_raw` method to go
+/// back, which only accepts the values of the variants.
+///
+/// Values shared by several C variants map to the one we kept as a variant,
+/// since the others are just constants aliasing it. The value of the hidden
+/// variant of a non-exhaustive enum, the `sentinel`, maps to `None`, since it
+/// doesn't stand for any C value.
+fn enum_conversions<'a>(ctx: &BindgenContext,
+                        name: &str,
+                        repr_name: &str,
+                        variants: &[(EnumVariantValue, String)],
+                        sentinel: Option<EnumVariantValue>,
+                        result: &mut CodegenResult<'a>) {
+    let enum_ident = ctx.rust_ident_raw(name);
+    let repr_ident = ctx.rust_ident_raw(repr_name);
+
+    let from_impl = quote_item!(ctx.ext_cx(),
+        impl From<$enum_ident> for $repr_ident {
+            fn from(value: $enum_ident) -> Self { value as $repr_ident }
+        }
+    )
+        .unwrap();
+    result.push(from_impl);
+
+    let pat = |node| {
+        P(ast::Pat {
+            id: ast::DUMMY_NODE_ID,
+            node: node,
+            span: ctx.span(),
+        })
+    };
+
+    let lit = |val| {
+        let expr = match val {
+            EnumVariantValue::Signed(v) => helpers::ast_ty::int_expr(v),
+            EnumVariantValue::Unsigned(v) => {
+                aster::AstBuilder::new().expr().uint(v)
+            }
+        };
+        pat(ast::PatKind::Lit(expr))
+    };
+
+    let mut arms = vec![];
+    for &(val, ref variant_name) in variants {
+        let variant_ident = ctx.rust_ident(variant_name);
+        arms.push(ast::Arm {
+            attrs: vec![],
+            pats: vec![lit(val)],
+            guard: None,
+            body: quote_expr!(ctx.ext_cx(), Some($enum_ident::$variant_ident)),
+        });
+    }
+    if let Some(val) = sentinel {
+        arms.push(ast::Arm {
+            attrs: vec![],
+            pats: vec![lit(val)],
+            guard: None,
+            body: quote_expr!(ctx.ext_cx(), None),
+        });
     }
+    arms.push(ast::Arm {
+        attrs: vec![],
+        pats: vec![pat(ast::PatKind::Wild)],
+        guard: None,
+        body: quote_expr!(ctx.ext_cx(), None),
+    });
+
+    let match_expr = aster::AstBuilder::new()
+        .expr()
+        .build_expr_kind(ast::ExprKind::Match(quote_expr!(ctx.ext_cx(), value),
+                                              arms));
+
+    let from_raw = quote_item!(ctx.ext_cx(),
+        impl $enum_ident {
+            pub fn from_raw(value: $repr_ident) -> Option<Self> {
+                $match_expr
+            }
+        }
+    )
+        .unwrap();
+    result.push(from_raw);
 }
 
 /// Fallible conversion to an opaque blob.
@@ -2454,9 +4410,24 @@ impl TryToRustTy for Type {
             TypeKind::NullPtr => {
                 Ok(raw_type(ctx, "c_void").to_ptr(true, ctx.span()))
             }
+            TypeKind::Int(ik) if ctx.options().fixed_width_integers &&
+                                 ik.is_c_integer() => {
+                let size = self.layout(ctx)
+                    .map(|layout| layout.size)
+                    .or_else(|| ik.known_size())
+                    .expect("C integer without layout?");
+                Ok(fixed_width_int_type(ik.is_signed(), size))
+            }
             TypeKind::Int(ik) => {
                 match ik {
-                    IntKind::Bool => Ok(aster::ty::TyBuilder::new().bool()),
+                    IntKind::Bool => {
+                        Ok(match ctx.options().c_bool_type {
+                            CBoolType::Bool => {
+                                aster::ty::TyBuilder::new().bool()
+                            }
+                            CBoolType::U8 => aster::ty::TyBuilder::new().u8(),
+                        })
+                    }
                     IntKind::Char => Ok(raw_type(ctx, "c_schar")),
                     IntKind::UChar => Ok(raw_type(ctx, "c_uchar")),
                     IntKind::Short => Ok(raw_type(ctx, "c_short")),
@@ -2493,11 +4464,7 @@ impl TryToRustTy for Type {
                 let float_path = float_kind_rust_type(ctx, fk);
 
                 ctx.generated_bindegen_complex();
-                Ok(if ctx.options().enable_cxx_namespaces {
-                    quote_ty!(ctx.ext_cx(), root::__BindgenComplex<$float_path>)
-                } else {
-                    quote_ty!(ctx.ext_cx(), __BindgenComplex<$float_path>)
-                })
+                Ok(helper_type(ctx, "__BindgenComplex", vec![float_path]))
             }
             TypeKind::Function(ref fs) => {
                 // We can't rely on the sizeof(Option<NonZero<_>>) ==
@@ -2513,18 +4480,39 @@ impl TryToRustTy for Type {
                 let ty = item.try_to_rust_ty(ctx, &())?;
                 Ok(aster::ty::TyBuilder::new().array(len).build(ty))
             }
-            TypeKind::Enum(..) => {
-                let path = item.namespace_aware_canonical_path(ctx);
-                Ok(aster::AstBuilder::new()
-                    .ty()
-                    .path()
-                    .ids(path)
-                    .build())
+            TypeKind::ConstGenericArray(item, len) => {
+                let ty = item.try_to_rust_ty(ctx, &())?;
+                let len = ctx.rust_ident(&len.canonical_name(ctx));
+                Ok(quote_ty!(ctx.ext_cx(), [$ty; $len]))
+            }
+            TypeKind::Enum(ref enum_) => {
+                let style = enum_style(ctx, enum_, item);
+                if ctx.options().enums_as_underlying_type &&
+                   (style == EnumVariation::Consts ||
+                    style == EnumVariation::ModuleConsts) {
+                    let name = enum_underlying_type(ctx, enum_, item);
+                    return Ok(aster::ty::TyBuilder::new().id(name));
+                }
+                Ok(enum_path_ty(ctx, item, style))
             }
             TypeKind::TemplateInstantiation(ref inst) => {
+                if let Some(name) = utils::stl_blob_name(ctx, item) {
+                    return Ok(helper_type(ctx, &name, vec![]));
+                }
                 inst.try_to_rust_ty(ctx, self)
             }
-            TypeKind::ResolvedTypeRef(inner) => inner.try_to_rust_ty(ctx, &()),
+            TypeKind::ResolvedTypeRef(inner) => {
+                // References keep the spelling of the type they were resolved
+                // from, which may be a well-known typedef we can translate
+                // directly.
+                let known = self.name().and_then(|name| {
+                    utils::type_from_named(ctx, name, inner)
+                });
+                match known {
+                    Some(ty) => Ok(ty),
+                    None => inner.try_to_rust_ty(ctx, &()),
+                }
+            }
             TypeKind::TemplateAlias(inner, _) |
             TypeKind::Alias(inner) => {
                 let template_params = item.used_template_params(ctx)
@@ -2566,7 +4554,7 @@ impl TryToRustTy for Type {
                                ctx.span()))
             }
             TypeKind::Pointer(inner) |
-            TypeKind::Reference(inner) => {
+            TypeKind::Reference(inner, _) => {
                 let inner = ctx.resolve_item(inner);
                 let inner_ty = inner.expect_type();
 
@@ -2576,9 +4564,17 @@ impl TryToRustTy for Type {
                 let ty = inner.to_rust_ty_or_opaque(ctx, &());
 
                 // Avoid the first function pointer level, since it's already
-                // represented in Rust.
+                // represented in Rust. Typedefs of function types aren't
+                // nullable on their own though, so they need the `Option`.
                 if inner_ty.canonical_type(ctx).is_function() {
-                    Ok(ty)
+                    if ctx.resolve_type_through_type_refs(inner.id())
+                        .is_function() {
+                        Ok(ty)
+                    } else {
+                        let prefix = ctx.trait_prefix();
+                        Ok(quote_ty!(ctx.ext_cx(),
+                                     ::$prefix::option::Option<$ty>))
+                    }
                 } else {
                     let is_const = self.is_const() ||
                                    inner.expect_type().is_const();
@@ -2590,9 +4586,24 @@ impl TryToRustTy for Type {
                 let ident = ctx.rust_ident(&name);
                 Ok(quote_ty!(ctx.ext_cx(), $ident))
             }
+            TypeKind::ObjCId |
+            TypeKind::ObjCSel if !ctx.options().enable_objc => {
+                let void = raw_type(ctx, "c_void");
+                Ok(void.to_ptr(false, ctx.span()))
+            }
             TypeKind::ObjCSel => Ok(quote_ty!(ctx.ext_cx(), objc::runtime::Sel)),
-            TypeKind::ObjCId |
-            TypeKind::ObjCInterface(..) => Ok(quote_ty!(ctx.ext_cx(), id)),
+            TypeKind::ObjCId => Ok(quote_ty!(ctx.ext_cx(), id)),
+            TypeKind::ObjCInterface(ref interface) => {
+                if ctx.options().enable_objc {
+                    Ok(quote_ty!(ctx.ext_cx(), id))
+                } else if interface.is_class() {
+                    let name = item.canonical_name(ctx);
+                    let ident = ctx.rust_ident(&name);
+                    Ok(quote_ty!(ctx.ext_cx(), $ident))
+                } else {
+                    Ok(raw_type(ctx, "c_void"))
+                }
+            }
             ref u @ TypeKind::UnresolvedTypeRef(..) => {
                 unreachable!("Should have been resolved after parsing {:?}!", u)
             }
@@ -2633,6 +4644,15 @@ impl TryToRustTy for TemplateInstantiation {
             }
         };
 
+        // If we don't know the value of every `const` generic argument, fall
+        // back to a blob with the layout of this instantiation.
+        let num_const_params = decl_params.iter()
+            .filter(|p| ctx.is_const_template_param(**p))
+            .count();
+        if self.const_template_arguments().len() != num_const_params {
+            return Err(error::Error::InstantiationOfOpaqueType);
+        }
+
         // TODO: If the decl type is a template class/struct
         // declaration's member template declaration, it could rely on
         // generic template parameters from its outer template
@@ -2642,14 +4662,25 @@ impl TryToRustTy for TemplateInstantiation {
         // that reconstruction at this time.
 
         if let ast::TyKind::Path(_, ref mut path) = ty.node {
-            let template_args = self.template_arguments()
-                .iter()
-                .zip(decl_params.iter())
-                // Only pass type arguments for the type parameters that
-                // the decl uses.
-                .filter(|&(_, param)| ctx.uses_template_parameter(decl, *param))
-                .map(|(arg, _)| arg.try_to_rust_ty(ctx, &()))
-                .collect::<error::Result<Vec<_>>>()?;
+            // The type and `const` arguments are kept apart, but each kind
+            // is in the order of its parameters.
+            let mut type_args = self.template_arguments().iter();
+            let mut const_args = self.const_template_arguments().iter();
+            let mut template_args = vec![];
+            for param in &decl_params {
+                // Only pass arguments for the parameters that the decl uses.
+                let used = ctx.uses_template_parameter(decl, *param);
+                if ctx.is_const_template_param(*param) {
+                    if let (true, Some(value)) = (used, const_args.next()) {
+                        // See `utils::template_generics`.
+                        let value = ctx.rust_ident_raw(&value.to_string());
+                        template_args.push(aster::ty::TyBuilder::new()
+                            .id(value));
+                    }
+                } else if let (true, Some(arg)) = (used, type_args.next()) {
+                    template_args.push(arg.try_to_rust_ty(ctx, &())?);
+                }
+            }
 
             path.segments.last_mut().unwrap().parameters = if
                 template_args.is_empty() {
@@ -2707,12 +4738,12 @@ impl CodeGenerator for Function {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   _whitelisted_items: &ItemSet,
+                   _whitelisted_items: &ItemIdSet,
                    item: &Item) {
         debug!("<Function as CodeGenerator>::codegen: item = {:?}", item);
 
         let name = self.name();
-        let mut canonical_name = item.canonical_name(ctx);
+        let mut canonical_name = item.canonical_name(ctx).to_string();
         let mangled_name = self.mangled_name();
 
         {
@@ -2733,7 +4764,37 @@ impl CodeGenerator for Function {
             _ => panic!("Signature kind is not a Function: {:?}", signature),
         };
 
-        let fndecl = utils::rust_fndecl_from_signature(ctx, signature_item);
+        if let Some(body) = self.macro_body() {
+            result.push(macro_fn(ctx, item, &canonical_name, signature, body));
+            return;
+        }
+
+        let mut fndecl = utils::rust_fndecl_from_signature(ctx, signature_item);
+
+        // The C++ ABI returns the classes that aren't trivially destructible
+        // through a hidden pointer, whatever their size, while Rust would
+        // return them in registers if they're small enough. So we make that
+        // pointer explicit, and wrap the function to return the value anyway.
+        //
+        // The wrappers of methods would need to know about it too, so this is
+        // only done for free functions.
+        let returns_via_out_pointer =
+            ctx.resolve_item(item.parent_id()).is_module() &&
+            returns_non_trivial_class(ctx, signature);
+        if returns_via_out_pointer {
+            let ret_ty = ctx.resolve_item(signature.return_type())
+                .to_rust_ty_or_opaque(ctx, &())
+                .to_ptr(false, ctx.span());
+            let decl = fndecl.unwrap();
+            let mut builder = aster::fn_decl::FnDeclBuilder::new()
+                .arg_id(OUT_POINTER_NAME)
+                .with_ty(ret_ty)
+                .with_args(decl.inputs);
+            if decl.variadic {
+                builder = builder.variadic();
+            }
+            fndecl = builder.build(ast::FunctionRetTy::Default(ctx.span()));
+        }
 
         let mut attributes = vec![];
 
@@ -2741,6 +4802,22 @@ impl CodeGenerator for Function {
             if let Some(comment) = item.comment() {
                 attributes.push(attributes::doc(comment));
             }
+            attributes.extend(argument_docs(ctx, signature)
+                .iter()
+                .map(|line| attributes::doc(line)));
+        }
+        attributes.extend(source_annotation(ctx, item));
+        if let Some(note) = self.visibility().doc_note() {
+            attributes.push(attributes::doc(note));
+        }
+        if signature.is_noexcept() {
+            attributes.push(attributes::doc("/// Declared `noexcept` in C++."));
+        }
+        attributes.extend(reference_notes(ctx, signature)
+            .iter()
+            .map(|note| attributes::doc(note)));
+        if ctx.options().cold_noreturn_functions && signature.is_divergent() {
+            attributes.push(attributes::cold());
         }
 
         if let Some(mangled) = mangled_name {
@@ -2770,13 +4847,202 @@ impl CodeGenerator for Function {
 
         let item = ForeignModBuilder::new(signature.abi()
                 .expect("Invalid abi for function!"))
+            .with_attrs(dylib_link(ctx, self.dll_storage()))
             .with_foreign_item(foreign_item)
             .build(ctx);
 
         result.push(item);
+
+        // There's no way to forward the arguments of a variadic function.
+        if returns_via_out_pointer && !signature.is_variadic() {
+            result.push(out_pointer_wrapper(ctx, &canonical_name, signature));
+        }
+    }
+}
+
+/// The name of the explicit out-pointer of the functions returning a class
+/// through a hidden pointer.
+const OUT_POINTER_NAME: &'static str = "__bindgen_ret";
+
+/// Whether the given function returns a C++ class that isn't trivially
+/// destructible, copyable or movable, which the C++ ABI returns through a
+/// hidden pointer.
+fn returns_non_trivial_class(ctx: &BindgenContext, sig: &FunctionSig) -> bool {
+    if sig.is_divergent() {
+        return false;
+    }
+    match *ctx.resolve_type(sig.return_type()).canonical_type(ctx).kind() {
+        TypeKind::Comp(ref info) => {
+            info.has_destructor(ctx) || info.has_non_trivial_copy(ctx)
+        }
+        _ => false,
+    }
+}
+
+/// Generate `<name>_by_value`, which calls the given function, declared with
+/// an explicit out-pointer, and returns the value it wrote there.
+fn out_pointer_wrapper(ctx: &BindgenContext,
+                       name: &str,
+                       signature: &FunctionSig)
+                       -> P<ast::Item> {
+    let fn_args = utils::fnsig_arguments(ctx, signature);
+    let fn_ret = utils::fnsig_return_ty(ctx, signature);
+    let decl = aster::fn_decl::FnDeclBuilder::new()
+        .with_args(fn_args)
+        .build(fn_ret);
+
+    let prefix = ctx.trait_prefix();
+    let out_pointer = ctx.rust_ident_raw(OUT_POINTER_NAME);
+    let mut args = vec![];
+    args.push(quote_expr!(ctx.ext_cx(), &mut $out_pointer));
+    args.extend(helpers::ast_ty::arguments_from_signature(signature, ctx));
+    let call = aster::expr::ExprBuilder::new()
+        .call()
+        .id(name)
+        .with_args(args)
+        .build();
+
+    let mut stmts = vec![];
+    stmts.push(quote_stmt!(ctx.ext_cx(),
+                           let mut $out_pointer =
+                               ::$prefix::mem::uninitialized())
+        .unwrap());
+    stmts.push(ast::Stmt {
+        id: ast::DUMMY_NODE_ID,
+        node: ast::StmtKind::Semi(call),
+        span: ctx.span(),
+    });
+    stmts.push(quote_stmt!(ctx.ext_cx(), $out_pointer).unwrap());
+
+    let block = ast::Block {
+        stmts: stmts,
+        id: ast::DUMMY_NODE_ID,
+        rules: ast::BlockCheckMode::Default,
+        span: ctx.span(),
+    };
+
+    let mut attrs = vec![];
+    if ctx.options().inline_wrappers {
+        attrs.push(attributes::inline());
+    }
+
+    let kind = ast::ItemKind::Fn(decl,
+                                 ast::Unsafety::Unsafe,
+                                 respan(ctx.span(), ast::Constness::NotConst),
+                                 Abi::Rust,
+                                 ast::Generics::default(),
+                                 P(block));
+    aster::AstBuilder::new()
+        .item()
+        .pub_()
+        .with_attrs(attrs)
+        .build_item_kind(format!("{}_by_value", name), kind)
+}
+
+/// Generate the inline function for a function-like macro, see
+/// `Builder::emit_macro_fns`.
+fn macro_fn(ctx: &BindgenContext,
+            item: &Item,
+            name: &str,
+            signature: &FunctionSig,
+            body: &MacroFnBody)
+            -> P<ast::Item> {
+    let fn_args = utils::fnsig_arguments(ctx, signature);
+    let fn_ret = utils::fnsig_return_ty(ctx, signature);
+    let decl = aster::fn_decl::FnDeclBuilder::new()
+        .with_args(fn_args)
+        .build(fn_ret);
+
+    let params = helpers::ast_ty::arguments_from_signature(signature, ctx);
+    let expr = match *body {
+        MacroFnBody::Call(callee, ref args) => {
+            let callee = ctx.resolve_item(callee).canonical_name(ctx);
+            let args = args.iter().map(|arg| match *arg {
+                MacroArg::Param(i) => params[i].clone(),
+                MacroArg::Int(val) => helpers::ast_ty::int_expr(val),
+            });
+            aster::expr::ExprBuilder::new()
+                .call()
+                .id(callee)
+                .with_args(args)
+                .build()
+        }
+        MacroFnBody::Expr(ref expr) => macro_expr(expr, &params, false),
+    };
+
+    let block = ast::Block {
+        stmts: vec![ast::Stmt {
+            id: ast::DUMMY_NODE_ID,
+            node: ast::StmtKind::Expr(expr),
+            span: ctx.span(),
+        }],
+        id: ast::DUMMY_NODE_ID,
+        rules: ast::BlockCheckMode::Default,
+        span: ctx.span(),
+    };
+
+    let mut attrs = vec![];
+    attrs.extend(source_annotation(ctx, item));
+    if ctx.options().inline_wrappers {
+        attrs.push(attributes::inline());
     }
+
+    let kind = ast::ItemKind::Fn(decl,
+                                 ast::Unsafety::Unsafe,
+                                 respan(ctx.span(), ast::Constness::NotConst),
+                                 Abi::Rust,
+                                 ast::Generics::default(),
+                                 P(block));
+    aster::AstBuilder::new()
+        .item()
+        .pub_()
+        .with_attrs(attrs)
+        .build_item_kind(name, kind)
 }
 
+/// Translate the integer expression of a function-like macro, where `params`
+/// are the expressions for its parameters. Operations nested in others are
+/// parenthesized, so precedence doesn't matter.
+fn macro_expr(expr: &MacroExpr,
+              params: &[P<ast::Expr>],
+              nested: bool)
+              -> P<ast::Expr> {
+    let builder = aster::AstBuilder::new().expr();
+    let expr = match *expr {
+        MacroExpr::Param(i) => return params[i].clone(),
+        MacroExpr::Int(val) => return helpers::ast_ty::int_expr(val),
+        MacroExpr::Unary(op, ref operand) => {
+            let op = match op {
+                UnaryOp::Neg => ast::UnOp::Neg,
+                UnaryOp::Not => ast::UnOp::Not,
+            };
+            builder.unary(op).build(macro_expr(operand, params, true))
+        }
+        MacroExpr::Binary(op, ref lhs, ref rhs) => {
+            let op = match op {
+                BinaryOp::Or => ast::BinOpKind::BitOr,
+                BinaryOp::Xor => ast::BinOpKind::BitXor,
+                BinaryOp::And => ast::BinOpKind::BitAnd,
+                BinaryOp::Shl => ast::BinOpKind::Shl,
+                BinaryOp::Shr => ast::BinOpKind::Shr,
+                BinaryOp::Add => ast::BinOpKind::Add,
+                BinaryOp::Sub => ast::BinOpKind::Sub,
+                BinaryOp::Mul => ast::BinOpKind::Mul,
+                BinaryOp::Div => ast::BinOpKind::Div,
+                BinaryOp::Rem => ast::BinOpKind::Rem,
+            };
+            builder.binary(op)
+                .build(macro_expr(lhs, params, true))
+                .build(macro_expr(rhs, params, true))
+        }
+    };
+
+    if nested {
+        aster::AstBuilder::new().expr().paren().build(expr)
+    } else {
+        expr
+    }
+}
 
 fn objc_method_codegen(ctx: &BindgenContext,
                        method: &ObjCMethod,
@@ -2866,8 +5132,15 @@ impl CodeGenerator for ObjCInterface {
     fn codegen<'a>(&self,
                    ctx: &BindgenContext,
                    result: &mut CodegenResult<'a>,
-                   _whitelisted_items: &ItemSet,
-                   _: &Item) {
+                   _whitelisted_items: &ItemIdSet,
+                   item: &Item) {
+        if !ctx.options().enable_objc {
+            if self.is_class() {
+                result.push(forward_declaration(ctx, item));
+            }
+            return;
+        }
+
         let mut impl_items = vec![];
         let mut trait_items = vec![];
 
@@ -2912,17 +5185,64 @@ impl CodeGenerator for ObjCInterface {
 
 
 
-pub fn codegen(context: &mut BindgenContext) -> Vec<P<ast::Item>> {
+pub fn codegen(context: &mut BindgenContext)
+               -> Result<Vec<P<ast::Item>>, ()> {
     context.gen(|context| {
         let counter = Cell::new(0);
         let mut result = CodegenResult::new(&counter);
 
         debug!("codegen: {:?}", context.options());
 
-        let whitelisted_items: ItemSet = context.whitelisted_items().collect();
+        let whitelisted_items: ItemIdSet = context.whitelisted_items().collect();
+
+        let mut any_missing_layout = false;
+        for &(user, id) in context.types_missing_from_whitelist() {
+            // There's no way to lay out `user` without the layout of a struct
+            // or union, but aliases and enums can be provided by the user.
+            let severity = match *context.resolve_type(id).kind() {
+                TypeKind::Comp(..) |
+                TypeKind::Opaque => {
+                    any_missing_layout = true;
+                    DiagnosticSeverity::Error
+                }
+                _ => DiagnosticSeverity::Warning,
+            };
+            let message = format!("`{}` uses `{}` by value, but it isn't \
+                                   whitelisted, and whitelisting isn't \
+                                   recursive",
+                                  user.canonical_path(context)[1..]
+                                      .join("::"),
+                                  id.canonical_path(context)[1..]
+                                      .join("::"));
+            context.diagnostic(severity, message);
+        }
+        if any_missing_layout {
+            return Err(());
+        }
+
+        if context.options().strict {
+            let messages: Vec<_> = context
+                .opaque_fallbacks_in(&whitelisted_items)
+                .iter()
+                .map(|fallback| {
+                    format!("`{}` (`{}`) would be approximated (strict \
+                             mode): {}",
+                            fallback.id.canonical_path(context)[1..]
+                                .join("::"),
+                            fallback.spelling,
+                            fallback.reason)
+                })
+                .collect();
+            if !messages.is_empty() {
+                for message in messages {
+                    context.diagnostic(DiagnosticSeverity::Error, message);
+                }
+                return Err(());
+            }
+        }
 
         if context.options().emit_ir {
-            for &id in whitelisted_items.iter() {
+            for id in whitelisted_items.iter() {
                 let item = context.resolve_item(id);
                 println!("ir: {:?} = {:#?}", id, item);
             }
@@ -2938,19 +5258,51 @@ pub fn codegen(context: &mut BindgenContext) -> Vec<P<ast::Item>> {
         context.resolve_item(context.root_module())
             .codegen(context, &mut result, &whitelisted_items, &());
 
-        result.items
+        let mut items =
+            visibility::rewrite(result.items,
+                                context.options().default_visibility);
+        if context.options().combined_constants_module {
+            let name = constants::CONSTANTS_MODULE_NAME;
+            if items.iter().any(|item| &*item.ident.name.as_str() == name) {
+                let message = format!("The combined constants module clashes \
+                                       with the top-level item named `{}`, \
+                                       rename the root module or the header",
+                                      name);
+                context.diagnostic(DiagnosticSeverity::Error, message);
+                return Err(());
+            }
+
+            let vis = visibility::top_level(context.options().default_visibility);
+            let module = constants::combine(&items, vis);
+            items.extend(module);
+        }
+        if context.options().sort_semantically {
+            items = sort::sort(items);
+        }
+        if context.options().allow_lints_per_item {
+            Ok(lints::allow_where_needed(items))
+        } else {
+            Ok(items)
+        }
     })
 }
 
 mod utils {
-    use super::{error, TryToRustTy, ToRustTyOrOpaque};
+    use super::{error, TryToRustTy, ToRustTyOrOpaque, layout_test_cfg};
+    use super::helpers::{BlobTyBuilder, attributes};
+    use DiagnosticSeverity;
     use aster;
     use ir::context::{BindgenContext, ItemId};
+    use ir::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
     use ir::function::FunctionSig;
+    use ir::int::KnownTypedef;
     use ir::item::{Item, ItemCanonicalPath};
+    use ir::item_id_set::ItemIdSet;
     use ir::ty::TypeKind;
+    use std::collections::{HashMap, HashSet};
     use std::mem;
     use syntax::ast;
+    use syntax::parse;
     use syntax::ptr::P;
 
     pub fn prepend_objc_header(ctx: &BindgenContext,
@@ -3009,6 +5361,16 @@ mod utils {
                 pub unsafe fn as_mut(&mut self) -> &mut T {
                     ::$prefix::mem::transmute(self)
                 }
+
+                #[inline]
+                pub fn as_ptr(&self) -> *const T {
+                    self as *const Self as *const T
+                }
+
+                #[inline]
+                pub fn as_mut_ptr(&mut self) -> *mut T {
+                    self as *mut Self as *mut T
+                }
             }
         )
             .unwrap();
@@ -3097,6 +5459,20 @@ mod utils {
                 pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
                     ::$prefix::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
                 }
+
+                // There's no way to know the length of the array here, so
+                // these aren't bounds-checked, just like in C. Use `as_slice`
+                // with the length when it's known.
+                #[inline]
+                pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+                    &*self.as_ptr().offset(index as isize)
+                }
+
+                #[inline]
+                pub unsafe fn get_unchecked_mut(&mut self, index: usize)
+                                                -> &mut T {
+                    &mut *self.as_mut_ptr().offset(index as isize)
+                }
             }
         )
             .unwrap();
@@ -3153,6 +5529,289 @@ mod utils {
         result.extend(old_items.into_iter());
     }
 
+    pub fn prepend_c_void_stub(ctx: &BindgenContext,
+                               result: &mut Vec<P<ast::Item>>) {
+        // The same definition libstd used before `core::ffi::c_void`.
+        let c_void = quote_item!(ctx.ext_cx(),
+            #[repr(u8)]
+            pub enum c_void {
+                __variant1,
+                __variant2,
+            }
+        )
+            .unwrap();
+
+        let items = vec![c_void];
+        let old_items = mem::replace(result, items);
+        result.extend(old_items.into_iter());
+    }
+
+    /// The name of the blob we generate for the given standard library
+    /// template instantiation, or `None` if it isn't one, or we can't generate
+    /// a blob for it, in which case it's treated like any other instantiation.
+    pub fn stl_blob_name(ctx: &BindgenContext, item: &Item) -> Option<String> {
+        if !item.is_stl_blob(ctx) {
+            return None;
+        }
+
+        let ty = item.expect_type();
+        if ty.layout(ctx).is_none() {
+            return None;
+        }
+
+        let spelling = match *ty.kind() {
+            TypeKind::TemplateInstantiation(ref inst) => inst.spelling(),
+            _ => None,
+        };
+
+        // `const std::vector<const int *>` -> `std_vector_const_int_ptr`.
+        //
+        // The qualifiers of the instantiation itself don't change its layout,
+        // but the ones of its arguments tell different instantiations apart.
+        spelling.map(|spelling| {
+            let mut spelling = spelling.trim();
+            loop {
+                let unqualified = ["const ", "volatile "]
+                    .iter()
+                    .find(|q| spelling.starts_with(*q))
+                    .map(|q| spelling[q.len()..].trim_left());
+                match unqualified {
+                    Some(rest) => spelling = rest,
+                    None => break,
+                }
+            }
+
+            let mut words = vec![];
+            let mut word = String::new();
+            for c in spelling.chars() {
+                if c.is_alphanumeric() || c == '_' {
+                    word.push(c);
+                    continue;
+                }
+                if !word.is_empty() {
+                    words.push(mem::replace(&mut word, String::new()));
+                }
+                match c {
+                    '*' => words.push("ptr".to_owned()),
+                    '&' => words.push("ref".to_owned()),
+                    _ => {}
+                }
+            }
+            if !word.is_empty() {
+                words.push(word);
+            }
+            words.join("_")
+        })
+    }
+
+    pub fn prepend_stl_blobs(ctx: &BindgenContext,
+                             whitelisted_items: &ItemIdSet,
+                             result: &mut Vec<P<ast::Item>>) {
+        let mut names_seen = HashSet::new();
+        let mut items = vec![];
+
+        for id in whitelisted_items.iter() {
+            let item = ctx.resolve_item(id);
+            let name = match stl_blob_name(ctx, item) {
+                Some(name) => name,
+                None => continue,
+            };
+
+            // The same instantiation can show up as more than one item.
+            if !names_seen.insert(name.clone()) {
+                continue;
+            }
+
+            let layout = item.expect_type().layout(ctx).unwrap();
+            let opaque = layout.opaque();
+
+            let mut attributes = vec![];
+            if let TypeKind::TemplateInstantiation(ref inst) =
+                *item.expect_type().kind() {
+                let comment = format!("/// Opaque blob for `{}`.",
+                                      inst.spelling().unwrap());
+                attributes.push(attributes::doc(&comment));
+            }
+            attributes.push(attributes::repr("C"));
+
+            let mut derives = vec![];
+            if item.can_derive_debug(ctx, ()) {
+                derives.push("Debug");
+            }
+
+            if item.can_derive_default(ctx, ()) {
+                derives.push("Default");
+            }
+
+            // It's only bytes, so it's fine to copy it around regardless of
+            // what the C++ type does.
+            if opaque.can_derive_copy(ctx, ()) {
+                derives.extend(&["Copy", "Clone"]);
+            }
+
+            if !derives.is_empty() {
+                attributes.push(attributes::derives(&derives));
+            }
+
+            let rust_name = ctx.rust_ident_raw(&name);
+            let blob_ty = BlobTyBuilder::new(layout).build();
+            let blob = quote_item!(ctx.ext_cx(),
+                pub struct $rust_name {
+                    pub _bindgen_opaque_blob: $blob_ty,
+                }
+            )
+                .unwrap()
+                .map(|mut blob| {
+                    blob.attrs = attributes;
+                    blob
+                });
+            items.push(blob);
+
+            if !ctx.options().layout_tests {
+                continue;
+            }
+
+            let fn_name = format!("bindgen_test_layout_{}", name);
+            let fn_name = ctx.rust_ident_raw(&fn_name);
+            let prefix = ctx.trait_prefix();
+            let size_of_expr = quote_expr!(ctx.ext_cx(),
+                                ::$prefix::mem::size_of::<$rust_name>());
+            let align_of_expr = quote_expr!(ctx.ext_cx(),
+                                ::$prefix::mem::align_of::<$rust_name>());
+            let size = layout.size;
+            let align = layout.align;
+            let test = quote_item!(ctx.ext_cx(),
+                #[test]
+                fn $fn_name() {
+                    assert_eq!($size_of_expr,
+                               $size,
+                               concat!("Size of: ", stringify!($rust_name)));
+                    assert_eq!($align_of_expr,
+                               $align,
+                               concat!("Alignment of ", stringify!($rust_name)));
+                })
+                .unwrap()
+                .map(|mut item| {
+                    item.attrs.extend(layout_test_cfg(ctx));
+                    item
+                });
+            items.push(test);
+        }
+
+        let old_items = mem::replace(result, items);
+        result.extend(old_items.into_iter());
+    }
+
+    pub fn prepend_bitfield_unit_type(ctx: &BindgenContext,
+                                      result: &mut Vec<P<ast::Item>>) {
+        // We keep the source of the unit in its own file so that it can be
+        // unit-tested, see `bitfield_unit.rs`. This module is expanded into
+        // `OUT_DIR`, and compilers disagree on what a relative path in there
+        // is relative to, so use an absolute one.
+        let source = include_str!(concat!(env!("CARGO_MANIFEST_DIR"),
+                                          "/src/codegen/bitfield_unit.rs"));
+        let krate = match parse::parse_crate_from_source_str(
+            "bitfield_unit.rs".into(),
+            source.into(),
+            ctx.ext_cx().parse_sess()) {
+            Ok(krate) => krate,
+            Err(mut e) => {
+                e.emit();
+                panic!("Couldn't parse the bitfield unit source");
+            }
+        };
+
+        let items = krate.module.items;
+        let old_items = mem::replace(result, items);
+        result.extend(old_items.into_iter());
+    }
+
+    /// Generate a `pub use` re-export at the crate root for every type inside
+    /// a namespace of the given root module, flattening its path into a
+    /// single name, like `pub use root::foo::Bar as foo_Bar;`.
+    ///
+    /// The flat name is the one the type would get without
+    /// `enable_cxx_namespaces`. Different paths can flatten to the same name,
+    /// like `a::b_c` and `a_b::c`, in which case we warn about it, and later
+    /// types get the first free numeric suffix, like overloaded functions do.
+    pub fn flat_aliases(ctx: &BindgenContext,
+                        root: &ast::Item)
+                        -> Vec<P<ast::Item>> {
+        let mut aliases = vec![];
+        let mut taken = HashMap::new();
+        let mut path = vec![root.ident];
+        collect_flat_aliases(ctx, root, &mut path, &mut taken, &mut aliases);
+        aliases
+    }
+
+    /// `taken` maps the flat names we've already used to the paths they
+    /// alias.
+    fn collect_flat_aliases(ctx: &BindgenContext,
+                            module: &ast::Item,
+                            path: &mut Vec<ast::Ident>,
+                            taken: &mut HashMap<String, String>,
+                            aliases: &mut Vec<P<ast::Item>>) {
+        let items = match module.node {
+            ast::ItemKind::Mod(ref m) => &m.items,
+            _ => return,
+        };
+
+        for item in items {
+            match item.node {
+                ast::ItemKind::Mod(..) => {
+                    path.push(item.ident);
+                    collect_flat_aliases(ctx, item, path, taken, aliases);
+                    path.pop();
+                }
+                ast::ItemKind::Struct(..) |
+                ast::ItemKind::Union(..) |
+                ast::ItemKind::Enum(..) |
+                ast::ItemKind::Ty(..) if path.len() > 1 => {
+                    let mut names = path[1..]
+                        .iter()
+                        .map(|ident| ident.name.as_str().to_string())
+                        .collect::<Vec<_>>();
+                    names.push(item.ident.name.as_str().to_string());
+                    let full_path = names.join("::");
+                    let flat_name = names.join("_");
+
+                    let mut alias_name = flat_name.clone();
+                    let mut suffix = 0;
+                    while taken.contains_key(&alias_name) {
+                        suffix += 1;
+                        alias_name = format!("{}{}", flat_name, suffix);
+                    }
+                    if suffix > 0 {
+                        let message = format!("Both `{}` and `{}` flatten to \
+                                               `{}`, aliasing the latter as \
+                                               `{}` instead",
+                                              taken[&flat_name],
+                                              full_path,
+                                              flat_name,
+                                              alias_name);
+                        ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                    }
+                    taken.insert(alias_name.clone(), full_path);
+
+                    let mut segments = path.clone();
+                    segments.push(item.ident);
+                    let target = aster::AstBuilder::new()
+                        .path()
+                        .ids(segments)
+                        .build();
+                    let alias = aster::AstBuilder::new()
+                        .item()
+                        .pub_()
+                        .use_()
+                        .build(target)
+                        .as_(ctx.rust_ident_raw(&alias_name));
+                    aliases.push(alias);
+                }
+                _ => {}
+            }
+        }
+    }
+
     pub fn build_templated_path(item: &Item,
                                 ctx: &BindgenContext,
                                 template_params: Vec<ItemId>)
@@ -3188,6 +5847,54 @@ mod utils {
         Ok(builder.build())
     }
 
+    /// The generics of the struct we generate for a template with the given
+    /// template parameters, like `<T, const N: usize>`.
+    ///
+    /// The syntax tree we build predates `const` generics and has no node for
+    /// them, so a `const` parameter is a type parameter named `const N` and
+    /// bounded by `usize`, which prints the same.
+    pub fn template_generics(ctx: &BindgenContext,
+                             template_params: &[ItemId])
+                             -> ast::Generics {
+        let mut generics = aster::AstBuilder::new().generics();
+        for param in template_params {
+            let name = ctx.resolve_type(*param).name().unwrap();
+            if ctx.is_const_template_param(*param) {
+                let name = format!("const {}", ctx.rust_mangle(name));
+                generics = generics.ty_param(ctx.rust_ident_raw(&name))
+                    .trait_bound("usize")
+                    .build()
+                    .build();
+            } else {
+                generics = generics.ty_param_id(ctx.rust_ident(name));
+            }
+        }
+        generics.build()
+    }
+
+    /// Build the type that the `impl` blocks of the struct we generate for a
+    /// template with the given name and template parameters are for, like
+    /// `Foo<T, N>`.
+    pub fn ty_for_impl(ctx: &BindgenContext,
+                       name: &str,
+                       template_params: &[ItemId])
+                       -> P<ast::Ty> {
+        let tys = template_params.iter()
+            .map(|param| {
+                let name = ctx.resolve_type(*param).name().unwrap();
+                aster::AstBuilder::new().ty().id(ctx.rust_ident(name))
+            })
+            .collect::<Vec<_>>();
+
+        aster::AstBuilder::new()
+            .ty()
+            .path()
+            .segment(name)
+            .with_tys(tys)
+            .build()
+            .build()
+    }
+
     fn primitive_ty(ctx: &BindgenContext, name: &str) -> P<ast::Ty> {
         let ident = ctx.rust_ident_raw(&name);
         quote_ty!(ctx.ext_cx(), $ident)
@@ -3195,27 +5902,33 @@ mod utils {
 
     pub fn type_from_named(ctx: &BindgenContext,
                            name: &str,
-                           _inner: ItemId)
+                           inner: ItemId)
                            -> Option<P<ast::Ty>> {
-        // FIXME: We could use the inner item to check this is really a
-        // primitive type but, who the heck overrides these anyway?
-        Some(match name {
-            "int8_t" => primitive_ty(ctx, "i8"),
-            "uint8_t" => primitive_ty(ctx, "u8"),
-            "int16_t" => primitive_ty(ctx, "i16"),
-            "uint16_t" => primitive_ty(ctx, "u16"),
-            "int32_t" => primitive_ty(ctx, "i32"),
-            "uint32_t" => primitive_ty(ctx, "u32"),
-            "int64_t" => primitive_ty(ctx, "i64"),
-            "uint64_t" => primitive_ty(ctx, "u64"),
-
-            "uintptr_t" | "size_t" => primitive_ty(ctx, "usize"),
-
-            "intptr_t" | "ptrdiff_t" | "ssize_t" => {
-                primitive_ty(ctx, "isize")
+        if ctx.options().use_libc {
+            match name {
+                "size_t" | "ssize_t" | "ptrdiff_t" | "intptr_t" |
+                "uintptr_t" | "wchar_t" => {
+                    return Some(super::helpers::ast_ty::libc_type(ctx, name));
+                }
+                _ => {}
             }
-            _ => return None,
-        })
+        }
+
+        if !ctx.options().convert_stdint_types {
+            return None;
+        }
+
+        let known = match KnownTypedef::from_name(name) {
+            Some(known) => known,
+            None => return None,
+        };
+
+        // Only trust the name if the typedef'd type agrees with it.
+        if !ctx.resolve_type(inner).matches_known_typedef(ctx, &known) {
+            return None;
+        }
+
+        Some(primitive_ty(ctx, known.rust_name))
     }
 
     pub fn rust_fndecl_from_signature(ctx: &BindgenContext,
@@ -3238,6 +5951,10 @@ mod utils {
     pub fn fnsig_return_ty(ctx: &BindgenContext,
                            sig: &FunctionSig)
                            -> ast::FunctionRetTy {
+        if sig.is_divergent() {
+            return ast::FunctionRetTy::Ty(aster::AstBuilder::new().ty().never());
+        }
+
         let return_item = ctx.resolve_item(sig.return_type());
         if let TypeKind::Void = *return_item.kind().expect_type().kind() {
             ast::FunctionRetTy::Default(ctx.span())
@@ -3246,12 +5963,29 @@ mod utils {
         }
     }
 
+    /// The names of the arguments of the Rust functions we generate for a
+    /// signature: the C ones, or `argN` for the unnamed ones.
+    pub fn fnsig_argument_names(ctx: &BindgenContext,
+                                sig: &FunctionSig)
+                                -> Vec<String> {
+        let mut unnamed_arguments = 0;
+        sig.argument_types().iter().map(|&(ref name, _)| {
+            match *name {
+                Some(ref name) => ctx.rust_mangle(name).into_owned(),
+                None => {
+                    unnamed_arguments += 1;
+                    format!("arg{}", unnamed_arguments)
+                }
+            }
+        }).collect()
+    }
+
     pub fn fnsig_arguments(ctx: &BindgenContext,
                            sig: &FunctionSig)
                            -> Vec<ast::Arg> {
         use super::ToPtr;
-        let mut unnamed_arguments = 0;
-        sig.argument_types().iter().map(|&(ref name, ty)| {
+        let names = fnsig_argument_names(ctx, sig);
+        sig.argument_types().iter().zip(names).map(|(&(_, ty), arg_name)| {
             let arg_item = ctx.resolve_item(ty);
             let arg_ty = arg_item.kind().expect_type();
 
@@ -3263,15 +5997,31 @@ mod utils {
             //     the array type derivation.
             //
             // [1]: http://c0x.coding-guidelines.com/6.7.5.3.html
+            //
+            // Unless asked otherwise, in which case we keep the length of the
+            // arrays that have one in a pointer to the whole array.
             let arg_ty = match *arg_ty.canonical_type(ctx).kind() {
-                TypeKind::Array(t, _) => {
+                TypeKind::Array(t, len) if len > 0 &&
+                    ctx.options().array_pointers_in_arguments => {
+                    arg_item.to_rust_ty_or_opaque(ctx, &())
+                        .to_ptr(ctx.resolve_type(t).is_const(), ctx.span())
+                }
+                TypeKind::Array(t, _) |
+                TypeKind::ConstGenericArray(t, _) => {
                     t.to_rust_ty_or_opaque(ctx, &())
                         .to_ptr(ctx.resolve_type(t).is_const(), ctx.span())
                 },
                 TypeKind::Pointer(inner) => {
                     let inner = ctx.resolve_item(inner);
                     let inner_ty = inner.expect_type();
-                    if let TypeKind::ObjCInterface(_) = *inner_ty.canonical_type(ctx).kind() {
+                    let is_objc_interface =
+                        match *inner_ty.canonical_type(ctx).kind() {
+                            TypeKind::ObjCInterface(_) => {
+                                ctx.options().enable_objc
+                            }
+                            _ => false,
+                        };
+                    if is_objc_interface {
                         quote_ty!(ctx.ext_cx(), id)
                     } else {
                         arg_item.to_rust_ty_or_opaque(ctx, &())
@@ -3282,14 +6032,6 @@ mod utils {
                 }
             };
 
-            let arg_name = match *name {
-                Some(ref name) => ctx.rust_mangle(name).into_owned(),
-                None => {
-                    unnamed_arguments += 1;
-                    format!("arg{}", unnamed_arguments)
-                }
-            };
-
             assert!(!arg_name.is_empty());
 
             ast::Arg {
@@ -0,0 +1,262 @@
+//! Sorting the generated items by kind and name.
+//!
+//! See `Builder::sort_semantically`. This pass runs on the generated items,
+//! once the code generation is done, so that it can't change the names of
+//! anything, like the overload suffixes or the layout tests, which depend on
+//! the order the items are generated in.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::mem;
+use syntax::ast;
+use syntax::print::pprust;
+use syntax::ptr::P;
+
+/// The groups the items are sorted into, in the order they're emitted.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Group {
+    /// `extern crate` and `use` items, kept in the order they were generated.
+    Imports,
+    /// Type aliases.
+    Aliases,
+    /// Enums.
+    Enums,
+    /// Structs and unions.
+    Structs,
+    /// Constants and statics.
+    Constants,
+    /// `extern` blocks, merged together where possible.
+    Externs,
+    /// Modules, whose items are sorted too.
+    Modules,
+    /// Everything else, like the impls of types that aren't generated in the
+    /// same module, kept in the order they were generated.
+    Others,
+}
+
+impl Group {
+    fn of(item: &ast::Item) -> Self {
+        match item.node {
+            ast::ItemKind::ExternCrate(..) |
+            ast::ItemKind::Use(..) => Group::Imports,
+            ast::ItemKind::Ty(..) => Group::Aliases,
+            ast::ItemKind::Enum(..) => Group::Enums,
+            ast::ItemKind::Struct(..) |
+            ast::ItemKind::Union(..) => Group::Structs,
+            ast::ItemKind::Const(..) |
+            ast::ItemKind::Static(..) => Group::Constants,
+            ast::ItemKind::ForeignMod(..) => Group::Externs,
+            ast::ItemKind::Mod(..) => Group::Modules,
+            _ => Group::Others,
+        }
+    }
+
+    /// Whether the items of this group are sorted by name.
+    fn is_sorted(&self) -> bool {
+        *self != Group::Imports && *self != Group::Others
+    }
+}
+
+/// A generated item, along with the impls and layout tests of the type it
+/// defines, if it's a type.
+struct Entry {
+    group: Group,
+    name: String,
+    item: P<ast::Item>,
+    companions: Vec<P<ast::Item>>,
+}
+
+/// Sort the given items, and the items of the modules they contain, into
+/// groups: imports, type aliases, enums, structs and unions, constants and
+/// statics, `extern` blocks, modules, and then everything else. The items of
+/// each group are sorted by name, except for the imports and the last group,
+/// which keep their order.
+///
+/// The impls and layout tests of a type follow it, and the `extern` blocks
+/// with the same ABI and attributes are merged into one, with their items
+/// sorted by name.
+pub fn sort(items: Vec<P<ast::Item>>) -> Vec<P<ast::Item>> {
+    let type_names: HashSet<String> = items.iter()
+        .filter(|item| match Group::of(item) {
+            Group::Aliases | Group::Enums | Group::Structs => true,
+            _ => false,
+        })
+        .map(|item| ident_str(&item.ident))
+        .collect();
+
+    let mut entries: Vec<Entry> = vec![];
+    let mut companions = vec![];
+    // The `extern` blocks, emptied, along with the foreign items of all the
+    // blocks that can be merged with them.
+    let mut externs: Vec<(P<ast::Item>, Vec<ast::ForeignItem>)> = vec![];
+    for item in items {
+        if let Some(owner) = companion_of(&item, &type_names) {
+            companions.push((owner, item));
+            continue;
+        }
+
+        let group = Group::of(&item);
+        if group == Group::Externs {
+            let (item, foreign_items) = take_foreign_items(item);
+            let position = externs.iter()
+                .position(|&(ref block, _)| can_merge(block, &item));
+            match position {
+                Some(position) => externs[position].1.extend(foreign_items),
+                None => externs.push((item, foreign_items)),
+            }
+            continue;
+        }
+
+        let item = match group {
+            Group::Modules => sort_module(item),
+            _ => item,
+        };
+        entries.push(Entry {
+            group: group,
+            name: ident_str(&item.ident),
+            item: item,
+            companions: vec![],
+        });
+    }
+
+    for (block, mut foreign_items) in externs {
+        foreign_items.sort_by_key(|item| ident_str(&item.ident));
+        let block = block.map(|mut block| {
+            if let ast::ItemKind::ForeignMod(ref mut foreign_mod) = block.node {
+                foreign_mod.items = foreign_items;
+            }
+            block
+        });
+        entries.push(Entry {
+            group: Group::Externs,
+            name: String::new(),
+            item: block,
+            companions: vec![],
+        });
+    }
+
+    for (owner, item) in companions {
+        let entry = entries.iter_mut()
+            .find(|entry| {
+                match entry.group {
+                    Group::Aliases | Group::Enums | Group::Structs => {
+                        entry.name == owner
+                    }
+                    _ => false,
+                }
+            })
+            .expect("Companions belong to a type of the same module");
+        entry.companions.push(item);
+    }
+
+    // The sort is stable, so the unsorted groups keep their order.
+    entries.sort_by(|a, b| {
+        match a.group.cmp(&b.group) {
+            Ordering::Equal if a.group.is_sorted() => a.name.cmp(&b.name),
+            ordering => ordering,
+        }
+    });
+
+    let mut sorted = vec![];
+    for entry in entries {
+        sorted.push(entry.item);
+        sorted.extend(entry.companions);
+    }
+    sorted
+}
+
+fn ident_str(ident: &ast::Ident) -> String {
+    ident.name.as_str().to_string()
+}
+
+/// The name of the type the given item is an impl or a layout test of, if
+/// it's one of `type_names`.
+///
+/// The impls of a trait for another type, like `impl From<Foo> for u32`,
+/// belong to the type given to the trait.
+fn companion_of(item: &ast::Item,
+                type_names: &HashSet<String>)
+                -> Option<String> {
+    let candidates = match item.node {
+        ast::ItemKind::Impl(_, _, _, ref trait_ref, ref self_ty, _) => {
+            let mut candidates = vec![];
+            candidates.extend(ty_name(self_ty));
+            if let Some(ref trait_ref) = *trait_ref {
+                let segment = trait_ref.path.segments.last();
+                if let Some(&Some(ref params)) =
+                    segment.map(|segment| &segment.parameters) {
+                    if let ast::PathParameters::AngleBracketed(ref data) =
+                        **params {
+                        candidates.extend(data.types
+                            .iter()
+                            .filter_map(|ty| ty_name(ty)));
+                    }
+                }
+            }
+            candidates
+        }
+        ast::ItemKind::Fn(..) => {
+            let name = ident_str(&item.ident);
+            const PREFIX: &'static str = "bindgen_test_layout_";
+            if !name.starts_with(PREFIX) {
+                return None;
+            }
+            vec![name[PREFIX.len()..].to_owned()]
+        }
+        _ => return None,
+    };
+
+    candidates.into_iter().find(|name| type_names.contains(name))
+}
+
+/// The name of the given type, if it's a single segment path, like `Foo` or
+/// `Foo<T>`.
+fn ty_name(ty: &ast::Ty) -> Option<String> {
+    match ty.node {
+        ast::TyKind::Path(None, ref path) if path.segments.len() == 1 => {
+            Some(ident_str(&path.segments[0].identifier))
+        }
+        _ => None,
+    }
+}
+
+/// Whether the two items are `extern` blocks with the same ABI and
+/// attributes.
+fn can_merge(a: &ast::Item, b: &ast::Item) -> bool {
+    match (&a.node, &b.node) {
+        (&ast::ItemKind::ForeignMod(ref a_mod),
+         &ast::ItemKind::ForeignMod(ref b_mod)) => {
+            a_mod.abi == b_mod.abi && attrs_str(a) == attrs_str(b)
+        }
+        _ => false,
+    }
+}
+
+/// The attributes of the given item, as strings, since the attributes
+/// themselves compare their ids too.
+fn attrs_str(item: &ast::Item) -> Vec<String> {
+    item.attrs.iter().map(pprust::attribute_to_string).collect()
+}
+
+/// Take the foreign items out of the given `extern` block.
+fn take_foreign_items(item: P<ast::Item>)
+                      -> (P<ast::Item>, Vec<ast::ForeignItem>) {
+    let mut foreign_items = vec![];
+    let item = item.map(|mut item| {
+        if let ast::ItemKind::ForeignMod(ref mut foreign_mod) = item.node {
+            foreign_items = mem::replace(&mut foreign_mod.items, vec![]);
+        }
+        item
+    });
+    (item, foreign_items)
+}
+
+fn sort_module(item: P<ast::Item>) -> P<ast::Item> {
+    item.map(|mut item| {
+        if let ast::ItemKind::Mod(ref mut module) = item.node {
+            let items = mem::replace(&mut module.items, vec![]);
+            module.items = sort(items);
+        }
+        item
+    })
+}
@@ -5,7 +5,7 @@ use super::helpers::BlobTyBuilder;
 use aster::struct_field::StructFieldBuilder;
 
 use ir::comp::CompInfo;
-use ir::context::BindgenContext;
+use ir::context::{BindgenContext, ItemId};
 use ir::layout::Layout;
 use ir::ty::{Type, TypeKind};
 use std::cmp;
@@ -131,6 +131,24 @@ impl<'a, 'ctx> StructLayoutTracker<'a, 'ctx> {
         }
     }
 
+    /// Whether the given base takes no space in the struct, because clang
+    /// placed the first member (at `first_member_offset` bits) where the base
+    /// would go. That's what the empty base optimization does, and we don't
+    /// always know the base is empty, e.g. if it's opaque.
+    pub fn base_is_empty_optimized(&self,
+                                   base_ty: &Type,
+                                   first_member_offset: Option<usize>)
+                                   -> bool {
+        let layout = match base_ty.layout(self.ctx) {
+            Some(layout) => layout,
+            None => return false,
+        };
+
+        first_member_offset.map_or(false, |offset| {
+            align_to(self.latest_offset, layout.align) >= offset / 8
+        })
+    }
+
     pub fn saw_bitfield_batch(&mut self, layout: Layout) {
         self.align_to_latest_field(layout);
 
@@ -147,6 +165,63 @@ impl<'a, 'ctx> StructLayoutTracker<'a, 'ctx> {
         // actually generate the dummy alignment.
     }
 
+    /// Add a padding field if a bitfield batch needs to start further than
+    /// where we are, which happens after a zero-width bitfield.
+    pub fn pad_bitfield_batch(&mut self,
+                              batch_offset: Option<usize>)
+                              -> Option<ast::StructField> {
+        let batch_offset = match batch_offset {
+            Some(offset) if offset / 8 > self.latest_offset => offset / 8,
+            _ => return None,
+        };
+
+        let padding_layout = Layout::new(batch_offset - self.latest_offset, 1);
+
+        debug!("Offset: <padding>: {} -> {}", self.latest_offset, batch_offset);
+
+        self.latest_offset = batch_offset;
+        self.latest_field_layout = Some(padding_layout);
+        self.last_field_was_bitfield = false;
+
+        Some(self.padding_field(padding_layout))
+    }
+
+    /// Flexible array members don't have a layout, and the marker field we
+    /// generate for them is byte-aligned, so Rust would put them right after
+    /// the previous field, instead of at the alignment of their elements
+    /// like C does, which is usually in what is otherwise the tail padding
+    /// of the struct. Pad explicitly up to there, so that it's the array that
+    /// claims those bytes, and not `pad_struct` once again.
+    fn pad_flexible_array(&mut self,
+                          elem: ItemId)
+                          -> Option<ast::StructField> {
+        if self.comp.packed() {
+            return None;
+        }
+
+        let elem_layout = match self.ctx.resolve_type(elem).layout(self.ctx) {
+            Some(layout) => layout,
+            None => return None,
+        };
+
+        let padding_bytes = self.padding_bytes(elem_layout);
+        if padding_bytes == 0 {
+            return None;
+        }
+
+        let padding_layout = Layout::new(padding_bytes, 1);
+
+        debug!("Offset: <padding>: {} -> {}",
+               self.latest_offset,
+               self.latest_offset + padding_bytes);
+
+        self.latest_offset += padding_bytes;
+        self.latest_field_layout = Some(padding_layout);
+        self.last_field_was_bitfield = false;
+
+        Some(self.padding_field(padding_layout))
+    }
+
     pub fn saw_union(&mut self, layout: Layout) {
         self.align_to_latest_field(layout);
 
@@ -164,7 +239,10 @@ impl<'a, 'ctx> StructLayoutTracker<'a, 'ctx> {
                      -> Option<ast::StructField> {
         let mut field_layout = match field_ty.layout(self.ctx) {
             Some(l) => l,
-            None => return None,
+            None => {
+                return field_ty.is_incomplete_array(self.ctx)
+                    .and_then(|elem| self.pad_flexible_array(elem));
+            }
         };
 
         if let TypeKind::Array(inner, len) =
@@ -199,8 +277,18 @@ impl<'a, 'ctx> StructLayoutTracker<'a, 'ctx> {
                 _ => self.padding_bytes(field_layout),
             };
 
+            // A field can be placed further than its type's alignment
+            // requires, for example with `__attribute__((aligned(N)))`. Rust
+            // won't do that for us, so we always need explicit padding up to
+            // the offset clang computed.
+            let over_aligned = field_offset.map_or(false, |offset| {
+                offset / 8 >
+                align_to(self.latest_offset, field_layout.align)
+            });
+
             // Otherwise the padding is useless.
-            let need_padding = padding_bytes >= field_layout.align ||
+            let need_padding = over_aligned ||
+                               padding_bytes >= field_layout.align ||
                                field_layout.align > mem::size_of::<*mut ()>();
 
             self.latest_offset += padding_bytes;
@@ -209,12 +297,13 @@ impl<'a, 'ctx> StructLayoutTracker<'a, 'ctx> {
                    self.latest_offset - padding_bytes,
                    self.latest_offset);
 
-            debug!("align field {} to {}/{} with {} padding bytes {:?}",
+            debug!("align field {} to {}/{} with {} padding bytes {:?}{}",
                    field_name,
                    self.latest_offset,
                    field_offset.unwrap_or(0) / 8,
                    padding_bytes,
-                   field_layout);
+                   field_layout,
+                   if over_aligned { " (over-aligned)" } else { "" });
 
             if need_padding && padding_bytes != 0 {
                 Some(Layout::new(padding_bytes,
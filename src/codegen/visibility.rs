@@ -0,0 +1,79 @@
+//! Rewriting the visibility of the generated items.
+//!
+//! The code generation always emits `pub` items, and this pass then adjusts
+//! them to the visibility the user asked for, so that every place that builds
+//! an item doesn't need to care.
+
+use Visibility;
+use syntax::ast;
+use syntax::codemap::DUMMY_SP;
+use syntax::fold::{self, Folder};
+use syntax::ptr::P;
+use syntax::util::small_vector::SmallVector;
+
+/// Change the visibility of the given generated items, according to
+/// `visibility`.
+pub fn rewrite(items: Vec<P<ast::Item>>,
+               visibility: Visibility)
+               -> Vec<P<ast::Item>> {
+    if visibility == Visibility::Public {
+        return items;
+    }
+
+    let mut rewriter = VisibilityRewriter {
+        visibility: visibility,
+        depth: 0,
+    };
+    items.into_iter()
+        .flat_map(|item| rewriter.fold_item(item).into_iter())
+        .collect()
+}
+
+/// The visibility the top-level items get with the given `visibility`.
+pub fn top_level(visibility: Visibility) -> ast::Visibility {
+    match visibility {
+        Visibility::Public => ast::Visibility::Public,
+        Visibility::Crate => ast::Visibility::Crate(DUMMY_SP),
+        Visibility::Private => ast::Visibility::Inherited,
+    }
+}
+
+struct VisibilityRewriter {
+    visibility: Visibility,
+    /// How many items deep we are. Top-level items, and the fields, methods
+    /// and foreign items they contain, are at depth 1.
+    depth: usize,
+}
+
+impl Folder for VisibilityRewriter {
+    fn fold_item(&mut self, item: P<ast::Item>) -> SmallVector<P<ast::Item>> {
+        self.depth += 1;
+        let items = fold::noop_fold_item(item, self);
+        self.depth -= 1;
+        items
+    }
+
+    fn fold_vis(&mut self, vis: ast::Visibility) -> ast::Visibility {
+        if vis != ast::Visibility::Public {
+            return vis;
+        }
+
+        match self.visibility {
+            Visibility::Public => vis,
+            Visibility::Crate => ast::Visibility::Crate(DUMMY_SP),
+            // Making the top-level items private is enough to make everything
+            // inside them unreachable from the outside. Leaving the rest
+            // alone keeps namespace modules able to refer to each other.
+            Visibility::Private if self.depth <= 1 => {
+                ast::Visibility::Inherited
+            }
+            Visibility::Private => vis,
+        }
+    }
+
+    fn fold_mac(&mut self, mac: ast::Mac) -> ast::Mac {
+        // The only macros we generate are the layout test assertions, which
+        // don't contain any items.
+        mac
+    }
+}
@@ -0,0 +1,166 @@
+//! Support for skipping binding generation when the inputs haven't changed.
+//!
+//! We hash the options used to generate the bindings, and every file clang
+//! read to parse the input headers, as reported by clang itself once it's
+//! done. That hash is stored next to the output file, along with the list of
+//! those files, and if hashing the same files again in the next run gives the
+//! same hash we can avoid invoking clang at all.
+//!
+//! System headers are intentionally not tracked, since they rarely change and
+//! hashing them all would defeat the purpose of this.
+
+use BindgenOptions;
+use clang::TranslationUnit;
+use std::collections::BTreeSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::Hasher;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Get the path of the file where the input hash for the given output file is
+/// stored, that is, `<output>.hash`.
+pub fn hash_file_for(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".hash");
+    PathBuf::from(name)
+}
+
+/// The inputs of the bindings written to a given output file.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    /// The output file.
+    output: PathBuf,
+    /// The options used to generate the bindings, as given by the user.
+    options: String,
+    /// The files the bindings were generated from.
+    dependencies: BTreeSet<String>,
+}
+
+impl Inputs {
+    /// Create the inputs of the bindings generated with the given options to
+    /// `output`, with no dependencies yet.
+    ///
+    /// This needs to be called before we add the flags we compute ourselves
+    /// to the options, so that they hash the same in the next run.
+    pub fn new(output: &str, options: &BindgenOptions) -> Self {
+        let mut dependencies = BTreeSet::new();
+        // The precompiled header isn't included from the input header, but
+        // its contents affect the bindings all the same.
+        if let Some(ref pch) = options.precompiled_header {
+            dependencies.insert(pch.clone());
+        }
+
+        Inputs {
+            output: PathBuf::from(output),
+            options: format!("{:?}", options),
+            dependencies: dependencies,
+        }
+    }
+
+    /// Is this the output file these inputs are for?
+    pub fn is_for(&self, output: &Path) -> bool {
+        self.output == output
+    }
+
+    /// Add the files clang read to parse the given translation unit to the
+    /// dependencies. See `dependencies`.
+    pub fn record_dependencies(&mut self, unit: &TranslationUnit) {
+        self.dependencies.extend(dependencies(unit));
+    }
+
+    /// Add the given files to the dependencies.
+    pub fn extend_dependencies<I>(&mut self, dependencies: I)
+        where I: IntoIterator<Item = String>,
+    {
+        self.dependencies.extend(dependencies);
+    }
+
+    /// Is the output generated from these inputs, as they were when it was
+    /// written?
+    ///
+    /// If so, the dependencies stored along with the hash become the
+    /// dependencies of these inputs, so that the hash can be stored again.
+    pub fn check_up_to_date(&mut self) -> bool {
+        if !self.output.is_file() {
+            return false;
+        }
+
+        let mut stored = String::new();
+        let read = File::open(hash_file_for(&self.output))
+            .and_then(|mut file| file.read_to_string(&mut stored));
+        if read.is_err() {
+            return false;
+        }
+
+        let mut lines = stored.lines();
+        let stored_hash = match lines.next() {
+            Some(hash) => hash.trim().to_owned(),
+            None => return false,
+        };
+        let dependencies: BTreeSet<String> =
+            lines.map(ToOwned::to_owned).collect();
+
+        match hash(&self.options, &dependencies) {
+            Ok(hash) if format!("{:016x}", hash) == stored_hash => {
+                self.dependencies = dependencies;
+                true
+            }
+            Ok(..) => false,
+            Err(e) => {
+                info!("Unable to hash the inputs, regenerating: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Store the hash of these inputs, and their dependencies, next to the
+    /// output.
+    ///
+    /// The hash is written to a temporary file first, and then renamed, so a
+    /// half-written hash file can never make us skip a regeneration.
+    pub fn store(&self) -> io::Result<()> {
+        let hash = try!(hash(&self.options, &self.dependencies));
+
+        let hash_file = hash_file_for(&self.output);
+        let mut tmp = hash_file.clone().into_os_string();
+        tmp.push(".tmp");
+
+        {
+            let mut file = try!(File::create(&tmp));
+            try!(writeln!(file, "{:016x}", hash));
+            for dependency in &self.dependencies {
+                try!(writeln!(file, "{}", dependency));
+            }
+            try!(file.flush());
+        }
+
+        fs::rename(tmp, hash_file)
+    }
+}
+
+/// Get the files clang read to parse the given translation unit, except the
+/// system headers.
+pub fn dependencies(unit: &TranslationUnit) -> Vec<String> {
+    unit.included_files()
+        .into_iter()
+        .filter(|file| !unit.is_system_header(file))
+        .filter_map(|file| file.name())
+        .collect()
+}
+
+/// Hash the given options, and the paths and contents of the given files.
+fn hash(options: &str, dependencies: &BTreeSet<String>) -> io::Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(options.as_bytes());
+
+    for dependency in dependencies {
+        let mut contents = vec![];
+        try!(File::open(dependency)
+            .and_then(|mut file| file.read_to_end(&mut contents)));
+        hasher.write(dependency.as_bytes());
+        hasher.write(&contents);
+    }
+
+    Ok(hasher.finish())
+}
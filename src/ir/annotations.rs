@@ -38,6 +38,9 @@ pub struct Annotations {
     /// Manually disable deriving copy/clone on this type. Only applies to
     /// struct or union types.
     disallow_copy: bool,
+    /// Manually disable the conversions between a rustified enum and its
+    /// underlying integer type.
+    disallow_conversions: bool,
     /// Whether fields should be marked as private or not. You can set this on
     /// structs (it will apply to all the fields), or individual fields.
     private_fields: Option<bool>,
@@ -58,6 +61,36 @@ pub struct Annotations {
     /// In that case, bindgen will generate a constant for `Bar` instead of
     /// `Baz`.
     constify_enum_variant: bool,
+    /// Whether the constants of this enum should be wrapped in a module named
+    /// after the enum, like the `constified_enum_module` option does.
+    ///
+    /// ```cpp
+    /// /** <div rustbindgen constify-module></div> */
+    /// enum Color { Red, Green };
+    /// ```
+    constify_enum_module: bool,
+    /// Whether this rustified enum should be non-exhaustive, like the
+    /// `rustified_non_exhaustive_enum` option does.
+    ///
+    /// ```cpp
+    /// /** <div rustbindgen nonexhaustive></div> */
+    /// enum Color { Red, Green };
+    /// ```
+    non_exhaustive_enum: bool,
+    /// Whether the user asserts this type can be sent across threads, and
+    /// wants an `unsafe impl Send` for it.
+    send: bool,
+    /// Whether the user asserts this type can be shared across threads, and
+    /// wants an `unsafe impl Sync` for it.
+    sync: bool,
+    /// Whether to skip the layout test of this type, because its layout
+    /// depends on the configuration, for example.
+    ///
+    /// ```cpp
+    /// /** <div rustbindgen no-layout-test></div> */
+    /// union Config { ... };
+    /// ```
+    no_layout_test: bool,
 }
 
 fn parse_accessor(s: &str) -> FieldAccessorKind {
@@ -76,9 +109,15 @@ impl Default for Annotations {
             hide: false,
             use_instead_of: None,
             disallow_copy: false,
+            disallow_conversions: false,
             private_fields: None,
             accessor_kind: None,
             constify_enum_variant: false,
+            constify_enum_module: false,
+            non_exhaustive_enum: false,
+            send: false,
+            sync: false,
+            no_layout_test: false,
         }
     }
 }
@@ -130,11 +169,32 @@ impl Annotations {
         self.use_instead_of.as_ref().map(|s| &**s)
     }
 
+    /// Set the path of the type this one replaces, see `use_instead_of`.
+    pub fn set_use_instead_of(&mut self, path: Vec<String>) {
+        self.use_instead_of = Some(path);
+    }
+
     /// Should we avoid implementing the `Copy` trait?
     pub fn disallow_copy(&self) -> bool {
         self.disallow_copy
     }
 
+    /// Should we avoid generating the conversions between this enum and its
+    /// underlying integer type?
+    pub fn disallow_conversions(&self) -> bool {
+        self.disallow_conversions
+    }
+
+    /// Should we implement `Send` for this type?
+    pub fn send(&self) -> bool {
+        self.send
+    }
+
+    /// Should we implement `Sync` for this type?
+    pub fn sync(&self) -> bool {
+        self.sync
+    }
+
     /// Should the fields be private?
     pub fn private_fields(&self) -> Option<bool> {
         self.private_fields
@@ -158,6 +218,10 @@ impl Annotations {
                     "opaque" => self.opaque = true,
                     "hide" => self.hide = true,
                     "nocopy" => self.disallow_copy = true,
+                    "noconvert" => self.disallow_conversions = true,
+                    "send" => self.send = true,
+                    "sync" => self.sync = true,
+                    "no-layout-test" => self.no_layout_test = true,
                     "replaces" => {
                         self.use_instead_of = Some(attr.value
                             .split("::")
@@ -171,6 +235,8 @@ impl Annotations {
                         self.accessor_kind = Some(parse_accessor(&attr.value))
                     }
                     "constant" => self.constify_enum_variant = true,
+                    "constify-module" => self.constify_enum_module = true,
+                    "nonexhaustive" => self.non_exhaustive_enum = true,
                     _ => {}
                 }
             }
@@ -185,4 +251,19 @@ impl Annotations {
     pub fn constify_enum_variant(&self) -> bool {
         self.constify_enum_variant
     }
+
+    /// Returns whether we've parsed a "constify-module" attribute.
+    pub fn constify_enum_module(&self) -> bool {
+        self.constify_enum_module
+    }
+
+    /// Returns whether we've parsed a "nonexhaustive" attribute.
+    pub fn non_exhaustive_enum(&self) -> bool {
+        self.non_exhaustive_enum
+    }
+
+    /// Returns whether we've parsed a "no-layout-test" attribute.
+    pub fn no_layout_test(&self) -> bool {
+        self.no_layout_test
+    }
 }
@@ -0,0 +1,48 @@
+//! Utilities for processing the raw doc comments we get from clang.
+
+/// Get the text of a raw doc comment, without its markers: the `///`, `//!`,
+/// `/**` and `/*!` openings, the `<` of trailing comments, the closing `*/`
+/// and the leading `*` of the lines of block comments. The lines are joined
+/// with spaces, so the result fits on a single line.
+pub fn text(raw: &str) -> String {
+    let raw = raw.trim();
+    let body = if raw.starts_with("/*") {
+        let body = &raw[2..];
+        if body.ends_with("*/") {
+            &body[..body.len() - 2]
+        } else {
+            body
+        }
+    } else {
+        raw
+    };
+
+    let mut words = vec![];
+    for line in body.lines() {
+        let line = line.trim();
+        let line = if line.starts_with("//") {
+            line[2..].trim_left_matches(|c| c == '/' || c == '!')
+        } else {
+            line.trim_left_matches('*').trim_left_matches('!')
+        };
+        let line = line.trim_left_matches('<').trim();
+        if !line.is_empty() {
+            words.push(line);
+        }
+    }
+    words.join(" ")
+}
+
+/// Whether the given comment text, as returned by `text`, holds nothing but
+/// `<div rustbindgen ...></div>` annotations, which are meant for us and not
+/// for the docs.
+pub fn is_annotation_only(text: &str) -> bool {
+    let mut rest = text.trim();
+    while rest.starts_with("<div rustbindgen") {
+        match rest.find("</div>") {
+            Some(end) => rest = rest[end + "</div>".len()..].trim_left(),
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
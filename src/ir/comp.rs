@@ -6,10 +6,12 @@ use super::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
 use super::item::Item;
 use super::layout::Layout;
 use super::traversal::{EdgeKind, Trace, Tracer};
-use super::ty::TemplateDeclaration;
+use super::ty::{TemplateDeclaration, TypeKind};
+use DiagnosticSeverity;
 use clang;
 use parse::{ClangItemParser, ParseError};
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 
 /// The kind of compound type.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -275,6 +277,9 @@ pub struct CompInfo {
     /// Whether this type has destructor.
     has_destructor: bool,
 
+    /// Whether this type has a user-provided copy or move constructor.
+    has_non_trivial_copy: bool,
+
     /// Whether this type has a base type with more than one member.
     ///
     /// TODO: We should be able to compute this.
@@ -307,9 +312,17 @@ pub struct CompInfo {
     /// around the template arguments.
     detect_has_destructor_cycle: Cell<bool>,
 
+    /// Used to detect if we've run in a has_non_trivial_copy cycle while
+    /// cycling around the template arguments.
+    detect_has_non_trivial_copy_cycle: Cell<bool>,
+
     /// Used to indicate when a struct has been forward declared. Usually used
     /// in headers so that APIs can't modify them directly.
     is_forward_declaration: bool,
+
+    /// The overload index of each method, that is, its position amongst the
+    /// methods with the same name. Lazily computed, see `method_overload_index`.
+    method_overload_indices: RefCell<Option<HashMap<ItemId, usize>>>,
 }
 
 impl CompInfo {
@@ -326,6 +339,7 @@ impl CompInfo {
             inner_vars: vec![],
             has_vtable: false,
             has_destructor: false,
+            has_non_trivial_copy: false,
             has_nonempty_base: false,
             has_non_type_template_params: false,
             packed: false,
@@ -333,7 +347,9 @@ impl CompInfo {
             detect_derive_debug_cycle: Cell::new(false),
             detect_derive_default_cycle: Cell::new(false),
             detect_has_destructor_cycle: Cell::new(false),
+            detect_has_non_trivial_copy_cycle: Cell::new(false),
             is_forward_declaration: false,
+            method_overload_indices: RefCell::new(None),
         }
     }
 
@@ -374,6 +390,49 @@ impl CompInfo {
         has_destructor
     }
 
+    /// Is copying or moving this type non-trivial, that is, does it, any of
+    /// its bases or by-value members have a user-provided copy or move
+    /// constructor, or a vtable?
+    pub fn has_non_trivial_copy(&self, ctx: &BindgenContext) -> bool {
+        if self.detect_has_non_trivial_copy_cycle.get() {
+            warn!("Cycle detected looking for copy constructors");
+            return false;
+        }
+
+        self.detect_has_non_trivial_copy_cycle.set(true);
+
+        let has_non_trivial_copy = self.has_non_trivial_copy ||
+                                   match self.kind {
+            CompKind::Union => false,
+            CompKind::Struct => {
+                self.has_vtable(ctx) ||
+                self.base_members.iter().any(|base| {
+                    ctx.resolve_type(base.ty).has_non_trivial_copy(ctx)
+                }) ||
+                self.fields.iter().any(|field| {
+                    ctx.resolve_type(field.ty)
+                        .has_non_trivial_copy(ctx)
+                })
+            }
+        };
+
+        self.detect_has_non_trivial_copy_cycle.set(false);
+
+        has_non_trivial_copy
+    }
+
+    /// Does this compound type have any reference fields, directly or in its
+    /// bases and by-value members? Those are never null, so an all-zeroes
+    /// value of this type isn't valid.
+    pub fn has_reference_fields(&self, ctx: &BindgenContext) -> bool {
+        self.base_members.iter().any(|base| {
+            ctx.resolve_type(base.ty).has_reference_fields(ctx)
+        }) ||
+        self.fields.iter().any(|field| {
+            ctx.resolve_type(field.ty).has_reference_fields(ctx)
+        })
+    }
+
     /// Compute the layout of this type.
     ///
     /// This is called as a fallback under some circumstances where LLVM doesn't
@@ -415,6 +474,13 @@ impl CompInfo {
         self.has_non_type_template_params
     }
 
+    /// Does this compound type have any `const` generic parameters? We can't
+    /// name the values of their arguments, so instantiations are opaque blobs
+    /// too.
+    pub fn has_const_template_params(&self, ctx: &BindgenContext) -> bool {
+        self.template_params.iter().any(|p| ctx.is_const_template_param(*p))
+    }
+
     /// Does this type have a virtual table?
     pub fn has_vtable(&self, ctx: &BindgenContext) -> bool {
         self.has_vtable ||
@@ -429,6 +495,37 @@ impl CompInfo {
         &self.methods
     }
 
+    /// Get the position of the method with the given signature amongst the
+    /// methods with the same name, if it's a method of this type.
+    ///
+    /// The indices of all the methods are computed and cached the first time
+    /// this is called, so each lookup afterwards is a single hash map access.
+    pub fn method_overload_index(&self,
+                                 ctx: &BindgenContext,
+                                 signature: ItemId)
+                                 -> Option<usize> {
+        if self.method_overload_indices.borrow().is_none() {
+            let mut indices = HashMap::new();
+            let mut overloads_by_name = HashMap::new();
+            for method in &self.methods {
+                let name = ctx.resolve_item(method.signature())
+                    .expect_function()
+                    .name();
+                let overloads = overloads_by_name.entry(name).or_insert(0);
+                indices.entry(method.signature()).or_insert(*overloads);
+                *overloads += 1;
+            }
+            *self.method_overload_indices.borrow_mut() = Some(indices);
+        }
+
+        self.method_overload_indices
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .get(&signature)
+            .cloned()
+    }
+
     /// Get this type's set of constructors.
     pub fn constructors(&self) -> &[ItemId] {
         &self.constructors
@@ -455,10 +552,10 @@ impl CompInfo {
                 "We handle template instantiations elsewhere");
 
         let mut cursor = ty.declaration();
-        let mut kind = Self::kind_from_cursor(&cursor);
+        let mut kind = Self::kind_from_cursor(&cursor, ctx);
         if kind.is_err() {
             if let Some(location) = location {
-                kind = Self::kind_from_cursor(&location);
+                kind = Self::kind_from_cursor(&location, ctx);
                 cursor = location;
             }
         }
@@ -468,13 +565,23 @@ impl CompInfo {
         debug!("CompInfo::from_ty({:?}, {:?})", kind, cursor);
 
         let mut ci = CompInfo::new(kind);
-        ci.is_forward_declaration =
-            location.map_or(true, |cur| match cur.kind() {
-                CXCursor_StructDecl |
-                CXCursor_UnionDecl |
-                CXCursor_ClassDecl => !cur.is_definition(),
-                _ => false,
-            });
+        // The declaration of a record type is its definition if there's one
+        // anywhere in the translation unit, even after the place we're
+        // parsing it from, so if it isn't there's nothing but forward
+        // declarations of it.
+        ci.is_forward_declaration = match cursor.kind() {
+            CXCursor_StructDecl |
+            CXCursor_UnionDecl |
+            CXCursor_ClassDecl => !cursor.is_definition(),
+            _ => {
+                location.map_or(true, |cur| match cur.kind() {
+                    CXCursor_StructDecl |
+                    CXCursor_UnionDecl |
+                    CXCursor_ClassDecl => !cur.is_definition(),
+                    _ => false,
+                })
+            }
+        };
 
         let mut maybe_anonymous_struct_field = None;
         cursor.visit(|cur| {
@@ -510,6 +617,22 @@ impl CompInfo {
                         }
                     }
 
+                    // A field of function type, rather than pointer to
+                    // function type, is ill-formed, though it's easy to write
+                    // through a typedef. Clang complains about it too, but
+                    // keeps the field around.
+                    let canonical_kind = cur.cur_type().canonical_type().kind();
+                    if canonical_kind == CXType_FunctionProto ||
+                       canonical_kind == CXType_FunctionNoProto {
+                        let message = format!("{}: Field `{}` has a function \
+                                               type, not a function pointer \
+                                               type, skipping it",
+                                              cur.location(),
+                                              cur.spelling());
+                        ctx.diagnostic(DiagnosticSeverity::Error, message);
+                        return CXChildVisit_Continue;
+                    }
+
                     let bit_width = cur.bit_width();
                     let field_type = Item::from_ty_or_ref(cur.cur_type(),
                                                           cur,
@@ -627,8 +750,17 @@ impl CompInfo {
                     debug_assert!(!(is_static && is_virtual), "How?");
 
                     ci.has_destructor |= cur.kind() == CXCursor_Destructor;
+                    ci.has_non_trivial_copy |=
+                        cur.is_user_provided_copy_or_move_constructor();
                     ci.has_vtable |= is_virtual;
 
+                    // Methods declared through a typedef of a function type
+                    // don't have their implicit `this` argument in their
+                    // type, so we can't generate them properly.
+                    if cur.cur_type().kind() == CXType_Typedef {
+                        return CXChildVisit_Continue;
+                    }
+
                     // This used to not be here, but then I tried generating
                     // stylo bindings with this (without path filters), and
                     // cried a lot with a method in gfx/Point.h
@@ -678,7 +810,10 @@ impl CompInfo {
                     }
                 }
                 CXCursor_NonTypeTemplateParameter => {
-                    ci.has_non_type_template_params = true;
+                    match Item::const_template_param(cur, ctx) {
+                        Some(param) => ci.template_params.push(param),
+                        None => ci.has_non_type_template_params = true,
+                    }
                 }
                 CXCursor_VarDecl => {
                     let linkage = cur.linkage();
@@ -704,11 +839,14 @@ impl CompInfo {
                 CXCursor_FunctionTemplate |
                 CXCursor_ConversionFunction => {}
                 _ => {
-                    warn!("unhandled comp member `{}` (kind {:?}) in `{}` ({})",
-                          cur.spelling(),
-                          clang::kind_to_str(cur.kind()),
-                          cursor.spelling(),
-                          cur.location());
+                    let message =
+                        format!("{}: unhandled comp member `{}` (kind {:?}) \
+                                 in `{}`",
+                                cur.location(),
+                                cur.spelling(),
+                                clang::kind_to_str(cur.kind()),
+                                cursor.spelling());
+                    ctx.diagnostic(DiagnosticSeverity::Warning, message);
                 }
             }
             CXChildVisit_Continue
@@ -722,7 +860,8 @@ impl CompInfo {
         Ok(ci)
     }
 
-    fn kind_from_cursor(cursor: &clang::Cursor)
+    fn kind_from_cursor(cursor: &clang::Cursor,
+                        ctx: &BindgenContext)
                         -> Result<CompKind, ParseError> {
         use clang_sys::*;
         Ok(match cursor.kind() {
@@ -738,7 +877,10 @@ impl CompInfo {
                 }
             }
             _ => {
-                warn!("Unknown kind for comp type: {:?}", cursor);
+                let message = format!("{}: Unknown kind for comp type: {:?}",
+                                      cursor.location(),
+                                      cursor);
+                ctx.diagnostic(DiagnosticSeverity::Warning, message);
                 return Err(ParseError::Continue);
             }
         })
@@ -766,6 +908,35 @@ impl CompInfo {
         self.packed
     }
 
+    /// Should we implement `Debug` by hand for this struct, because it can't
+    /// derive it only since some of its fields are arrays longer than
+    /// `RUST_DERIVE_IN_ARRAY_LIMIT`? Those fields are printed as slices then.
+    ///
+    /// Packed structs and templates aren't supported: the fields of the
+    /// former need to be copied out first, and the latter may not know their
+    /// field types.
+    pub fn needs_debug_impl_via_slices(&self, ctx: &BindgenContext) -> bool {
+        if !ctx.options().impl_debug_for_long_arrays ||
+           self.kind != CompKind::Struct || self.packed ||
+           !self.template_params.is_empty() ||
+           self.has_non_type_template_params {
+            return false;
+        }
+
+        let mut needs_slices = false;
+        let can_impl = self.fields.iter().all(|field| {
+            if field.can_derive_debug(ctx, ()) {
+                return true;
+            }
+            needs_slices = true;
+            match *ctx.resolve_type(field.ty).canonical_type(ctx).kind() {
+                TypeKind::Array(inner, _) => inner.can_derive_debug(ctx, ()),
+                _ => false,
+            }
+        });
+        can_impl && needs_slices
+    }
+
     /// Returns whether this type needs an explicit vtable because it has
     /// virtual methods and none of its base classes has already a vtable.
     pub fn needs_explicit_vtable(&self, ctx: &BindgenContext) -> bool {
@@ -821,6 +992,11 @@ impl CanDeriveDebug for CompInfo {
         }
 
         if self.kind == CompKind::Union {
+            // We implement `Debug` by hand then, see the `CompInfo` codegen.
+            if ctx.options().impl_debug_for_unions {
+                return true;
+            }
+
             if ctx.options().unstable_rust {
                 return false;
             }
@@ -832,13 +1008,23 @@ impl CanDeriveDebug for CompInfo {
 
         self.detect_derive_debug_cycle.set(true);
 
+        // The `Debug` implementation of packed structs copies the fields out,
+        // so they need to be `Copy`. Structs with long arrays may get a
+        // `Debug` implementation too, printing them as slices.
         let can_derive_debug = {
             self.base_members
                 .iter()
-                .all(|base| base.ty.can_derive_debug(ctx, ())) &&
-            self.fields
+                .all(|base| {
+                    base.ty.can_derive_debug(ctx, ()) &&
+                    (!self.packed || base.ty.can_derive_copy(ctx, ()))
+                }) &&
+            (self.fields
                 .iter()
-                .all(|f| f.can_derive_debug(ctx, ()))
+                .all(|f| {
+                    f.can_derive_debug(ctx, ()) &&
+                    (!self.packed || f.can_derive_copy(ctx, ()))
+                }) ||
+             self.needs_debug_impl_via_slices(ctx))
         };
 
         self.detect_derive_debug_cycle.set(false);
@@ -861,6 +1047,10 @@ impl CanDeriveDefault for CompInfo {
             return true;
         }
 
+        if self.is_forward_declaration {
+            return false;
+        }
+
         if self.kind == CompKind::Union {
             if ctx.options().unstable_rust {
                 return false;
@@ -902,7 +1092,7 @@ impl<'a> CanDeriveCopy<'a> for CompInfo {
         // NOTE: Take into account that while unions in C and C++ are copied by
         // default, the may have an explicit destructor in C++, so we can't
         // defer this check just for the union case.
-        if self.has_destructor(ctx) {
+        if self.has_destructor(ctx) || self.is_forward_declaration {
             return false;
         }
 
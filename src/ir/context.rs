@@ -2,25 +2,35 @@
 
 use super::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
 use super::int::IntKind;
-use super::item::{Item, ItemCanonicalPath, ItemSet};
+use super::item::{Item, ItemCanonicalName, ItemCanonicalPath, ItemSet,
+                  Location};
+use super::interner::{Interner, Name};
+use super::item_id_set::ItemIdSet;
 use super::item_kind::ItemKind;
+use super::macro_fn::MacroFunction;
 use super::module::{Module, ModuleKind};
 use super::named::{UsedTemplateParameters, analyze};
 use super::template::TemplateInstantiation;
-use super::traversal::{self, Edge, ItemTraversal};
+use super::traversal::{self, Edge, EdgeKind, ItemTraversal, Trace};
 use super::ty::{FloatKind, TemplateDeclaration, Type, TypeKind};
-use BindgenOptions;
+use {AliasVariation, BindgenOptions, Diagnostic, DiagnosticCallback,
+     DiagnosticSeverity};
 use cexpr;
 use callbacks::ParseCallbacks;
 use clang::{self, Cursor};
 use clang_sys;
 use parse::ClangItemParser;
+use regex_set::last_matching_style;
 use std::borrow::Cow;
-use std::cell::Cell;
-use std::collections::{HashMap, hash_map};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::collections::btree_map::{self, BTreeMap};
 use std::fmt;
-use std::iter::IntoIterator;
+use std::fs;
+use std::iter::{self, IntoIterator};
+use std::mem;
+use std::path::PathBuf;
+use std::rc::Rc;
 use syntax::ast::Ident;
 use syntax::codemap::{DUMMY_SP, Span};
 use syntax::ext::base::ExtCtxt;
@@ -36,6 +46,12 @@ impl ItemId {
     pub fn as_usize(&self) -> usize {
         self.0
     }
+
+    /// Get the id with the given numeric representation. Only meant to be
+    /// used by dense collections of ids, like `ItemIdSet`.
+    pub fn from_usize(id: usize) -> Self {
+        ItemId(id)
+    }
 }
 
 impl CanDeriveDebug for ItemId {
@@ -88,6 +104,18 @@ impl<'ctx> fmt::Debug for GenContext<'ctx> {
     }
 }
 
+/// A type that bindgen couldn't fully understand, and approximated instead,
+/// usually with an opaque blob.
+#[derive(Debug, Clone)]
+pub struct OpaqueFallback {
+    /// The item that was approximated.
+    pub id: ItemId,
+    /// The spelling of the original type.
+    pub spelling: String,
+    /// Why we couldn't understand it.
+    pub reason: &'static str,
+}
+
 /// A context used during parsing and generation of structs.
 #[derive(Debug)]
 pub struct BindgenContext<'ctx> {
@@ -102,12 +130,24 @@ pub struct BindgenContext<'ctx> {
 
     /// Clang USR to type map. This is needed to be able to associate types with
     /// item ids during parsing.
+    ///
+    /// This, `named_types` and `modules` are only used for lookups, never
+    /// iterated, so their order doesn't leak into the generated bindings.
     types: HashMap<TypeKey, ItemId>,
 
     /// Maps from a cursor to the item id of the named template type parameter
     /// for that cursor.
     named_types: HashMap<clang::Cursor, ItemId>,
 
+    /// Maps from a cursor to the item id of the `const` generic parameter we
+    /// generate for that non-type template parameter, see
+    /// `Builder::const_generics`.
+    const_template_params: HashMap<clang::Cursor, ItemId>,
+
+    /// The item ids in `const_template_params`, to tell them apart from
+    /// template type parameters.
+    const_template_param_ids: ItemIdSet,
+
     /// A cursor to module map. Similar reason than above.
     modules: HashMap<Cursor, ItemId>,
 
@@ -135,8 +175,38 @@ pub struct BindgenContext<'ctx> {
     /// expression parsing.
     parsed_macros: HashMap<Vec<u8>, cexpr::expr::EvalResult>,
 
+    /// The function-like macros to generate functions for, along with their
+    /// definitions, once every function is parsed. See
+    /// `Builder::emit_macro_fns`.
+    macro_fns: Vec<(MacroFunction, Cursor)>,
+
     /// The active replacements collected from replaces="xxx" annotations.
-    replacements: HashMap<Vec<String>, ItemId>,
+    ///
+    /// It's a BTreeMap for the same reason `items` is.
+    replacements: BTreeMap<Vec<Name>, ItemId>,
+
+    /// The types we couldn't understand and approximated, in case we're asked
+    /// to be strict about it. See `BindgenOptions::strict`.
+    opaque_fallbacks: Vec<OpaqueFallback>,
+
+    /// The diagnostics we emitted so far, to hand them back with the
+    /// bindings. See `BindgenContext::diagnostic`.
+    diagnostics: RefCell<Vec<Diagnostic>>,
+
+    /// The callbacks given to the `Builder`.
+    callbacks: Callbacks,
+
+    /// The canonical path of each item, lazily computed during the codegen
+    /// phase, once the paths can't change anymore.
+    ///
+    /// Computing a path walks all the ancestors of the item, and it's queried
+    /// a lot while whitelisting and during codegen (every `is_hidden` and
+    /// `is_opaque` call needs it), which was quadratic on big headers.
+    canonical_paths: RefCell<HashMap<ItemId, Rc<Vec<Name>>>>,
+
+    /// The names of the items, interned so they're shared by all the items
+    /// whose names contain them.
+    names: Interner,
 
     collected_typerefs: bool,
 
@@ -156,28 +226,120 @@ pub struct BindgenContext<'ctx> {
     /// Whether a bindgen complex was generated
     generated_bindegen_complex: Cell<bool>,
 
+    /// Whether the `c_void` stub was used, see `BindgenOptions::core_ffi_c_void`.
+    generated_c_void_stub: Cell<bool>,
+
     /// Map from an item's id to the set of template parameter items that it
     /// uses. See `ir::named` for more details. Always `Some` during the codegen
     /// phase.
-    used_template_parameters: Option<HashMap<ItemId, ItemSet>>,
+    used_template_parameters: Option<BTreeMap<ItemId, ItemSet>>,
+
+    /// The aliases that should be generated as new types instead of type
+    /// aliases, along with the kind of new type. See
+    /// `Builder::new_type_alias`. Always `Some` during the codegen phase.
+    new_type_aliases: Option<BTreeMap<ItemId, AliasVariation>>,
+
+    /// The `typedef` each unnamed enum is declared in, like in
+    /// `typedef enum { ... } foo;`, if any. See
+    /// `Item::anonymous_enum_typedef`. Only `Some` during the codegen phase.
+    anonymous_enum_typedefs: Option<HashMap<ItemId, ItemId>>,
+
+    /// The canonical paths more than one generated type ends up with, which
+    /// the module of a constified enum has to steer clear of. See
+    /// `codegen::enum_module_name`. Only `Some` during the codegen phase.
+    shared_type_paths: Option<HashSet<Vec<Name>>>,
+
+    /// The `#[cfg]` predicate the code generated for each item needs to be
+    /// wrapped in, if any. See `Builder::wrap_matching_items_in_cfg`. Only
+    /// populated during the codegen phase.
+    item_cfgs: HashMap<ItemId, String>,
+
+    /// The location files of the headers whose items are all whitelisted,
+    /// that is, the input headers and those matching the whitelisted files,
+    /// when requested. See `Builder::only_primary_header`. Only populated
+    /// during the codegen phase.
+    primary_files: HashSet<String>,
+
+    /// The module each top-level item goes to when generating a module per
+    /// header, keyed by the item's id. See `Builder::module_per_header`.
+    header_modules: HashMap<ItemId, String>,
+
+    /// The name we use for every source file, keyed by its unique id, so that
+    /// items get the same location file regardless of how their header was
+    /// included. See `Location::from_cursor`.
+    file_names: HashMap<[u64; 3], String>,
+
+    /// When not whitelisting recursively, the types the whitelisted items only
+    /// refer to through pointers, which we forward declare. Always `Some`
+    /// during the codegen phase.
+    forward_declared_types: Option<ItemIdSet>,
+
+    /// When not whitelisting recursively, the types the whitelisted items use
+    /// by value but that aren't whitelisted, along with the first item using
+    /// each of them.
+    types_missing_from_whitelist: Vec<(ItemId, ItemId)>,
+
+    /// The declarations we don't parse, because clang couldn't make sense of
+    /// them, see `Builder::fail_on_clang_error`. They're keyed by
+    /// `declaration_key`.
+    skipped_declarations: HashSet<String>,
+}
+
+/// What identifies a declaration, whichever cursor we reach it from: its USR,
+/// or its location if it has none.
+fn declaration_key(declaration: &clang::Cursor) -> String {
+    declaration.usr()
+        .unwrap_or_else(|| declaration.location().to_string())
 }
 
 /// A traversal of whitelisted items.
 pub type WhitelistedItems<'ctx, 'gen> = ItemTraversal<'ctx,
                                                       'gen,
-                                                      ItemSet,
+                                                      ItemIdSet,
                                                       Vec<ItemId>,
                                                       fn(Edge) -> bool>;
 
+/// The callbacks given to the `Builder`.
+///
+/// These are kept apart from the `BindgenOptions`, which can then be cloned
+/// and sent to the threads parsing other headers, see
+/// `Builder::parallel_parsing`.
+#[derive(Debug, Default)]
+pub struct Callbacks {
+    /// A user-provided visitor to allow customizing different kinds of
+    /// situations.
+    pub parse_callbacks: Option<Box<ParseCallbacks>>,
+
+    /// A user-provided sink for the diagnostics emitted while generating the
+    /// bindings.
+    pub diagnostic_callback: Option<DiagnosticCallback>,
+}
+
+/// Like the `UnwindSafe` impl of `BindgenOptions`, this is sort of a lie, but
+/// the callbacks are never shared across panic boundaries.
+impl ::std::panic::UnwindSafe for Callbacks {}
+
+impl Callbacks {
+    /// Hand the given diagnostic to the user's diagnostic callback, if any.
+    pub fn diagnostic(&self, diagnostic: &Diagnostic) {
+        if let Some(ref cb) = self.diagnostic_callback {
+            cb.call(diagnostic);
+        }
+    }
+}
+
 impl<'ctx> BindgenContext<'ctx> {
-    /// Construct the context for the given `options`.
-    pub fn new(options: BindgenOptions) -> Self {
+    /// Construct the context for the given `options` and `callbacks`.
+    pub fn new(options: BindgenOptions, callbacks: Callbacks) -> Self {
         use clang_sys;
 
         let index = clang::Index::new(false, true);
 
-        let parse_options =
+        let mut parse_options =
             clang_sys::CXTranslationUnit_DetailedPreprocessingRecord;
+        if options.precompiled_header_output.is_some() {
+            parse_options |= clang_sys::CXTranslationUnit_ForSerialization;
+        }
         let translation_unit =
             clang::TranslationUnit::parse(&index,
                                           "",
@@ -186,18 +348,27 @@ impl<'ctx> BindgenContext<'ctx> {
                                           parse_options)
                 .expect("TranslationUnit::parse failed");
 
-        let root_module = Self::build_root_module(ItemId(0));
+        let root_module =
+            Self::build_root_module(ItemId(0), &options.root_module_name);
         let mut me = BindgenContext {
             items: Default::default(),
             types: Default::default(),
             named_types: Default::default(),
+            const_template_params: Default::default(),
+            const_template_param_ids: ItemIdSet::new(),
             modules: Default::default(),
             next_item_id: ItemId(1),
             root_module: root_module.id(),
             current_module: root_module.id(),
             currently_parsed_types: vec![],
             parsed_macros: Default::default(),
+            macro_fns: vec![],
             replacements: Default::default(),
+            opaque_fallbacks: vec![],
+            diagnostics: Default::default(),
+            callbacks: callbacks,
+            canonical_paths: Default::default(),
+            names: Default::default(),
             collected_typerefs: false,
             gen_ctx: None,
             span: DUMMY_SP,
@@ -205,7 +376,18 @@ impl<'ctx> BindgenContext<'ctx> {
             translation_unit: translation_unit,
             options: options,
             generated_bindegen_complex: Cell::new(false),
+            generated_c_void_stub: Cell::new(false),
             used_template_parameters: None,
+            new_type_aliases: None,
+            anonymous_enum_typedefs: None,
+            shared_type_paths: None,
+            item_cfgs: HashMap::new(),
+            primary_files: HashSet::new(),
+            header_modules: HashMap::new(),
+            file_names: HashMap::new(),
+            forward_declared_types: None,
+            types_missing_from_whitelist: vec![],
+            skipped_declarations: HashSet::new(),
         };
 
         me.add_item(root_module, None, None);
@@ -233,9 +415,14 @@ impl<'ctx> BindgenContext<'ctx> {
             .expect("should have been parsing a type, if we finished parsing a type")
     }
 
+    /// Get the callbacks given to the `Builder`.
+    pub fn callbacks(&self) -> &Callbacks {
+        &self.callbacks
+    }
+
     /// Get the user-provided callbacks by reference, if any.
     pub fn parse_callbacks(&self) -> Option<&ParseCallbacks> {
-        self.options().parse_callbacks.as_ref().map(|t| &**t)
+        self.callbacks.parse_callbacks.as_ref().map(|t| &**t)
     }
 
     /// Define a new item.
@@ -243,7 +430,7 @@ impl<'ctx> BindgenContext<'ctx> {
     /// This inserts it into the internal items set, and its type into the
     /// internal types set.
     pub fn add_item(&mut self,
-                    item: Item,
+                    mut item: Item,
                     declaration: Option<Cursor>,
                     location: Option<Cursor>) {
         debug!("BindgenContext::add_item({:?}, declaration: {:?}, loc: {:?}",
@@ -255,6 +442,15 @@ impl<'ctx> BindgenContext<'ctx> {
                       item.kind().expect_type().is_opaque(),
                       "Adding a type without declaration?");
 
+        let source = {
+            let file_names = &mut self.file_names;
+            declaration.iter()
+                .chain(location.iter())
+                .find(|cursor| cursor.is_valid())
+                .and_then(|cursor| Location::from_cursor(cursor, file_names))
+        };
+        item.set_location(source);
+
         let id = item.id();
         let is_type = item.kind().is_type();
         let is_unnamed = is_type && item.expect_type().name().is_none();
@@ -303,7 +499,8 @@ impl<'ctx> BindgenContext<'ctx> {
             } else if let Some(usr) = declaration.usr() {
                 TypeKey::USR(usr)
             } else {
-                warn!("Valid declaration with no USR: {:?}, {:?}",
+                warn!("{}: Valid declaration with no USR: {:?}, {:?}",
+                      declaration.location(),
                       declaration,
                       location);
                 TypeKey::Declaration(declaration)
@@ -343,6 +540,46 @@ impl<'ctx> BindgenContext<'ctx> {
         self.named_types.get(definition).cloned()
     }
 
+    /// Add a new `const` generic parameter, defined by the given non-type
+    /// template parameter, to this context's item set.
+    pub fn add_const_template_param(&mut self,
+                                    item: Item,
+                                    definition: clang::Cursor) {
+        debug!("BindgenContext::add_const_template_param: item = {:?}; \
+                definition = {:?}",
+               item,
+               definition);
+
+        assert!(item.expect_type().is_named(),
+                "Should directly be a named type, not a resolved reference or anything");
+        assert_eq!(definition.kind(),
+                   clang_sys::CXCursor_NonTypeTemplateParameter);
+
+        let id = item.id();
+        let old_item = self.items.insert(id, item);
+        assert!(old_item.is_none(),
+                "should not have already associated an item with the given id");
+
+        let old_param = self.const_template_params.insert(definition, id);
+        assert!(old_param.is_none(),
+                "should not have already associated a const parameter with this id");
+        self.const_template_param_ids.insert(id);
+    }
+
+    /// Get the `const` generic parameter defined at the given cursor location,
+    /// if we've already added one.
+    pub fn get_const_template_param(&self,
+                                    definition: &clang::Cursor)
+                                    -> Option<ItemId> {
+        self.const_template_params.get(definition).cloned()
+    }
+
+    /// Is the given item a `const` generic parameter rather than a template
+    /// type parameter?
+    pub fn is_const_template_param(&self, id: ItemId) -> bool {
+        self.const_template_param_ids.contains(&id)
+    }
+
     // TODO: Move all this syntax crap to other part of the code.
 
     /// Given that we are in the codegen phase, get the syntex context.
@@ -452,7 +689,8 @@ impl<'ctx> BindgenContext<'ctx> {
         //
         // We leverage the ResolvedTypeRef thing, though, which is cool :P.
 
-        let mut replacements = vec![];
+        let mut replaceable: BTreeMap<Vec<Name>, Vec<ItemId>> =
+            BTreeMap::new();
 
         for (id, item) in self.items.iter() {
             if item.annotations().use_instead_of().is_some() {
@@ -474,17 +712,66 @@ impl<'ctx> BindgenContext<'ctx> {
             }
 
             let path = item.canonical_path(self);
-            let replacement = self.replacements.get(&path[1..]);
-
-            if let Some(replacement) = replacement {
-                if replacement != id {
-                    // We set this just after parsing the annotation. It's
-                    // very unlikely, but this can happen.
-                    if self.items.get(replacement).is_some() {
-                        replacements.push((*id, *replacement));
-                    }
+            replaceable.entry(path[1..].to_vec()).or_insert(vec![]).push(*id);
+        }
+
+        let mut replacements = vec![];
+        let mut resolved_paths = vec![];
+
+        for (name, replacement) in self.replacements.iter() {
+            // We set this just after parsing the annotation. It's very
+            // unlikely, but this can happen.
+            let replacement_item = match self.items.get(replacement) {
+                Some(item) => item,
+                None => continue,
+            };
+
+            // The path in the annotation may be relative to the scope the
+            // replacement is declared in, so look it up like C++ would, from
+            // the innermost scope outwards.
+            let scope = replacement_item.parent_id().canonical_path(self);
+            let scope = &scope[1..];
+            let path = (0..scope.len() + 1)
+                .rev()
+                .map(|len| {
+                    let mut path = scope[..len].to_vec();
+                    path.extend_from_slice(name);
+                    path
+                })
+                .find(|path| replaceable.contains_key(path));
+
+            let path = match path {
+                Some(path) => path,
+                None => {
+                    let message = format!("{}: `{}` doesn't name any type \
+                                           that can be replaced",
+                                          replacement_item
+                                              .location_for_diagnostics(),
+                                          name.join("::"));
+                    self.diagnostic(DiagnosticSeverity::Warning, message);
+                    continue;
+                }
+            };
+
+            for id in &replaceable[&path] {
+                if id != replacement {
+                    replacements.push((*id, *replacement));
                 }
             }
+
+            if path != *name {
+                resolved_paths.push((*replacement, path));
+            }
+        }
+
+        // Names are derived from the replaced path, so it needs to be complete.
+        for (replacement, path) in resolved_paths {
+            self.items
+                .get_mut(&replacement)
+                .unwrap()
+                .set_replaced_path(path.iter()
+                    .map(ToString::to_string)
+                    .collect());
         }
 
         for (id, replacement) in replacements {
@@ -533,6 +820,9 @@ impl<'ctx> BindgenContext<'ctx> {
                 .unwrap()
                 .set_parent_for_replacement(old_parent);
         }
+
+        // We may have computed the paths of the items we just reparented.
+        self.canonical_paths.borrow_mut().clear();
     }
 
     /// Enter the code generation phase, invoke the given callback `cb`, and
@@ -573,7 +863,14 @@ impl<'ctx> BindgenContext<'ctx> {
             self.process_replacements();
         }
 
+        self.find_anonymous_enum_typedefs();
+        self.find_primary_files();
         self.find_used_template_parameters();
+        self.find_new_type_aliases();
+        self.find_item_cfgs();
+        self.find_types_outside_whitelist();
+        self.assign_header_modules();
+        self.find_shared_type_paths();
 
         let ret = cb(self);
         self.gen_ctx = None;
@@ -608,7 +905,7 @@ impl<'ctx> BindgenContext<'ctx> {
         } else {
             // If you aren't recursively whitelisting, then we can't really make
             // any sense of template parameter usage, and you're on your own.
-            let mut used_params = HashMap::new();
+            let mut used_params = BTreeMap::new();
             for id in self.whitelisted_items() {
                 used_params.entry(id)
                     .or_insert(id.self_template_params(self)
@@ -619,6 +916,546 @@ impl<'ctx> BindgenContext<'ctx> {
         }
     }
 
+    /// Whether we only whitelist what some given files declare.
+    fn whitelisting_files(&self) -> bool {
+        self.options.only_primary_header ||
+        !self.options.whitelisted_files.is_empty()
+    }
+
+    fn find_primary_files(&mut self) {
+        if !self.whitelisting_files() {
+            return;
+        }
+
+        // Clang may spell the same header differently than the user did, so
+        // we compare canonical paths when we can.
+        fn canonical(path: &str) -> PathBuf {
+            fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path))
+        }
+
+        let inputs: Vec<PathBuf> = if self.options.only_primary_header {
+            self.options
+                .input_header
+                .iter()
+                .chain(self.options.extra_input_headers.iter())
+                .map(|header| canonical(header))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let primary_files = {
+            let files: HashSet<&str> = self.items
+                .values()
+                .filter_map(|item| item.location())
+                .map(|location| &*location.file)
+                .collect();
+
+            files.into_iter()
+                .filter(|file| {
+                    self.options.whitelisted_files.matches(file) ||
+                    inputs.contains(&canonical(file))
+                })
+                .map(|file| file.to_owned())
+                .collect()
+        };
+        self.primary_files = primary_files;
+    }
+
+    fn find_anonymous_enum_typedefs(&mut self) {
+        let mut typedefs = HashMap::new();
+        // The items are visited in declaration order, so if there are several
+        // `typedef`s in the declaration, the first one wins.
+        for (&id, item) in self.items() {
+            let mut inner = match item.as_type().map(|ty| ty.kind()) {
+                Some(&TypeKind::Alias(inner)) => inner,
+                _ => continue,
+            };
+            while let Some(&TypeKind::ResolvedTypeRef(next)) =
+                self.resolve_item(inner).as_type().map(|ty| ty.kind()) {
+                inner = next;
+            }
+
+            let inner_item = self.resolve_item(inner);
+            let is_unnamed_enum = inner_item.as_type()
+                .map_or(false, |ty| ty.is_enum() && ty.name().is_none());
+            if is_unnamed_enum && inner_item.parent_id() == item.parent_id() {
+                typedefs.entry(inner).or_insert(id);
+            }
+        }
+        self.anonymous_enum_typedefs = Some(typedefs);
+    }
+
+    fn find_new_type_aliases(&mut self) {
+        let mut new_type_aliases = BTreeMap::new();
+        if self.options.new_type_aliases.is_empty() &&
+           self.options.new_type_alias_deref.is_empty() &&
+           self.options.default_alias_style == AliasVariation::TypeAlias {
+            self.new_type_aliases = Some(new_type_aliases);
+            return;
+        }
+
+        for id in self.whitelisted_items() {
+            let item = self.resolve_item(id);
+            let is_alias = item.as_type().map_or(false, |ty| match *ty.kind() {
+                TypeKind::Alias(..) => true,
+                _ => false,
+            });
+            if !is_alias {
+                continue;
+            }
+            match self.alias_style(item) {
+                AliasVariation::TypeAlias => {}
+                variation => {
+                    new_type_aliases.insert(id, variation);
+                }
+            }
+        }
+
+        // In a chain of matching aliases, only the outermost one becomes a new
+        // type, otherwise we'd end up with new types wrapping new types.
+        let mut inner_aliases = vec![];
+        for &id in new_type_aliases.keys() {
+            let mut inner = match *self.resolve_type(id).kind() {
+                TypeKind::Alias(inner) => inner,
+                _ => unreachable!(),
+            };
+            loop {
+                inner = match *self.resolve_type(inner).kind() {
+                    TypeKind::ResolvedTypeRef(next) => next,
+                    TypeKind::Alias(next) => {
+                        inner_aliases.push(inner);
+                        next
+                    }
+                    _ => break,
+                };
+            }
+        }
+
+        for id in inner_aliases {
+            new_type_aliases.remove(&id);
+        }
+
+        self.new_type_aliases = Some(new_type_aliases);
+    }
+
+    /// Figure out the `#[cfg]` predicate the code generated for each
+    /// whitelisted item needs, see `Builder::wrap_matching_items_in_cfg`.
+    ///
+    /// Items matching one of the regexes get its predicate. Other types get
+    /// the predicate of the items that use them, as long as they all agree, so
+    /// they don't stay around when nothing needs them. Conversely, a type
+    /// that's used by an item with another predicate, or without one, can't
+    /// go away, so it gets none, even if it matches.
+    fn find_item_cfgs(&mut self) {
+        if self.options.cfg_items.is_empty() {
+            return;
+        }
+
+        let items: Vec<ItemId> = self.whitelisted_items()
+            .filter(|id| !self.resolve_item(*id).is_module())
+            .collect();
+
+        let mut matched = HashMap::new();
+        let mut users: HashMap<ItemId, Vec<ItemId>> = HashMap::new();
+        for &id in &items {
+            let name = id.canonical_path(self)[1..].join("::");
+            let predicate = self.options
+                .cfg_items
+                .iter()
+                .find(|&&(_, ref set)| set.matches(&name));
+            if let Some(&(ref predicate, _)) = predicate {
+                matched.insert(id, predicate.clone());
+            }
+
+            id.trace(self,
+                     &mut |sub_id: ItemId, _| if sub_id != id {
+                         users.entry(sub_id).or_insert(vec![]).push(id);
+                     },
+                     &());
+        }
+
+        // Absent entries are types we don't know about yet. Once they're
+        // known, they can only go from a predicate to none, so this
+        // terminates.
+        let mut cfgs: HashMap<ItemId, Option<String>> = HashMap::new();
+        for &id in &items {
+            let is_type = self.resolve_item(id).as_type().is_some();
+            if !is_type || !users.contains_key(&id) {
+                cfgs.insert(id, matched.get(&id).cloned());
+            }
+        }
+
+        loop {
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for &id in &items {
+                    if !users.contains_key(&id) ||
+                       cfgs.get(&id).map_or(false, |cfg| cfg.is_none()) ||
+                       self.resolve_item(id).as_type().is_none() {
+                        continue;
+                    }
+
+                    let mut agreed = matched.get(&id).cloned();
+                    let mut conflict = false;
+                    for user in &users[&id] {
+                        match cfgs.get(user) {
+                            None => {}
+                            Some(&None) => conflict = true,
+                            Some(&Some(ref predicate)) => {
+                                if agreed.as_ref()
+                                    .map_or(false, |p| p != predicate) {
+                                    conflict = true;
+                                }
+                                agreed = Some(predicate.clone());
+                            }
+                        }
+                    }
+
+                    let known = users[&id].iter().any(|u| cfgs.contains_key(u));
+                    let cfg = if conflict {
+                        None
+                    } else if known {
+                        agreed
+                    } else {
+                        continue;
+                    };
+
+                    if cfgs.get(&id) != Some(&cfg) {
+                        cfgs.insert(id, cfg);
+                        changed = true;
+                    }
+                }
+            }
+
+            // What's left is only used by other unknown types, like cycles of
+            // pointers, so just go with what they match.
+            let unknown: Vec<_> = items.iter()
+                .filter(|id| !cfgs.contains_key(id))
+                .cloned()
+                .collect();
+            if unknown.is_empty() {
+                break;
+            }
+            for id in unknown {
+                cfgs.insert(id, matched.get(&id).cloned());
+            }
+        }
+
+        self.item_cfgs = cfgs.into_iter()
+            .filter_map(|(id, cfg)| cfg.map(|cfg| (id, cfg)))
+            .collect();
+    }
+
+    /// The `#[cfg]` predicate to wrap the code generated for the given item
+    /// in, if any.
+    pub fn item_cfg(&self, id: ItemId) -> Option<&str> {
+        self.item_cfgs.get(&id).map(|cfg| &**cfg)
+    }
+
+    /// Assign a module to every top-level item that isn't a namespace, named
+    /// after the header that declared the item. Headers with the same file stem
+    /// get a numeric suffix, and items without a location, like builtins, go
+    /// to a `builtins` module.
+    fn assign_header_modules(&mut self) {
+        if !self.options.module_per_header {
+            return;
+        }
+
+        let root = self.root_module;
+        let mut names_by_file = HashMap::<Option<String>, String>::new();
+        let mut taken = HashSet::new();
+        let mut header_modules = HashMap::new();
+
+        for (&id, item) in &self.items {
+            if id == root || item.parent_id() != root || item.is_module() {
+                continue;
+            }
+
+            let file = item.location().map(|location| location.file.clone());
+            let name = names_by_file.entry(file.clone())
+                .or_insert_with(|| {
+                    let stem = match file {
+                        Some(ref file) => {
+                            self.rust_mangle(&header_module_stem(file))
+                                .into_owned()
+                        }
+                        None => "builtins".to_owned(),
+                    };
+                    let mut name = stem.clone();
+                    let mut suffix = 1;
+                    while !taken.insert(name.clone()) {
+                        name = format!("{}{}", stem, suffix);
+                        suffix += 1;
+                    }
+                    name
+                })
+                .clone();
+            header_modules.insert(id, name);
+        }
+
+        self.header_modules = header_modules;
+    }
+
+    fn find_shared_type_paths(&mut self) {
+        let mut seen = HashSet::new();
+        let mut shared = HashSet::new();
+        for item in self.items.values() {
+            if item.is_hidden(self) {
+                continue;
+            }
+
+            let ty = match item.kind().as_type() {
+                Some(ty) => ty,
+                None => continue,
+            };
+
+            match *ty.kind() {
+                TypeKind::Comp(..) |
+                TypeKind::Enum(..) => {}
+                TypeKind::TemplateAlias(inner, _) |
+                TypeKind::Alias(inner) => {
+                    // We don't generate anything for the `typedef enum foo foo`
+                    // pattern, see the alias codegen.
+                    if self.resolve_item(inner).canonical_name(self) ==
+                       item.canonical_name(self) {
+                        continue;
+                    }
+                }
+                _ => continue,
+            }
+
+            let path = item.canonical_path(self);
+            if !seen.insert(path.clone()) {
+                shared.insert(path);
+            }
+        }
+        self.shared_type_paths = Some(shared);
+    }
+
+    /// The module named after a header the given item goes to, if we're
+    /// generating a module per header and the item isn't inside a namespace.
+    pub fn header_module(&self, item: &Item) -> Option<&str> {
+        if self.header_modules.is_empty() {
+            return None;
+        }
+
+        let mut item = item;
+        while item.parent_id() != self.root_module {
+            if item.id() == self.root_module {
+                return None;
+            }
+            item = self.resolve_item(item.parent_id());
+        }
+
+        self.header_modules.get(&item.id()).map(|name| &**name)
+    }
+
+    /// When not whitelisting recursively, sort the types the whitelisted items
+    /// refer to but that aren't whitelisted themselves into the ones only used
+    /// behind pointers, that we can forward declare, and the ones used by
+    /// value, that we can't generate anything sensible for.
+    fn find_types_outside_whitelist(&mut self) {
+        let mut forward_declared = ItemIdSet::new();
+        let mut missing = vec![];
+
+        if !self.options.whitelist_recursively {
+            let whitelisted: ItemIdSet = self.whitelisted_items().collect();
+            for id in &whitelisted {
+                let mut edges = vec![];
+                id.trace(self,
+                         &mut |sub_id: ItemId, kind: EdgeKind| {
+                             edges.push((sub_id, kind))
+                         },
+                         &());
+
+                for (sub_id, kind) in edges {
+                    match kind {
+                        // These are generated along with the item, if at all.
+                        EdgeKind::TemplateParameterDefinition |
+                        EdgeKind::TemplateDeclaration |
+                        EdgeKind::InnerType |
+                        EdgeKind::InnerVar |
+                        EdgeKind::Method |
+                        EdgeKind::Constructor => continue,
+                        _ => {}
+                    }
+                    self.find_outside_whitelist(&whitelisted,
+                                                id,
+                                                sub_id,
+                                                false,
+                                                &mut forward_declared,
+                                                &mut missing);
+                }
+            }
+        }
+
+        self.forward_declared_types = Some(forward_declared);
+        self.types_missing_from_whitelist = missing;
+    }
+
+    fn find_outside_whitelist(&self,
+                              whitelisted: &ItemIdSet,
+                              user: ItemId,
+                              id: ItemId,
+                              behind_pointer: bool,
+                              forward_declared: &mut ItemIdSet,
+                              missing: &mut Vec<(ItemId, ItemId)>) {
+        if whitelisted.contains(&id) {
+            return;
+        }
+
+        let item = self.resolve_item(id);
+        if item.is_hidden(self) {
+            return;
+        }
+
+        let ty = match item.as_type() {
+            Some(ty) => ty,
+            None => return,
+        };
+
+        match *ty.kind() {
+            TypeKind::Pointer(inner) |
+            TypeKind::Reference(inner, _) => {
+                self.find_outside_whitelist(whitelisted,
+                                            user,
+                                            inner,
+                                            true,
+                                            forward_declared,
+                                            missing);
+            }
+            TypeKind::ResolvedTypeRef(inner) |
+            TypeKind::Array(inner, _) |
+            TypeKind::ConstGenericArray(inner, _) => {
+                self.find_outside_whitelist(whitelisted,
+                                            user,
+                                            inner,
+                                            behind_pointer,
+                                            forward_declared,
+                                            missing);
+            }
+            TypeKind::Function(ref sig) => {
+                let args = sig.argument_types().iter().map(|&(_, arg)| arg);
+                for sub_id in iter::once(sig.return_type()).chain(args) {
+                    self.find_outside_whitelist(whitelisted,
+                                                user,
+                                                sub_id,
+                                                false,
+                                                forward_declared,
+                                                missing);
+                }
+            }
+            TypeKind::TemplateInstantiation(ref inst) => {
+                // We can't forward declare a template, since we need to know
+                // its parameters.
+                self.find_outside_whitelist(whitelisted,
+                                            user,
+                                            inst.template_definition(),
+                                            false,
+                                            forward_declared,
+                                            missing);
+                for &arg in inst.template_arguments() {
+                    self.find_outside_whitelist(whitelisted,
+                                                user,
+                                                arg,
+                                                behind_pointer,
+                                                forward_declared,
+                                                missing);
+                }
+            }
+            TypeKind::Comp(..) |
+            TypeKind::Alias(..) |
+            TypeKind::TemplateAlias(..) |
+            TypeKind::Enum(..) |
+            TypeKind::Opaque => {
+                // Anonymous types are generated along with their parent.
+                if ty.name().is_none() {
+                    return;
+                }
+
+                if behind_pointer && id.self_template_params(self).is_none() {
+                    // The types that aren't defined anywhere in the headers
+                    // are up to the user to provide, with `raw_line` for
+                    // example, as they always were.
+                    let is_incomplete = ty.as_comp()
+                        .map_or(false, |ci| ci.is_forward_declaration());
+                    if !is_incomplete {
+                        forward_declared.insert(id);
+                    }
+                } else if !missing.iter().any(|&(_, m)| m == id) {
+                    missing.push((user, id));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the types we forward declare because the whitelisted items only
+    /// use them behind pointers. See `Builder::whitelist_recursively`.
+    ///
+    /// This method may only be called during the codegen phase.
+    pub fn forward_declared_types(&self) -> &ItemIdSet {
+        self.forward_declared_types
+            .as_ref()
+            .expect("should have found the forward declared types if we're in \
+                     codegen")
+    }
+
+    /// Get the types the whitelisted items use by value but that aren't
+    /// whitelisted, each along with the first item we found using it. Only
+    /// relevant when not whitelisting recursively.
+    ///
+    /// Only the structs and unions among these make the generation fail: the
+    /// bindings can refer to the rest by name, and leave them to the user.
+    pub fn types_missing_from_whitelist(&self) -> &[(ItemId, ItemId)] {
+        &self.types_missing_from_whitelist
+    }
+
+    /// The `typedef` the unnamed enum with the given `id` is declared in, if
+    /// any.
+    ///
+    /// The `typedef`s are only known once parsing is done, so this always
+    /// returns `None` before the codegen phase.
+    pub fn anonymous_enum_typedef(&self, id: ItemId) -> Option<ItemId> {
+        self.anonymous_enum_typedefs
+            .as_ref()
+            .and_then(|typedefs| typedefs.get(&id).cloned())
+    }
+
+    /// Is the given canonical path shared by more than one generated type?
+    ///
+    /// This method may only be called during the codegen phase.
+    pub fn is_shared_type_path(&self, path: &[Name]) -> bool {
+        self.shared_type_paths
+            .as_ref()
+            .expect("should have found the shared paths if we're in codegen")
+            .contains(path)
+    }
+
+    /// Should the alias with the given `id` be generated as a new type wrapping
+    /// the aliased type instead of a plain type alias?
+    ///
+    /// This method may only be called during the codegen phase.
+    pub fn is_new_type_alias(&self, id: ItemId) -> bool {
+        self.new_type_aliases
+            .as_ref()
+            .expect("should have found the new type aliases if we're in codegen")
+            .contains_key(&id)
+    }
+
+    /// Should the new type generated for the alias with the given `id`
+    /// implement `Deref` and `DerefMut` to the aliased type?
+    ///
+    /// This method may only be called during the codegen phase.
+    pub fn new_type_alias_derefs(&self, id: ItemId) -> bool {
+        self.new_type_aliases
+            .as_ref()
+            .expect("should have found the new type aliases if we're in codegen")
+            .get(&id) == Some(&AliasVariation::NewTypeDeref)
+    }
+
     /// Return `true` if `item` uses the given `template_param`, `false`
     /// otherwise.
     ///
@@ -656,8 +1493,8 @@ impl<'ctx> BindgenContext<'ctx> {
         assert!(old_item.is_none(), "Inserted type twice?");
     }
 
-    fn build_root_module(id: ItemId) -> Item {
-        let module = Module::new(Some("root".into()), ModuleKind::Normal);
+    fn build_root_module(id: ItemId, name: &str) -> Item {
+        let module = Module::new(Some(name.into()), ModuleKind::Normal);
         Item::new(id, None, None, id, ItemKind::Module(module))
     }
 
@@ -666,6 +1503,33 @@ impl<'ctx> BindgenContext<'ctx> {
         self.root_module
     }
 
+    /// Don't parse the given declaration, nor its redeclarations.
+    pub fn skip_declaration(&mut self, declaration: &clang::Cursor) {
+        self.skipped_declarations.insert(declaration_key(declaration));
+    }
+
+    /// Is the given cursor a declaration we shouldn't parse?
+    pub fn is_skipped_declaration(&self, cursor: &clang::Cursor) -> bool {
+        !self.skipped_declarations.is_empty() && cursor.is_declaration() &&
+        self.skipped_declarations.contains(&declaration_key(cursor))
+    }
+
+    /// Did we find any namespace while parsing?
+    pub fn has_namespaces(&self) -> bool {
+        !self.modules.is_empty()
+    }
+
+    /// Stop emulating C++ namespaces, so the contents of the root module end
+    /// up at the top level, see `Builder::inline_root_module`.
+    pub fn inline_root_module(&mut self) {
+        assert!(!self.in_codegen_phase(),
+                "Paths are settled by the time we generate code");
+        self.options.enable_cxx_namespaces = false;
+
+        // We may have computed some paths through the root module already.
+        self.canonical_paths.borrow_mut().clear();
+    }
+
     /// Resolve the given `ItemId` as a type.
     ///
     /// Panics if there is no item for the given `ItemId` or if the resolved
@@ -918,8 +1782,11 @@ impl<'ctx> BindgenContext<'ctx> {
                     }
                 }
                 _ => {
-                    warn!("Found template arg cursor we can't handle: {:?}",
-                          child);
+                    let message = format!("{}: Found template arg cursor we \
+                                           can't handle: {:?}",
+                                          child.location(),
+                                          child);
+                    self.diagnostic(DiagnosticSeverity::Warning, message);
                     found_const_arg = true;
                 }
             }
@@ -932,14 +1799,19 @@ impl<'ctx> BindgenContext<'ctx> {
             // arguments. For example, `Foo<true, 5>` versus `Bar<bool, int>`.
             // We can't handle these instantiations, so just punt in this
             // situation...
-            warn!("Found template instantiated with a const value; \
-                   bindgen can't handle this kind of template instantiation!");
+            let message = format!("{}: Found template instantiated with a \
+                                   const value; bindgen can't handle this \
+                                   kind of template instantiation!",
+                                  location.location());
+            self.diagnostic(DiagnosticSeverity::Warning, message);
             return None;
         }
 
         if args.len() != num_expected_args {
-            warn!("Found a template with an unexpected number of template \
-                   arguments");
+            let message = format!("{}: Found a template with an unexpected \
+                                   number of template arguments",
+                                  location.location());
+            self.diagnostic(DiagnosticSeverity::Warning, message);
             return None;
         }
 
@@ -1075,6 +1947,12 @@ impl<'ctx> BindgenContext<'ctx> {
         with_id
     }
 
+    /// Get an upper bound of the ids of all the items created so far, that
+    /// is, the id the next item will get.
+    pub fn item_id_bound(&self) -> usize {
+        self.next_item_id.0
+    }
+
     /// Returns the next item id to be used for an item.
     pub fn next_item_id(&mut self) -> ItemId {
         let ret = self.next_item_id;
@@ -1094,6 +1972,9 @@ impl<'ctx> BindgenContext<'ctx> {
             CXType_UChar | CXType_Char_U => TypeKind::Int(IntKind::UChar),
             CXType_Short => TypeKind::Int(IntKind::Short),
             CXType_UShort => TypeKind::Int(IntKind::UShort),
+            // `wchar_t` is 16 bits wide on Windows, and 32 bits wide
+            // pretty much everywhere else, so ask clang.
+            CXType_WChar if ty.size() == 4 => TypeKind::Int(IntKind::I32),
             CXType_WChar | CXType_Char16 => TypeKind::Int(IntKind::U16),
             CXType_Char32 => TypeKind::Int(IntKind::U32),
             CXType_Long => TypeKind::Int(IntKind::Long),
@@ -1154,6 +2035,42 @@ impl<'ctx> BindgenContext<'ctx> {
         self.parsed_macros.insert(id, value);
     }
 
+    /// Note a function-like macro to generate a function for once every
+    /// function is parsed.
+    pub fn note_macro_fn(&mut self, macro_fn: MacroFunction, cursor: Cursor) {
+        self.macro_fns.push((macro_fn, cursor));
+    }
+
+    /// Add the functions for the function-like macros noted so far, skipping
+    /// the ones we can't translate with a warning.
+    pub fn add_macro_fns(&mut self) {
+        debug_assert!(!self.in_codegen_phase());
+        let current_module = self.current_module;
+        for (macro_fn, cursor) in mem::replace(&mut self.macro_fns, vec![]) {
+            let name = macro_fn.name().to_owned();
+            let function = match macro_fn.into_function(self) {
+                Ok(function) => function,
+                Err(reason) => {
+                    let message = format!("{}: Skipping function-like macro \
+                                           `{}`, since {}",
+                                          cursor.location(),
+                                          name,
+                                          reason);
+                    self.diagnostic(DiagnosticSeverity::Warning, message);
+                    continue;
+                }
+            };
+
+            let id = self.next_item_id();
+            let item = Item::new(id,
+                                 None,
+                                 None,
+                                 current_module,
+                                 ItemKind::Function(function));
+            self.add_item(item, None, Some(cursor));
+        }
+    }
+
     /// Are we in the codegen phase?
     pub fn in_codegen_phase(&self) -> bool {
         self.gen_ctx.is_some()
@@ -1165,14 +2082,15 @@ impl<'ctx> BindgenContext<'ctx> {
     /// Replacement types are declared using the `replaces="xxx"` annotation,
     /// and implies that the original type is hidden.
     pub fn replace(&mut self, name: &[String], potential_ty: ItemId) {
-        match self.replacements.entry(name.into()) {
-            hash_map::Entry::Vacant(entry) => {
+        let name: Vec<_> = name.iter().map(|n| self.names.intern(n)).collect();
+        match self.replacements.entry(name.clone()) {
+            btree_map::Entry::Vacant(entry) => {
                 debug!("Defining replacement for {:?} as {:?}",
                        name,
                        potential_ty);
                 entry.insert(potential_ty);
             }
-            hash_map::Entry::Occupied(occupied) => {
+            btree_map::Entry::Occupied(occupied) => {
                 warn!("Replacement for {:?} already defined as {:?}; \
                        ignoring duplicate replacement definition as {:?}",
                       name,
@@ -1182,9 +2100,89 @@ impl<'ctx> BindgenContext<'ctx> {
         }
     }
 
+    /// Note that we couldn't understand the given type, and approximated it
+    /// with the item `id` instead.
+    pub fn record_opaque_fallback(&mut self,
+                                  id: ItemId,
+                                  ty: &clang::Type,
+                                  reason: &'static str) {
+        debug!("Falling back for {:?} ({}): {}", id, ty.spelling(), reason);
+        self.opaque_fallbacks.push(OpaqueFallback {
+            id: id,
+            spelling: ty.spelling(),
+            reason: reason,
+        });
+    }
+
+    /// Get the types we approximated that are part of the given set of items.
+    pub fn opaque_fallbacks_in(&self,
+                               items: &ItemIdSet)
+                               -> Vec<&OpaqueFallback> {
+        self.opaque_fallbacks
+            .iter()
+            .filter(|fallback| items.contains(&fallback.id))
+            .collect()
+    }
+
+    /// Report a condition the user may want to act on, like something we
+    /// don't understand in the input headers.
+    ///
+    /// The message is logged, handed to the user's diagnostic callback if
+    /// there's any, and kept to be returned along with the bindings.
+    pub fn diagnostic(&self, severity: DiagnosticSeverity, message: String) {
+        match severity {
+            DiagnosticSeverity::Error => error!("{}", message),
+            DiagnosticSeverity::Warning => warn!("{}", message),
+            DiagnosticSeverity::Note => info!("{}", message),
+        }
+
+        let diagnostic = Diagnostic {
+            severity: severity,
+            message: message,
+        };
+        self.callbacks.diagnostic(&diagnostic);
+        self.diagnostics.borrow_mut().push(diagnostic);
+    }
+
+    /// Take the diagnostics reported so far with `diagnostic`.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        mem::replace(&mut *self.diagnostics.borrow_mut(), vec![])
+    }
+
+    /// Get the canonical path of the item with the given `id`, using `compute`
+    /// to compute it if it's not cached yet.
+    ///
+    /// Paths are only cached during the codegen phase, since they can change
+    /// while we're still parsing.
+    pub fn canonical_path_of<F>(&self,
+                                id: ItemId,
+                                compute: F)
+                                -> Rc<Vec<Name>>
+        where F: FnOnce() -> Vec<Name>,
+    {
+        if !self.in_codegen_phase() {
+            return Rc::new(compute());
+        }
+
+        if let Some(path) = self.canonical_paths.borrow().get(&id) {
+            return path.clone();
+        }
+
+        // Note that computing the path may need other paths, so we can't keep
+        // the cache borrowed meanwhile.
+        let path = Rc::new(compute());
+        self.canonical_paths.borrow_mut().insert(id, path.clone());
+        path
+    }
+
+    /// Get the interner for item names.
+    pub fn names(&self) -> &Interner {
+        &self.names
+    }
+
     /// Is the item with the given `name` hidden? Or is the item with the given
     /// `name` and `id` replaced by another type, and effectively hidden?
-    pub fn hidden_by_name(&self, path: &[String], id: ItemId) -> bool {
+    pub fn hidden_by_name(&self, path: &[Name], id: ItemId) -> bool {
         debug_assert!(self.in_codegen_phase(),
                       "You're not supposed to call this yet");
         self.options.hidden_types.matches(&path[1..].join("::")) ||
@@ -1193,7 +2191,7 @@ impl<'ctx> BindgenContext<'ctx> {
 
     /// Has the item with the given `name` and `id` been replaced by another
     /// type?
-    pub fn is_replaced_type(&self, path: &[String], id: ItemId) -> bool {
+    pub fn is_replaced_type(&self, path: &[Name], id: ItemId) -> bool {
         match self.replacements.get(path) {
             Some(replaced_by) if *replaced_by != id => true,
             _ => false,
@@ -1201,17 +2199,112 @@ impl<'ctx> BindgenContext<'ctx> {
     }
 
     /// Is the type with the given `name` marked as opaque?
-    pub fn opaque_by_name(&self, path: &[String]) -> bool {
+    pub fn opaque_by_name(&self, path: &[Name]) -> bool {
         debug_assert!(self.in_codegen_phase(),
                       "You're not supposed to call this yet");
         self.options.opaque_types.matches(&path[1..].join("::"))
     }
 
+    /// Is the type with the given `name` one of the standard library templates
+    /// we generate sized blobs for?
+    ///
+    /// Implementation namespaces like libc++'s `std::__1` are allowed between
+    /// `std` and the template name, in case they're kept around.
+    pub fn stl_blob_by_name(&self, path: &[Name]) -> bool {
+        const STL_BLOB_TEMPLATES: &'static [&'static str] =
+            &["vector", "unique_ptr", "shared_ptr", "weak_ptr"];
+
+        if !self.options.stl_blobs || path.len() < 3 || path[1] != "std" {
+            return false;
+        }
+
+        let (name, namespaces) = path[2..].split_last().unwrap();
+        STL_BLOB_TEMPLATES.contains(&&**name) &&
+        namespaces.iter().all(|ns| ns.starts_with("__"))
+    }
+
+    /// Has the inline namespace with the given canonical path been asked to be
+    /// kept as its own module?
+    pub fn keep_inline_namespace_by_name(&self, path: &[Name]) -> bool {
+        self.options.keep_inline_namespaces.matches(&path[1..].join("::"))
+    }
+
+    /// Has the type with the given `name` been asserted to be `Send`?
+    pub fn send_by_name(&self, path: &[Name]) -> bool {
+        debug_assert!(self.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        self.options.send_types.matches(&path[1..].join("::"))
+    }
+
+    /// Has the type with the given `name` been asserted to be `Sync`?
+    pub fn sync_by_name(&self, path: &[Name]) -> bool {
+        debug_assert!(self.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        self.options.sync_types.matches(&path[1..].join("::"))
+    }
+
+    /// The paths of the traits the user wants implemented for the type with
+    /// the given canonical path, without duplicates, in the order they were
+    /// registered, followed by the ones from the parse callbacks.
+    pub fn traits_to_impl(&self, path: &[Name]) -> Vec<String> {
+        debug_assert!(self.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        let name = path[1..].join("::");
+
+        let mut traits: Vec<String> = self.options
+            .trait_impls
+            .iter()
+            .filter(|&&(_, ref set)| set.matches(&name))
+            .map(|&(ref trait_, _)| trait_.clone())
+            .collect();
+        if let Some(callbacks) = self.parse_callbacks() {
+            for trait_ in callbacks.implemented_traits(&name) {
+                if !traits.contains(&trait_) {
+                    traits.push(trait_);
+                }
+            }
+        }
+        traits
+    }
+
+    /// How should the given alias be generated? See
+    /// `Builder::default_alias_style`.
+    fn alias_style(&self, item: &Item) -> AliasVariation {
+        let name = item.canonical_path(self)[1..].join("::");
+        let options = &self.options;
+        let sets = [(AliasVariation::TypeAlias, &options.type_aliases),
+                    (AliasVariation::NewType, &options.new_type_aliases),
+                    (AliasVariation::NewTypeDeref,
+                     &options.new_type_alias_deref)];
+        let names = [name];
+        match last_matching_style(&sets, &options.alias_style_order, &names) {
+            Some((variation, conflicting)) => {
+                if conflicting {
+                    let message = format!("{}: `{}` matches the regexes of \
+                                           several typedef styles, using the \
+                                           one given last: {:?}",
+                                          item.location_for_diagnostics(),
+                                          names[0],
+                                          variation);
+                    self.diagnostic(DiagnosticSeverity::Warning, message);
+                }
+                variation
+            }
+            None => options.default_alias_style,
+        }
+    }
+
     /// Get the options used to configure this bindgen context.
     pub fn options(&self) -> &BindgenOptions {
         &self.options
     }
 
+    /// Consume this context, and get back the options and the callbacks used
+    /// to create it.
+    pub fn into_parts(self) -> (BindgenOptions, Callbacks) {
+        (self.options, self.callbacks)
+    }
+
     /// Tokenizes a namespace cursor in order to get the name and kind of the
     /// namespace,
     fn tokenize_namespace(&self,
@@ -1284,6 +2377,38 @@ impl<'ctx> BindgenContext<'ctx> {
         module_id
     }
 
+    /// Get the module a declaration belongs to, according to its semantic
+    /// parents rather than where it's lexically declared, so that for example
+    /// an out-of-line definition of a namespaced function ends up in its
+    /// namespace.
+    ///
+    /// Falls back to the current module if the declaration isn't directly in a
+    /// namespace we know about.
+    pub fn module_of(&self, cursor: &clang::Cursor) -> ItemId {
+        use clang_sys::*;
+        let mut parent = cursor.semantic_parent();
+        loop {
+            if !parent.is_valid() {
+                return self.current_module;
+            }
+
+            match parent.kind() {
+                CXCursor_TranslationUnit => return self.root_module,
+                CXCursor_Namespace => {
+                    return self.modules
+                        .get(&parent.canonical())
+                        .cloned()
+                        .unwrap_or(self.current_module);
+                }
+                // `extern "C"` and `extern "C++"` blocks don't introduce a
+                // scope of their own.
+                CXCursor_LinkageSpec |
+                CXCursor_UnexposedDecl => parent = parent.semantic_parent(),
+                _ => return self.current_module,
+            }
+        }
+    }
+
     /// Start traversing the module with the given `module_id`, invoke the
     /// callback `cb`, and then return to traversing the original module.
     pub fn with_module<F>(&mut self, module_id: ItemId, cb: F)
@@ -1313,7 +2438,15 @@ impl<'ctx> BindgenContext<'ctx> {
                 // game.
                 if self.options().whitelisted_types.is_empty() &&
                    self.options().whitelisted_functions.is_empty() &&
-                   self.options().whitelisted_vars.is_empty() {
+                   self.options().whitelisted_vars.is_empty() &&
+                   !self.whitelisting_files() {
+                    return true;
+                }
+
+                // Everything declared in the primary headers is whitelisted,
+                // the rest only when they refer to it.
+                if item.location()
+                    .map_or(false, |loc| self.primary_files.contains(&loc.file)) {
                     return true;
                 }
 
@@ -1323,7 +2456,7 @@ impl<'ctx> BindgenContext<'ctx> {
                     return true;
                 }
 
-                let name = item.canonical_path(self)[1..].join("::");
+                let name = item.cached_canonical_path(self)[1..].join("::");
                 debug!("whitelisted_items: testing {:?}", name);
                 match *item.kind() {
                     ItemKind::Module(..) => true,
@@ -1350,7 +2483,8 @@ impl<'ctx> BindgenContext<'ctx> {
                             if let TypeKind::Enum(ref enum_) = *ty.kind() {
                                 if ty.name().is_none() &&
                                    enum_.variants().iter().any(|variant| {
-                                    prefix_path.push(variant.name().into());
+                                    prefix_path.push(self.names
+                                        .intern(variant.name()));
                                     let name = prefix_path[1..].join("::");
                                     prefix_path.pop().unwrap();
                                     self.options()
@@ -1402,6 +2536,16 @@ impl<'ctx> BindgenContext<'ctx> {
     pub fn need_bindegen_complex_type(&self) -> bool {
         self.generated_bindegen_complex.get()
     }
+
+    /// Call if the `c_void` stub is used instead of `core::ffi::c_void`.
+    pub fn generated_c_void_stub(&self) {
+        self.generated_c_void_stub.set(true)
+    }
+
+    /// Whether we need to generate the `c_void` stub.
+    pub fn need_c_void_stub(&self) -> bool {
+        self.generated_c_void_stub.get()
+    }
 }
 
 /// A type that we are in the middle of parsing.
@@ -1467,3 +2611,21 @@ impl TemplateDeclaration for PartialType {
         }
     }
 }
+
+/// A valid module name for the header at the given path, based on its file
+/// stem.
+fn header_module_stem(file: &str) -> String {
+    use std::path::Path;
+
+    let stem = Path::new(file)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("header");
+    let mut name: String = stem.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.chars().next().map_or(true, |c| c.is_numeric()) {
+        name.insert(0, '_');
+    }
+    name
+}
@@ -74,13 +74,21 @@ impl Enum {
                     }
                 });
 
-        let type_name = ty.spelling();
-        let type_name = if type_name.is_empty() {
+        // C spells the type as `enum foo`, and the unnamed ones as something
+        // like `enum (anonymous at foo.h:1:1)`, but the callbacks only get
+        // `foo`, like in C++.
+        let spelling = ty.spelling();
+        let type_name = if spelling.starts_with("enum ") {
+            &spelling["enum ".len()..]
+        } else {
+            &spelling[..]
+        };
+        let type_name = if type_name.is_empty() ||
+                           type_name.starts_with('(') {
             None
         } else {
             Some(type_name)
         };
-        let type_name = type_name.as_ref().map(String::as_str);
 
         declaration.visit(|cursor| {
             if cursor.kind() == CXCursor_EnumConstantDecl {
@@ -166,6 +174,11 @@ impl EnumVariant {
         &self.name
     }
 
+    /// Get this variant's doc comment, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_ref().map(|c| &**c)
+    }
+
     /// Get this variant's value.
     pub fn val(&self) -> EnumVariantValue {
         self.val
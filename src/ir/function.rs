@@ -3,8 +3,10 @@
 use super::context::{BindgenContext, ItemId};
 use super::dot::DotAttributes;
 use super::item::Item;
+use super::macro_fn::MacroFnBody;
 use super::traversal::{EdgeKind, Trace, Tracer};
-use super::ty::TypeKind;
+use super::ty::{RUST_DERIVE_FUNPTR_LIMIT, TypeKind};
+use DiagnosticSeverity;
 use clang;
 use clang_sys::CXCallingConv;
 use ir::derive::CanDeriveDebug;
@@ -12,6 +14,92 @@ use parse::{ClangItemParser, ClangSubItemParser, ParseError, ParseResult};
 use std::io;
 use syntax::abi;
 
+/// The linkage of a function or a variable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Linkage {
+    /// The symbol can be referred to from other translation units.
+    External,
+    /// The symbol is only visible in its own translation unit, like `static`
+    /// functions, so there's nothing we can link against.
+    Internal,
+}
+
+impl Linkage {
+    /// Get the linkage of the given function or variable declaration.
+    pub fn from_cursor(cursor: &clang::Cursor) -> Self {
+        use clang_sys::*;
+        match cursor.linkage() {
+            CXLinkage_External |
+            CXLinkage_UniqueExternal => Linkage::External,
+            _ => Linkage::Internal,
+        }
+    }
+}
+
+/// The visibility of the symbol of a function or a variable in the library
+/// it's defined in, like `__attribute__((visibility("hidden")))`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    /// The symbol is exported from the library.
+    Default,
+    /// The symbol isn't exported from the library, so linking against it
+    /// will most likely fail unless the definition ends up in the same
+    /// binary.
+    Hidden,
+}
+
+impl SymbolVisibility {
+    /// Get the visibility of the given function or variable declaration.
+    pub fn from_cursor(cursor: &clang::Cursor) -> Self {
+        use clang_sys::*;
+        match cursor.visibility() {
+            CXVisibility_Hidden => SymbolVisibility::Hidden,
+            _ => SymbolVisibility::Default,
+        }
+    }
+
+    /// The doc note to put on the generated items with this visibility, if
+    /// any.
+    pub fn doc_note(&self) -> Option<&'static str> {
+        match *self {
+            SymbolVisibility::Default => None,
+            SymbolVisibility::Hidden => {
+                Some("/// Note: this symbol has hidden visibility, so it's \
+                      most likely not exported from the library that \
+                      defines it.")
+            }
+        }
+    }
+}
+
+/// How a function or a variable is imported from or exported to a DLL, with
+/// `__declspec(dllimport)` or `__declspec(dllexport)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DllStorage {
+    /// The symbol comes from another DLL.
+    Import,
+    /// The symbol is exported from the DLL being built.
+    Export,
+}
+
+impl DllStorage {
+    /// Get the DLL storage class of the given function or variable
+    /// declaration, if any.
+    pub fn from_cursor(cursor: &clang::Cursor) -> Option<Self> {
+        use clang_sys::*;
+        let mut storage = None;
+        cursor.visit(|attr| {
+            match attr.kind() {
+                CXCursor_DLLImport => storage = Some(DllStorage::Import),
+                CXCursor_DLLExport => storage = Some(DllStorage::Export),
+                _ => {}
+            }
+            CXChildVisit_Continue
+        });
+        storage
+    }
+}
+
 /// A function declaration, with a signature, arguments, and argument names.
 ///
 /// The argument names vector must be the same length as the ones in the
@@ -29,6 +117,19 @@ pub struct Function {
 
     /// The doc comment on the function, if any.
     comment: Option<String>,
+
+    /// The linkage of the function.
+    linkage: Linkage,
+
+    /// The visibility of the function's symbol.
+    visibility: SymbolVisibility,
+
+    /// Whether the function is imported from or exported to a DLL.
+    dll_storage: Option<DllStorage>,
+
+    /// What to do instead of linking against a symbol, if this function comes
+    /// from a function-like macro.
+    macro_body: Option<MacroFnBody>,
 }
 
 impl Function {
@@ -36,13 +137,20 @@ impl Function {
     pub fn new(name: String,
                mangled_name: Option<String>,
                sig: ItemId,
-               comment: Option<String>)
+               comment: Option<String>,
+               linkage: Linkage,
+               visibility: SymbolVisibility,
+               dll_storage: Option<DllStorage>)
                -> Self {
         Function {
             name: name,
             mangled_name: mangled_name,
             signature: sig,
             comment: comment,
+            linkage: linkage,
+            visibility: visibility,
+            dll_storage: dll_storage,
+            macro_body: None,
         }
     }
 
@@ -60,6 +168,32 @@ impl Function {
     pub fn signature(&self) -> ItemId {
         self.signature
     }
+
+    /// Get this function's linkage.
+    pub fn linkage(&self) -> Linkage {
+        self.linkage
+    }
+
+    /// Get the visibility of this function's symbol.
+    pub fn visibility(&self) -> SymbolVisibility {
+        self.visibility
+    }
+
+    /// Whether this function is imported from or exported to a DLL.
+    pub fn dll_storage(&self) -> Option<DllStorage> {
+        self.dll_storage
+    }
+
+    /// Get what this function does, if it comes from a function-like macro.
+    pub fn macro_body(&self) -> Option<&MacroFnBody> {
+        self.macro_body.as_ref()
+    }
+
+    /// Make this function do `body`, since it comes from a function-like
+    /// macro.
+    pub fn set_macro_body(&mut self, body: MacroFnBody) {
+        self.macro_body = Some(body);
+    }
 }
 
 impl DotAttributes for Function {
@@ -75,6 +209,19 @@ impl DotAttributes for Function {
                           mangled));
         }
 
+        try!(writeln!(out,
+                      "<tr><td>linkage</td><td>{:?}</td></tr>",
+                      self.linkage));
+        try!(writeln!(out,
+                      "<tr><td>visibility</td><td>{:?}</td></tr>",
+                      self.visibility));
+
+        if let Some(storage) = self.dll_storage {
+            try!(writeln!(out,
+                          "<tr><td>dll storage</td><td>{:?}</td></tr>",
+                          storage));
+        }
+
         Ok(())
     }
 }
@@ -89,11 +236,22 @@ pub struct FunctionSig {
     /// declared.
     argument_types: Vec<(Option<String>, ItemId)>,
 
+    /// The doc comments of the arguments, if any, in the same order as
+    /// `argument_types`.
+    argument_comments: Vec<Option<String>>,
+
     /// Whether this function is variadic.
     is_variadic: bool,
 
     /// The ABI of this function.
     abi: Option<abi::Abi>,
+
+    /// Whether this function never returns, like `abort`.
+    is_divergent: bool,
+
+    /// Whether this C++ function promises not to throw, with `noexcept` or
+    /// `throw()`.
+    is_noexcept: bool,
 }
 
 fn get_abi(cc: CXCallingConv) -> Option<abi::Abi> {
@@ -138,6 +296,103 @@ pub fn cursor_mangling(ctx: &BindgenContext,
     Some(mangling)
 }
 
+/// Whether the function with the given type and declaration never returns,
+/// because of the GNU `noreturn` attribute, C11's `_Noreturn`, or C++11's
+/// `[[noreturn]]`.
+fn is_noreturn(ctx: &BindgenContext,
+               ty: &clang::Type,
+               cursor: &clang::Cursor)
+               -> bool {
+    use clang_sys::*;
+
+    // The GNU attribute is part of the function type, even when it's only on
+    // another declaration of the function.
+    if ty.spelling().contains("__attribute__((noreturn))") {
+        return true;
+    }
+
+    // The rest are only attributes of the declaration, which libclang doesn't
+    // expose, so we look at their tokens.
+    let mut noreturn = false;
+    cursor.visit(|attr| {
+        if attr.kind() != CXCursor_UnexposedAttr {
+            return CXChildVisit_Continue;
+        }
+        let tokens = ctx.translation_unit().tokens(&attr).unwrap_or(vec![]);
+        noreturn = tokens.iter().any(|token| match &*token.spelling {
+            "noreturn" | "__noreturn__" | "_Noreturn" => true,
+            _ => false,
+        });
+        if noreturn {
+            CXChildVisit_Break
+        } else {
+            CXChildVisit_Continue
+        }
+    });
+    noreturn
+}
+
+/// The readable name of the C++ operator function with the given spelling,
+/// like `op_add` for `operator+`, if it's an operator we have a name for.
+pub fn operator_name(spelling: &str) -> Option<&'static str> {
+    if !spelling.starts_with("operator") {
+        return None;
+    }
+    Some(match spelling["operator".len()..].trim() {
+        "+" => "op_add",
+        "-" => "op_sub",
+        "*" => "op_mul",
+        "/" => "op_div",
+        "%" => "op_rem",
+        "^" => "op_bitxor",
+        "&" => "op_bitand",
+        "|" => "op_bitor",
+        "~" => "op_bitnot",
+        "!" => "op_not",
+        "=" => "op_assign",
+        "<" => "op_lt",
+        ">" => "op_gt",
+        "+=" => "op_add_assign",
+        "-=" => "op_sub_assign",
+        "*=" => "op_mul_assign",
+        "/=" => "op_div_assign",
+        "%=" => "op_rem_assign",
+        "^=" => "op_bitxor_assign",
+        "&=" => "op_bitand_assign",
+        "|=" => "op_bitor_assign",
+        "<<" => "op_shl",
+        ">>" => "op_shr",
+        "<<=" => "op_shl_assign",
+        ">>=" => "op_shr_assign",
+        "==" => "op_eq",
+        "!=" => "op_ne",
+        "<=" => "op_le",
+        ">=" => "op_ge",
+        "&&" => "op_and",
+        "||" => "op_or",
+        "++" => "op_inc",
+        "--" => "op_dec",
+        "," => "op_comma",
+        "->*" => "op_arrow_star",
+        "->" => "op_arrow",
+        "()" => "op_call",
+        "[]" => "op_index",
+        _ => return None,
+    })
+}
+
+/// Whether the function with the given type has a non-throwing exception
+/// specification, that is `noexcept`, `noexcept(true)` or `throw()`.
+///
+/// libclang doesn't expose exception specifications, but they're part of the
+/// spelling of the function type, like `void (int) noexcept`.
+fn is_noexcept(ty: &clang::Type) -> bool {
+    let spelling = ty.spelling();
+    let spelling = spelling.trim_right_matches(" __attribute__((noreturn))");
+    spelling.ends_with(" noexcept") || spelling.ends_with(" noexcept(true)") ||
+    spelling.ends_with(" throw()")
+}
+
 impl FunctionSig {
     /// Construct a new function signature.
     pub fn new(return_type: ItemId,
@@ -145,11 +400,15 @@ impl FunctionSig {
                is_variadic: bool,
                abi: Option<abi::Abi>)
                -> Self {
+        let argument_comments = vec![None; arguments.len()];
         FunctionSig {
             return_type: return_type,
             argument_types: arguments,
+            argument_comments: argument_comments,
             is_variadic: is_variadic,
             abi: abi,
+            is_divergent: false,
+            is_noexcept: false,
         }
     }
 
@@ -166,9 +425,13 @@ impl FunctionSig {
             return Err(ParseError::Continue);
         }
 
-        // Don't parse operatorxx functions in C++
+        // Don't parse operatorxx functions in C++, unless asked to emit the
+        // free ones we have a name for.
         let spelling = cursor.spelling();
-        if spelling.starts_with("operator") {
+        if spelling.starts_with("operator") &&
+           !(ctx.options().emit_operator_functions &&
+             cursor.kind() == CXCursor_FunctionDecl &&
+             operator_name(&spelling).is_some()) {
             return Err(ParseError::Continue);
         }
 
@@ -178,6 +441,7 @@ impl FunctionSig {
             ty.declaration()
         };
 
+        let mut comments = vec![];
         let mut args: Vec<_> = match cursor.kind() {
             CXCursor_FunctionDecl |
             CXCursor_Constructor |
@@ -195,6 +459,7 @@ impl FunctionSig {
                         let name =
                             if name.is_empty() { None } else { Some(name) };
                         let ty = Item::from_ty_or_ref(arg_ty, *arg, None, ctx);
+                        comments.push(arg.raw_comment());
                         (name, ty)
                     })
                     .collect()
@@ -211,6 +476,7 @@ impl FunctionSig {
                         let name =
                             if name.is_empty() { None } else { Some(name) };
                         args.push((name, ty));
+                        comments.push(c.raw_comment());
                     }
                     CXChildVisit_Continue
                 });
@@ -236,11 +502,13 @@ impl FunctionSig {
                 let ptr =
                     Item::builtin_type(TypeKind::Pointer(class), is_const, ctx);
                 args.insert(0, (Some("this".into()), ptr));
+                comments.insert(0, None);
             } else if is_virtual {
                 let void = Item::builtin_type(TypeKind::Void, false, ctx);
                 let ptr =
                     Item::builtin_type(TypeKind::Pointer(void), false, ctx);
                 args.insert(0, (Some("this".into()), ptr));
+                comments.insert(0, None);
             }
         }
 
@@ -259,7 +527,11 @@ impl FunctionSig {
                        "Invalid ABI for function signature")
         }
 
-        Ok(Self::new(ret, args, ty.is_variadic(), abi))
+        let mut sig = Self::new(ret, args, ty.is_variadic(), abi);
+        sig.argument_comments = comments;
+        sig.is_divergent = is_noreturn(ctx, ty, &cursor);
+        sig.is_noexcept = is_noexcept(ty);
+        Ok(sig)
     }
 
     /// Get this function signature's return type.
@@ -272,11 +544,27 @@ impl FunctionSig {
         &self.argument_types
     }
 
+    /// Get the doc comments of this function signature's arguments, in the
+    /// same order as `argument_types`.
+    pub fn argument_comments(&self) -> &[Option<String>] {
+        &self.argument_comments
+    }
+
     /// Get this function signature's ABI.
     pub fn abi(&self) -> Option<abi::Abi> {
         self.abi
     }
 
+    /// Does this function never return?
+    pub fn is_divergent(&self) -> bool {
+        self.is_divergent
+    }
+
+    /// Is this function declared not to throw?
+    pub fn is_noexcept(&self) -> bool {
+        self.is_noexcept
+    }
+
     /// Is this function signature variadic?
     pub fn is_variadic(&self) -> bool {
         // Clang reports some functions as variadic when they *might* be
@@ -301,24 +589,30 @@ impl ClangSubItemParser for Function {
 
         debug!("Function::parse({:?}, {:?})", cursor, cursor.cur_type());
 
-        let visibility = cursor.visibility();
-        if visibility != CXVisibility_Default {
-            return Err(ParseError::Continue);
-        }
-
         if cursor.access_specifier() == CX_CXXPrivate {
             return Err(ParseError::Continue);
         }
 
+        // There's no symbol to link against for inline functions, and we
+        // don't generate any wrappers for them either, which is also why
+        // `static inline` ones are skipped before their linkage is checked.
         if cursor.is_inlined_function() {
             return Err(ParseError::Continue);
         }
 
-        let linkage = cursor.linkage();
-        if linkage != CXLinkage_External &&
-           linkage != CXLinkage_UniqueExternal {
+        let linkage = Linkage::from_cursor(&cursor);
+        if linkage == Linkage::Internal &&
+           !context.options().generate_static_fns_anyway {
+            let message = format!("{}: Skipping `{}`, since it has internal \
+                                   linkage and there would be nothing to \
+                                   link against",
+                                  cursor.location(),
+                                  cursor.spelling());
+            context.diagnostic(DiagnosticSeverity::Warning, message);
             return Err(ParseError::Continue);
         }
+        let visibility = SymbolVisibility::from_cursor(&cursor);
+        let dll_storage = DllStorage::from_cursor(&cursor);
 
         // Grab the signature using Item::from_ty.
         let sig =
@@ -334,7 +628,13 @@ impl ClangSubItemParser for Function {
 
         let comment = cursor.raw_comment();
 
-        let function = Self::new(name, mangled_name, sig, comment);
+        let function = Self::new(name,
+                                 mangled_name,
+                                 sig,
+                                 comment,
+                                 linkage,
+                                 visibility,
+                                 dll_storage);
         Ok(ParseResult::New(function, Some(cursor)))
     }
 }
@@ -364,7 +664,6 @@ impl CanDeriveDebug for FunctionSig {
     type Extra = ();
 
     fn can_derive_debug(&self, _ctx: &BindgenContext, _: ()) -> bool {
-        const RUST_DERIVE_FUNPTR_LIMIT: usize = 12;
         if self.argument_types.len() > RUST_DERIVE_FUNPTR_LIMIT {
             return false;
         }
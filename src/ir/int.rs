@@ -1,5 +1,6 @@
 //! Intermediate representation for integral types.
 
+
 /// Which integral type are we dealing with?
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum IntKind {
@@ -91,6 +92,16 @@ impl IntKind {
         }
     }
 
+    /// Is this one of the C integer types, whose width depends on the target?
+    pub fn is_c_integer(&self) -> bool {
+        use self::IntKind::*;
+        match *self {
+            Char | UChar | Short | UShort | Int | UInt | Long | ULong |
+            LongLong | ULongLong => true,
+            _ => false,
+        }
+    }
+
     /// If this type has a known size, return it (in bytes). This is to
     /// alleviate libclang sometimes not giving us a layout (like in the case
     /// when an enum is defined inside a class with template parameters).
@@ -111,3 +122,57 @@ impl IntKind {
         val >= 0 || self.is_signed()
     }
 }
+
+/// A well-known integer typedef, like `uint32_t`, which maps directly to a
+/// Rust primitive type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KnownTypedef {
+    /// The Rust primitive type this typedef maps to.
+    pub rust_name: &'static str,
+    /// Whether the typedef'd type is signed.
+    pub is_signed: bool,
+    /// The size of the typedef'd type in bytes, or `None` if it's
+    /// pointer-sized.
+    pub size: Option<usize>,
+}
+
+impl KnownTypedef {
+    /// Look up the given typedef name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (rust_name, is_signed, size) = match name {
+            "int8_t" => ("i8", true, Some(1)),
+            "uint8_t" => ("u8", false, Some(1)),
+            "int16_t" => ("i16", true, Some(2)),
+            "uint16_t" => ("u16", false, Some(2)),
+            "int32_t" => ("i32", true, Some(4)),
+            "uint32_t" => ("u32", false, Some(4)),
+            "int64_t" => ("i64", true, Some(8)),
+            "uint64_t" => ("u64", false, Some(8)),
+
+            "uintptr_t" | "size_t" => ("usize", false, None),
+            "intptr_t" | "ptrdiff_t" | "ssize_t" => ("isize", true, None),
+            _ => return None,
+        };
+
+        Some(KnownTypedef {
+            rust_name: rust_name,
+            is_signed: is_signed,
+            size: size,
+        })
+    }
+
+    /// Whether an integer of the given kind and size (in bytes) can be
+    /// represented by this typedef's Rust type, on a target with pointers of
+    /// the given size. Headers are free to define these names to whatever they
+    /// want, and we don't want to silently change the layout of anything if
+    /// they do.
+    pub fn matches(&self,
+                   kind: IntKind,
+                   size: usize,
+                   pointer_size: usize)
+                   -> bool {
+        let expected_size = self.size.unwrap_or(pointer_size);
+        kind != IntKind::Bool && kind.is_signed() == self.is_signed &&
+        size == expected_size
+    }
+}
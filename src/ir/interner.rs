@@ -0,0 +1,129 @@
+//! Interning of item names.
+//!
+//! The same names come up over and over while naming items: every item inside
+//! a namespace or a class has the names of its ancestors as part of its
+//! canonical name, for example. Interning them means each of those names is
+//! only stored once, and handing it around is just a reference count bump
+//! instead of a new `String`.
+
+use aster::ident::ToIdent;
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use syntax::ast;
+
+/// An interned name.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Name(Rc<String>);
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        **self.0 == *other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Name {
+    fn eq(&self, other: &&'a str) -> bool {
+        **self.0 == **other
+    }
+}
+
+impl PartialEq<Name> for str {
+    fn eq(&self, other: &Name) -> bool {
+        *self == **other.0
+    }
+}
+
+impl<'a> PartialEq<Name> for &'a str {
+    fn eq(&self, other: &Name) -> bool {
+        **self == **other.0
+    }
+}
+
+impl PartialEq<Name> for String {
+    fn eq(&self, other: &Name) -> bool {
+        *self == *other.0
+    }
+}
+
+impl ToIdent for Name {
+    fn to_ident(&self) -> ast::Ident {
+        (&**self.0).to_ident()
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self.0, f)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self.0, f)
+    }
+}
+
+/// The set of names interned so far.
+#[derive(Debug, Default)]
+pub struct Interner {
+    names: RefCell<HashSet<Name>>,
+}
+
+impl Interner {
+    /// Get the interned version of `name`, interning it if it wasn't already.
+    pub fn intern(&self, name: &str) -> Name {
+        if let Some(interned) = self.names.borrow().get(name) {
+            return interned.clone();
+        }
+        self.insert(name.to_owned())
+    }
+
+    /// Like `intern`, but reuses the given `String` if it needs to be
+    /// interned.
+    pub fn intern_string(&self, name: String) -> Name {
+        if let Some(interned) = self.names.borrow().get(&*name) {
+            return interned.clone();
+        }
+        self.insert(name)
+    }
+
+    /// The number of distinct names interned so far.
+    pub fn len(&self) -> usize {
+        self.names.borrow().len()
+    }
+
+    /// Has nothing been interned yet?
+    pub fn is_empty(&self) -> bool {
+        self.names.borrow().is_empty()
+    }
+
+    fn insert(&self, name: String) -> Name {
+        let interned = Name(Rc::new(name));
+        self.names.borrow_mut().insert(interned.clone());
+        interned
+    }
+}
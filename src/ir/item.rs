@@ -4,23 +4,70 @@ use super::annotations::Annotations;
 use super::context::{BindgenContext, ItemId, PartialType};
 use super::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
 use super::dot::DotAttributes;
-use super::function::Function;
+use super::function::{Function, operator_name};
+use super::interner::Name;
 use super::item_kind::ItemKind;
 use super::layout::Opaque;
+use super::macro_fn::MacroFnBody;
 use super::module::Module;
 use super::template::AsNamed;
 use super::traversal::{EdgeKind, Trace, Tracer};
 use super::ty::{TemplateDeclaration, Type, TypeKind};
+use {DiagnosticSeverity, HiddenAliasVariation};
 use clang;
 use clang_sys;
 use parse::{ClangItemParser, ClangSubItemParser, ParseError, ParseResult};
+use std::borrow::Cow;
 use std::cell::{Cell, RefCell};
-use std::collections::BTreeSet;
-use std::fmt::Write;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
 use std::io;
 use std::iter;
+use std::rc::Rc;
 use regex;
 
+/// The place in the input headers where an item was declared.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// The file the item was declared in.
+    pub file: String,
+    /// The line of the declaration, starting at 1.
+    pub line: usize,
+    /// The column of the declaration, starting at 1.
+    pub column: usize,
+}
+
+impl Location {
+    /// Get the location of the given cursor, unless it doesn't come from an
+    /// actual file, like builtin definitions.
+    ///
+    /// The same file can be included through different paths, so `file_names`
+    /// keeps the name we first saw each file with, keyed by clang's unique id
+    /// for it, and that's the one we use.
+    pub fn from_cursor(cursor: &clang::Cursor,
+                       file_names: &mut HashMap<[u64; 3], String>)
+                       -> Option<Self> {
+        let (file, line, column, _) = cursor.location().location();
+        file.name().map(|name| {
+            let name = match file.unique_id() {
+                Some(id) => file_names.entry(id).or_insert(name).clone(),
+                None => name,
+            };
+            Location {
+                file: name,
+                line: line,
+                column: column,
+            }
+        })
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
 /// A trait to get the canonical name from an item.
 ///
 /// This is the trait that will eventually isolate all the logic related to name
@@ -33,7 +80,7 @@ use regex;
 /// return any rust keyword from here.
 pub trait ItemCanonicalName {
     /// Get the canonical name for this item.
-    fn canonical_name(&self, ctx: &BindgenContext) -> String;
+    fn canonical_name(&self, ctx: &BindgenContext) -> Name;
 }
 
 /// The same, but specifies the path that needs to be followed to reach an item.
@@ -54,10 +101,23 @@ pub trait ItemCanonicalPath {
     /// the whole path.
     fn namespace_aware_canonical_path(&self,
                                       ctx: &BindgenContext)
-                                      -> Vec<String>;
+                                      -> Vec<Name>;
 
     /// Get the canonical path for this item.
-    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<String>;
+    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<Name>;
+
+    /// Get the canonical path for this item in any phase, not only in the
+    /// codegen one, like while we're still parsing.
+    ///
+    /// Outside of the codegen phase the path is computed from the parent
+    /// chain as it is at the time, without caching it or settling anything
+    /// else needed for naming. This means that it's `None` if the item, one of
+    /// its ancestors, or a type they refer to isn't known yet, or if it's an
+    /// anonymous type which hasn't been numbered yet.
+    ///
+    /// Note that the result may still differ from the final codegen path,
+    /// for example if the item is replaced by another one later on.
+    fn try_canonical_path(&self, ctx: &BindgenContext) -> Option<Vec<Name>>;
 }
 
 /// A trait for iterating over an item and its parents and up its ancestor chain
@@ -71,7 +131,7 @@ pub trait ItemAncestors {
 
 cfg_if! {
     if #[cfg(debug_assertions)] {
-        type DebugOnlyItemSet = ItemSet;
+        type DebugOnlyItemSet = super::item_id_set::ItemIdSet;
     } else {
         struct DebugOnlyItemSet;
 
@@ -162,7 +222,7 @@ impl AsNamed for ItemKind {
 
 // Pure convenience
 impl ItemCanonicalName for ItemId {
-    fn canonical_name(&self, ctx: &BindgenContext) -> String {
+    fn canonical_name(&self, ctx: &BindgenContext) -> Name {
         debug_assert!(ctx.in_codegen_phase(),
                       "You're not supposed to call this yet");
         ctx.resolve_item(*self).canonical_name(ctx)
@@ -172,17 +232,22 @@ impl ItemCanonicalName for ItemId {
 impl ItemCanonicalPath for ItemId {
     fn namespace_aware_canonical_path(&self,
                                       ctx: &BindgenContext)
-                                      -> Vec<String> {
+                                      -> Vec<Name> {
         debug_assert!(ctx.in_codegen_phase(),
                       "You're not supposed to call this yet");
         ctx.resolve_item(*self).namespace_aware_canonical_path(ctx)
     }
 
-    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<String> {
+    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<Name> {
         debug_assert!(ctx.in_codegen_phase(),
                       "You're not supposed to call this yet");
         ctx.resolve_item(*self).canonical_path(ctx)
     }
+
+    fn try_canonical_path(&self, ctx: &BindgenContext) -> Option<Vec<Name>> {
+        ctx.resolve_item_fallible(*self)
+            .and_then(|item| item.try_canonical_path(ctx))
+    }
 }
 
 impl ItemAncestors for ItemId {
@@ -221,6 +286,12 @@ impl Trace for Item {
             return;
         }
 
+        // Standard library blobs only need their layout, so we don't want to
+        // pull in the template, nor its arguments.
+        if self.is_stl_blob(ctx) {
+            return;
+        }
+
         match *self.kind() {
             ItemKind::Type(ref ty) => {
                 // There are some types, like resolved type references, where we
@@ -235,6 +306,11 @@ impl Trace for Item {
                 // Just the same way, it has not real meaning for a function to
                 // be opaque, so we trace across it.
                 tracer.visit(fun.signature());
+
+                // The function a macro forwards to has to be generated along.
+                if let Some(&MacroFnBody::Call(callee, _)) = fun.macro_body() {
+                    tracer.visit(callee);
+                }
             }
             ItemKind::Var(ref var) => {
                 tracer.visit_kind(var.ty(), EdgeKind::VarType);
@@ -288,7 +364,9 @@ impl CanDeriveDefault for Item {
         ctx.options().derive_default &&
         match self.kind {
             ItemKind::Type(ref ty) => {
-                if self.is_opaque(ctx) {
+                if self.is_forward_declaration(ctx) {
+                    false
+                } else if self.is_opaque(ctx) {
                     ty.layout(ctx)
                         .map_or(false,
                                 |l| l.opaque().can_derive_default(ctx, ()))
@@ -313,7 +391,13 @@ impl<'a> CanDeriveCopy<'a> for Item {
 
         let result = match self.kind {
             ItemKind::Type(ref ty) => {
-                if self.is_opaque(ctx) {
+                // Rust forbids `Copy` on types that implement `Drop`, and a
+                // type with a C++ destructor can't be copied bitwise anyway,
+                // so this holds even if we're making it opaque. Forward
+                // declarations don't get to be `Copy` either.
+                if ty.has_destructor(ctx) || self.is_forward_declaration(ctx) {
+                    false
+                } else if self.is_opaque(ctx) {
                     ty.layout(ctx)
                         .map_or(true, |l| l.opaque().can_derive_copy(ctx, ()))
                 } else {
@@ -331,7 +415,9 @@ impl<'a> CanDeriveCopy<'a> for Item {
     fn can_derive_copy_in_array(&self, ctx: &BindgenContext, _: ()) -> bool {
         match self.kind {
             ItemKind::Type(ref ty) => {
-                if self.is_opaque(ctx) {
+                if ty.has_destructor(ctx) || self.is_forward_declaration(ctx) {
+                    false
+                } else if self.is_opaque(ctx) {
                     ty.layout(ctx)
                         .map_or(true, |l| {
                             l.opaque().can_derive_copy_in_array(ctx, ())
@@ -383,13 +469,15 @@ pub struct Item {
     ///
     /// This is a fairly used operation during codegen so this makes bindgen
     /// considerably faster in those cases.
-    canonical_name_cache: RefCell<Option<String>>,
+    canonical_name_cache: RefCell<Option<Name>>,
 
     /// A doc comment over the item, if any.
     comment: Option<String>,
     /// Annotations extracted from the doc comment, or the default ones
     /// otherwise.
     annotations: Annotations,
+    /// Where this item was declared, if we know.
+    location: Option<Location>,
     /// An item's parent id. This will most likely be a class where this item
     /// was declared, or a module, etc.
     ///
@@ -427,6 +515,7 @@ impl Item {
             parent_id: parent_id,
             comment: comment,
             annotations: annotations.unwrap_or_default(),
+            location: None,
             kind: kind,
             detect_derive_debug_cycle: Cell::new(false),
             detect_derive_copy_cycle: Cell::new(false),
@@ -463,11 +552,37 @@ impl Item {
         self.parent_id = id;
     }
 
+    /// Set the full path of the type this item replaces, once we've resolved
+    /// the one in its `replaces` annotation, which may be relative.
+    pub fn set_replaced_path(&mut self, path: Vec<String>) {
+        self.annotations.set_use_instead_of(path);
+    }
+
     /// Get this `Item`'s comment, if it has any.
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_ref().map(|c| &**c)
     }
 
+    /// Get the place where this `Item` was declared, if we know it.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Set the place where this `Item` was declared. This is done by the
+    /// context when the item is added to it, from the cursor of the
+    /// declaration.
+    pub fn set_location(&mut self, location: Option<Location>) {
+        self.location = location;
+    }
+
+    /// Describe where this `Item` was declared, for diagnostics.
+    pub fn location_for_diagnostics(&self) -> String {
+        match self.location {
+            Some(ref location) => location.to_string(),
+            None => "<unknown location>".into(),
+        }
+    }
+
     /// What kind of item is this?
     pub fn kind(&self) -> &ItemKind {
         &self.kind
@@ -572,10 +687,19 @@ impl Item {
     ///
     /// This may be due to either annotations or to other kind of configuration.
     pub fn is_hidden(&self, ctx: &BindgenContext) -> bool {
+        self.is_marked_hidden(ctx) && !ctx.options().emit_hidden_items
+    }
+
+    /// Whether this item was asked to be hidden, even if we still generate it
+    /// with `#[doc(hidden)]`. See `Builder::emit_hidden_items`.
+    pub fn is_marked_hidden(&self, ctx: &BindgenContext) -> bool {
         debug_assert!(ctx.in_codegen_phase(),
                       "You're not supposed to call this yet");
         self.annotations.hide() ||
-        ctx.hidden_by_name(&self.canonical_path(ctx), self.id)
+        ctx.hidden_by_name(&self.cached_canonical_path(ctx), self.id) ||
+        (ctx.options().hidden_alias_variation ==
+         HiddenAliasVariation::Skip &&
+         self.is_alias_of_hidden_type(ctx))
     }
 
     /// Is this item opaque?
@@ -584,7 +708,135 @@ impl Item {
                       "You're not supposed to call this yet");
         self.annotations.opaque() ||
         self.as_type().map_or(false, |ty| ty.is_opaque()) ||
-        ctx.opaque_by_name(&self.canonical_path(ctx))
+        ctx.opaque_by_name(&self.cached_canonical_path(ctx)) ||
+        ctx.stl_blob_by_name(&self.cached_canonical_path(ctx)) ||
+        (ctx.options().hidden_alias_variation ==
+         HiddenAliasVariation::Opaque &&
+         self.is_alias_of_hidden_type(ctx))
+    }
+
+    /// Is this an instantiation of one of the standard library templates we
+    /// generate sized blobs for?
+    pub fn is_stl_blob(&self, ctx: &BindgenContext) -> bool {
+        debug_assert!(ctx.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        match self.as_type().map(|ty| ty.kind()) {
+            Some(&TypeKind::TemplateInstantiation(..)) => {
+                ctx.stl_blob_by_name(&self.cached_canonical_path(ctx))
+            }
+            _ => false,
+        }
+    }
+
+    /// Has the user asserted that this type is safe to send across threads?
+    pub fn is_send(&self, ctx: &BindgenContext) -> bool {
+        debug_assert!(ctx.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        self.annotations.send() ||
+        ctx.send_by_name(&self.cached_canonical_path(ctx))
+    }
+
+    /// Has the user asserted that this type is safe to share across threads?
+    pub fn is_sync(&self, ctx: &BindgenContext) -> bool {
+        debug_assert!(ctx.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        self.annotations.sync() ||
+        ctx.sync_by_name(&self.cached_canonical_path(ctx))
+    }
+
+    /// Is this a type we only generate a forward declaration for, either
+    /// because it's never defined, or because we forward declare it instead of
+    /// whitelisting it?
+    ///
+    /// These take precedence over the type being opaque: without a definition
+    /// there's no layout to make an opaque blob out of.
+    pub fn is_forward_declaration(&self, ctx: &BindgenContext) -> bool {
+        debug_assert!(ctx.in_codegen_phase(),
+                      "You're not supposed to call this yet");
+        ctx.forward_declared_types().contains(&self.id) ||
+        self.as_type()
+            .and_then(|ty| ty.as_comp())
+            .map_or(false, |ci| ci.is_forward_declaration())
+    }
+
+    /// Is this a type alias of a hidden type?
+    fn is_alias_of_hidden_type(&self, ctx: &BindgenContext) -> bool {
+        let mut target = match self.as_type().map(|ty| ty.kind()) {
+            Some(&TypeKind::Alias(inner)) |
+            Some(&TypeKind::TemplateAlias(inner, _)) => ctx.resolve_item(inner),
+            _ => return false,
+        };
+
+        while let Some(&TypeKind::ResolvedTypeRef(next)) =
+            target.as_type().map(|ty| ty.kind()) {
+            target = ctx.resolve_item(next);
+        }
+
+        target.is_hidden(ctx)
+    }
+
+    /// Get this item's canonical path, as cached by the context.
+    ///
+    /// This is like `canonical_path`, but avoids cloning the path, which is
+    /// worth it for hot callers that only need to inspect it.
+    pub fn cached_canonical_path(&self,
+                                 ctx: &BindgenContext)
+                                 -> Rc<Vec<Name>> {
+        ctx.canonical_path_of(self.id, || self.compute_canonical_path(ctx))
+    }
+
+    fn compute_canonical_path(&self, ctx: &BindgenContext) -> Vec<Name> {
+        if let Some(path) = self.annotations().use_instead_of() {
+            let mut ret =
+                vec![ctx.resolve_item(ctx.root_module()).name(ctx).get()];
+            ret.extend(path.iter().map(|name| ctx.names().intern(name)));
+            return ret;
+        }
+
+        let target = ctx.resolve_item(self.name_target(ctx));
+        let mut path: Vec<_> = target.ancestors(ctx)
+            .chain(iter::once(ctx.root_module()))
+            .map(|id| ctx.resolve_item(id))
+            .filter(|item| {
+                item.id() == target.id() ||
+                (item.is_module() && !item.is_inlined_namespace(ctx))
+            })
+            .map(|item| {
+                ctx.resolve_item(item.name_target(ctx))
+                    .name(ctx)
+                    .within_namespaces()
+                    .get()
+            })
+            .collect();
+        path.reverse();
+        path
+    }
+
+    /// Can this item's name be computed without numbering it? Only anonymous
+    /// types need to be, which happens the first time they're named.
+    fn has_settled_name(&self) -> bool {
+        let ty = match self.as_type() {
+            Some(ty) => ty,
+            None => return true,
+        };
+
+        match *ty.kind() {
+            TypeKind::Comp(..) |
+            TypeKind::Enum(..) => {
+                ty.name().is_some() || self.local_id.get().is_some()
+            }
+            _ => true,
+        }
+    }
+
+    /// Is this an inline namespace whose contents we generate directly in the
+    /// parent module?
+    pub fn is_inlined_namespace(&self, ctx: &BindgenContext) -> bool {
+        self.as_module().map_or(false, |module| {
+            module.is_inline() &&
+            !ctx.options().conservative_inline_namespaces &&
+            !ctx.keep_inline_namespace_by_name(&self.canonical_path(ctx))
+        })
     }
 
     /// Is this a reference to another type?
@@ -609,6 +861,14 @@ impl Item {
 
     /// Get the target item id for name generation.
     fn name_target(&self, ctx: &BindgenContext) -> ItemId {
+        self.try_name_target(ctx)
+            .expect("Name targets should be resolved by now")
+    }
+
+    /// Like `name_target`, but returns `None` instead of panicking if some
+    /// item along the way isn't in the context yet, which may happen while
+    /// we're still parsing.
+    fn try_name_target(&self, ctx: &BindgenContext) -> Option<ItemId> {
         let mut targets_seen = DebugOnlyItemSet::new();
         let mut item = self;
 
@@ -617,26 +877,45 @@ impl Item {
             targets_seen.insert(item.id());
 
             if self.annotations().use_instead_of().is_some() {
-                return self.id();
+                return Some(self.id());
             }
 
-            match *item.kind() {
+            let next = match *item.kind() {
                 ItemKind::Type(ref ty) => {
                     match *ty.kind() {
-                        TypeKind::ResolvedTypeRef(inner) => {
-                            item = ctx.resolve_item(inner);
-                        }
+                        TypeKind::ResolvedTypeRef(inner) => inner,
                         TypeKind::TemplateInstantiation(ref inst) => {
-                            item = ctx.resolve_item(inst.template_definition());
+                            inst.template_definition()
+                        }
+                        TypeKind::Enum(..) => {
+                            return Some(item.anonymous_enum_typedef(ctx)
+                                .unwrap_or(item.id()));
                         }
-                        _ => return item.id(),
+                        _ => return Some(item.id()),
                     }
                 }
-                _ => return item.id(),
-            }
+                _ => return Some(item.id()),
+            };
+
+            item = match ctx.resolve_item_fallible(next) {
+                Some(item) => item,
+                None => return None,
+            };
         }
     }
 
+    /// The `typedef` this unnamed enum is declared in, like in
+    /// `typedef enum { ... } foo;`, if any.
+    ///
+    /// The enum is named after the `typedef`, so that it's generated only
+    /// once, under that name, and the alias itself is skipped. If there are
+    /// several `typedef`s in the declaration, the first one wins.
+    pub fn anonymous_enum_typedef(&self,
+                                  ctx: &BindgenContext)
+                                  -> Option<ItemId> {
+        ctx.anonymous_enum_typedef(self.id())
+    }
+
     /// Get this function item's name, or `None` if this item is not a function.
     fn func_name(&self) -> Option<&str> {
         match *self.kind() {
@@ -648,67 +927,64 @@ impl Item {
     /// Get the overload index for this method. If this is not a method, return
     /// `None`.
     fn overload_index(&self, ctx: &BindgenContext) -> Option<usize> {
-        self.func_name().and_then(|func_name| {
-            let parent = ctx.resolve_item(self.parent_id());
-            if let ItemKind::Type(ref ty) = *parent.kind() {
-                if let TypeKind::Comp(ref ci) = *ty.kind() {
-                    // All the constructors have the same name, so no need to
-                    // resolve and check.
-                    return ci.constructors()
-                        .iter()
-                        .position(|c| *c == self.id())
-                        .or_else(|| {
-                            ci.methods()
-                                .iter()
-                                .filter(|m| {
-                                    let item = ctx.resolve_item(m.signature());
-                                    let func = item.expect_function();
-                                    func.name() == func_name
-                                })
-                                .position(|m| m.signature() == self.id())
-                        });
-                }
+        if self.func_name().is_none() {
+            return None;
+        }
+
+        let parent = ctx.resolve_item(self.parent_id());
+        if let ItemKind::Type(ref ty) = *parent.kind() {
+            if let TypeKind::Comp(ref ci) = *ty.kind() {
+                // All the constructors have the same name, so no need to
+                // resolve and check.
+                return ci.constructors()
+                    .iter()
+                    .position(|c| *c == self.id())
+                    .or_else(|| ci.method_overload_index(ctx, self.id()));
             }
+        }
 
-            None
-        })
+        None
     }
 
     /// Get this item's base name (aka non-namespaced name).
-    fn base_name(&self, ctx: &BindgenContext) -> String {
+    fn base_name(&self, ctx: &BindgenContext) -> Name {
+        let names = ctx.names();
         if let Some(path) = self.annotations().use_instead_of() {
-            return path.last().unwrap().clone();
+            return names.intern(path.last().unwrap());
         }
 
         match *self.kind() {
-            ItemKind::Var(ref var) => var.name().to_owned(),
+            ItemKind::Var(ref var) => names.intern(var.name()),
             ItemKind::Module(ref module) => {
-                module.name()
-                    .map(ToOwned::to_owned)
-                    .unwrap_or_else(|| {
-                        format!("_bindgen_mod_{}", self.exposed_id(ctx))
-                    })
+                match module.name() {
+                    Some(name) => names.intern(name),
+                    None => {
+                        names.intern_string(format!("_bindgen_mod_{}",
+                                                    self.exposed_id(ctx)))
+                    }
+                }
             }
             ItemKind::Type(ref ty) => {
                 let name = match *ty.kind() {
                     TypeKind::ResolvedTypeRef(..) => panic!("should have resolved this in name_target()"),
                     _ => ty.name(),
                 };
-                name.map(ToOwned::to_owned)
-                    .unwrap_or_else(|| {
-                        format!("_bindgen_ty_{}", self.exposed_id(ctx))
-                    })
+                match name {
+                    Some(name) => names.intern(name),
+                    None => {
+                        names.intern_string(format!("_bindgen_ty_{}",
+                                                    self.exposed_id(ctx)))
+                    }
+                }
             }
             ItemKind::Function(ref fun) => {
-                let mut name = fun.name().to_owned();
-
-                if let Some(idx) = self.overload_index(ctx) {
-                    if idx > 0 {
-                        write!(&mut name, "{}", idx).unwrap();
+                let name = operator_name(fun.name()).unwrap_or(fun.name());
+                match self.overload_index(ctx) {
+                    Some(idx) if idx > 0 => {
+                        names.intern_string(format!("{}{}", name, idx))
                     }
+                    _ => names.intern(name),
                 }
-
-                name
             }
         }
     }
@@ -727,15 +1003,16 @@ impl Item {
     pub fn real_canonical_name(&self,
                                ctx: &BindgenContext,
                                opt: &NameOptions)
-                               -> String {
+                               -> Name {
         let target = ctx.resolve_item(self.name_target(ctx));
+        let interner = ctx.names();
 
         // Short-circuit if the target has an override, and just use that.
         if let Some(path) = target.annotations.use_instead_of() {
             if ctx.options().enable_cxx_namespaces {
-                return path.last().unwrap().clone();
+                return interner.intern(path.last().unwrap());
             }
-            return path.join("_").to_owned();
+            return interner.intern_string(path.join("_"));
         }
 
         let base_name = target.base_name(ctx);
@@ -768,9 +1045,20 @@ impl Item {
             names.push(base_name);
         }
 
-        let name = names.join("_");
+        // Most names don't need to be joined or mangled, and can be handed
+        // back as they are.
+        if names.len() == 1 {
+            let needs_mangling = match ctx.rust_mangle(&names[0]) {
+                Cow::Borrowed(..) => false,
+                Cow::Owned(..) => true,
+            };
+            if !needs_mangling {
+                return names.pop().unwrap();
+            }
+        }
 
-        ctx.rust_mangle(&name).into_owned()
+        let name = names.join("_");
+        interner.intern_string(ctx.rust_mangle(&name).into_owned())
     }
 
     fn exposed_id(&self, ctx: &BindgenContext) -> String {
@@ -825,6 +1113,11 @@ impl DotAttributes for Item {
                        <tr><td>name</td><td>{}</td></tr>",
                       self.id,
                       self.name(ctx).get()));
+        if let Some(ref location) = self.location {
+            try!(writeln!(out,
+                          "<tr><td>location</td><td>{}</td></tr>",
+                          location));
+        }
         self.kind.dot_attributes(ctx, out)
     }
 }
@@ -862,6 +1155,29 @@ impl TemplateDeclaration for ItemKind {
     }
 }
 
+/// If `ty` refers to a struct, union or class that we're in the middle of
+/// parsing, like the `Node` in `struct Node { struct Node* next; }`, get the id
+/// of its item.
+fn partially_parsed_record(ty: &clang::Type,
+                           ctx: &BindgenContext)
+                           -> Option<ItemId> {
+    use clang_sys::*;
+
+    let decl = ty.declaration();
+    match decl.kind() {
+        CXCursor_StructDecl |
+        CXCursor_UnionDecl |
+        CXCursor_ClassDecl => {}
+        _ => return None,
+    }
+
+    let canonical = decl.canonical();
+    ctx.currently_parsed_types()
+        .iter()
+        .find(|partial| *partial.decl() == canonical)
+        .map(|partial| partial.id())
+}
+
 // An utility function to handle recursing inside nested types.
 fn visit_child(cur: clang::Cursor,
                id: ItemId,
@@ -927,8 +1243,8 @@ impl ClangItemParser for Item {
         let comment = cursor.raw_comment();
         let annotations = Annotations::new(&cursor);
 
-        let current_module = ctx.current_module();
-        let relevant_parent_id = parent_id.unwrap_or(current_module);
+        let relevant_parent_id =
+            parent_id.unwrap_or_else(|| ctx.module_of(&cursor));
 
         macro_rules! try_parse {
             ($what:ident) => {
@@ -978,8 +1294,10 @@ impl ClangItemParser for Item {
             }
         }
 
-        // Guess how does clang treat extern "C" blocks?
-        if cursor.kind() == CXCursor_UnexposedDecl {
+        // Guess how does clang treat extern "C" blocks? Older libclang versions
+        // don't expose them, newer ones do as linkage specs.
+        if cursor.kind() == CXCursor_UnexposedDecl ||
+           cursor.kind() == CXCursor_LinkageSpec {
             Err(ParseError::Recurse)
         } else {
             // We whitelist cursors here known to be unhandled, to prevent being
@@ -991,17 +1309,21 @@ impl ClangItemParser for Item {
                 CXCursor_UsingDirective |
                 CXCursor_StaticAssert |
                 CXCursor_InclusionDirective => {
-                    debug!("Unhandled cursor kind {:?}: {:?}",
-                           cursor.kind(),
-                           cursor);
+                    debug!("{}: Unhandled cursor kind {}: {}",
+                           cursor.location(),
+                           clang::kind_to_str(cursor.kind()),
+                           cursor.spelling());
                 }
                 _ => {
                     // ignore toplevel operator overloads
                     let spelling = cursor.spelling();
                     if !spelling.starts_with("operator") {
-                        error!("Unhandled cursor kind {:?}: {:?}",
-                               cursor.kind(),
-                               cursor);
+                        let message = format!("{}: Unhandled cursor kind {}: \
+                                               {}",
+                                              cursor.location(),
+                                              clang::kind_to_str(cursor.kind()),
+                                              spelling);
+                        ctx.diagnostic(DiagnosticSeverity::Error, message);
                     }
                 }
             }
@@ -1049,6 +1371,9 @@ impl ClangItemParser for Item {
                                          parent_id,
                                          ctx)
                 .unwrap_or_else(|_| {
+                    ctx.record_opaque_fallback(potential_id,
+                                               &ty,
+                                               "failed to parse the type");
                     Item::new_opaque_type(potential_id, &ty, ctx)
                 });
         }
@@ -1116,9 +1441,18 @@ impl ClangItemParser for Item {
 
             if ty.is_associated_type() ||
                location.cur_type().is_associated_type() {
+                ctx.record_opaque_fallback(id, ty, "associated type");
                 return Ok(Item::new_opaque_type(id, ty, ctx));
             }
 
+            // The record we're parsing can be referred to through an unexposed
+            // type from its own members, and it shouldn't become a named type
+            // (or an opaque blob) then.
+            if let Some(partial) = partially_parsed_record(ty, ctx) {
+                debug!("Avoiding recursion parsing type: {:?}", ty);
+                return Ok(partial);
+            }
+
             if let Some(id) = Item::named_type(Some(id), location, ctx) {
                 return Ok(id);
             }
@@ -1217,13 +1551,21 @@ impl ClangItemParser for Item {
                 //
                 // This is what happens with some template members, for example.
                 if let Err(ParseError::Recurse) = result {
-                    warn!("Unknown type, assuming named template type: \
-                          id = {:?}; spelling = {}",
-                          id,
-                          ty.spelling());
-                    Item::named_type(Some(id), location, ctx)
-                        .map(Ok)
-                        .unwrap_or(Err(ParseError::Recurse))
+                    let message = format!("{}: Unknown type, assuming named \
+                                           template type: id = {:?}; \
+                                           spelling = {}",
+                                          location.location(),
+                                          id,
+                                          ty.spelling());
+                    ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                    let named = Item::named_type(Some(id), location, ctx);
+                    if let Some(named) = named {
+                        ctx.record_opaque_fallback(named,
+                                                   ty,
+                                                   "unknown type, assumed to \
+                                                    be a template parameter");
+                    }
+                    named.map(Ok).unwrap_or(Err(ParseError::Recurse))
                 } else {
                     result
                 }
@@ -1397,10 +1739,48 @@ impl ClangItemParser for Item {
         ctx.add_named_type(item, definition);
         Some(id)
     }
+
+    fn const_template_param(location: clang::Cursor,
+                            ctx: &mut BindgenContext)
+                            -> Option<ItemId> {
+        use clang_sys::*;
+
+        debug_assert_eq!(location.kind(), CXCursor_NonTypeTemplateParameter);
+
+        if !ctx.options().const_generics {
+            return None;
+        }
+
+        if let Some(id) = ctx.get_const_template_param(&location) {
+            return Some(id);
+        }
+
+        // We generate these as `usize` parameters, so only unsigned integers
+        // make sense here. Anything else keeps the template opaque.
+        match location.cur_type().canonical_type().kind() {
+            CXType_UChar | CXType_UShort | CXType_UInt | CXType_ULong |
+            CXType_ULongLong => {}
+            _ => return None,
+        }
+
+        let name = location.spelling();
+        if name.is_empty() {
+            return None;
+        }
+
+        let id = ctx.next_item_id();
+        let item = Item::new(id,
+                             None,
+                             None,
+                             ctx.root_module(),
+                             ItemKind::Type(Type::named(name)));
+        ctx.add_const_template_param(item, location);
+        Some(id)
+    }
 }
 
 impl ItemCanonicalName for Item {
-    fn canonical_name(&self, ctx: &BindgenContext) -> String {
+    fn canonical_name(&self, ctx: &BindgenContext) -> Name {
         debug_assert!(ctx.in_codegen_phase(),
                       "You're not supposed to call this yet");
         if self.canonical_name_cache.borrow().is_none() {
@@ -1413,52 +1793,73 @@ impl ItemCanonicalName for Item {
                 Some(self.name(ctx).get())
             };
         }
-        return self.canonical_name_cache.borrow().as_ref().unwrap().clone();
+        self.canonical_name_cache.borrow().as_ref().unwrap().clone()
     }
 }
 
 impl ItemCanonicalPath for Item {
     fn namespace_aware_canonical_path(&self,
                                       ctx: &BindgenContext)
-                                      -> Vec<String> {
-        let path = self.canonical_path(ctx);
+                                      -> Vec<Name> {
+        let names = ctx.names();
+        let mut path = self.canonical_path(ctx);
+        let header_module = ctx.header_module(self);
         if ctx.options().enable_cxx_namespaces {
+            if let Some(module) = header_module {
+                path.insert(1, names.intern(module));
+            }
             return path;
         }
-        if ctx.options().disable_name_namespacing {
-            return vec![path.last().unwrap().clone()];
+
+        let name = if ctx.options().disable_name_namespacing {
+            path.last().unwrap().clone()
+        } else {
+            names.intern_string(path[1..].join("_"))
+        };
+
+        // Without the root module to go through, header modules refer to each
+        // other's items relative to the top level, which is their parent.
+        match header_module {
+            Some(module) => {
+                vec![names.intern("self"),
+                     names.intern("super"),
+                     names.intern(module),
+                     name]
+            }
+            None => vec![name],
         }
-        return vec![path[1..].join("_")];
     }
 
-    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<String> {
-        if let Some(path) = self.annotations().use_instead_of() {
-            let mut ret =
-                vec![ctx.resolve_item(ctx.root_module()).name(ctx).get()];
-            ret.extend_from_slice(path);
-            return ret;
+    fn canonical_path(&self, ctx: &BindgenContext) -> Vec<Name> {
+        (*self.cached_canonical_path(ctx)).clone()
+    }
+
+    fn try_canonical_path(&self, ctx: &BindgenContext) -> Option<Vec<Name>> {
+        if ctx.in_codegen_phase() {
+            return Some(self.canonical_path(ctx));
         }
 
-        let target = ctx.resolve_item(self.name_target(ctx));
-        let mut path: Vec<_> = target.ancestors(ctx)
-            .chain(iter::once(ctx.root_module()))
-            .map(|id| ctx.resolve_item(id))
-            .filter(|item| {
-                item.id() == target.id() ||
-                item.as_module().map_or(false, |module| {
-                    !module.is_inline() ||
-                    ctx.options().conservative_inline_namespaces
-                })
-            })
-            .map(|item| {
-                ctx.resolve_item(item.name_target(ctx))
-                    .name(ctx)
-                    .within_namespaces()
-                    .get()
-            })
-            .collect();
-        path.reverse();
-        path
+        // Walk the parent chain by hand rather than with `ancestors()`, since
+        // some of the parents, or the types they refer to, may not have been
+        // added to the context yet.
+        let mut current = self.try_name_target(ctx)
+            .and_then(|target| ctx.resolve_item_fallible(target));
+        while let Some(item) = current {
+            let target = match item.try_name_target(ctx)
+                .and_then(|target| ctx.resolve_item_fallible(target)) {
+                Some(target) => target,
+                None => return None,
+            };
+            if !target.has_settled_name() {
+                return None;
+            }
+            if item.id() == ctx.root_module() {
+                return Some(self.compute_canonical_path(ctx));
+            }
+            current = ctx.resolve_item_fallible(item.parent_id());
+        }
+
+        None
     }
 }
 
@@ -1490,8 +1891,144 @@ impl<'item, 'ctx> NameOptions<'item, 'ctx> {
         self
     }
 
-    /// Construct a name `String`
-    pub fn get(&self) -> String {
+    /// Construct the name.
+    pub fn get(&self) -> Name {
         self.item.real_canonical_name(self.ctx, self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ItemCanonicalPath;
+    use ir::context::BindgenContext;
+    use std::env;
+    use std::fs::File;
+    use std::io::Write;
+
+    /// Parse the given C++ source, with namespaces enabled.
+    fn parse_cxx(name: &str, source: &[u8]) -> BindgenContext<'static> {
+        let header = env::temp_dir().join(name);
+        File::create(&header)
+            .and_then(|mut f| f.write_all(source))
+            .unwrap();
+        let header = header.to_str().unwrap().to_owned();
+
+        ::ensure_libclang_is_loaded();
+        let mut options = ::builder()
+            .header(header.clone())
+            .enable_cxx_namespaces()
+            .clang_arg("-x")
+            .clang_arg("c++")
+            .options;
+        options.build().unwrap();
+        options.clang_args.push(header);
+
+        let mut ctx = BindgenContext::new(options, Default::default());
+        ::parse(&mut ctx).expect("Should parse the header");
+        ctx
+    }
+
+    #[test]
+    fn cached_canonical_paths_match_computed_ones() {
+        let mut ctx = parse_cxx("bindgen-canonical-paths.hpp",
+                                b"namespace a {\n\
+                                    namespace b { struct C { int x; }; }\n\
+                                    inline namespace d { struct E { int y; }; }\n\
+                                  }\n\
+                                  namespace {\n\
+                                    struct F { struct G { int z; } g; };\n\
+                                  }\n");
+
+        ctx.gen(|ctx| {
+            for (_, item) in ctx.items() {
+                let computed = item.compute_canonical_path(ctx);
+                assert_eq!(item.canonical_path(ctx), computed);
+                // Now it's cached, so it should come from the cache.
+                assert_eq!(item.canonical_path(ctx), computed);
+            }
+        });
+    }
+
+    #[test]
+    fn try_canonical_path_works_before_codegen() {
+        use ir::ty::TypeKind;
+
+        let mut ctx = parse_cxx("bindgen-try-canonical-paths.hpp",
+                                b"namespace a {\n\
+                                    namespace b { struct C { int x; }; }\n\
+                                    struct { int y; } anon;\n\
+                                  }\n");
+
+        let mut named = None;
+        let mut anonymous = None;
+        for (id, item) in ctx.items() {
+            let ty = match item.as_type() {
+                Some(ty) => ty,
+                None => continue,
+            };
+            if let TypeKind::Comp(..) = *ty.kind() {
+                match ty.name() {
+                    Some("C") => named = Some(*id),
+                    None => anonymous = Some(*id),
+                    _ => {}
+                }
+            }
+        }
+        let named = named.expect("Should have parsed C");
+        let anonymous = anonymous.expect("Should have parsed the anon struct");
+
+        // We're still not in the codegen phase here.
+        let expected = vec!["root", "a", "b", "C"]
+            .iter()
+            .map(|s| ctx.names().intern(s))
+            .collect();
+        assert_eq!(named.try_canonical_path(&ctx), Some(expected));
+        assert_eq!(anonymous.try_canonical_path(&ctx), None);
+
+        ctx.gen(|ctx| {
+            assert_eq!(named.try_canonical_path(ctx),
+                       Some(named.canonical_path(ctx)));
+            assert_eq!(anonymous.try_canonical_path(ctx),
+                       Some(anonymous.canonical_path(ctx)));
+        });
+    }
+
+    #[test]
+    fn try_canonical_path_with_unresolved_refs() {
+        use super::Item;
+        use ir::item_kind::ItemKind;
+        use ir::ty::{Type, TypeKind};
+
+        let mut ctx = parse_cxx("bindgen-try-canonical-unresolved.hpp",
+                                b"struct A { int x; };\n");
+
+        // While parsing, a type may refer to one that hasn't been added to
+        // the context yet.
+        let missing = ctx.next_item_id();
+        let id = ctx.next_item_id();
+        let root = ctx.root_module();
+        let ty = Type::new(None,
+                           None,
+                           TypeKind::ResolvedTypeRef(missing),
+                           false);
+        let item = Item::new(id, None, None, root, ItemKind::Type(ty));
+
+        assert_eq!(item.try_canonical_path(&ctx), None);
+    }
+
+    #[test]
+    fn unhandled_cursor_warnings_have_a_location() {
+        // Namespace aliases aren't handled.
+        let mut ctx = parse_cxx("bindgen-unhandled-cursor.hpp",
+                                b"namespace foo {}\nnamespace bar = foo;\n");
+
+        let diagnostics = ctx.take_diagnostics();
+        let message = diagnostics.iter()
+            .map(|d| &d.message)
+            .find(|m| m.contains("Unhandled cursor kind"))
+            .expect("Should warn about the namespace alias");
+        assert!(message.contains("bindgen-unhandled-cursor.hpp:2:"),
+                "Expected a location in {:?}",
+                message);
+    }
+}
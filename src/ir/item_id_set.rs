@@ -0,0 +1,195 @@
+//! A dense set of item ids, backed by a bit set.
+//!
+//! Item ids are dense indices handed out sequentially while parsing, so a bit
+//! set is both smaller and way faster than a `BTreeSet<ItemId>` for the sets
+//! that end up containing a big part of the IR, like the set of whitelisted
+//! items. Iteration is in id order, just like `BTreeSet`, so anything that
+//! relies on a deterministic order keeps working.
+
+use super::context::ItemId;
+use std::fmt;
+use std::iter::FromIterator;
+
+const BITS: usize = 64;
+
+/// A set of `ItemId`s.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ItemIdSet {
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl ItemIdSet {
+    /// Construct a new, empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new, empty set that can hold the ids in `0..capacity`
+    /// without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ItemIdSet {
+            bits: vec![0; (capacity + BITS - 1) / BITS],
+            len: 0,
+        }
+    }
+
+    /// The number of ids in this set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is this set empty?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Does this set contain the given id?
+    pub fn contains(&self, id: &ItemId) -> bool {
+        let (word, mask) = Self::position(*id);
+        self.bits.get(word).map_or(false, |bits| bits & mask != 0)
+    }
+
+    /// Add the given id to this set. Returns whether it wasn't already there.
+    pub fn insert(&mut self, id: ItemId) -> bool {
+        let (word, mask) = Self::position(id);
+        if word >= self.bits.len() {
+            self.bits.resize(word + 1, 0);
+        }
+
+        let bits = &mut self.bits[word];
+        if *bits & mask != 0 {
+            return false;
+        }
+
+        *bits |= mask;
+        self.len += 1;
+        true
+    }
+
+    /// Remove the given id from this set. Returns whether it was there.
+    pub fn remove(&mut self, id: &ItemId) -> bool {
+        let (word, mask) = Self::position(*id);
+        match self.bits.get_mut(word) {
+            Some(bits) => {
+                if *bits & mask == 0 {
+                    return false;
+                }
+                *bits &= !mask;
+            }
+            None => return false,
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// Iterate over the ids in this set, in increasing order.
+    pub fn iter(&self) -> Iter {
+        Iter {
+            bits: &self.bits,
+            word: 0,
+            current: self.bits.first().cloned().unwrap_or(0),
+        }
+    }
+
+    fn position(id: ItemId) -> (usize, u64) {
+        let id = id.as_usize();
+        (id / BITS, 1 << (id % BITS))
+    }
+}
+
+impl fmt::Debug for ItemIdSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<ItemId> for ItemIdSet {
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item = ItemId>,
+    {
+        let mut set = ItemIdSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<ItemId> for ItemIdSet {
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item = ItemId>,
+    {
+        for id in iter {
+            self.insert(id);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ItemIdSet {
+    type Item = ItemId;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the ids of an `ItemIdSet`, in increasing order.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    bits: &'a [u64],
+    /// The index of the word we're currently looking at.
+    word: usize,
+    /// The bits of the current word we haven't yielded yet.
+    current: u64,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = ItemId;
+
+    fn next(&mut self) -> Option<ItemId> {
+        while self.current == 0 {
+            self.word += 1;
+            if self.word >= self.bits.len() {
+                return None;
+            }
+            self.current = self.bits[self.word];
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.current &= self.current - 1;
+        Some(ItemId::from_usize(self.word * BITS + bit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ir::context::ItemId;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn behaves_like_a_btree_set() {
+        let ids = [0, 1, 63, 64, 65, 127, 128, 1000, 64, 1];
+
+        let mut set = ItemIdSet::with_capacity(10);
+        let mut expected = BTreeSet::new();
+        for &id in ids.iter() {
+            let id = ItemId::from_usize(id);
+            assert_eq!(set.insert(id), expected.insert(id));
+        }
+
+        assert_eq!(set.len(), expected.len());
+        assert_eq!(set.iter().collect::<Vec<_>>(),
+                   expected.iter().cloned().collect::<Vec<_>>());
+        assert!(set.contains(&ItemId::from_usize(1000)));
+        assert!(!set.contains(&ItemId::from_usize(999)));
+        assert!(!set.contains(&ItemId::from_usize(100000)));
+
+        assert!(set.remove(&ItemId::from_usize(64)));
+        assert!(!set.remove(&ItemId::from_usize(64)));
+        expected.remove(&ItemId::from_usize(64));
+        assert_eq!(set.iter().collect::<Vec<_>>(),
+                   expected.iter().cloned().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,548 @@
+//! Intermediate representation of function-like macros we can turn into
+//! functions, like `#define FLAG(x) (1u << (x))` or
+//! `#define mylib_init() mylib_init_ex(0)`.
+//!
+//! See `Builder::emit_macro_fns`.
+
+use super::context::{BindgenContext, ItemId};
+use super::function::{Function, FunctionSig, Linkage, SymbolVisibility};
+use super::int::IntKind;
+use super::item::Item;
+use super::item_kind::ItemKind;
+use super::ty::{Type, TypeKind};
+use cexpr;
+use cexpr::token::{Kind, Token};
+use parse::ClangItemParser;
+use syntax::abi;
+
+/// A unary operator of a macro expression.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UnaryOp {
+    /// `-x`.
+    Neg,
+    /// `~x`.
+    Not,
+}
+
+/// A binary operator of a macro expression.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// `x | y`.
+    Or,
+    /// `x ^ y`.
+    Xor,
+    /// `x & y`.
+    And,
+    /// `x << y`.
+    Shl,
+    /// `x >> y`.
+    Shr,
+    /// `x + y`.
+    Add,
+    /// `x - y`.
+    Sub,
+    /// `x * y`.
+    Mul,
+    /// `x / y`.
+    Div,
+    /// `x % y`.
+    Rem,
+}
+
+/// The binary operators, from the loosest to the tightest binding ones.
+const BINARY_OPS: &'static [&'static [(&'static str, BinaryOp)]] =
+    &[&[("|", BinaryOp::Or)],
+      &[("^", BinaryOp::Xor)],
+      &[("&", BinaryOp::And)],
+      &[("<<", BinaryOp::Shl), (">>", BinaryOp::Shr)],
+      &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+      &[("*", BinaryOp::Mul), ("/", BinaryOp::Div), ("%", BinaryOp::Rem)]];
+
+/// An integer expression over the parameters of a function-like macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacroExpr {
+    /// The parameter with the given index.
+    Param(usize),
+    /// An integer literal.
+    Int(i64),
+    /// A unary operation.
+    Unary(UnaryOp, Box<MacroExpr>),
+    /// A binary operation.
+    Binary(BinaryOp, Box<MacroExpr>, Box<MacroExpr>),
+}
+
+impl MacroExpr {
+    /// Does this expression use any of the macro parameters?
+    fn uses_params(&self) -> bool {
+        match *self {
+            MacroExpr::Param(..) => true,
+            MacroExpr::Int(..) => false,
+            MacroExpr::Unary(_, ref e) => e.uses_params(),
+            MacroExpr::Binary(_, ref l, ref r) => {
+                l.uses_params() || r.uses_params()
+            }
+        }
+    }
+
+    /// Does this expression negate anything?
+    fn negates(&self) -> bool {
+        match *self {
+            MacroExpr::Param(..) |
+            MacroExpr::Int(..) => false,
+            MacroExpr::Unary(op, ref e) => op == UnaryOp::Neg || e.negates(),
+            MacroExpr::Binary(_, ref l, ref r) => l.negates() || r.negates(),
+        }
+    }
+
+    /// Evaluate this expression, which must not use any parameter. Like C
+    /// does, this wraps around on overflow.
+    fn eval(&self) -> Option<i64> {
+        match *self {
+            MacroExpr::Param(..) => None,
+            MacroExpr::Int(val) => Some(val),
+            MacroExpr::Unary(UnaryOp::Neg, ref e) => {
+                e.eval().map(|val| val.wrapping_neg())
+            }
+            MacroExpr::Unary(UnaryOp::Not, ref e) => e.eval().map(|val| !val),
+            MacroExpr::Binary(op, ref l, ref r) => {
+                let (l, r) = match (l.eval(), r.eval()) {
+                    (Some(l), Some(r)) => (l, r),
+                    _ => return None,
+                };
+                match op {
+                    BinaryOp::Or => Some(l | r),
+                    BinaryOp::Xor => Some(l ^ r),
+                    BinaryOp::And => Some(l & r),
+                    BinaryOp::Shl => Some(l.wrapping_shl(r as u32)),
+                    BinaryOp::Shr => Some(l.wrapping_shr(r as u32)),
+                    BinaryOp::Add => Some(l.wrapping_add(r)),
+                    BinaryOp::Sub => Some(l.wrapping_sub(r)),
+                    BinaryOp::Mul => Some(l.wrapping_mul(r)),
+                    BinaryOp::Div if r != 0 => Some(l.wrapping_div(r)),
+                    BinaryOp::Rem if r != 0 => Some(l.wrapping_rem(r)),
+                    BinaryOp::Div | BinaryOp::Rem => None,
+                }
+            }
+        }
+    }
+}
+
+/// An argument a macro passes to the function it forwards to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MacroArg {
+    /// The macro parameter with the given index.
+    Param(usize),
+    /// A constant.
+    Int(i64),
+}
+
+/// What the function generated for a function-like macro does.
+#[derive(Debug)]
+pub enum MacroFnBody {
+    /// Call the given function with the given arguments.
+    Call(ItemId, Vec<MacroArg>),
+    /// Compute an integer expression.
+    Expr(MacroExpr),
+}
+
+/// A function-like macro whose replacement is either a single call or an
+/// integer expression over its parameters, as parsed from its definition.
+#[derive(Debug)]
+pub struct MacroFunction {
+    /// The name of the macro.
+    name: String,
+    /// The names of its parameters.
+    params: Vec<String>,
+    /// The function it calls, if it's a call.
+    callee: Option<String>,
+    /// The arguments of the call, or the single expression it computes
+    /// otherwise.
+    exprs: Vec<MacroExpr>,
+}
+
+impl MacroFunction {
+    /// Parse the tokens of a function-like macro definition, from its name to
+    /// the end of its replacement, or explain why we can't.
+    pub fn parse(tokens: &[Token]) -> Result<Self, String> {
+        let mut parser = Parser {
+            tokens: tokens,
+            pos: 0,
+            params: vec![],
+        };
+
+        let name = try!(parser.ident());
+        try!(parser.expect("("));
+        if !parser.eat(")") {
+            loop {
+                if parser.peek("...") {
+                    return Err("it's variadic".into());
+                }
+                let param = try!(parser.ident());
+                parser.params.push(param);
+                if parser.eat(")") {
+                    break;
+                }
+                try!(parser.expect(","));
+            }
+        }
+
+        // Look through any parentheses around the whole replacement.
+        let mut end = tokens.len();
+        while end - parser.pos >= 2 && parser.peek("(") &&
+              parser.matching_paren(parser.pos) == Some(end - 1) {
+            parser.pos += 1;
+            end -= 1;
+        }
+        parser.tokens = &tokens[..end];
+
+        if parser.pos == end {
+            return Err("its replacement is empty".into());
+        }
+
+        let is_call = parser.peek_kind(Kind::Identifier) &&
+                      !parser.params.iter().any(|p| parser.peek(p)) &&
+                      parser.tokens
+            .get(parser.pos + 1)
+            .map_or(false, |t| &*t.raw == b"(");
+
+        let (callee, exprs) = if is_call {
+            let callee = try!(parser.ident());
+            try!(parser.expect("("));
+            let mut args = vec![];
+            if !parser.eat(")") {
+                loop {
+                    args.push(try!(parser.expr(0)));
+                    if parser.eat(")") {
+                        break;
+                    }
+                    try!(parser.expect(","));
+                }
+            }
+            (Some(callee), args)
+        } else {
+            (None, vec![try!(parser.expr(0))])
+        };
+
+        if parser.pos != end {
+            return Err(format!("`{}` is unexpected in its replacement",
+                               parser.current()));
+        }
+
+        Ok(MacroFunction {
+            name: name,
+            params: parser.params,
+            callee: callee,
+            exprs: exprs,
+        })
+    }
+
+    /// The name of this macro.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Build the function to generate for this macro, or explain why we
+    /// can't.
+    pub fn into_function(self,
+                         ctx: &mut BindgenContext)
+                         -> Result<Function, String> {
+        let (sig, body) = match self.callee {
+            Some(ref callee) => try!(self.forwarding_signature(callee, ctx)),
+            None => try!(self.arithmetic_signature(ctx)),
+        };
+
+        let ty = Type::new(None, None, TypeKind::Function(sig), false);
+        let sig_id = ctx.next_item_id();
+        let module = ctx.root_module();
+        ctx.add_item(Item::new(sig_id, None, None, module, ItemKind::Type(ty)),
+                     None,
+                     None);
+
+        let mut function = Function::new(self.name,
+                                         None,
+                                         sig_id,
+                                         None,
+                                         Linkage::External,
+                                         SymbolVisibility::Default,
+                                         None);
+        function.set_macro_body(body);
+        Ok(function)
+    }
+
+    /// The signature of a macro forwarding to `callee`, whose parameters take
+    /// the type of the arguments of `callee` they're passed as.
+    fn forwarding_signature(&self,
+                            callee: &str,
+                            ctx: &BindgenContext)
+                            -> Result<(FunctionSig, MacroFnBody), String> {
+        let callee_id = ctx.items()
+            .find(|&(_, item)| {
+                let is_callee = item.kind()
+                    .as_function()
+                    .map_or(false, |f| f.name() == callee);
+                is_callee && ctx.resolve_item(item.parent_id()).is_module()
+            })
+            .map(|(id, _)| *id);
+        let callee_id = match callee_id {
+            Some(id) => id,
+            None => return Err(format!("`{}` isn't a known function", callee)),
+        };
+
+        let callee_sig = ctx.resolve_item(callee_id)
+            .kind()
+            .expect_function()
+            .signature();
+        let callee_sig = match *ctx.resolve_type(callee_sig)
+            .canonical_type(ctx)
+            .kind() {
+            TypeKind::Function(ref sig) => sig,
+            _ => unreachable!(),
+        };
+
+        if callee_sig.is_variadic() {
+            return Err(format!("`{}` is variadic", callee));
+        }
+        if callee_sig.argument_types().len() != self.exprs.len() {
+            return Err(format!("it passes {} arguments to `{}`, which takes \
+                                {}",
+                               self.exprs.len(),
+                               callee,
+                               callee_sig.argument_types().len()));
+        }
+
+        let mut param_tys = vec![None; self.params.len()];
+        let mut args = vec![];
+        for (expr, &(_, ty)) in self.exprs
+            .iter()
+            .zip(callee_sig.argument_types()) {
+            if let MacroExpr::Param(i) = *expr {
+                if param_tys[i].map_or(false, |t| t != ty) {
+                    return Err(format!("it passes `{}` as arguments of \
+                                        different types",
+                                       self.params[i]));
+                }
+                param_tys[i] = Some(ty);
+                args.push(MacroArg::Param(i));
+                continue;
+            }
+
+            if expr.uses_params() {
+                return Err(format!("it computes an argument of `{}` from \
+                                    its parameters",
+                                   callee));
+            }
+
+            let kind = match *ctx.resolve_type(ty).canonical_type(ctx).kind() {
+                TypeKind::Int(kind) => kind,
+                _ => {
+                    return Err(format!("it passes a constant where `{}` \
+                                        doesn't take an integer",
+                                       callee))
+                }
+            };
+            let val = match expr.eval() {
+                Some(val) => val,
+                None => return Err("it divides by zero".into()),
+            };
+            if val < 0 && !kind.is_signed() {
+                return Err(format!("it passes a negative constant where \
+                                    `{}` takes an unsigned integer",
+                                   callee));
+            }
+            args.push(MacroArg::Int(val));
+        }
+
+        let mut arguments = vec![];
+        for (name, ty) in self.params.iter().zip(param_tys) {
+            match ty {
+                Some(ty) => arguments.push((Some(name.clone()), ty)),
+                None => {
+                    return Err(format!("it doesn't pass its parameter `{}` \
+                                        along",
+                                       name))
+                }
+            }
+        }
+
+        let sig = FunctionSig::new(callee_sig.return_type(),
+                                   arguments,
+                                   false,
+                                   Some(abi::Abi::C));
+        Ok((sig, MacroFnBody::Call(callee_id, args)))
+    }
+
+    /// The signature of a macro computing an integer expression, where
+    /// everything has the same integer type.
+    fn arithmetic_signature(&self,
+                            ctx: &mut BindgenContext)
+                            -> Result<(FunctionSig, MacroFnBody), String> {
+        let expr = self.exprs[0].clone();
+        let kind = ctx.parse_callbacks()
+            .and_then(|c| c.int_macro_fn(&self.name))
+            .unwrap_or(IntKind::U32);
+        if expr.negates() && !kind.is_signed() {
+            return Err("it negates an unsigned integer".into());
+        }
+
+        let ty = Item::builtin_type(TypeKind::Int(kind), false, ctx);
+        let arguments = self.params
+            .iter()
+            .map(|name| (Some(name.clone()), ty))
+            .collect();
+        let sig = FunctionSig::new(ty, arguments, false, Some(abi::Abi::C));
+        Ok((sig, MacroFnBody::Expr(expr)))
+    }
+}
+
+/// A recursive descent parser over the tokens of a macro definition.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    params: Vec<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn current(&self) -> String {
+        self.tokens
+            .get(self.pos)
+            .map_or(String::new(), |t| String::from_utf8_lossy(&t.raw).into())
+    }
+
+    fn peek(&self, raw: &str) -> bool {
+        self.tokens.get(self.pos).map_or(false, |t| &*t.raw == raw.as_bytes())
+    }
+
+    fn peek_kind(&self, kind: Kind) -> bool {
+        self.tokens.get(self.pos).map_or(false, |t| t.kind == kind)
+    }
+
+    fn eat(&mut self, raw: &str) -> bool {
+        let found = self.peek(raw);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, raw: &str) -> Result<(), String> {
+        if self.eat(raw) {
+            Ok(())
+        } else if self.pos == self.tokens.len() {
+            Err(format!("`{}` is missing at the end", raw))
+        } else {
+            Err(format!("`{}` is unexpected where `{}` should be",
+                        self.current(),
+                        raw))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        if !self.peek_kind(Kind::Identifier) {
+            return Err(format!("`{}` is unexpected where an identifier \
+                                should be",
+                               self.current()));
+        }
+        let ident = self.current();
+        self.pos += 1;
+        Ok(ident)
+    }
+
+    /// The index of the parenthesis closing the one at `open`, if any.
+    fn matching_paren(&self, open: usize) -> Option<usize> {
+        let mut depth = 0;
+        for (i, token) in self.tokens.iter().enumerate().skip(open) {
+            match &*token.raw {
+                b"(" => depth += 1,
+                b")" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Parse an expression made of operators binding at least as tightly as
+    /// the ones at `level` of `BINARY_OPS`.
+    fn expr(&mut self, level: usize) -> Result<MacroExpr, String> {
+        if level == BINARY_OPS.len() {
+            return self.unary();
+        }
+
+        let mut lhs = try!(self.expr(level + 1));
+        'outer: loop {
+            for &(raw, op) in BINARY_OPS[level] {
+                if self.eat(raw) {
+                    let rhs = try!(self.expr(level + 1));
+                    lhs = MacroExpr::Binary(op, Box::new(lhs), Box::new(rhs));
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn unary(&mut self) -> Result<MacroExpr, String> {
+        if self.eat("+") {
+            return self.unary();
+        }
+        if self.eat("-") {
+            let e = try!(self.unary());
+            return Ok(MacroExpr::Unary(UnaryOp::Neg, Box::new(e)));
+        }
+        if self.eat("~") {
+            let e = try!(self.unary());
+            return Ok(MacroExpr::Unary(UnaryOp::Not, Box::new(e)));
+        }
+        if self.eat("(") {
+            if self.peek_kind(Kind::Keyword) {
+                return Err("it has a cast".into());
+            }
+            let e = try!(self.expr(0));
+            try!(self.expect(")"));
+            return Ok(e);
+        }
+
+        let token = match self.tokens.get(self.pos) {
+            Some(token) => token,
+            None => return Err("its replacement ends unexpectedly".into()),
+        };
+        match token.kind {
+            Kind::Identifier => {
+                let name = self.current();
+                match self.params.iter().position(|p| *p == name) {
+                    Some(i) => {
+                        self.pos += 1;
+                        Ok(MacroExpr::Param(i))
+                    }
+                    None => {
+                        Err(format!("it uses `{}`, which isn't one of its \
+                                     parameters",
+                                    name))
+                    }
+                }
+            }
+            Kind::Literal => {
+                use cexpr::expr::EvalResult;
+                use cexpr::nom::IResult;
+                use std::num::Wrapping;
+
+                let literal = &self.tokens[self.pos..self.pos + 1];
+                match cexpr::expr::expr(literal) {
+                    IResult::Done(_, EvalResult::Int(Wrapping(val))) => {
+                        self.pos += 1;
+                        Ok(MacroExpr::Int(val))
+                    }
+                    _ => {
+                        Err(format!("`{}` isn't an integer",
+                                    self.current()))
+                    }
+                }
+            }
+            _ => Err(format!("`{}` is unexpected", self.current())),
+        }
+    }
+}
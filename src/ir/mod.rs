@@ -4,6 +4,7 @@
 //! the IR.
 
 pub mod annotations;
+pub mod comment;
 pub mod comp;
 pub mod context;
 pub mod derive;
@@ -11,9 +12,12 @@ pub mod dot;
 pub mod enum_ty;
 pub mod function;
 pub mod int;
+pub mod interner;
 pub mod item;
+pub mod item_id_set;
 pub mod item_kind;
 pub mod layout;
+pub mod macro_fn;
 pub mod module;
 pub mod named;
 pub mod template;
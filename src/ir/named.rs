@@ -131,7 +131,7 @@ use super::item::ItemSet;
 use super::template::AsNamed;
 use super::traversal::{EdgeKind, Trace};
 use super::ty::{TemplateDeclaration, TypeKind};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 /// An analysis in the monotone framework.
@@ -310,7 +310,7 @@ impl<'ctx, 'gen> UsedTemplateParameters<'ctx, 'gen> {
 impl<'ctx, 'gen> MonotoneFramework for UsedTemplateParameters<'ctx, 'gen> {
     type Node = ItemId;
     type Extra = &'ctx BindgenContext<'gen>;
-    type Output = HashMap<ItemId, ItemSet>;
+    type Output = BTreeMap<ItemId, ItemSet>;
 
     fn new(ctx: &'ctx BindgenContext<'gen>)
            -> UsedTemplateParameters<'ctx, 'gen> {
@@ -347,7 +347,11 @@ impl<'ctx, 'gen> MonotoneFramework for UsedTemplateParameters<'ctx, 'gen> {
                         // opaque templates. Hence the unwrap_or.
                         let params = decl.self_template_params(ctx)
                             .unwrap_or(vec![]);
-                        for (arg, param) in args.iter().zip(params.iter()) {
+                        // There are no arguments for `const` generic
+                        // parameters, only for type parameters.
+                        let params = params.iter()
+                            .filter(|p| !ctx.is_const_template_param(**p));
+                        for (arg, param) in args.iter().zip(params) {
                             dependencies.entry(*arg)
                                 .or_insert(vec![])
                                 .push(*param);
@@ -399,7 +403,10 @@ impl<'ctx, 'gen> MonotoneFramework for UsedTemplateParameters<'ctx, 'gen> {
 
                 let params = decl.self_template_params(self.ctx)
                     .unwrap_or(vec![]);
-                for (arg, param) in args.iter().zip(params.iter()) {
+                let ctx = self.ctx;
+                let params = params.iter()
+                    .filter(|p| !ctx.is_const_template_param(**p));
+                for (arg, param) in args.iter().zip(params) {
                     let used_by_definition = self.used
                                                  [&inst.template_definition()]
                         .as_ref()
@@ -454,7 +461,7 @@ impl<'ctx, 'gen> MonotoneFramework for UsedTemplateParameters<'ctx, 'gen> {
 }
 
 impl<'ctx, 'gen> From<UsedTemplateParameters<'ctx, 'gen>>
-    for HashMap<ItemId, ItemSet> {
+    for BTreeMap<ItemId, ItemSet> {
     fn from(used_templ_params: UsedTemplateParameters<'ctx, 'gen>) -> Self {
         used_templ_params.used
             .into_iter()
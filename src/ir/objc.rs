@@ -84,6 +84,11 @@ impl ObjCInterface {
         }
     }
 
+    /// Is this a class interface, rather than a protocol or a category?
+    pub fn is_class(&self) -> bool {
+        !self.is_protocol && self.category.is_none()
+    }
+
     /// List of the methods defined in this interface
     pub fn methods(&self) -> &Vec<ObjCMethod> {
         &self.methods
@@ -239,6 +244,11 @@ impl Trace for ObjCInterface {
     fn trace<T>(&self, context: &BindgenContext, tracer: &mut T, _: &())
         where T: Tracer,
     {
+        // Opaque handles don't need anything else.
+        if !context.options().enable_objc {
+            return;
+        }
+
         for method in &self.methods {
             method.signature.trace(context, tracer, &());
         }
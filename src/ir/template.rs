@@ -60,6 +60,11 @@ pub struct TemplateInstantiation {
     /// The concrete template arguments, which will be substituted in the
     /// definition for the generic template parameters.
     args: Vec<ItemId>,
+    /// The values of the arguments for the `const` generic parameters of the
+    /// definition, in order, like the `16` in `Buf<16>`.
+    const_args: Vec<u64>,
+    /// How this instantiation is spelled in C++, if it comes from clang.
+    spelling: Option<String>,
 }
 
 impl TemplateInstantiation {
@@ -72,6 +77,8 @@ impl TemplateInstantiation {
         TemplateInstantiation {
             definition: template_definition,
             args: template_args.into_iter().collect(),
+            const_args: vec![],
+            spelling: None,
         }
     }
 
@@ -85,20 +92,40 @@ impl TemplateInstantiation {
         &self.args[..]
     }
 
+    /// Get the values of the `const` generic arguments used in this
+    /// instantiation.
+    pub fn const_template_arguments(&self) -> &[u64] {
+        &self.const_args[..]
+    }
+
+    /// Get the C++ spelling of this instantiation, like `std::vector<int>`,
+    /// if we know it.
+    pub fn spelling(&self) -> Option<&str> {
+        self.spelling.as_ref().map(|s| &**s)
+    }
+
     /// Parse a `TemplateInstantiation` from a clang `Type`.
     pub fn from_ty(ty: &clang::Type,
                    ctx: &mut BindgenContext)
                    -> TemplateInstantiation {
         use clang_sys::*;
 
-        let template_args = ty.template_args()
-            .map_or(vec![], |args| {
-                args.filter(|t| t.kind() != CXType_Invalid)
-                    .map(|t| {
-                        Item::from_ty_or_ref(t, t.declaration(), None, ctx)
-                    })
-                    .collect()
-            });
+        let mut template_args = vec![];
+        let mut const_args = vec![];
+        if let Some(args) = ty.template_args() {
+            for (index, arg) in args.enumerate() {
+                if arg.kind() != CXType_Invalid {
+                    let id = Item::from_ty_or_ref(arg,
+                                                  arg.declaration(),
+                                                  None,
+                                                  ctx);
+                    template_args.push(id);
+                } else if let Some(value) =
+                    ty.declaration().template_arg_value(index as u32) {
+                    const_args.push(value);
+                }
+            }
+        }
 
         let definition = ty.declaration()
             .specialized()
@@ -124,7 +151,11 @@ impl TemplateInstantiation {
         let template_definition =
             Item::from_ty_or_ref(definition.cur_type(), definition, None, ctx);
 
-        TemplateInstantiation::new(template_definition, template_args)
+        let mut inst = TemplateInstantiation::new(template_definition,
+                                                  template_args);
+        inst.const_args = const_args;
+        inst.spelling = Some(ty.spelling());
+        inst
     }
 
     /// Does this instantiation have a vtable?
@@ -138,6 +169,14 @@ impl TemplateInstantiation {
         ctx.resolve_type(self.definition).has_destructor(ctx) ||
         self.args.iter().any(|arg| ctx.resolve_type(*arg).has_destructor(ctx))
     }
+
+    /// Is copying or moving this template instantiation non-trivial?
+    pub fn has_non_trivial_copy(&self, ctx: &BindgenContext) -> bool {
+        ctx.resolve_type(self.definition).has_non_trivial_copy(ctx) ||
+        self.args.iter().any(|arg| {
+            ctx.resolve_type(*arg).has_non_trivial_copy(ctx)
+        })
+    }
 }
 
 impl<'a> CanDeriveCopy<'a> for TemplateInstantiation {
@@ -168,7 +207,8 @@ impl CanDeriveDebug for TemplateInstantiation {
                 // For non-type template parameters, we generate an opaque
                 // blob, and in this case the instantiation has a better
                 // idea of the layout than the definition does.
-                if c.has_non_type_template_params() {
+                if c.has_non_type_template_params() ||
+                   c.has_const_template_params(ctx) {
                     let opaque = layout.unwrap_or(Layout::zero()).opaque();
                     Some(opaque.can_derive_debug(ctx, ()))
                 } else {
@@ -2,6 +2,7 @@
 
 use super::context::{BindgenContext, ItemId};
 use super::item::ItemSet;
+use super::item_id_set::ItemIdSet;
 use std::collections::{BTreeMap, VecDeque};
 
 /// An outgoing edge in the IR graph is a reference from some item to another
@@ -230,6 +231,16 @@ impl<'ctx, 'gen> TraversalStorage<'ctx, 'gen> for ItemSet {
     }
 }
 
+impl<'ctx, 'gen> TraversalStorage<'ctx, 'gen> for ItemIdSet {
+    fn new(ctx: &'ctx BindgenContext<'gen>) -> Self {
+        ItemIdSet::with_capacity(ctx.item_id_bound())
+    }
+
+    fn add(&mut self, _: Option<ItemId>, item: ItemId) -> bool {
+        self.insert(item)
+    }
+}
+
 /// A `TraversalStorage` implementation that keeps track of how we first reached
 /// each item. This is useful for providing debug assertions with meaningful
 /// diagnostic messages about dangling items.
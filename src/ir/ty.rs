@@ -6,12 +6,13 @@ use super::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault};
 use super::dot::DotAttributes;
 use super::enum_ty::Enum;
 use super::function::FunctionSig;
-use super::int::IntKind;
+use super::int::{IntKind, KnownTypedef};
 use super::item::{Item, ItemAncestors};
 use super::layout::{Layout, Opaque};
 use super::objc::ObjCInterface;
 use super::template::{AsNamed, TemplateInstantiation};
 use super::traversal::{EdgeKind, Trace, Tracer};
+use DiagnosticSeverity;
 use clang::{self, Cursor};
 use parse::{ClangItemParser, ParseError, ParseResult};
 use std::cell::Cell;
@@ -185,6 +186,10 @@ pub struct Type {
 /// We need type-level integers yesterday :'(
 pub const RUST_DERIVE_IN_ARRAY_LIMIT: usize = 32;
 
+/// The maximum number of arguments of a function pointer for which Rust
+/// implements `Debug`. Function pointers are always `Copy` and `Clone`, though.
+pub const RUST_DERIVE_FUNPTR_LIMIT: usize = 12;
+
 impl Type {
     /// Get the underlying `CompInfo` for this type, or `None` if this is some
     /// other kind of type.
@@ -280,6 +285,7 @@ impl Type {
             TypeKind::NullPtr |
             TypeKind::Function(..) |
             TypeKind::Array(..) |
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Reference(..) |
             TypeKind::Pointer(..) |
             TypeKind::BlockPointer |
@@ -396,6 +402,26 @@ impl Type {
         result
     }
 
+    /// Whether this type is, or contains by value, a C++ reference.
+    pub fn has_reference_fields(&self, ctx: &BindgenContext) -> bool {
+        match self.kind {
+            TypeKind::Reference(..) => true,
+            TypeKind::TemplateAlias(t, _) |
+            TypeKind::Alias(t) |
+            TypeKind::Array(t, _) |
+            TypeKind::ConstGenericArray(t, _) |
+            TypeKind::ResolvedTypeRef(t) => {
+                ctx.resolve_type(t).has_reference_fields(ctx)
+            }
+            TypeKind::TemplateInstantiation(ref inst) => {
+                ctx.resolve_type(inst.template_definition())
+                    .has_reference_fields(ctx)
+            }
+            TypeKind::Comp(ref info) => info.has_reference_fields(ctx),
+            _ => false,
+        }
+    }
+
     /// Returns whether this type has a destructor.
     pub fn has_destructor(&self, ctx: &BindgenContext) -> bool {
         match self.kind {
@@ -412,6 +438,22 @@ impl Type {
         }
     }
 
+    /// Returns whether copying or moving this type is non-trivial.
+    pub fn has_non_trivial_copy(&self, ctx: &BindgenContext) -> bool {
+        match self.kind {
+            TypeKind::TemplateAlias(t, _) |
+            TypeKind::Alias(t) |
+            TypeKind::ResolvedTypeRef(t) => {
+                ctx.resolve_type(t).has_non_trivial_copy(ctx)
+            }
+            TypeKind::TemplateInstantiation(ref inst) => {
+                inst.has_non_trivial_copy(ctx)
+            }
+            TypeKind::Comp(ref info) => info.has_non_trivial_copy(ctx),
+            _ => false,
+        }
+    }
+
     /// Whether this named type is an invalid C++ identifier. This is done to
     /// avoid generating invalid code with some cases we can't handle, see:
     ///
@@ -437,6 +479,47 @@ impl Type {
         first_valid && chars.all(|c| c.is_alphanumeric() || c == '_')
     }
 
+    /// The spelling of the given type without any `const` or `volatile`
+    /// qualifiers, which are tracked separately.
+    fn unqualified_spelling(ty: &clang::Type) -> String {
+        ty.spelling()
+            .split_whitespace()
+            .filter(|word| match *word {
+                "const" | "volatile" | "restrict" | "__restrict" |
+                "__restrict__" => false,
+                _ => true,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether this type can be represented by the Rust type of the given
+    /// well-known typedef, that is, whether it's an integer of the same width
+    /// and signedness.
+    pub fn matches_known_typedef(&self,
+                                 ctx: &BindgenContext,
+                                 known: &KnownTypedef)
+                                 -> bool {
+        let canonical = match self.safe_canonical_type(ctx) {
+            Some(canonical) => canonical,
+            None => return false,
+        };
+
+        match *canonical.kind() {
+            TypeKind::Int(kind) => {
+                canonical.layout(ctx)
+                    .map(|layout| layout.size)
+                    .or_else(|| kind.known_size())
+                    .map_or(false, |size| {
+                        known.matches(kind,
+                                      size,
+                                      ctx.options().target_pointer_size())
+                    })
+            }
+            _ => false,
+        }
+    }
+
     /// See safe_canonical_type.
     pub fn canonical_type<'tr>(&'tr self,
                                ctx: &'tr BindgenContext)
@@ -456,6 +539,7 @@ impl Type {
         match self.kind {
             TypeKind::Named |
             TypeKind::Array(..) |
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Comp(..) |
             TypeKind::Opaque |
             TypeKind::Int(..) |
@@ -493,6 +577,7 @@ impl Type {
             TypeKind::Function(..) |
             TypeKind::Pointer(..) |
             TypeKind::Array(..) |
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Reference(..) |
             TypeKind::TemplateInstantiation(..) |
             TypeKind::ResolvedTypeRef(..) => true,
@@ -567,6 +652,7 @@ impl DotAttributes for TypeKind {
                    TypeKind::Alias(..) => "Alias",
                    TypeKind::TemplateAlias(..) => "TemplateAlias",
                    TypeKind::Array(..) => "Array",
+                   TypeKind::ConstGenericArray(..) => "ConstGenericArray",
                    TypeKind::Function(..) => "Function",
                    TypeKind::Enum(..) => "Enum",
                    TypeKind::Pointer(..) => "Pointer",
@@ -655,11 +741,12 @@ impl TemplateDeclaration for TypeKind {
             TypeKind::Float(_) |
             TypeKind::Complex(_) |
             TypeKind::Array(..) |
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Function(_) |
             TypeKind::Enum(_) |
             TypeKind::Pointer(_) |
             TypeKind::BlockPointer |
-            TypeKind::Reference(_) |
+            TypeKind::Reference(..) |
             TypeKind::UnresolvedTypeRef(..) |
             TypeKind::Named |
             TypeKind::Alias(_) |
@@ -678,6 +765,7 @@ impl CanDeriveDebug for Type {
             TypeKind::Array(t, len) => {
                 len <= RUST_DERIVE_IN_ARRAY_LIMIT && t.can_derive_debug(ctx, ())
             }
+            TypeKind::ConstGenericArray(t, _) => t.can_derive_debug(ctx, ()),
             TypeKind::ResolvedTypeRef(t) |
             TypeKind::TemplateAlias(t, _) |
             TypeKind::Alias(t) => t.can_derive_debug(ctx, ()),
@@ -692,6 +780,7 @@ impl CanDeriveDebug for Type {
                 }
                 return true;
             }
+            TypeKind::Function(ref sig) => sig.can_derive_debug(ctx, ()),
             TypeKind::TemplateInstantiation(ref inst) => {
                 inst.can_derive_debug(ctx, self.layout(ctx))
             }
@@ -719,6 +808,8 @@ impl CanDeriveDefault for Type {
                 self.layout
                     .map_or(true, |l| l.opaque().can_derive_default(ctx, ()))
             }
+            // `Default` is only implemented for arrays of up to 32 elements.
+            TypeKind::ConstGenericArray(..) |
             TypeKind::Void |
             TypeKind::Named |
             TypeKind::TemplateInstantiation(..) |
@@ -749,6 +840,9 @@ impl<'a> CanDeriveCopy<'a> for Type {
                 len <= RUST_DERIVE_IN_ARRAY_LIMIT &&
                 t.can_derive_copy_in_array(ctx, ())
             }
+            TypeKind::ConstGenericArray(t, _) => {
+                t.can_derive_copy_in_array(ctx, ())
+            }
             TypeKind::ResolvedTypeRef(t) |
             TypeKind::TemplateAlias(t, _) |
             TypeKind::Alias(t) => t.can_derive_copy(ctx, ()),
@@ -774,7 +868,14 @@ impl<'a> CanDeriveCopy<'a> for Type {
             TypeKind::ResolvedTypeRef(t) |
             TypeKind::TemplateAlias(t, _) |
             TypeKind::Alias(t) |
-            TypeKind::Array(t, _) => t.can_derive_copy_in_array(ctx, ()),
+            TypeKind::ConstGenericArray(t, _) => {
+                t.can_derive_copy_in_array(ctx, ())
+            }
+            // The limit applies to every dimension of nested arrays.
+            TypeKind::Array(t, len) => {
+                len <= RUST_DERIVE_IN_ARRAY_LIMIT &&
+                t.can_derive_copy_in_array(ctx, ())
+            }
             TypeKind::Named => false,
             _ => self.can_derive_copy(ctx, item),
         }
@@ -805,6 +906,26 @@ impl FloatKind {
     }
 }
 
+/// The kind of a C++ reference.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReferenceKind {
+    /// An lvalue reference, as in `int&`.
+    LValue,
+    /// An rvalue reference, as in `int&&`.
+    RValue,
+}
+
+impl ReferenceKind {
+    /// A short description of what the pointers we lower these references to
+    /// stand for, to put in the docs of the items using them.
+    pub fn doc_note(&self) -> &'static str {
+        match *self {
+            ReferenceKind::LValue => "C++ reference, never null",
+            ReferenceKind::RValue => "C++ rvalue reference, never null",
+        }
+    }
+}
+
 /// The different kinds of types that we can parse.
 #[derive(Debug)]
 pub enum TypeKind {
@@ -842,6 +963,10 @@ pub enum TypeKind {
     /// An array of a type and a lenght.
     Array(ItemId, usize),
 
+    /// An array of a type whose length is the given `const` generic
+    /// parameter, see `Builder::const_generics`.
+    ConstGenericArray(ItemId, ItemId),
+
     /// A function type, with a given signature.
     Function(FunctionSig),
 
@@ -855,8 +980,9 @@ pub enum TypeKind {
     /// A pointer to an Apple block.
     BlockPointer,
 
-    /// A reference to a type, as in: int& foo().
-    Reference(ItemId),
+    /// A reference to a type, as in: int& foo(), or an rvalue reference, as
+    /// in: int&& foo().
+    Reference(ItemId, ReferenceKind),
 
     /// An instantiation of an abstract template definition with a set of
     /// concrete template arguments.
@@ -908,6 +1034,9 @@ impl Type {
             TypeKind::Array(inner, size) => {
                 size == 0 || ctx.resolve_type(inner).is_unsized(ctx)
             }
+            TypeKind::ConstGenericArray(inner, _) => {
+                ctx.resolve_type(inner).is_unsized(ctx)
+            }
             TypeKind::ResolvedTypeRef(inner) |
             TypeKind::Alias(inner) |
             TypeKind::TemplateAlias(inner, _) => {
@@ -937,6 +1066,31 @@ impl Type {
         }
     }
 
+    /// Find the `const` generic parameter a dependently sized array declared
+    /// at `location` uses as its length, if any.
+    ///
+    /// Only lengths that are spelled as exactly one known parameter, like
+    /// `char data[N]`, are handled, since other expressions can't be
+    /// represented in a Rust array type.
+    fn const_generic_array_len(location: &clang::Cursor,
+                               ctx: &BindgenContext)
+                               -> Option<ItemId> {
+        use clang_sys::*;
+
+        let mut params = vec![];
+        location.visit(|child| {
+            if child.kind() == CXCursor_DeclRefExpr {
+                if let Some(param) = child.referenced()
+                    .and_then(|refd| ctx.get_const_template_param(&refd)) {
+                    params.push(param);
+                }
+            }
+            CXChildVisit_Continue
+        });
+
+        if params.len() == 1 { params.pop() } else { None }
+    }
+
     /// This is another of the nasty methods. This one is the one that takes
     /// care of the core logic of converting a clang type to a `Type`.
     ///
@@ -949,6 +1103,24 @@ impl Type {
                          ctx: &mut BindgenContext)
                          -> Result<ParseResult<Self>, ParseError> {
         use clang_sys::*;
+
+        // Newer libclang versions wrap almost every reference to a named type
+        // in an elaborated type, for the `struct` in `struct Foo`, or for a
+        // qualified name. Look through it right away, so that the special
+        // cases below see the type they know about, instead of having it fall
+        // through to an unexposed or opaque type.
+        if ty.kind() == CXType_Elaborated {
+            let named = ty.named();
+            // Without `clang_Type_getNamedType` we get the same type back.
+            if named != *ty {
+                return Self::from_clang_ty(potential_id,
+                                           &named,
+                                           location,
+                                           parent_id,
+                                           ctx);
+            }
+        }
+
         {
             let already_resolved = ctx.builtin_or_resolved_ty(potential_id,
                                                               parent_id,
@@ -982,9 +1154,15 @@ impl Type {
 
         if location.kind() == CXCursor_ClassTemplatePartialSpecialization {
             // Sorry! (Not sorry)
-            warn!("Found a partial template specialization; bindgen does not \
-                   support partial template specialization! Constructing \
-                   opaque type instead.");
+            let message = format!("{}: Found a partial template \
+                                   specialization; bindgen does not support \
+                                   partial template specialization! \
+                                   Constructing opaque type instead.",
+                                  location.location());
+            ctx.diagnostic(DiagnosticSeverity::Warning, message);
+            ctx.record_opaque_fallback(potential_id,
+                                       ty,
+                                       "partial template specialization");
             return Ok(ParseResult::New(Opaque::from_clang_ty(&canonical_ty),
                                        None));
         }
@@ -997,6 +1175,30 @@ impl Type {
             TypeKind::TemplateInstantiation(inst)
         } else {
             match ty_kind {
+                CXType_Unexposed if ctx.options().convert_stdint_types &&
+                                    *ty != canonical_ty &&
+                                    canonical_ty.kind() != CXType_Invalid &&
+                                    KnownTypedef::from_name(
+                                        &Self::unqualified_spelling(ty))
+                                        .is_some() => {
+                    // Don't collapse a well-known typedef like `uint32_t`
+                    // into its underlying builtin type right away, or we'd
+                    // lose its name, and generate `c_uint` instead of `u32`.
+                    let spelling = Self::unqualified_spelling(ty);
+                    let known = KnownTypedef::from_name(&spelling).unwrap();
+                    let inner =
+                        Item::from_ty_or_ref(canonical_ty, location, None, ctx);
+                    if !ctx.resolve_type(inner)
+                        .matches_known_typedef(ctx, &known) {
+                        return Self::from_clang_ty(potential_id,
+                                                   &canonical_ty,
+                                                   location,
+                                                   parent_id,
+                                                   ctx);
+                    }
+                    name = spelling;
+                    TypeKind::Alias(inner)
+                }
                 CXType_Unexposed if *ty != canonical_ty &&
                                     canonical_ty.kind() != CXType_Invalid &&
                                     ty.ret_type().is_none() &&
@@ -1204,7 +1406,9 @@ impl Type {
                                     return Err(ParseError::Recurse);
                                 }
 
-                                warn!("invalid type {:?}", ty);
+                                warn!("{}: invalid type {:?}",
+                                      location.location(),
+                                      ty);
                                 return Err(ParseError::Continue);
                             }
                         }
@@ -1263,8 +1467,6 @@ impl Type {
                     TypeKind::Pointer(inner)
                 }
                 CXType_BlockPointer => TypeKind::BlockPointer,
-                // XXX: RValueReference is most likely wrong, but I don't think we
-                // can even add bindings for that, so huh.
                 CXType_RValueReference |
                 CXType_LValueReference => {
                     let inner = Item::from_ty_or_ref(ty.pointee_type()
@@ -1272,11 +1474,26 @@ impl Type {
                                                      location,
                                                      None,
                                                      ctx);
-                    TypeKind::Reference(inner)
+                    let kind = if ty_kind == CXType_RValueReference {
+                        ReferenceKind::RValue
+                    } else {
+                        ReferenceKind::LValue
+                    };
+                    TypeKind::Reference(inner, kind)
                 }
-                // XXX DependentSizedArray is wrong
-                CXType_VariableArray |
                 CXType_DependentSizedArray => {
+                    let inner = Item::from_ty(ty.elem_type().as_ref().unwrap(),
+                                              location,
+                                              None,
+                                              ctx)
+                        .expect("Not able to resolve array element?");
+                    // XXX Arrays with any other dependent length are wrong
+                    match Self::const_generic_array_len(&location, ctx) {
+                        Some(len) => TypeKind::ConstGenericArray(inner, len),
+                        None => TypeKind::Pointer(inner),
+                    }
+                }
+                CXType_VariableArray => {
                     let inner = Item::from_ty(ty.elem_type().as_ref().unwrap(),
                                               location,
                                               None,
@@ -1348,13 +1565,6 @@ impl Type {
                         .expect("Not able to resolve array element?");
                     TypeKind::Array(inner, ty.num_elements().unwrap())
                 }
-                CXType_Elaborated => {
-                    return Self::from_clang_ty(potential_id,
-                                               &ty.named(),
-                                               location,
-                                               parent_id,
-                                               ctx);
-                }
                 CXType_ObjCId => TypeKind::ObjCId,
                 CXType_ObjCSel => TypeKind::ObjCSel,
                 CXType_ObjCClass |
@@ -1365,10 +1575,13 @@ impl Type {
                     TypeKind::ObjCInterface(interface)
                 }
                 _ => {
-                    error!("unsupported type: kind = {:?}; ty = {:?}; at {:?}",
-                           ty.kind(),
-                           ty,
-                           location);
+                    let message = format!("{}: unsupported type: kind = \
+                                           {:?}; ty = {:?}; at {:?}",
+                                          location.location(),
+                                          ty.kind(),
+                                          ty,
+                                          location);
+                    ctx.diagnostic(DiagnosticSeverity::Error, message);
                     return Err(ParseError::Continue);
                 }
             }
@@ -1391,12 +1604,16 @@ impl Trace for Type {
     {
         match *self.kind() {
             TypeKind::Pointer(inner) |
-            TypeKind::Reference(inner) |
+            TypeKind::Reference(inner, _) |
             TypeKind::Array(inner, _) |
             TypeKind::Alias(inner) |
             TypeKind::ResolvedTypeRef(inner) => {
                 tracer.visit_kind(inner, EdgeKind::TypeReference);
             }
+            TypeKind::ConstGenericArray(inner, len) => {
+                tracer.visit_kind(inner, EdgeKind::TypeReference);
+                tracer.visit_kind(len, EdgeKind::TypeReference);
+            }
             TypeKind::TemplateAlias(inner, ref template_params) => {
                 tracer.visit_kind(inner, EdgeKind::TypeReference);
                 for &item in template_params {
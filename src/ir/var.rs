@@ -2,10 +2,13 @@
 
 use super::context::{BindgenContext, ItemId};
 use super::dot::DotAttributes;
-use super::function::cursor_mangling;
+use super::function::{DllStorage, Linkage, SymbolVisibility,
+                      cursor_mangling};
 use super::int::IntKind;
 use super::item::Item;
+use super::macro_fn::MacroFunction;
 use super::ty::{FloatKind, TypeKind};
+use DiagnosticSeverity;
 use cexpr;
 use clang;
 use parse::{ClangItemParser, ClangSubItemParser, ParseError, ParseResult};
@@ -40,6 +43,12 @@ pub struct Var {
     val: Option<VarType>,
     /// Whether this variable is const.
     is_const: bool,
+    /// The visibility of the variable's symbol.
+    visibility: SymbolVisibility,
+    /// Whether this variable is imported from or exported to a DLL.
+    dll_storage: Option<DllStorage>,
+    /// Whether this variable is thread-local, like `__thread int foo;`.
+    is_thread_local: bool,
 }
 
 impl Var {
@@ -48,7 +57,10 @@ impl Var {
                mangled: Option<String>,
                ty: ItemId,
                val: Option<VarType>,
-               is_const: bool)
+               is_const: bool,
+               visibility: SymbolVisibility,
+               dll_storage: Option<DllStorage>,
+               is_thread_local: bool)
                -> Var {
         assert!(!name.is_empty());
         Var {
@@ -57,6 +69,9 @@ impl Var {
             ty: ty,
             val: val,
             is_const: is_const,
+            visibility: visibility,
+            dll_storage: dll_storage,
+            is_thread_local: is_thread_local,
         }
     }
 
@@ -84,6 +99,21 @@ impl Var {
     pub fn mangled_name(&self) -> Option<&str> {
         self.mangled_name.as_ref().map(|n| &**n)
     }
+
+    /// Get the visibility of this variable's symbol.
+    pub fn visibility(&self) -> SymbolVisibility {
+        self.visibility
+    }
+
+    /// Whether this variable is imported from or exported to a DLL.
+    pub fn dll_storage(&self) -> Option<DllStorage> {
+        self.dll_storage
+    }
+
+    /// Is this variable thread-local?
+    pub fn is_thread_local(&self) -> bool {
+        self.is_thread_local
+    }
 }
 
 impl DotAttributes for Var {
@@ -97,12 +127,26 @@ impl DotAttributes for Var {
             try!(writeln!(out, "<tr><td>const</td><td>true</td></tr>"));
         }
 
+        if self.is_thread_local {
+            try!(writeln!(out, "<tr><td>thread local</td><td>true</td></tr>"));
+        }
+
         if let Some(ref mangled) = self.mangled_name {
             try!(writeln!(out,
                           "<tr><td>mangled name</td><td>{}</td></tr>",
                           mangled));
         }
 
+        try!(writeln!(out,
+                      "<tr><td>visibility</td><td>{:?}</td></tr>",
+                      self.visibility));
+
+        if let Some(storage) = self.dll_storage {
+            try!(writeln!(out,
+                          "<tr><td>dll storage</td><td>{:?}</td></tr>",
+                          storage));
+        }
+
         Ok(())
     }
 }
@@ -121,6 +165,13 @@ impl ClangSubItemParser for Var {
                     visitor.parsed_macro(&cursor.spelling());
                 }
 
+                if cursor.is_macro_function_like() {
+                    if ctx.options().emit_macro_fns {
+                        note_macro_fn(ctx, &cursor);
+                    }
+                    return Err(ParseError::Continue);
+                }
+
                 let value = parse_macro(ctx, &cursor, ctx.translation_unit());
 
                 let (id, value) = match value {
@@ -195,8 +246,15 @@ impl ClangSubItemParser for Var {
 
                 let ty = Item::builtin_type(type_kind, true, ctx);
 
-                Ok(ParseResult::New(Var::new(name, None, ty, Some(val), true),
-                                    Some(cursor)))
+                let var = Var::new(name,
+                                   None,
+                                   ty,
+                                   Some(val),
+                                   true,
+                                   SymbolVisibility::Default,
+                                   None,
+                                   false);
+                Ok(ParseResult::New(var, Some(cursor)))
             }
             CXCursor_VarDecl => {
                 let name = cursor.spelling();
@@ -230,22 +288,40 @@ impl ClangSubItemParser for Var {
 
                 let is_integer = canonical_ty.map_or(false, |t| t.is_integer());
                 let is_float = canonical_ty.map_or(false, |t| t.is_float());
+                let is_enum = canonical_ty.map_or(false, |t| match *t.kind() {
+                    TypeKind::Enum(..) => true,
+                    _ => false,
+                });
 
                 // TODO: We could handle `char` more gracefully.
                 // TODO: Strings, though the lookup is a bit more hard (we need
                 // to look at the canonical type of the pointee too, and check
                 // is char, u8, or i8 I guess).
-                let value = if is_integer {
+                //
+                // The value of a variable that isn't `const` (or `constexpr`)
+                // can change at runtime, so it needs to stay a static.
+                let value = if (is_integer || is_float || is_enum) &&
+                               !is_const {
+                    None
+                } else if is_integer {
                     let kind = match *canonical_ty.unwrap().kind() {
                         TypeKind::Int(kind) => kind,
                         _ => unreachable!(),
                     };
 
-                    let mut val = cursor.evaluate()
-                        .and_then(|v| v.as_int())
-                        .map(|val| val as i64);
+                    // `clang_Cursor_Evaluate` truncates the value to 32 bits,
+                    // so try to evaluate the initializer ourselves first.
+                    //
+                    // FIXME: Use `clang_EvalResult_getAsLongLong` once we
+                    // can rely on libclang 4.0.
+                    let tu = ctx.translation_unit();
+                    let mut val = get_integer_from_initializer(&cursor, tu);
+                    if val.is_none() {
+                        val = cursor.evaluate()
+                            .and_then(|v| v.as_int())
+                            .map(|val| val as i64);
+                    }
                     if val.is_none() || !kind.signedness_matches(val.unwrap()) {
-                        let tu = ctx.translation_unit();
                         val = get_integer_literal_from_cursor(&cursor, tu);
                     }
 
@@ -258,14 +334,54 @@ impl ClangSubItemParser for Var {
                     cursor.evaluate()
                         .and_then(|v| v.as_double())
                         .map(VarType::Float)
+                } else if is_enum {
+                    cursor.evaluate()
+                        .and_then(|v| v.as_int())
+                        .map(|val| VarType::Int(val as i64))
                 } else {
                     cursor.evaluate()
                         .and_then(|v| v.as_literal_string())
                         .map(VarType::String)
                 };
 
+                // Unless asked to inline their values, keep referring to the
+                // variables defined in some library through their symbol, if
+                // there's any.
+                let linkage = Linkage::from_cursor(&cursor);
+                let value = if linkage == Linkage::External &&
+                               !ctx.options().generate_inline_const_vars &&
+                               has_symbol(&cursor, ctx.translation_unit()) {
+                    None
+                } else {
+                    value
+                };
+
+                // Without a value, we need to link against the variable,
+                // which we can't do if it's `static`.
+                if value.is_none() && linkage == Linkage::Internal &&
+                   !ctx.options().generate_static_fns_anyway {
+                    let message = format!("{}: Skipping `{}`, since it has \
+                                           internal linkage and there would \
+                                           be nothing to link against",
+                                          cursor.location(),
+                                          cursor.spelling());
+                    ctx.diagnostic(DiagnosticSeverity::Warning, message);
+                    return Err(ParseError::Continue);
+                }
+
                 let mangling = cursor_mangling(ctx, &cursor);
-                let var = Var::new(name, mangling, ty, value, is_const);
+                let visibility = SymbolVisibility::from_cursor(&cursor);
+                let dll_storage = DllStorage::from_cursor(&cursor);
+                let is_thread_local =
+                    is_thread_local(&cursor, ctx.translation_unit());
+                let var = Var::new(name,
+                                   mangling,
+                                   ty,
+                                   value,
+                                   is_const,
+                                   visibility,
+                                   dll_storage,
+                                   is_thread_local);
 
                 Ok(ParseResult::New(var, Some(cursor)))
             }
@@ -298,6 +414,27 @@ fn parse_macro(ctx: &BindgenContext,
     }
 }
 
+/// Parse a function-like macro to generate a function for later on, see
+/// `Builder::emit_macro_fns`.
+fn note_macro_fn(ctx: &mut BindgenContext, cursor: &clang::Cursor) {
+    let parsed = match ctx.translation_unit().cexpr_tokens(cursor) {
+        Some(tokens) => MacroFunction::parse(&tokens),
+        None => return,
+    };
+
+    match parsed {
+        Ok(macro_fn) => ctx.note_macro_fn(macro_fn, *cursor),
+        Err(reason) => {
+            let message = format!("{}: Skipping function-like macro `{}`, \
+                                   since {}",
+                                  cursor.location(),
+                                  cursor.spelling(),
+                                  reason);
+            ctx.diagnostic(DiagnosticSeverity::Warning, message);
+        }
+    }
+}
+
 fn parse_int_literal_tokens(cursor: &clang::Cursor,
                             unit: &clang::TranslationUnit)
                             -> Option<i64> {
@@ -316,6 +453,99 @@ fn parse_int_literal_tokens(cursor: &clang::Cursor,
     }
 }
 
+/// Is the given variable declared thread-local, with `__thread`,
+/// `_Thread_local` or `thread_local`?
+///
+/// FIXME: Use `clang_getCursorTLSKind` once we can rely on libclang 6.0.
+fn is_thread_local(cursor: &clang::Cursor,
+                   unit: &clang::TranslationUnit)
+                   -> bool {
+    let name = cursor.spelling();
+    let tokens = match unit.tokens(cursor) {
+        Some(tokens) => tokens,
+        None => return false,
+    };
+
+    // Only look at the specifiers before the name, the initializer may well
+    // call a function named `thread_local`.
+    tokens.iter()
+        .take_while(|token| token.spelling != name)
+        .any(|token| match &*token.spelling {
+            "__thread" | "_Thread_local" | "thread_local" => true,
+            _ => false,
+        })
+}
+
+/// Whether we can expect the given externally linked variable to have a symbol
+/// to link against.
+///
+/// That's not the case for the static data members only declared (and
+/// initialized) in their class, without an out-of-line definition, nor for
+/// `constexpr` ones, which are only emitted where they're odr-used.
+fn has_symbol(cursor: &clang::Cursor, unit: &clang::TranslationUnit) -> bool {
+    use clang_sys::*;
+
+    let is_member = match cursor.semantic_parent().kind() {
+        CXCursor_StructDecl |
+        CXCursor_UnionDecl |
+        CXCursor_ClassDecl |
+        CXCursor_ClassTemplate => true,
+        _ => false,
+    };
+    if is_member && cursor.definition().is_none() {
+        return false;
+    }
+
+    let name = cursor.spelling();
+    match unit.tokens(cursor) {
+        Some(tokens) => {
+            !tokens.iter()
+                .take_while(|token| token.spelling != name)
+                .any(|token| token.spelling == "constexpr")
+        }
+        None => true,
+    }
+}
+
+/// Evaluate the whole initializer expression of the given variable with
+/// cexpr, which doesn't truncate the result like `clang_Cursor_Evaluate`
+/// does. This only works for initializers that don't refer to any other
+/// declaration.
+fn get_integer_from_initializer(cursor: &clang::Cursor,
+                                unit: &clang::TranslationUnit)
+                                -> Option<i64> {
+    use cexpr::{expr, nom};
+    use cexpr::expr::EvalResult;
+    use cexpr::token::Kind;
+    use clang_sys::*;
+
+    let mut initializer = None;
+    cursor.visit(|c| {
+        if c.is_expression() {
+            initializer = Some(c);
+        }
+        CXChildVisit_Continue
+    });
+
+    let tokens = match initializer.and_then(|c| unit.cexpr_tokens(&c)) {
+        Some(tokens) => tokens,
+        None => return None,
+    };
+
+    match expr::expr(&tokens) {
+        // Make sure we've evaluated the whole initializer, and not just a
+        // prefix of it we understand, like the `1` in `1 + sizeof(int)`.
+        nom::IResult::Done(rest, EvalResult::Int(Wrapping(val))) => {
+            let fully_parsed = rest.iter().all(|token| {
+                token.kind == Kind::Punctuation &&
+                (&*token.raw == b";" || &*token.raw == b",")
+            });
+            if fully_parsed { Some(val) } else { None }
+        }
+        _ => None,
+    }
+}
+
 fn get_integer_literal_from_cursor(cursor: &clang::Cursor,
                                    unit: &clang::TranslationUnit)
                                    -> Option<i64> {
@@ -58,35 +58,58 @@ macro_rules! doc_mod {
 }
 
 mod clang;
+mod incremental;
 mod ir;
 mod parse;
 mod regex_set;
+mod split;
+mod targets;
 mod uses;
 
 pub mod callbacks;
 
+pub use clang::Capabilities as ClangCapabilities;
+
 #[cfg(rustfmt)]
 mod codegen;
 
 doc_mod!(clang, clang_docs);
+doc_mod!(incremental, incremental_docs);
 doc_mod!(ir, ir_docs);
 doc_mod!(parse, parse_docs);
 doc_mod!(regex_set, regex_set_docs);
+doc_mod!(split, split_docs);
+doc_mod!(targets, targets_docs);
 doc_mod!(uses, uses_docs);
 
 mod codegen {
     include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
+
+    // The bitfield unit is parsed from source at codegen time, but we also
+    // compile it in for testing. These can't be declared in `codegen/mod.rs`,
+    // since the build script expands it without `cfg(test)`, and would strip
+    // them.
+    #[cfg(test)]
+    #[allow(warnings)]
+    mod bitfield_unit;
+    #[cfg(all(test, target_endian = "little"))]
+    mod bitfield_unit_tests;
 }
 
-use ir::context::{BindgenContext, ItemId};
+use ir::context::{BindgenContext, Callbacks, ItemId};
 use ir::item::Item;
 use parse::{ClangItemParser, ParseError};
 use regex_set::RegexSet;
 
-use std::fs::OpenOptions;
-use std::io::{self, Write};
+use std::env;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::mem;
 use std::path::Path;
+use std::str;
 use std::sync::Arc;
+use std::thread;
 
 use syntax::ast;
 use syntax::codemap::{DUMMY_SP, Span};
@@ -160,6 +183,7 @@ impl Default for CodegenConfig {
 #[derive(Debug,Default)]
 pub struct Builder {
     options: BindgenOptions,
+    callbacks: Callbacks,
 }
 
 /// Construct a new [`Builder`](./struct.Builder.html).
@@ -168,10 +192,52 @@ pub fn builder() -> Builder {
 }
 
 impl Builder {
-    /// Set the input C/C++ header.
+    /// Set the input C/C++ header, replacing any other input header.
     pub fn header<T: Into<String>>(mut self, header: T) -> Builder {
         let header = header.into();
         self.options.input_header = Some(header);
+        self.options.extra_input_headers.clear();
+        self
+    }
+
+    /// Set the input C/C++ headers, replacing any other input header.
+    ///
+    /// By default, they're parsed together, as if the first one included all
+    /// the others. See `parallel_parsing` for parsing each of them on its
+    /// own.
+    pub fn headers<I>(mut self, headers: I) -> Builder
+        where I: IntoIterator,
+              I::Item: Into<String>,
+    {
+        self.options.input_header = None;
+        self.options.extra_input_headers.clear();
+        for header in headers {
+            self = self.add_header(header);
+        }
+        self
+    }
+
+    /// Add an input C/C++ header to the ones set with `header` or `headers`.
+    pub fn add_header<T: Into<String>>(mut self, header: T) -> Builder {
+        let header = header.into();
+        if self.options.input_header.is_none() {
+            self.options.input_header = Some(header);
+        } else {
+            self.options.extra_input_headers.push(header);
+        }
+        self
+    }
+
+    /// Parse each input header on its own thread, in its own clang
+    /// translation unit, and merge the resulting bindings, keeping a single
+    /// copy of the items generated for more than one header.
+    ///
+    /// This only pays off when the headers are mostly independent of each
+    /// other, since whatever they include is parsed once per header. It can't
+    /// be used along with parse callbacks, nor when generating for multiple
+    /// targets, and the headers are parsed sequentially in those cases.
+    pub fn parallel_parsing(mut self, doit: bool) -> Self {
+        self.options.parallel_parsing = doit;
         self
     }
 
@@ -200,6 +266,14 @@ impl Builder {
     /// This can be used to get bindgen to generate _exactly_ the types you want
     /// in your bindings, and then import other types manually via other means
     /// (like `raw_line`).
+    ///
+    /// The types the whitelisted items only use behind pointers are forward
+    /// declared as opaque zero-sized structs, unless they're hidden or never
+    /// defined in the headers, so the pointers still typecheck. Using a
+    /// struct or union that isn't whitelisted nor hidden by value makes the
+    /// generation fail, since there's no way to generate a correct layout for
+    /// it. Using an alias or an enum like that only gets a warning, and it's
+    /// up to the user to provide it.
     pub fn whitelist_recursively(mut self, doit: bool) -> Self {
         self.options.whitelist_recursively = doit;
         self
@@ -212,6 +286,18 @@ impl Builder {
         self
     }
 
+    /// Whether to generate bindings for the Objective-C interfaces, protocols
+    /// and categories, with traits to send messages through the `objc` crate.
+    /// Defaults to true.
+    ///
+    /// If set to false, every `@interface` becomes an opaque handle type that
+    /// can only be used behind pointers, and `id` and `SEL` become void
+    /// pointers, so the C bindings around them don't need the `objc` crate.
+    pub fn enable_objc(mut self, doit: bool) -> Self {
+        self.options.enable_objc = doit;
+        self
+    }
+
     /// Whether to use the clang-provided name mangling. This is true and
     /// probably needed for C++ features.
     ///
@@ -238,6 +324,28 @@ impl Builder {
         self
     }
 
+    /// Generate the hidden items anyway, either hidden with the `hide`
+    /// annotation or with `hide_type`, but marked `#[doc(hidden)]`, instead
+    /// of leaving them out.
+    ///
+    /// This keeps the code using them compiling, while still hiding them
+    /// from the docs. They're still public, since the items that aren't
+    /// hidden may use them in their public API.
+    pub fn emit_hidden_items(mut self, doit: bool) -> Builder {
+        self.options.emit_hidden_items = doit;
+        self
+    }
+
+    /// Choose what to do with the type aliases whose aliased type is hidden.
+    /// See `HiddenAliasVariation` for the available choices. By default,
+    /// they're kept as they are.
+    pub fn hidden_alias_variation(mut self,
+                                  variation: HiddenAliasVariation)
+                                  -> Self {
+        self.options.hidden_alias_variation = variation;
+        self
+    }
+
     /// Treat the given type as opaque in the generated bindings. Regular
     /// expressions are supported.
     pub fn opaque_type<T: AsRef<str>>(mut self, arg: T) -> Builder {
@@ -245,6 +353,138 @@ impl Builder {
         self
     }
 
+    /// Generate sized opaque blobs for the instantiations of a few well-known
+    /// C++ standard library templates, like `std::vector`,
+    /// `std::unique_ptr`, and `std::shared_ptr`, instead of going through
+    /// their implementation details.
+    ///
+    /// Each instantiation gets its own type, named after its C++ spelling,
+    /// and the templates themselves are made opaque.
+    pub fn enable_stl_blobs(mut self, doit: bool) -> Self {
+        self.options.stl_blobs = doit;
+        self
+    }
+
+    /// Generate `const` generic parameters for the unsigned integer template
+    /// parameters of C++ class templates that are used as array lengths, like
+    /// `template<size_t N> struct Buf { char data[N]; }`.
+    ///
+    /// This needs a toolchain that supports const generics. Without it, these
+    /// templates aren't generated at all, and their instantiations are sized
+    /// opaque blobs.
+    pub fn const_generics(mut self, doit: bool) -> Self {
+        self.options.const_generics = doit;
+        self
+    }
+
+    /// Implement `Send` for the types matching the given regular expression.
+    ///
+    /// This is inherently unsafe: bindgen can't check it, so you're asserting
+    /// that the type can really be sent across threads. The same can be
+    /// achieved for a single type with the `<div rustbindgen send></div>`
+    /// annotation.
+    pub fn send_type<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.send_types.insert(arg);
+        self
+    }
+
+    /// Implement `Sync` for the types matching the given regular expression.
+    ///
+    /// Like `send_type`, this is an unchecked assertion that the type can
+    /// really be shared across threads. The equivalent annotation is
+    /// `<div rustbindgen sync></div>`.
+    pub fn sync_type<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.sync_types.insert(arg);
+        self
+    }
+
+    /// Implement the trait with the given path, like `::my::Ops`, for the
+    /// types matching the given regular expression. Can be used multiple
+    /// times, and the impls are generated in the order they were added. See
+    /// also `ParseCallbacks::implemented_traits`.
+    ///
+    /// The generated impls are empty, so the trait must be implementable as a
+    /// marker: it can't have any item without a default. The impls aren't
+    /// `unsafe` either, so unsafe traits can't be used.
+    pub fn add_impl_for<T, P>(mut self, arg: T, trait_path: P) -> Builder
+        where T: AsRef<str>,
+              P: Into<String>,
+    {
+        let trait_path = trait_path.into();
+        let position = self.options
+            .trait_impls
+            .iter()
+            .position(|&(ref p, _)| *p == trait_path);
+        match position {
+            Some(i) => self.options.trait_impls[i].1.insert(arg),
+            None => {
+                let mut set = RegexSet::default();
+                set.insert(arg);
+                self.options.trait_impls.push((trait_path, set));
+            }
+        }
+        self
+    }
+
+    /// Generate the typedefs matching the given regular expression as new
+    /// types, that is, `#[repr(transparent)]` tuple structs wrapping the
+    /// aliased type, instead of plain type aliases:
+    ///
+    /// ```c
+    /// typedef uint32_t handle_t;
+    /// ```
+    ///
+    /// becomes `pub struct handle_t(pub u32);`, so it can't be mixed up with
+    /// other integers.
+    ///
+    /// In a chain of matching typedefs only the outermost one becomes a new
+    /// type, and wraps the next one, which stays a type alias. Typedefs of
+    /// pointers wrap the pointer itself. Templated aliases are always
+    /// generated as type aliases.
+    pub fn new_type_alias<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.new_type_aliases.insert(arg);
+        self.options.alias_style_order.push(AliasVariation::NewType);
+        self
+    }
+
+    /// Like `new_type_alias`, but also implement `Deref` and `DerefMut` to the
+    /// aliased type for the generated new types.
+    pub fn new_type_alias_deref<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.new_type_alias_deref.insert(arg);
+        self.options.alias_style_order.push(AliasVariation::NewTypeDeref);
+        self
+    }
+
+    /// Generate the typedefs matching the given regular expression as plain
+    /// type aliases, whatever the `default_alias_style` is.
+    pub fn type_alias<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.type_aliases.insert(arg);
+        self.options.alias_style_order.push(AliasVariation::TypeAlias);
+        self
+    }
+
+    /// Choose how to generate the typedefs that no regular expression given to
+    /// `type_alias`, `new_type_alias` or `new_type_alias_deref` matches. See
+    /// `AliasVariation` for the available choices. By default, they're type
+    /// aliases.
+    ///
+    /// If the regular expressions of several of those options match the same
+    /// typedef, the one given last wins, with a warning.
+    pub fn default_alias_style(mut self,
+                               variation: AliasVariation)
+                               -> Builder {
+        self.options.default_alias_style = variation;
+        self
+    }
+
+    /// Whether to implement `From` both ways between the new types generated
+    /// for typedefs and the types they wrap, so `.into()` converts between
+    /// them. Defaults to false. See `new_type_alias`.
+    pub fn newtype_conversions(mut self, doit: bool) -> Builder {
+        self.options.newtype_conversions = doit;
+        self
+    }
+
     /// Whitelist the given type so that it (and all types that it transitively
     /// refers to) appears in the generated bindings. Regular expressions are
     /// supported.
@@ -269,6 +509,23 @@ impl Builder {
         self
     }
 
+    /// Whitelist everything declared in the headers whose path matches the
+    /// given regular expression, as if they had been passed as input headers.
+    /// See `only_primary_header`.
+    pub fn whitelisted_file<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.whitelisted_files.insert(arg);
+        self
+    }
+
+    /// Only generate the items declared in the input headers (and in the
+    /// files matching `whitelisted_file`), plus whatever those transitively
+    /// refer to. Everything else the headers include, like system headers,
+    /// is only generated when referenced.
+    pub fn only_primary_header(mut self, doit: bool) -> Self {
+        self.options.only_primary_header = doit;
+        self
+    }
+
     /// Mark the given enum (or set of enums, if using a pattern) as being
     /// bitfield-like. Regular expressions are supported.
     ///
@@ -276,16 +533,88 @@ impl Builder {
     /// expressions are supported.
     pub fn bitfield_enum<T: AsRef<str>>(mut self, arg: T) -> Builder {
         self.options.bitfield_enums.insert(arg);
+        self.options.enum_style_order.push(EnumVariation::Bitfield);
+        self
+    }
+
+    /// Mark the given enum (or set of enums, if using a pattern) as being a
+    /// Rust `enum`, whatever the `default_enum_style` is. Regular expressions
+    /// are supported.
+    pub fn rustified_enum<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.rustified_enums.insert(arg);
+        self.options.enum_style_order.push(EnumVariation::Rust);
+        self
+    }
+
+    /// Choose how to generate the enums that no per-enum option applies to.
+    /// See `EnumVariation` for the available choices. By default, they're
+    /// Rust `enum`s.
+    ///
+    /// The per-enum options take precedence, in this order: the
+    /// `constify-module` annotation first, then the regular expressions given
+    /// to `rustified_enum`, `bitfield_enum`, `constified_enum` and
+    /// `constified_enum_module`. If the regular expressions of several of
+    /// those match the same enum, the one given last wins, with a warning.
+    pub fn default_enum_style(mut self, variation: EnumVariation) -> Builder {
+        self.options.default_enum_style = variation;
         self
     }
 
     /// Mark the given enum (or set of enums, if using a pattern) as being
     /// constant.
     ///
-    /// This makes bindgen generate constants instead of enums. Regular
-    /// expressions are supported.
+    /// This makes bindgen generate constants instead of enums, along with a
+    /// type alias of the enum's underlying type, like `pub type Color = u32;`,
+    /// that the constants, and the fields and arguments of the enum type, are
+    /// typed with. Unlike rustified enums, this allows any value, even those
+    /// without a variant. Regular expressions are supported.
     pub fn constified_enum<T: AsRef<str>>(mut self, arg: T) -> Builder {
         self.options.constified_enums.insert(arg);
+        self.options.enum_style_order.push(EnumVariation::Consts);
+        self
+    }
+
+    /// Mark the given enum (or set of enums, if using a pattern) as being
+    /// constant, with its constants grouped in a module named after the enum.
+    ///
+    /// The module also contains a `Type` alias for the enum's integer type,
+    /// which is what the rest of the bindings use to refer to the enum.
+    /// Regular expressions are supported.
+    pub fn constified_enum_module<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.constified_enum_modules.insert(arg);
+        self.options.enum_style_order.push(EnumVariation::ModuleConsts);
+        self
+    }
+
+    /// Refer to constified enums, with or without a module, by their
+    /// underlying integer type, like `u32`, in the fields and arguments of
+    /// the enum type, instead of by the type alias generated for the enum.
+    /// The enum's constants and alias are still generated. Rust and bitfield
+    /// enums are unaffected.
+    pub fn enums_as_underlying_type(mut self, doit: bool) -> Builder {
+        self.options.enums_as_underlying_type = doit;
+        self
+    }
+
+    /// Mark the given rustified enum (or set of enums, if using a pattern) as
+    /// non-exhaustive, so that code matching on it keeps building when the C
+    /// enum gains values. Regular expressions are supported.
+    ///
+    /// By default this adds a `#[doc(hidden)]` `__Nonexhaustive` variant,
+    /// with a value that none of the other variants have, see
+    /// `non_exhaustive_attribute` for the alternative.
+    pub fn rustified_non_exhaustive_enum<T: AsRef<str>>(mut self,
+                                                        arg: T)
+                                                        -> Builder {
+        self.options.rustified_non_exhaustive_enums.insert(arg);
+        self
+    }
+
+    /// Make the non-exhaustive enums so with the `#[non_exhaustive]`
+    /// attribute, instead of with a hidden variant. This requires the
+    /// `non_exhaustive` feature.
+    pub fn non_exhaustive_attribute(mut self, doit: bool) -> Builder {
+        self.options.non_exhaustive_attribute = doit;
         self
     }
 
@@ -296,12 +625,251 @@ impl Builder {
         self
     }
 
+    /// Wrap the code generated for the items matching the given regular
+    /// expression in a `#[cfg]` attribute with the given predicate, like
+    /// `feature = "gpu"`. Can be used multiple times.
+    ///
+    /// Types that are only needed by the matching items are wrapped too, but
+    /// types that other items need are always generated, even if they match.
+    pub fn wrap_matching_items_in_cfg<T, P>(mut self,
+                                            arg: T,
+                                            predicate: P)
+                                            -> Builder
+        where T: AsRef<str>,
+              P: Into<String>,
+    {
+        let predicate = predicate.into();
+        let position = self.options
+            .cfg_items
+            .iter()
+            .position(|&(ref p, _)| *p == predicate);
+        match position {
+            Some(i) => self.options.cfg_items[i].1.insert(arg),
+            None => {
+                let mut set = RegexSet::default();
+                set.insert(arg);
+                self.options.cfg_items.push((predicate, set));
+            }
+        }
+        self
+    }
+
+    /// Allow the given lint for the whole generated file, by adding it to a
+    /// `#![allow(...)]` attribute at the very top of the output, before any
+    /// raw line. No such attribute is emitted unless this is called, or
+    /// `allow_triggered_lints` is enabled.
+    ///
+    /// The lints given here override the ones `allow_triggered_lints` would
+    /// compute.
+    pub fn allow_attr<T: Into<String>>(mut self, lint: T) -> Builder {
+        self.options.allow_attrs.push(lint.into());
+        self
+    }
+
+    /// Allow, with a `#![allow(...)]` attribute at the top of the output, the
+    /// lints that the generated items actually trigger: `non_camel_case_types`
+    /// for types and enum variants, `non_snake_case` for functions and fields,
+    /// `non_upper_case_globals` for constants and statics, and `dead_code`
+    /// unless the items are `pub`.
+    ///
+    /// Note that inner attributes can't be used in files that are `include!`d.
+    pub fn allow_triggered_lints(mut self, doit: bool) -> Builder {
+        self.options.allow_triggered_lints = doit;
+        self
+    }
+
+    /// Use the precompiled header at `path` (passed to clang as `-include-pch`)
+    /// when parsing the input header.
+    ///
+    /// The precompiled header must have been generated with exactly the same
+    /// clang arguments (target, language standard, defines...) as the ones
+    /// used to generate these bindings, otherwise clang rejects it, and the
+    /// generation fails.
+    pub fn precompiled_header<T: Into<String>>(mut self, path: T) -> Builder {
+        self.options.precompiled_header = Some(path.into());
+        self
+    }
+
+    /// Save the parsed input header as a precompiled header at `path`, so
+    /// the next generations can use it via `precompiled_header`.
+    pub fn emit_precompiled_header<T: Into<String>>(mut self,
+                                                     path: T)
+                                                     -> Builder {
+        self.options.precompiled_header_output = Some(path.into());
+        self
+    }
+
+    /// Fail to generate the bindings if any of the whitelisted types couldn't
+    /// be fully understood, and would be approximated (usually by an opaque
+    /// blob) instead. Each of those types is reported to stderr, along with
+    /// the reason.
+    pub fn strict(mut self, doit: bool) -> Self {
+        self.options.strict = doit;
+        self
+    }
+
+    /// Whether to report the warnings and notes clang emits while parsing the
+    /// headers, see `Builder::diagnostic_callback`. Errors are always
+    /// reported. Defaults to true.
+    pub fn report_parse_warnings(mut self, doit: bool) -> Self {
+        self.options.report_parse_warnings = doit;
+        self
+    }
+
+    /// Whether to fail to generate the bindings if clang emits any error while
+    /// parsing the headers. Defaults to true.
+    ///
+    /// If set to false, the bindings are generated anyway, but they'll likely
+    /// be incomplete. The errors can be inspected with
+    /// `Bindings::diagnostics`.
+    pub fn abort_on_parse_errors(mut self, doit: bool) -> Self {
+        self.options.abort_on_parse_errors = doit;
+        self
+    }
+
+    /// Whether to treat the clang errors we can recover from, like the use of
+    /// an identifier poisoned with `#pragma GCC poison`, as any other error.
+    /// Defaults to false.
+    ///
+    /// Otherwise, the declarations they affect are skipped, with a warning,
+    /// and the rest of the bindings are generated as usual.
+    pub fn fail_on_clang_error(mut self, doit: bool) -> Self {
+        self.options.fail_on_clang_error = doit;
+        self
+    }
+
+    /// Whether to generate the constant variables with an initializer we can
+    /// evaluate, like `extern const int VERSION = 3;`, as Rust constants
+    /// instead of referring to their symbols. Defaults to true.
+    ///
+    /// The variables with internal linkage are always generated as constants,
+    /// since there's no symbol to refer to.
+    pub fn generate_inline_const_vars(mut self, doit: bool) -> Self {
+        self.options.generate_inline_const_vars = doit;
+        self
+    }
+
+    /// Whether to generate inline functions for the function-like macros
+    /// whose replacement is either a single call to a function, like
+    /// `#define mylib_init() mylib_init_ex(0)`, or an integer expression over
+    /// their parameters, like `#define FLAG(x) (1u << (x))`. Defaults to
+    /// false.
+    ///
+    /// The parameters of the former take the types of the arguments of the
+    /// function they're passed as. Everything is a `u32` in the latter, unless
+    /// `ParseCallbacks::int_macro_fn` says otherwise. The macros that can't be
+    /// translated are skipped with a warning.
+    pub fn emit_macro_fns(mut self, doit: bool) -> Self {
+        self.options.emit_macro_fns = doit;
+        self
+    }
+
+    /// Whether to generate a `constants` module re-exporting all the
+    /// constants, from macros, enums, and variables, wherever they were
+    /// generated. The constants with the same name get a numeric suffix, in
+    /// the order they are generated, unless they are for different targets.
+    /// It's an error for another top-level item to be named `constants`.
+    /// Defaults to false.
+    pub fn combined_constants_module(mut self, doit: bool) -> Self {
+        self.options.combined_constants_module = doit;
+        self
+    }
+
+    /// Whether to sort the generated items of each module by kind, and then
+    /// by name, instead of keeping them in the order of the input headers:
+    /// type aliases, enums, structs and unions, constants and statics, and
+    /// then the functions and variables, in a single `extern` block. The
+    /// impls and layout tests of a type follow it. Defaults to false.
+    pub fn sort_semantically(mut self, doit: bool) -> Self {
+        self.options.sort_semantically = doit;
+        self
+    }
+
+    /// Whether to generate the parameters declared as arrays with a length,
+    /// like `int arr[16]`, as pointers to the whole array, like
+    /// `*mut [c_int; 16]`, instead of pointers to their first element, which
+    /// is what they decay to in C. Defaults to false.
+    pub fn array_pointers_in_arguments(mut self, doit: bool) -> Self {
+        self.options.array_pointers_in_arguments = doit;
+        self
+    }
+
+    /// Whether to generate the struct fields of C++ reference type as
+    /// `ptr::NonNull<T>`, which has the same layout as a raw pointer, instead
+    /// of raw pointers. References are never null, so this is more precise,
+    /// but `NonNull` needs Rust 1.25 or later. Defaults to false.
+    pub fn references_are_nonnull(mut self, doit: bool) -> Self {
+        self.options.references_are_nonnull = doit;
+        self
+    }
+
+    /// Whether to mark the functions that never return, which are generated
+    /// returning `!`, with `#[cold]`. Defaults to false.
+    pub fn cold_noreturn_functions(mut self, doit: bool) -> Self {
+        self.options.cold_noreturn_functions = doit;
+        self
+    }
+
+    /// Whether to generate the functions and variables with internal linkage,
+    /// like `static` ones, that are skipped by default since there's nothing
+    /// to link against. Useful if the header's translation unit is compiled
+    /// into the crate.
+    ///
+    /// Inline functions, `static` or not, are skipped regardless, since we
+    /// don't generate any wrappers to call them through.
+    pub fn generate_static_fns_anyway(mut self, doit: bool) -> Self {
+        self.options.generate_static_fns_anyway = doit;
+        self
+    }
+
+    /// Generate the free C++ operator functions, which are skipped by default,
+    /// with readable names: `operator+` becomes `op_add`, `operator[]` becomes
+    /// `op_index`, and so on. Overloads get the usual numeric suffix.
+    ///
+    /// Conversion operators, and `new` and `delete`, are still skipped.
+    pub fn emit_operator_functions(mut self, doit: bool) -> Self {
+        self.options.emit_operator_functions = doit;
+        self
+    }
+
+    /// Generate the bindings for the given target triple.
+    ///
+    /// This can be called multiple times, in which case the bindings are
+    /// generated for each of the targets and merged together: the items that
+    /// differ between targets (for example, because they contain a `long` or a
+    /// `wchar_t`) are emitted once per target, gated behind the appropriate
+    /// `#[cfg(...)]` attribute.
+    pub fn target<T: Into<String>>(mut self, triple: T) -> Builder {
+        self.options.targets.push(triple.into());
+        self
+    }
+
     /// Add an argument to be passed straight through to clang.
     pub fn clang_arg<T: Into<String>>(mut self, arg: T) -> Builder {
         self.options.clang_args.push(arg.into());
         self
     }
 
+    /// Add several arguments to be passed straight through to clang.
+    pub fn clang_args<I>(mut self, args: I) -> Builder
+        where I: IntoIterator,
+              I::Item: AsRef<str>,
+    {
+        for arg in args {
+            self = self.clang_arg(arg.as_ref());
+        }
+        self
+    }
+
+    /// Whether to ask the `clang` binary for its default include paths, and
+    /// pass them to libclang, which doesn't always know about them. This is
+    /// on by default, and skipped anyway if a target or a sysroot is given
+    /// through the clang arguments.
+    pub fn detect_include_paths(mut self, doit: bool) -> Builder {
+        self.options.detect_include_paths = doit;
+        self
+    }
+
     /// Make the generated bindings link the given shared library.
     pub fn link<T: Into<String>>(mut self, library: T) -> Builder {
         self.options.links.push((library.into(), LinkType::Default));
@@ -333,6 +901,17 @@ impl Builder {
         self
     }
 
+    /// Avoid translating well-known integer typedefs like `uint32_t` or
+    /// `size_t` directly to Rust primitive types like `u32` or `usize`, and
+    /// generate them as regular type aliases instead.
+    ///
+    /// Note that the translation never happens when a header defines one of
+    /// these names to something of a different width or signedness anyway.
+    pub fn no_convert_stdint_types(mut self) -> Self {
+        self.options.convert_stdint_types = false;
+        self
+    }
+
     /// Set whether `Debug` should be derived by default.
     pub fn derive_debug(mut self, doit: bool) -> Self {
         self.options.derive_debug = doit;
@@ -345,12 +924,102 @@ impl Builder {
         self
     }
 
+    /// Along with `derive_default`, implement `Default` with `mem::zeroed()`
+    /// for the types that can't derive it, like the ones with pointer fields.
+    ///
+    /// This is opt-in because a zeroed value isn't necessarily a valid one:
+    /// pointers end up null, for example.
+    pub fn impl_default_via_zeroed(mut self, doit: bool) -> Self {
+        self.options.impl_default_via_zeroed = doit;
+        self
+    }
+
+    /// Along with `derive_debug`, implement `Debug` by hand for unions, which
+    /// can't derive it in general. The implementation only prints the name
+    /// and size of the union, since there's no way to know which of its
+    /// fields is the active one.
+    pub fn impl_debug_for_unions(mut self, doit: bool) -> Self {
+        self.options.impl_debug_for_unions = doit;
+        self
+    }
+
+    /// Along with `derive_debug`, implement `Debug` by hand for the structs
+    /// that can't derive it only because they contain arrays of more than 32
+    /// elements. Those arrays are printed as slices.
+    pub fn impl_debug_for_long_arrays(mut self, doit: bool) -> Self {
+        self.options.impl_debug_for_long_arrays = doit;
+        self
+    }
+
+    /// Generate the `static const` members of classes as associated constants
+    /// of the class, like `impl Foo { pub const LIMIT: c_int = 10; }`,
+    /// instead of free constants named like `Foo_LIMIT`. Associated constants
+    /// need Rust 1.20 or newer.
+    ///
+    /// Members whose value can't be evaluated are still generated as
+    /// `extern` statics.
+    pub fn associated_constants(mut self, doit: bool) -> Builder {
+        self.options.associated_constants = doit;
+        self
+    }
+
+    /// Allow the naming lints (`non_camel_case_types`, `non_snake_case` and
+    /// `non_upper_case_globals`) only on the generated items whose names
+    /// would trigger them, instead of on the whole root module when C++
+    /// namespaces are enabled, or not at all.
+    pub fn allow_lints_per_item(mut self, doit: bool) -> Builder {
+        self.options.allow_lints_per_item = doit;
+        self
+    }
+
+    /// Set the visibility of the generated items. See `Visibility` for the
+    /// available choices. By default, everything is `pub`.
+    pub fn default_visibility(mut self, visibility: Visibility) -> Builder {
+        self.options.default_visibility = visibility;
+        self
+    }
+
+    /// Choose the Rust type of C's `_Bool` and C++'s `bool`. See `CBoolType`
+    /// for the available choices. By default, they're translated to `bool`.
+    pub fn c_bool_type(mut self, ty: CBoolType) -> Builder {
+        self.options.c_bool_type = ty;
+        self
+    }
+
+    /// Translate the C integer types, like `long`, to the Rust primitive of
+    /// the width they have on the target, like `i32` or `i64`, instead of the
+    /// `c_long` and friends that change width with the platform.
+    ///
+    /// The bindings are then only right for the targets where the widths are
+    /// the same, so their layout tests are restricted to the architecture
+    /// they were generated for.
+    pub fn fixed_width_integers(mut self, doit: bool) -> Builder {
+        self.options.fixed_width_integers = doit;
+        self
+    }
+
     /// Emit Clang AST.
     pub fn emit_clang_ast(mut self) -> Builder {
         self.options.emit_ast = true;
         self
     }
 
+    /// Emit the Clang AST of every declaration whose name matches the given
+    /// regular expression, to stderr or to the file given to
+    /// `emit_ast_output`. This is way less noisy than `emit_clang_ast` when
+    /// debugging a single type.
+    pub fn emit_ast_for<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.emit_ast_for.insert(arg);
+        self
+    }
+
+    /// Write the Clang AST dumped because of `emit_ast_for` to the given file
+    /// instead of stderr.
+    pub fn emit_ast_output<T: Into<String>>(mut self, path: T) -> Builder {
+        self.options.emit_ast_output = Some(path.into());
+        self
+    }
+
     /// Emit IR.
     pub fn emit_ir(mut self) -> Builder {
         self.options.emit_ir = true;
@@ -363,6 +1032,37 @@ impl Builder {
         self
     }
 
+    /// With C++ namespaces enabled, name the module everything else lives in
+    /// `name` instead of `root`.
+    pub fn root_module_name<T: Into<String>>(mut self, name: T) -> Builder {
+        self.options.root_module_name = name.into();
+        self
+    }
+
+    /// With C++ namespaces enabled, emit the contents of the root module at
+    /// the top level if the headers don't declare any namespace, rather than
+    /// inside of a module of their own.
+    pub fn inline_root_module(mut self) -> Builder {
+        self.options.inline_root_module = true;
+        self
+    }
+
+    /// With C++ namespaces enabled, also emit a `pub use` re-export at the
+    /// top level for every type inside a namespace, with its namespaced path
+    /// flattened into a single name, like `pub use root::foo::Bar as
+    /// foo_Bar;`.
+    pub fn emit_flat_aliases(mut self) -> Builder {
+        self.options.emit_flat_aliases = true;
+        self
+    }
+
+    /// Emit a `/// <file>:<line>` doc line on every generated type and
+    /// function, pointing at where it was declared in the headers.
+    pub fn emit_source_annotations(mut self, doit: bool) -> Builder {
+        self.options.emit_source_annotations = doit;
+        self
+    }
+
     /// Disable auto-namespacing of names if namespaces are disabled.
     ///
     /// By default, if namespaces are disabled, bindgen tries to mangle the
@@ -415,6 +1115,17 @@ impl Builder {
         self
     }
 
+    /// Keep generating the inline namespaces matching the given regular
+    /// expression as their own modules, like `conservative_inline_namespaces`
+    /// does for all of them, while still inlining the rest.
+    ///
+    /// The regular expression is matched against the namespace's path, like
+    /// `std::__cxx11`.
+    pub fn keep_inline_namespace<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.keep_inline_namespaces.insert(arg);
+        self
+    }
+
     /// Ignore functions.
     pub fn ignore_functions(mut self) -> Builder {
         self.options.codegen_config.functions = false;
@@ -433,57 +1144,244 @@ impl Builder {
         self
     }
 
-    /// Use core instead of libstd in the generated bindings.
-    pub fn use_core(mut self) -> Builder {
-        self.options.use_core = true;
+    /// Generate the thread-local variables, like `__thread int foo;`, as
+    /// `#[thread_local]` statics. This requires the unstable `thread_local`
+    /// feature, so they're skipped by default.
+    pub fn thread_local_statics(mut self, doit: bool) -> Builder {
+        self.options.thread_local_statics = doit;
         self
     }
 
-    /// Use the given prefix for the raw types instead of `::std::os::raw`.
-    pub fn ctypes_prefix<T: Into<String>>(mut self, prefix: T) -> Builder {
-        self.options.ctypes_prefix = Some(prefix.into());
+    /// Along with the constant `extern` statics holding C strings, generate
+    /// safe accessors for them, assuming the strings are never modified:
+    ///
+    /// * `extern const char version[];` gets
+    ///   `pub fn version_cstr() -> &'static CStr`.
+    /// * `extern const char* const name;` gets
+    ///   `pub fn name_cstr() -> Option<&'static CStr>`, which is `None` when
+    ///   the pointer is null.
+    /// * `extern const char* const names[3];` gets
+    ///   `pub fn names_array() -> &'static [*const c_char; 3]`.
+    ///
+    /// Statics that aren't constant, like `extern char buf[16];` or
+    /// `extern const char* name;`, don't get one, since they could change
+    /// while the accessor's reference is alive.
+    ///
+    /// The accessors need `std`, so they aren't generated along with
+    /// `use_core`.
+    pub fn c_string_constants(mut self, doit: bool) -> Builder {
+        self.options.c_string_constants = doit;
         self
     }
 
-    /// Allows configuring types in different situations, see the `ParseCallbacks`
-    /// documentation.
-    pub fn parse_callbacks(mut self, cb: Box<callbacks::ParseCallbacks>) -> Self {
-        self.options.parse_callbacks = Some(cb);
+    /// Link the functions and variables declared with
+    /// `__declspec(dllimport)` against the given DLL, by putting them in
+    /// `extern` blocks with `#[link(name = "<lib>", kind = "dylib")]`.
+    ///
+    /// This only has an effect when targeting Windows, since nowhere else are
+    /// the declarations marked like that.
+    pub fn dynamic_link_name<T: Into<String>>(mut self, lib: T) -> Builder {
+        self.options.dynamic_link_name = Some(lib.into());
         self
     }
 
-    /// Choose what to generate using a CodegenConfig.
-    pub fn with_codegen_config(mut self, config: CodegenConfig) -> Self {
-        self.options.codegen_config = config;
+    /// Use core instead of libstd in the generated bindings.
+    pub fn use_core(mut self) -> Builder {
+        self.options.use_core = true;
         self
     }
 
-    /// Prepend the enum name to constant or bitfield variants.
-    pub fn prepend_enum_name(mut self, doit: bool) -> Self {
-        self.options.prepend_enum_name = doit;
+    /// A preset for `#![no_std]` crates: along with `use_core`, this avoids
+    /// everything else that needs libstd or a test harness. It sets the ctypes
+    /// prefix to `::core::ffi` unless one was given already, takes `c_void`
+    /// from `core::ffi` (see `core_ffi_c_void`), refers to `Clone` by its full
+    /// path, stops deriving `Debug` and stops generating layout tests. Each of
+    /// these but the `c_void` and `Clone` paths can be overridden again after
+    /// calling this.
+    pub fn no_std(mut self) -> Builder {
+        self.options.no_std = true;
+        self.options.use_core = true;
+        self.options.derive_debug = false;
+        self.options.layout_tests = false;
+        if self.options.ctypes_prefix.is_none() {
+            self.options.ctypes_prefix = Some("::core::ffi".into());
+        }
         self
     }
 
-    /// Generate the Rust bindings using the options built up thus far.
-    pub fn generate<'ctx>(self) -> Result<Bindings<'ctx>, ()> {
-        Bindings::generate(self.options, None)
+    /// Set whether `core::ffi::c_void` is available to the bindings, which is
+    /// where `c_void` comes from along with `no_std`. If it isn't, an
+    /// opaque `c_void` enum is emitted in the bindings instead. This is
+    /// true by default.
+    pub fn core_ffi_c_void(mut self, doit: bool) -> Builder {
+        self.options.core_ffi_c_void = doit;
+        self
+    }
+
+    /// Set whether to generate the `#[test]`s that check the layout of the
+    /// generated types. This is true by default.
+    pub fn layout_tests(mut self, doit: bool) -> Builder {
+        self.options.layout_tests = doit;
+        self
+    }
+
+    /// Use the given prefix for the raw types instead of `::std::os::raw`.
+    pub fn ctypes_prefix<T: Into<String>>(mut self, prefix: T) -> Builder {
+        self.options.ctypes_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Use the `libc` crate's definitions for the C primitive types, like
+    /// `::libc::c_int` or `::libc::c_void`, and for the platform-width
+    /// typedefs `size_t`, `ssize_t`, `ptrdiff_t`, `intptr_t`, `uintptr_t` and
+    /// `wchar_t`. This takes precedence over `ctypes_prefix`.
+    ///
+    /// The crate using the bindings needs an `extern crate libc;` at its root.
+    pub fn use_libc(mut self, doit: bool) -> Builder {
+        self.options.use_libc = doit;
+        self
+    }
+
+    /// Allows configuring types in different situations, see the `ParseCallbacks`
+    /// documentation.
+    pub fn parse_callbacks(mut self, cb: Box<callbacks::ParseCallbacks>) -> Self {
+        self.callbacks.parse_callbacks = Some(cb);
+        self
+    }
+
+    /// Call `cb` with every diagnostic emitted while generating the bindings,
+    /// both the ones from clang and bindgen's own warnings about the things
+    /// it doesn't understand. They're also available afterwards from
+    /// `Bindings::diagnostics`.
+    pub fn diagnostic_callback(mut self,
+                               cb: Box<Fn(DiagnosticSeverity, &str)>)
+                               -> Self {
+        self.callbacks.diagnostic_callback = Some(DiagnosticCallback(cb));
+        self
+    }
+
+    /// Choose what to generate using a CodegenConfig.
+    pub fn with_codegen_config(mut self, config: CodegenConfig) -> Self {
+        self.options.codegen_config = config;
+        self
+    }
+
+    /// Prepend the enum name to constant or bitfield variants.
+    pub fn prepend_enum_name(mut self, doit: bool) -> Self {
+        self.options.prepend_enum_name = doit;
+        self
+    }
+
+    /// Mark the Rust functions forwarding to a native one, like the methods
+    /// wrapping C++ member functions, as `#[inline]`, so that calling them
+    /// costs the same as calling the native function directly. This is the
+    /// default.
+    ///
+    /// Disable it to get an actual symbol for each of them, to link to
+    /// dynamically for example.
+    pub fn inline_wrappers(mut self, doit: bool) -> Self {
+        self.options.inline_wrappers = doit;
+        self
+    }
+
+    /// Group the top-level items in one module per header, named after the
+    /// header that declared them, and re-export them all from the top level so
+    /// the paths of the items don't change. Items in C++ namespaces stay in
+    /// their namespace module.
+    ///
+    /// Combined with `split_output`, each of those modules gets its own file.
+    pub fn module_per_header(mut self, doit: bool) -> Builder {
+        self.options.module_per_header = doit;
+        self
+    }
+
+    /// Write the bindings to one file per top-level module in the given
+    /// directory, instead of to a single file.
+    ///
+    /// The directory gets a `mod.rs` with the items of the root module, which
+    /// declares the rest of modules. See `Bindings::write_split`.
+    pub fn split_output<T: Into<String>>(mut self, dir: T) -> Builder {
+        self.options.split_output = Some(dir.into());
+        self
+    }
+
+    /// Skip regenerating the bindings if nothing changed since they were last
+    /// written to `output`.
+    ///
+    /// The options, and the files clang read to parse the input headers,
+    /// except the system headers, are hashed when the bindings are written to
+    /// `output` with `write_to_file`. The hash is stored in `<output>.hash`,
+    /// along with the list of those files. Before parsing, the hash of the
+    /// options and of the listed files is computed again, and if it matches
+    /// the stored one and `output` exists, its contents are returned as the
+    /// bindings without invoking clang at all.
+    pub fn hash_inputs_to<T: Into<String>>(mut self, output: T) -> Builder {
+        self.options.input_hash_output = Some(output.into());
+        self
+    }
+
+    /// Regenerate the bindings even if the input hash configured via
+    /// `hash_inputs_to` says nothing changed.
+    pub fn force_regeneration(mut self, doit: bool) -> Self {
+        self.options.force_regeneration = doit;
+        self
+    }
+
+    /// Check the options given so far, like the regexes given to `hide_type`
+    /// and friends, without generating anything, and get a message saying
+    /// what's wrong with them, if anything.
+    ///
+    /// `generate` fails on invalid options too, but this allows rejecting them
+    /// right when setting up the builder.
+    pub fn validate(&self) -> Result<(), String> {
+        self.options.clone().build()
+    }
+
+    /// Generate the Rust bindings using the options built up thus far.
+    pub fn generate<'ctx>(self) -> Result<Bindings<'ctx>, ()> {
+        Bindings::generate_with_callbacks(self.options, self.callbacks, None)
     }
 }
 
 /// Configuration options for generated bindings.
 ///
 /// Deprecated: use a `Builder` instead.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deprecated]
 pub struct BindgenOptions {
     /// The set of types that have been blacklisted and should not appear
     /// anywhere in the generated code.
     pub hidden_types: RegexSet,
 
+    /// True if we should generate the hidden items as `#[doc(hidden)]` instead
+    /// of leaving them out.
+    pub emit_hidden_items: bool,
+
     /// The set of types that should be treated as opaque structures in the
     /// generated code.
     pub opaque_types: RegexSet,
 
+    /// True if we should generate sized blobs for the instantiations of the
+    /// well-known standard library templates.
+    pub stl_blobs: bool,
+
+    /// True if we should generate `const` generic parameters for the integer
+    /// template parameters used as array lengths.
+    pub const_generics: bool,
+
+    /// What to do with the type aliases of hidden types.
+    pub hidden_alias_variation: HiddenAliasVariation,
+
+    /// The set of types we should implement `Send` for.
+    pub send_types: RegexSet,
+
+    /// The set of types we should implement `Sync` for.
+    pub sync_types: RegexSet,
+
+    /// The traits to implement for the types matching each set of regular
+    /// expressions. See `Builder::add_impl_for`.
+    pub trait_impls: Vec<(String, RegexSet)>,
+
     /// The set of types that we should have bindings for in the generated
     /// code.
     ///
@@ -492,27 +1390,108 @@ pub struct BindgenOptions {
     /// and the generated Rust code as including everything that gets marked.
     pub whitelisted_types: RegexSet,
 
+    /// The set of typedefs that should be generated as type aliases, whatever
+    /// the default typedef style is.
+    pub type_aliases: RegexSet,
+
+    /// The set of typedefs that should be generated as new types instead of
+    /// type aliases.
+    pub new_type_aliases: RegexSet,
+
+    /// The set of typedefs that should be generated as new types
+    /// implementing `Deref` and `DerefMut` to the aliased type.
+    pub new_type_alias_deref: RegexSet,
+
+    /// The style of each of the typedef patterns above, in the order they were
+    /// given, to settle which one wins when patterns of several styles match.
+    pub alias_style_order: Vec<AliasVariation>,
+
+    /// How to generate the typedefs no pattern matches.
+    pub default_alias_style: AliasVariation,
+
+    /// Whether to implement `From` both ways between the new types and the
+    /// types they wrap.
+    pub newtype_conversions: bool,
+
     /// Whitelisted functions. See docs for `whitelisted_types` for more.
     pub whitelisted_functions: RegexSet,
 
     /// Whitelisted variables. See docs for `whitelisted_types` for more.
     pub whitelisted_vars: RegexSet,
 
+    /// The headers whose declarations are all whitelisted, matched against
+    /// their path.
+    pub whitelisted_files: RegexSet,
+
+    /// Whether to only whitelist the items declared in the input headers.
+    /// See `Builder::only_primary_header`.
+    pub only_primary_header: bool,
+
+    /// The enum patterns to mark an enum as a Rust enum.
+    pub rustified_enums: RegexSet,
+
     /// The enum patterns to mark an enum as bitfield.
     pub bitfield_enums: RegexSet,
 
     /// The enum patterns to mark an enum as constant.
     pub constified_enums: RegexSet,
 
+    /// The enum patterns to mark an enum as a module of constants.
+    pub constified_enum_modules: RegexSet,
+
+    /// The style of each of the enum patterns above, in the order they were
+    /// given, to settle which one wins when patterns of several styles match.
+    pub enum_style_order: Vec<EnumVariation>,
+
+    /// How to generate the enums no pattern or annotation applies to.
+    pub default_enum_style: EnumVariation,
+
+    /// Whether to refer to constified enums by their underlying integer type.
+    /// See `Builder::enums_as_underlying_type`.
+    pub enums_as_underlying_type: bool,
+
+    /// The enum patterns to mark a rustified enum as non-exhaustive.
+    pub rustified_non_exhaustive_enums: RegexSet,
+
+    /// True if we should use `#[non_exhaustive]` for non-exhaustive enums
+    /// instead of a hidden variant.
+    pub non_exhaustive_attribute: bool,
+
     /// Whether we should generate builtins or not.
     pub builtins: bool,
 
     /// The set of libraries we should link in the generated Rust code.
     pub links: Vec<(String, LinkType)>,
 
+    /// The visibility of the generated items.
+    pub default_visibility: Visibility,
+
+    /// The Rust type of C and C++ booleans.
+    pub c_bool_type: CBoolType,
+
+    /// True if we should translate the C integer types to the Rust primitives
+    /// of the same width on the target.
+    pub fixed_width_integers: bool,
+
+    /// True if we should allow the naming lints on the generated items that
+    /// need it.
+    pub allow_lints_per_item: bool,
+
+    /// True if we should generate the `static const` members of classes as
+    /// associated constants.
+    pub associated_constants: bool,
+
     /// True if we should dump the Clang AST for debugging purposes.
     pub emit_ast: bool,
 
+    /// The declarations whose Clang AST we should dump for debugging
+    /// purposes.
+    pub emit_ast_for: RegexSet,
+
+    /// The file to dump the Clang AST of `emit_ast_for` to, instead of
+    /// stderr.
+    pub emit_ast_output: Option<String>,
+
     /// True if we should dump our internal IR for debugging purposes.
     pub emit_ir: bool,
 
@@ -523,6 +1502,22 @@ pub struct BindgenOptions {
     /// generated bindings.
     pub enable_cxx_namespaces: bool,
 
+    /// The name of the module holding everything else when emulating C++
+    /// namespaces.
+    pub root_module_name: String,
+
+    /// True if we should emit the contents of the root module at the top level
+    /// when there are no namespaces to emulate.
+    pub inline_root_module: bool,
+
+    /// True if we should re-export every namespaced type under a flat name at
+    /// the top level of the generated bindings.
+    pub emit_flat_aliases: bool,
+
+    /// True if we should annotate the generated items with the location they
+    /// were declared at.
+    pub emit_source_annotations: bool,
+
     /// True if we should avoid mangling names with namespaces.
     pub disable_name_namespacing: bool,
 
@@ -534,16 +1529,53 @@ pub struct BindgenOptions {
     /// and types.
     pub derive_default: bool,
 
+    /// True if we should implement `Default` with `mem::zeroed()` for the
+    /// types we can't derive it for.
+    pub impl_default_via_zeroed: bool,
+
+    /// True if we should implement `Debug` by hand for unions.
+    pub impl_debug_for_unions: bool,
+
+    /// True if we should implement `Debug` by hand for structs with arrays
+    /// too long to derive it.
+    pub impl_debug_for_long_arrays: bool,
+
     /// True if we can use unstable Rust code in the bindings, false if we
     /// cannot.
     pub unstable_rust: bool,
 
+    /// True if we should generate thread-local variables as `#[thread_local]`
+    /// statics.
+    pub thread_local_statics: bool,
+
+    /// True if we should generate safe accessors for the statics holding C
+    /// strings.
+    pub c_string_constants: bool,
+
+    /// The DLL to link the `__declspec(dllimport)` functions and variables
+    /// against, if any.
+    pub dynamic_link_name: Option<String>,
+
     /// True if we should avoid using libstd to use libcore instead.
     pub use_core: bool,
 
+    /// True if the bindings are for a `#![no_std]` crate, see
+    /// `Builder::no_std`.
+    pub no_std: bool,
+
+    /// True if `core::ffi::c_void` can be used along with `no_std`.
+    pub core_ffi_c_void: bool,
+
+    /// True if we should generate layout tests for the generated types.
+    pub layout_tests: bool,
+
     /// An optional prefix for the "raw" types, like `c_int`, `c_void`...
     pub ctypes_prefix: Option<String>,
 
+    /// True if we should use the `libc` crate's definitions for the "raw"
+    /// types and the platform-width typedefs, like `size_t`.
+    pub use_libc: bool,
+
     /// True if we should generate constant names that are **directly** under
     /// namespaces.
     pub namespaced_constants: bool,
@@ -554,23 +1586,45 @@ pub struct BindgenOptions {
     /// Whether we should convert float types to f32/f64 types.
     pub convert_floats: bool,
 
+    /// Whether we should translate well-known integer typedefs like
+    /// `uint32_t` to Rust primitive types.
+    pub convert_stdint_types: bool,
+
     /// The set of raw lines to prepend to the generated Rust code.
     pub raw_lines: Vec<String>,
 
+    /// The `#[cfg]` predicates to wrap the items matching each set of regular
+    /// expressions in. See `Builder::wrap_matching_items_in_cfg`.
+    pub cfg_items: Vec<(String, RegexSet)>,
+
+    /// The lints to allow with a `#![allow(...)]` attribute at the top of the
+    /// generated Rust code.
+    pub allow_attrs: Vec<String>,
+
+    /// True if we should allow the lints triggered by the generated items at
+    /// the top of the generated Rust code, unless `allow_attrs` is given.
+    pub allow_triggered_lints: bool,
+
     /// The set of arguments to pass straight through to Clang.
     pub clang_args: Vec<String>,
 
+    /// True if we should pass the default include paths of the `clang` binary
+    /// to libclang.
+    pub detect_include_paths: bool,
+
     /// The input header file.
     pub input_header: Option<String>,
 
+    /// The rest of the input header files, if any.
+    pub extra_input_headers: Vec<String>,
+
+    /// Whether to parse each input header on its own thread.
+    pub parallel_parsing: bool,
+
     /// Generate a dummy C/C++ file that includes the header and has dummy uses
     /// of all types defined therein. See the `uses` module for more.
     pub dummy_uses: Option<String>,
 
-    /// A user-provided visitor to allow customizing different kinds of
-    /// situations.
-    pub parse_callbacks: Option<Box<callbacks::ParseCallbacks>>,
-
     /// Which kind of items should we generate? By default, we'll generate all
     /// of them.
     pub codegen_config: CodegenConfig,
@@ -580,6 +1634,10 @@ pub struct BindgenOptions {
     /// See the builder method description for more details.
     pub conservative_inline_namespaces: bool,
 
+    /// The inline namespaces we should keep as their own modules even if we're
+    /// not being conservative about them.
+    pub keep_inline_namespaces: RegexSet,
+
     /// Wether to keep documentation comments in the generated output. See the
     /// documentation for more details.
     pub generate_comments: bool,
@@ -591,6 +1649,10 @@ pub struct BindgenOptions {
     /// generate '#[macro_use] extern crate objc;'
     pub objc_extern_crate: bool,
 
+    /// Whether to generate bindings for Objective-C, instead of opaque
+    /// handles.
+    pub enable_objc: bool,
+
     /// Whether to use the clang-provided name mangling. This is true and
     /// probably needed for C++ features.
     ///
@@ -602,6 +1664,78 @@ pub struct BindgenOptions {
 
     /// Whether to prepend the enum name to bitfield or constant variants.
     pub prepend_enum_name: bool,
+
+    /// Whether to mark the functions forwarding to native ones as `#[inline]`.
+    pub inline_wrappers: bool,
+
+    /// The output file whose inputs we should hash to avoid regenerating it
+    /// if nothing changed. See the builder method description for more
+    /// details.
+    pub input_hash_output: Option<String>,
+
+    /// Whether to regenerate the bindings even if the input hash matches.
+    pub force_regeneration: bool,
+
+    /// Whether to group the top-level items in one module per header.
+    pub module_per_header: bool,
+
+    /// The directory to write the bindings to, one file per top-level module,
+    /// if any.
+    pub split_output: Option<String>,
+
+    /// The target triples to generate the bindings for. If there's more than
+    /// one, the bindings of each target are merged with `#[cfg]` attributes.
+    pub targets: Vec<String>,
+
+    /// Whether to report the clang warnings and notes.
+    pub report_parse_warnings: bool,
+
+    /// Whether clang errors make the generation fail.
+    pub abort_on_parse_errors: bool,
+
+    /// Whether the clang errors we could recover from by skipping the
+    /// declarations they affect are treated as any other error.
+    pub fail_on_clang_error: bool,
+
+    /// Whether to fail if any whitelisted type needs to be approximated.
+    pub strict: bool,
+
+    /// Whether to generate the functions and variables with internal linkage.
+    pub generate_static_fns_anyway: bool,
+
+    /// Whether to generate the free C++ operator functions.
+    pub emit_operator_functions: bool,
+
+    /// Whether to generate the constant variables we can evaluate as Rust
+    /// constants.
+    pub generate_inline_const_vars: bool,
+
+    /// Whether to generate functions for the function-like macros we can
+    /// translate.
+    pub emit_macro_fns: bool,
+
+    /// Whether to mark the functions that never return with `#[cold]`.
+    pub cold_noreturn_functions: bool,
+
+    /// Whether to generate the array parameters with a length as pointers to
+    /// the whole array.
+    pub array_pointers_in_arguments: bool,
+
+    /// Whether to generate the reference fields as `ptr::NonNull<T>`.
+    pub references_are_nonnull: bool,
+
+    /// Whether to generate a module re-exporting all the constants.
+    pub combined_constants_module: bool,
+
+    /// Whether to sort the generated items by kind and name.
+    pub sort_semantically: bool,
+
+    /// The precompiled header to use when parsing, if any.
+    pub precompiled_header: Option<String>,
+
+    /// Where to save the parsed input header as a precompiled header, if
+    /// anywhere.
+    pub precompiled_header_output: Option<String>,
 }
 
 /// TODO(emilio): This is sort of a lie (see the error message that results from
@@ -610,14 +1744,86 @@ pub struct BindgenOptions {
 impl ::std::panic::UnwindSafe for BindgenOptions {}
 
 impl BindgenOptions {
-    fn build(&mut self) {
-        self.whitelisted_vars.build();
-        self.whitelisted_types.build();
-        self.whitelisted_functions.build();
-        self.hidden_types.build();
-        self.opaque_types.build();
-        self.bitfield_enums.build();
-        self.constified_enums.build();
+    /// Build the regex sets of these options, failing with a message naming
+    /// the first invalid regex the user gave us, if any.
+    fn build(&mut self) -> Result<(), String> {
+        let sets = vec![("whitelisted variable", &mut self.whitelisted_vars),
+                        ("whitelisted type", &mut self.whitelisted_types),
+                        ("whitelisted function",
+                         &mut self.whitelisted_functions),
+                        ("whitelisted file", &mut self.whitelisted_files),
+                        ("hidden type", &mut self.hidden_types),
+                        ("opaque type", &mut self.opaque_types),
+                        ("kept inline namespace",
+                         &mut self.keep_inline_namespaces),
+                        ("send type", &mut self.send_types),
+                        ("sync type", &mut self.sync_types),
+                        ("rustified enum", &mut self.rustified_enums),
+                        ("bitfield enum", &mut self.bitfield_enums),
+                        ("constified enum", &mut self.constified_enums),
+                        ("constified enum module",
+                         &mut self.constified_enum_modules),
+                        ("rustified non-exhaustive enum",
+                         &mut self.rustified_non_exhaustive_enums),
+                        ("type alias", &mut self.type_aliases),
+                        ("new type alias", &mut self.new_type_aliases),
+                        ("new type alias with deref",
+                         &mut self.new_type_alias_deref),
+                        ("emit AST", &mut self.emit_ast_for)];
+
+        for (what, set) in sets {
+            if let Err(e) = set.build() {
+                return Err(format!("Invalid {} regex: {}", what, e));
+            }
+        }
+
+        for &mut (_, ref mut set) in &mut self.cfg_items {
+            if let Err(e) = set.build() {
+                return Err(format!("Invalid cfg'd item regex: {}", e));
+            }
+        }
+
+        for &mut (_, ref mut set) in &mut self.trait_impls {
+            if let Err(e) = set.build() {
+                return Err(format!("Invalid trait impl regex: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The target triple passed to clang, if any, either as
+    /// `--target=<triple>`, `--target <triple>` or `-target <triple>`.
+    pub fn target_triple(&self) -> Option<&str> {
+        let mut triple = None;
+        let mut args = self.clang_args.iter();
+        while let Some(arg) = args.next() {
+            if arg.starts_with("--target=") {
+                triple = Some(&arg["--target=".len()..]);
+            } else if arg == "--target" || arg == "-target" {
+                triple = args.next().map(|triple| &triple[..]);
+            }
+        }
+        triple
+    }
+
+    /// The size of a pointer in bytes on the target we're generating bindings
+    /// for, either the one we were given with `--target`, or the one we run
+    /// on.
+    pub fn target_pointer_size(&self) -> usize {
+        match self.target_triple() {
+            Some(triple) => targets::pointer_size(triple),
+            None => mem::size_of::<*mut ()>(),
+        }
+    }
+
+    /// Whether we're generating bindings for an MSVC target, either because
+    /// we were told so with `--target`, or because it's what we run on.
+    pub fn is_msvc_target(&self) -> bool {
+        match self.target_triple() {
+            Some(triple) => targets::is_msvc(triple),
+            None => cfg!(target_env = "msvc"),
+        }
     }
 }
 
@@ -625,39 +1831,248 @@ impl Default for BindgenOptions {
     fn default() -> BindgenOptions {
         BindgenOptions {
             hidden_types: Default::default(),
+            emit_hidden_items: false,
             opaque_types: Default::default(),
+            stl_blobs: false,
+            const_generics: false,
+            send_types: Default::default(),
+            sync_types: Default::default(),
+            hidden_alias_variation: HiddenAliasVariation::Keep,
+            type_aliases: Default::default(),
+            new_type_aliases: Default::default(),
+            new_type_alias_deref: Default::default(),
+            alias_style_order: vec![],
+            default_alias_style: AliasVariation::TypeAlias,
+            newtype_conversions: false,
             whitelisted_types: Default::default(),
             whitelisted_functions: Default::default(),
             whitelisted_vars: Default::default(),
+            whitelisted_files: Default::default(),
+            only_primary_header: false,
+            rustified_enums: Default::default(),
             bitfield_enums: Default::default(),
             constified_enums: Default::default(),
+            constified_enum_modules: Default::default(),
+            enum_style_order: vec![],
+            default_enum_style: EnumVariation::Rust,
+            enums_as_underlying_type: false,
+            rustified_non_exhaustive_enums: Default::default(),
+            non_exhaustive_attribute: false,
             builtins: false,
             links: vec![],
+            default_visibility: Visibility::Public,
+            c_bool_type: CBoolType::Bool,
+            fixed_width_integers: false,
+            allow_lints_per_item: false,
+            associated_constants: false,
             emit_ast: false,
+            emit_ast_for: Default::default(),
+            emit_ast_output: None,
             emit_ir: false,
             emit_ir_graphviz: None,
             derive_debug: true,
             derive_default: false,
+            impl_default_via_zeroed: false,
+            impl_debug_for_unions: false,
+            impl_debug_for_long_arrays: false,
             enable_cxx_namespaces: false,
+            root_module_name: "root".into(),
+            inline_root_module: false,
+            emit_flat_aliases: false,
+            emit_source_annotations: false,
             disable_name_namespacing: false,
             unstable_rust: true,
+            thread_local_statics: false,
+            c_string_constants: false,
+            dynamic_link_name: None,
             use_core: false,
+            no_std: false,
+            core_ffi_c_void: true,
+            layout_tests: true,
             ctypes_prefix: None,
+            use_libc: false,
             namespaced_constants: true,
             msvc_mangling: false,
             convert_floats: true,
+            convert_stdint_types: true,
             raw_lines: vec![],
+            cfg_items: vec![],
+            trait_impls: vec![],
+            allow_attrs: vec![],
+            allow_triggered_lints: false,
             clang_args: vec![],
+            detect_include_paths: true,
             input_header: None,
+            extra_input_headers: vec![],
+            parallel_parsing: false,
             dummy_uses: None,
-            parse_callbacks: None,
             codegen_config: CodegenConfig::all(),
             conservative_inline_namespaces: false,
+            keep_inline_namespaces: Default::default(),
             generate_comments: true,
             whitelist_recursively: true,
             objc_extern_crate: false,
+            enable_objc: true,
             enable_mangling: true,
             prepend_enum_name: true,
+            inline_wrappers: true,
+            input_hash_output: None,
+            force_regeneration: false,
+            module_per_header: false,
+            split_output: None,
+            targets: vec![],
+            report_parse_warnings: true,
+            abort_on_parse_errors: true,
+            fail_on_clang_error: false,
+            strict: false,
+            generate_static_fns_anyway: false,
+            emit_operator_functions: false,
+            generate_inline_const_vars: true,
+            emit_macro_fns: false,
+            cold_noreturn_functions: false,
+            array_pointers_in_arguments: false,
+            references_are_nonnull: false,
+            combined_constants_module: false,
+            sort_semantically: false,
+            precompiled_header: None,
+            precompiled_header_output: None,
+        }
+    }
+}
+
+/// What to do with a type alias whose aliased type is hidden, like
+/// `FooAlias` in:
+///
+/// ```c++
+/// struct Foo { int a; };   // hidden
+/// typedef Foo FooAlias;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HiddenAliasVariation {
+    /// Keep the alias as is, assuming the hidden type is provided somewhere
+    /// else, like the hidden type itself.
+    Keep,
+    /// Make the alias an opaque blob with the layout of the hidden type.
+    Opaque,
+    /// Hide the alias too.
+    Skip,
+}
+
+impl str::FromStr for HiddenAliasVariation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => Ok(HiddenAliasVariation::Keep),
+            "opaque" => Ok(HiddenAliasVariation::Opaque),
+            "skip" => Ok(HiddenAliasVariation::Skip),
+            _ => Err(format!("Invalid hidden alias variation: {}", s)),
+        }
+    }
+}
+
+/// How to generate a C enum, see `Builder::default_enum_style`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EnumVariation {
+    /// A Rust `enum`. Only sound if the C code never stores a value without a
+    /// variant in it.
+    Rust,
+    /// A newtype struct with bitwise operators, see `Builder::bitfield_enum`.
+    Bitfield,
+    /// A constant per variant, typed with an alias of the enum's integer
+    /// type, see `Builder::constified_enum`.
+    Consts,
+    /// The same, with the constants in a module named after the enum, see
+    /// `Builder::constified_enum_module`.
+    ModuleConsts,
+}
+
+impl str::FromStr for EnumVariation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(EnumVariation::Rust),
+            "bitfield" => Ok(EnumVariation::Bitfield),
+            "consts" => Ok(EnumVariation::Consts),
+            "moduleconsts" => Ok(EnumVariation::ModuleConsts),
+            _ => Err(format!("Invalid enum variation: {}", s)),
+        }
+    }
+}
+
+/// How to generate a C typedef, see `Builder::default_alias_style`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AliasVariation {
+    /// A type alias, like `pub type handle_t = u32;`.
+    TypeAlias,
+    /// A new type wrapping the aliased type, see `Builder::new_type_alias`.
+    NewType,
+    /// The same, also implementing `Deref` and `DerefMut` to the aliased
+    /// type, see `Builder::new_type_alias_deref`.
+    NewTypeDeref,
+}
+
+impl str::FromStr for AliasVariation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "type_alias" => Ok(AliasVariation::TypeAlias),
+            "new_type" => Ok(AliasVariation::NewType),
+            "new_type_deref" => Ok(AliasVariation::NewTypeDeref),
+            _ => Err(format!("Invalid alias variation: {}", s)),
+        }
+    }
+}
+
+/// The visibility of the generated items.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    /// Everything is `pub`.
+    Public,
+    /// Everything is `pub(crate)`, for bindings that are only used internally
+    /// and shouldn't be re-exported.
+    Crate,
+    /// The top-level items and their fields and methods are private, so the
+    /// bindings are only usable from the module they're included into.
+    Private,
+}
+
+impl str::FromStr for Visibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pub" => Ok(Visibility::Public),
+            "pub(crate)" => Ok(Visibility::Crate),
+            "private" => Ok(Visibility::Private),
+            _ => Err(format!("Invalid visibility: {}", s)),
+        }
+    }
+}
+
+/// The Rust type to translate C and C++ booleans to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CBoolType {
+    /// Rust's `bool`, which is the most convenient to use. However, any value
+    /// other than 0 and 1 in a `bool` is undefined behavior in Rust, while C
+    /// code may store anything in its booleans, through a cast or a `memcpy`
+    /// for example.
+    Bool,
+    /// A `u8`, which has the same size and alignment, and accepts any value.
+    /// Zero is false, and anything else is true.
+    U8,
+}
+
+impl str::FromStr for CBoolType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bool" => Ok(CBoolType::Bool),
+            "u8" => Ok(CBoolType::U8),
+            _ => Err(format!("Invalid bool type: {}", s)),
         }
     }
 }
@@ -675,6 +2090,43 @@ pub enum LinkType {
     Framework,
 }
 
+/// The bindings generated for a single header by `generate_source`.
+struct GeneratedSource {
+    /// The bindings, as source.
+    source: String,
+    /// The diagnostics emitted while generating them.
+    diagnostics: Vec<Diagnostic>,
+    /// The files clang read to parse the header, if the inputs are hashed.
+    /// See `incremental::dependencies`.
+    dependencies: Vec<String>,
+}
+
+/// Generate the bindings for a single header, and get them back as source.
+fn generate_source(options: BindgenOptions) -> Result<GeneratedSource, ()> {
+    ensure_libclang_is_loaded();
+
+    let hash_inputs = options.input_hash_output.is_some();
+
+    let mut context = BindgenContext::new(options, Callbacks::default());
+    try!(parse(&mut context));
+    let dependencies = if hash_inputs {
+        incremental::dependencies(context.translation_unit())
+    } else {
+        vec![]
+    };
+    let items = try!(codegen::codegen(&mut context));
+    let diagnostics = context.take_diagnostics();
+
+    let source: Vec<_> = items.iter()
+        .map(|item| pprust::item_to_string(item))
+        .collect();
+    Ok(GeneratedSource {
+        source: source.join("\n"),
+        diagnostics: diagnostics,
+        dependencies: dependencies,
+    })
+}
+
 fn ensure_libclang_is_loaded() {
     if clang_sys::is_loaded() {
         return;
@@ -696,11 +2148,59 @@ fn ensure_libclang_is_loaded() {
     clang_sys::set_library(Some(LIBCLANG.clone()));
 }
 
+/// The severity of a `Diagnostic`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    /// A note, usually giving more context about a previous diagnostic.
+    Note,
+    /// A warning.
+    Warning,
+    /// An error, which will most likely lead to incomplete bindings.
+    Error,
+}
+
+/// A diagnostic emitted while generating the bindings, either by clang while
+/// parsing the input headers, like a missing include or a syntax error, or by
+/// bindgen itself, like a type it had to make opaque because it couldn't
+/// understand it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The severity of the diagnostic.
+    pub severity: DiagnosticSeverity,
+    /// The diagnostic message, including the source location it refers to.
+    pub message: String,
+}
+
+/// The callback given to `Builder::diagnostic_callback`.
+pub struct DiagnosticCallback(Box<Fn(DiagnosticSeverity, &str)>);
+
+impl DiagnosticCallback {
+    /// Hand the given diagnostic to the callback.
+    pub fn call(&self, diagnostic: &Diagnostic) {
+        (self.0)(diagnostic.severity, &diagnostic.message)
+    }
+}
+
+impl fmt::Debug for DiagnosticCallback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DiagnosticCallback")
+    }
+}
+
 /// Generated Rust bindings.
 #[derive(Debug)]
 pub struct Bindings<'ctx> {
-    context: BindgenContext<'ctx>,
+    /// The context used to generate these bindings, or `None` if we reused
+    /// the previous output because the inputs didn't change.
+    context: Option<BindgenContext<'ctx>>,
     module: ast::Mod,
+    /// The previously generated source, if we skipped the regeneration.
+    cached_output: Option<String>,
+    /// The inputs whose hash to store when writing these bindings, if
+    /// requested.
+    inputs: Option<incremental::Inputs>,
+    /// The diagnostics emitted while generating these bindings.
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl<'ctx> Bindings<'ctx> {
@@ -708,53 +2208,350 @@ impl<'ctx> Bindings<'ctx> {
     ///
     /// Deprecated - use a `Builder` instead
     #[deprecated]
-    pub fn generate(mut options: BindgenOptions,
+    pub fn generate(options: BindgenOptions,
                     span: Option<Span>)
                     -> Result<Bindings<'ctx>, ()> {
+        Self::generate_with_callbacks(options, Callbacks::default(), span)
+    }
+
+    fn generate_with_callbacks(mut options: BindgenOptions,
+                               callbacks: Callbacks,
+                               span: Option<Span>)
+                               -> Result<Bindings<'ctx>, ()> {
         let span = span.unwrap_or(DUMMY_SP);
+
+        if let Err(message) = options.build() {
+            let diagnostic = Diagnostic {
+                severity: DiagnosticSeverity::Error,
+                message: message,
+            };
+            error!("{}", diagnostic.message);
+            callbacks.diagnostic(&diagnostic);
+            return Err(());
+        }
+
+        let mut inputs = match options.input_hash_output.clone() {
+            Some(output) => {
+                // Forcing the regeneration shouldn't invalidate the hash.
+                let force = mem::replace(&mut options.force_regeneration,
+                                         false);
+                let inputs = incremental::Inputs::new(&output, &options);
+                options.force_regeneration = force;
+                Some((output, inputs))
+            }
+            None => None,
+        };
+
+        if let Some((ref output, ref mut inputs)) = inputs {
+            if !options.force_regeneration && inputs.check_up_to_date() {
+                let mut cached = String::new();
+                let read = File::open(output)
+                    .and_then(|mut file| file.read_to_string(&mut cached));
+                if read.is_ok() {
+                    info!("Inputs of {} didn't change, skipping regeneration",
+                          output);
+                    return Ok(Bindings {
+                        context: None,
+                        module: ast::Mod {
+                            inner: span,
+                            items: vec![],
+                        },
+                        cached_output: Some(cached),
+                        inputs: Some(inputs.clone()),
+                        diagnostics: vec![],
+                    });
+                }
+            }
+        }
+        let inputs = inputs.map(|(_, inputs)| inputs);
+
         ensure_libclang_is_loaded();
 
-        options.build();
-
-        // TODO: Make this path fixup configurable?
-        if let Some(clang) = clang_sys::support::Clang::find(None) {
-            // If --target is specified, assume caller knows what they're doing
-            // and don't mess with include paths for them
-            let has_target_arg = options.clang_args
-                .iter()
-                .rposition(|arg| arg.starts_with("--target"))
-                .is_some();
-            if !has_target_arg {
-                // TODO: distinguish C and C++ paths? C++'s should be enough, I
-                // guess.
-                for path in clang.cpp_search_paths.into_iter() {
-                    if let Ok(path) = path.into_os_string().into_string() {
-                        options.clang_args.push("-isystem".to_owned());
-                        options.clang_args.push(path);
-                    }
+        let targets = mem::replace(&mut options.targets, vec![]);
+        if targets.len() > 1 &&
+           (options.precompiled_header.is_some() ||
+            options.precompiled_header_output.is_some()) {
+            error!("Precompiled headers are target-specific, and can't be \
+                    used when generating for multiple targets");
+            return Err(());
+        }
+
+        if targets.len() == 1 {
+            options.clang_args.push(format!("--target={}", targets[0]));
+        }
+
+        // When cross-compiling from a build script, generate the bindings for
+        // the target being built, unless we were given one already.
+        if targets.is_empty() && options.target_triple().is_none() {
+            if let (Ok(target), Ok(host)) = (env::var("TARGET"),
+                                             env::var("HOST")) {
+                if target != host {
+                    options.clang_args.push(format!("--target={}", target));
                 }
             }
         }
 
+        // MSVC headers are full of `__declspec`s clang either doesn't know
+        // about or doesn't honor, which it already ignores, so there's no
+        // point in warning about them.
+        if options.is_msvc_target() {
+            options.clang_args.push("-Wno-ignored-attributes".into());
+            options.clang_args.push("-Wno-unknown-attributes".into());
+        }
+
+        // Each target gets its own `--target` flag later, so we never detect
+        // the include paths in that case.
+        let mut setup_diagnostics = vec![];
+        if targets.len() <= 1 {
+            setup_diagnostics.extend(detect_include_paths(&mut options,
+                                                          &callbacks));
+        }
+        setup_diagnostics.extend(missing_capabilities(&callbacks));
+
+        if let Some(pch) = options.precompiled_header.clone() {
+            options.clang_args.push("-include-pch".into());
+            options.clang_args.push(pch);
+        }
+
+        let mut parallel = options.parallel_parsing &&
+                           !options.extra_input_headers.is_empty();
+        if parallel &&
+           (targets.len() > 1 || callbacks.parse_callbacks.is_some() ||
+            callbacks.diagnostic_callback.is_some() ||
+            options.precompiled_header_output.is_some()) {
+            let diagnostic = Diagnostic {
+                severity: DiagnosticSeverity::Warning,
+                message: "Can't parse the headers in parallel with callbacks, \
+                          multiple targets, or when emitting a precompiled \
+                          header; parsing them sequentially"
+                    .into(),
+            };
+            warn!("{}", diagnostic.message);
+            callbacks.diagnostic(&diagnostic);
+            setup_diagnostics.push(diagnostic);
+            parallel = false;
+        }
+
+        if parallel {
+            return Self::generate_parallel(options,
+                                           callbacks,
+                                           span,
+                                           inputs,
+                                           setup_diagnostics);
+        }
+
+        for header in options.extra_input_headers.clone() {
+            options.clang_args.push("-include".into());
+            options.clang_args.push(header);
+        }
+
         if let Some(h) = options.input_header.as_ref() {
             options.clang_args.push(h.clone())
         }
 
-        let mut context = BindgenContext::new(options);
+        if targets.len() > 1 {
+            return Self::generate_for_targets(options,
+                                              callbacks,
+                                              targets,
+                                              span,
+                                              inputs);
+        }
+
+        let mut context = BindgenContext::new(options, callbacks);
+        let mut diagnostics = setup_diagnostics;
+        try!(parse(&mut context));
+
+        let mut inputs = inputs;
+        if let Some(ref mut inputs) = inputs {
+            inputs.record_dependencies(context.translation_unit());
+        }
+
+        if let Some(ref path) = context.options()
+            .precompiled_header_output {
+            if let Err(e) = context.translation_unit().save(path) {
+                error!("Unable to save the precompiled header to {} \
+                        (error {})",
+                       path,
+                       e);
+                return Err(());
+            }
+            info!("Saved the precompiled header to {}", path);
+        }
+
+        let module = ast::Mod {
+            inner: span,
+            items: try!(codegen::codegen(&mut context)),
+        };
+        diagnostics.extend(context.take_diagnostics());
+
+        Ok(Bindings {
+            context: Some(context),
+            module: module,
+            cached_output: None,
+            inputs: inputs,
+            diagnostics: diagnostics,
+        })
+    }
+
+    /// Generate the bindings for each input header on its own thread, and
+    /// merge them.
+    ///
+    /// Each header gets its own `BindgenContext`, and what gets merged is the
+    /// generated code, so the contexts never share items, and their item ids
+    /// can't collide. The other threads send their bindings back as source,
+    /// since the AST can't leave the thread it was built in (the interner is
+    /// thread-local), and we parse them again here.
+    fn generate_parallel(mut options: BindgenOptions,
+                         callbacks: Callbacks,
+                         span: Span,
+                         mut inputs: Option<incremental::Inputs>,
+                         mut diagnostics: Vec<Diagnostic>)
+                         -> Result<Bindings<'ctx>, ()> {
+        let extra_headers = options.extra_input_headers.clone();
+        let handles: Vec<_> = extra_headers.iter()
+            .map(|header| {
+                let mut options = options.clone();
+                options.input_header = Some(header.clone());
+                options.extra_input_headers = vec![];
+                options.clang_args.push(header.clone());
+
+                thread::spawn(move || generate_source(options))
+            })
+            .collect();
+
+        if let Some(h) = options.input_header.clone() {
+            options.clang_args.push(h);
+        }
+
+        let mut context = BindgenContext::new(options, callbacks);
         try!(parse(&mut context));
+        if let Some(ref mut inputs) = inputs {
+            inputs.record_dependencies(context.translation_unit());
+        }
+        let mut per_header = vec![try!(codegen::codegen(&mut context))];
+        diagnostics.extend(context.take_diagnostics());
+
+        let sess = syntax::parse::ParseSess::new();
+        for (header, handle) in extra_headers.into_iter().zip(handles) {
+            let generated = match handle.join() {
+                Ok(result) => try!(result),
+                Err(..) => {
+                    error!("Generating the bindings for {} panicked", header);
+                    return Err(());
+                }
+            };
+            diagnostics.extend(generated.diagnostics);
+            if let Some(ref mut inputs) = inputs {
+                inputs.extend_dependencies(generated.dependencies);
+            }
+            let source = generated.source;
+
+            let parsed =
+                syntax::parse::parse_crate_from_source_str(header.clone(),
+                                                           source,
+                                                           &sess);
+            match parsed {
+                Ok(krate) => per_header.push(krate.module.items),
+                Err(mut e) => {
+                    e.cancel();
+                    error!("Unable to parse back the bindings generated for {}",
+                           header);
+                    return Err(());
+                }
+            }
+        }
 
         let module = ast::Mod {
             inner: span,
-            items: codegen::codegen(&mut context),
+            items: targets::dedup(per_header),
+        };
+
+        Ok(Bindings {
+            context: Some(context),
+            module: module,
+            cached_output: None,
+            inputs: inputs,
+            diagnostics: diagnostics,
+        })
+    }
+
+    /// Generate the bindings for each of the given target triples, and merge
+    /// them. See the `targets` module.
+    fn generate_for_targets(options: BindgenOptions,
+                            callbacks: Callbacks,
+                            triples: Vec<String>,
+                            span: Span,
+                            mut inputs: Option<incremental::Inputs>)
+                            -> Result<Bindings<'ctx>, ()> {
+        let mut parts = Some((options, callbacks));
+        let mut context: Option<BindgenContext> = None;
+        let mut per_target = vec![];
+        let mut diagnostics = vec![];
+
+        for triple in &triples {
+            let (mut options, callbacks) = match context.take() {
+                Some(previous) => {
+                    let (mut options, callbacks) = previous.into_parts();
+                    // Drop the previous `--target` flag.
+                    options.clang_args.pop();
+                    (options, callbacks)
+                }
+                None => parts.take().unwrap(),
+            };
+
+            options.clang_args.push(format!("--target={}", triple));
+
+            let mut target_context = BindgenContext::new(options, callbacks);
+            try!(parse(&mut target_context));
+            if let Some(ref mut inputs) = inputs {
+                inputs.record_dependencies(target_context.translation_unit());
+            }
+            per_target.push(try!(codegen::codegen(&mut target_context)));
+            diagnostics.extend(target_context.take_diagnostics());
+            context = Some(target_context);
+        }
+
+        let module = ast::Mod {
+            inner: span,
+            items: targets::merge(&triples, per_target),
         };
 
         Ok(Bindings {
             context: context,
             module: module,
+            cached_output: None,
+            inputs: inputs,
+            diagnostics: diagnostics,
         })
     }
 
+    /// Get the diagnostics emitted while generating these bindings: first the
+    /// ones clang emitted while parsing the input headers, then bindgen's own.
+    ///
+    /// Note that, unless `Builder::abort_on_parse_errors(false)` was used, this
+    /// can't contain any clang error, since the generation fails in that case.
+    /// Use `Builder::diagnostic_callback` to get those.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Print the warnings among the diagnostics to stderr, like the layout
+    /// tests skipped because of a `no-layout-test` annotation.
+    pub fn emit_warnings(&self) {
+        let stderr = io::stderr();
+        let mut stderr = stderr.lock();
+        for diagnostic in &self.diagnostics {
+            if diagnostic.severity == DiagnosticSeverity::Warning {
+                let _ = writeln!(stderr, "warning: {}", diagnostic.message);
+            }
+        }
+    }
+
     /// Convert these bindings into a Rust AST.
+    ///
+    /// Note that if the regeneration was skipped because the inputs didn't
+    /// change (see `Builder::hash_inputs_to`), there's no AST to return, and
+    /// this returns no items.
     pub fn into_ast(self) -> Vec<P<ast::Item>> {
         self.module.items
     }
@@ -764,39 +2561,126 @@ impl<'ctx> Bindings<'ctx> {
         let mut mod_str = vec![];
         {
             let ref_writer = Box::new(mod_str.by_ref()) as Box<Write>;
-            self.write(ref_writer).expect("Could not write bindings to string");
+            self.write_source(ref_writer)
+                .expect("Could not write bindings to string");
         }
         String::from_utf8(mod_str).unwrap()
     }
 
     /// Write these bindings as source text to a file.
+    ///
+    /// If the inputs are being hashed to this file, this also refreshes the
+    /// stored hash. See `Builder::hash_inputs_to`.
     pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
         let file = try!(OpenOptions::new()
             .write(true)
             .truncate(true)
             .create(true)
-            .open(path));
-        self.write(Box::new(file))
+            .open(path.as_ref()));
+        try!(self.write(Box::new(file)));
+
+        match self.inputs {
+            Some(ref inputs) if inputs.is_for(path.as_ref()) => inputs.store(),
+            _ => Ok(()),
+        }
     }
 
     /// Write these bindings as source text to the given `Write`able.
-    pub fn write<'a>(&self, mut writer: Box<Write + 'a>) -> io::Result<()> {
-        try!(writer.write("/* automatically generated by rust-bindgen */\n\n"
-            .as_bytes()));
+    ///
+    /// If a split output directory was requested, the bindings are written
+    /// there instead, and `writer` is left untouched.
+    pub fn write<'a>(&self, writer: Box<Write + 'a>) -> io::Result<()> {
+        let split_output = self.context
+            .as_ref()
+            .and_then(|context| context.options().split_output.as_ref());
+        if let Some(dir) = split_output {
+            return self.write_split(dir);
+        }
 
-        for line in self.context.options().raw_lines.iter() {
-            try!(writer.write(line.as_bytes()));
-            try!(writer.write("\n".as_bytes()));
+        self.write_source(writer)
+    }
+
+    /// Write these bindings to the given directory, with one file per
+    /// top-level module.
+    ///
+    /// The root module is written to `mod.rs`, which declares the rest of the
+    /// modules, so the bindings can be included with a single `mod`
+    /// declaration. Paths through the root module (`root::foo::Bar`) are
+    /// rewritten to be relative to the module they appear in.
+    pub fn write_split<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let context = match self.context {
+            Some(ref context) => context,
+            None => {
+                return Err(io::Error::new(io::ErrorKind::Other,
+                                          "Can't split bindings that weren't \
+                                           regenerated"));
+            }
+        };
+
+        let dir = dir.as_ref();
+        try!(fs::create_dir_all(dir));
+
+        let file_allows = self.file_allows(context);
+        let files = split::split(self.module.clone(),
+                                 context.options().enable_cxx_namespaces,
+                                 context.options().module_per_header);
+        for (i, file) in files.iter().enumerate() {
+            let writer = try!(OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(dir.join(&file.name)));
+
+            // Only the `mod.rs` file gets the allowed lints and raw lines.
+            let (allows, raw_lines): (&[String], &[String]) = if i == 0 {
+                (&file_allows, &context.options().raw_lines)
+            } else {
+                (&[], &[])
+            };
+
+            try!(write_module(Box::new(writer),
+                              allows,
+                              raw_lines,
+                              &file.module,
+                              &file.attrs,
+                              &file.submodules));
         }
-        if !self.context.options().raw_lines.is_empty() {
-            try!(writer.write("\n".as_bytes()));
+
+        Ok(())
+    }
+
+    fn write_source<'a>(&self, mut writer: Box<Write + 'a>) -> io::Result<()> {
+        let context = match self.context {
+            Some(ref context) => context,
+            None => {
+                let cached = self.cached_output
+                    .as_ref()
+                    .expect("Bindings without context nor cached output?");
+                try!(writer.write_all(cached.as_bytes()));
+                return writer.flush();
+            }
+        };
+
+        write_module(writer,
+                     &self.file_allows(context),
+                     &context.options().raw_lines,
+                     &self.module,
+                     &[],
+                     &[])
+    }
+
+    /// The lints to allow for the whole generated file.
+    fn file_allows(&self, context: &BindgenContext) -> Vec<String> {
+        let options = context.options();
+        if !options.allow_attrs.is_empty() || !options.allow_triggered_lints {
+            return options.allow_attrs.clone();
         }
 
-        let mut ps = pprust::rust_printer(writer);
-        try!(ps.print_mod(&self.module, &[]));
-        try!(ps.print_remaining_comments());
-        try!(eof(&mut ps.s));
-        ps.s.out.flush()
+        codegen::lints::triggered_by(&self.module.items,
+                                     options.default_visibility)
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect()
     }
 
     /// Generate and write dummy uses of all the types we parsed, if we've been
@@ -804,8 +2688,14 @@ impl<'ctx> Bindings<'ctx> {
     ///
     /// See the `uses` module for more information.
     pub fn write_dummy_uses(&mut self) -> io::Result<()> {
-        let file = if let Some(ref dummy_path) =
-            self.context.options().dummy_uses {
+        let context = match self.context {
+            Some(ref mut context) => context,
+            // We didn't parse anything, so the previous dummy uses are still
+            // valid.
+            None => return Ok(()),
+        };
+
+        let file = if let Some(ref dummy_path) = context.options().dummy_uses {
             Some(try!(OpenOptions::new()
                 .write(true)
                 .truncate(true)
@@ -816,13 +2706,129 @@ impl<'ctx> Bindings<'ctx> {
         };
 
         if let Some(file) = file {
-            try!(uses::generate_dummy_uses(&mut self.context, file));
+            try!(uses::generate_dummy_uses(context, file));
         }
 
         Ok(())
     }
 }
 
+/// Write the given module as source text, with the raw lines prepended, and
+/// the given submodules declared at the end.
+fn write_module<'a>(mut writer: Box<Write + 'a>,
+                    allows: &[String],
+                    raw_lines: &[String],
+                    module: &ast::Mod,
+                    attrs: &[ast::Attribute],
+                    submodules: &[String])
+                    -> io::Result<()> {
+    try!(writer.write("/* automatically generated by rust-bindgen */\n\n"
+        .as_bytes()));
+
+    if !allows.is_empty() {
+        try!(writeln!(writer, "#![allow({})]\n", allows.join(", ")));
+    }
+
+    for line in raw_lines.iter() {
+        try!(writer.write(line.as_bytes()));
+        try!(writer.write("\n".as_bytes()));
+    }
+    if !raw_lines.is_empty() {
+        try!(writer.write("\n".as_bytes()));
+    }
+
+    let mut ps = pprust::rust_printer(writer);
+    try!(ps.print_mod(module, attrs));
+    try!(ps.print_remaining_comments());
+    try!(eof(&mut ps.s));
+    try!(ps.s.out.flush());
+
+    for submodule in submodules {
+        try!(writeln!(ps.s.out, "pub mod {};", submodule));
+    }
+    ps.s.out.flush()
+}
+
+/// Append the default include search paths of the `clang` binary (the one in
+/// `CLANG_PATH`, or the first one found in the `PATH`) to the clang arguments,
+/// so that system headers like `<stddef.h>` resolve as they would when
+/// compiling.
+///
+/// Nothing is detected if the user gave a target or a sysroot, since then the
+/// paths of the host compiler would be wrong. The user's own `-I` paths are
+/// always searched before the `-isystem` paths we add.
+///
+/// Returns a warning if we couldn't find a `clang` binary to ask.
+fn detect_include_paths(options: &mut BindgenOptions,
+                        callbacks: &Callbacks)
+                        -> Option<Diagnostic> {
+    if !options.detect_include_paths {
+        return None;
+    }
+
+    let has_explicit_paths = options.clang_args.iter().any(|arg| {
+        arg.starts_with("--target") || arg == "-target" ||
+        arg.starts_with("--sysroot") ||
+        arg.starts_with("-isysroot") || arg == "-nostdinc"
+    });
+    if has_explicit_paths {
+        return None;
+    }
+
+    // `Clang::find` can't cope with a missing `PATH`.
+    let clang = if env::var_os("CLANG_PATH").is_some() ||
+                   env::var_os("PATH").is_some() {
+        clang_sys::support::Clang::find(None)
+    } else {
+        None
+    };
+
+    // TODO: distinguish C and C++ paths? C++'s should be enough, I guess.
+    let paths = clang.map_or(vec![], |clang| clang.cpp_search_paths);
+    if paths.is_empty() {
+        let diagnostic = Diagnostic {
+            severity: DiagnosticSeverity::Warning,
+            message: "Couldn't find a clang binary to detect the default \
+                      include paths from, set CLANG_PATH to point to one"
+                .to_owned(),
+        };
+        warn!("{}", diagnostic.message);
+        callbacks.diagnostic(&diagnostic);
+        return Some(diagnostic);
+    }
+
+    for path in paths {
+        if let Ok(path) = path.into_os_string().into_string() {
+            options.clang_args.push("-isystem".to_owned());
+            options.clang_args.push(path);
+        }
+    }
+
+    None
+}
+
+/// Warn about the libclang capabilities we have to do without, see
+/// `clang::Capabilities`.
+fn missing_capabilities(callbacks: &Callbacks) -> Vec<Diagnostic> {
+    let version = clang::extract_clang_version();
+    clang::Capabilities::detect()
+        .missing()
+        .into_iter()
+        .map(|(capability, fallback)| {
+            let diagnostic = Diagnostic {
+                severity: DiagnosticSeverity::Warning,
+                message: format!("{} lacks the `{}` capability, {}",
+                                 version,
+                                 capability,
+                                 fallback),
+            };
+            warn!("{}", diagnostic.message);
+            callbacks.diagnostic(&diagnostic);
+            diagnostic
+        })
+        .collect()
+}
+
 /// Determines whether the given cursor is in any of the files matched by the
 /// options.
 fn filter_builtins(ctx: &BindgenContext, cursor: &clang::Cursor) -> bool {
@@ -834,7 +2840,7 @@ pub fn parse_one(ctx: &mut BindgenContext,
                  cursor: clang::Cursor,
                  parent: Option<ItemId>)
                  -> clang_sys::CXChildVisitResult {
-    if !filter_builtins(ctx, &cursor) {
+    if !filter_builtins(ctx, &cursor) || ctx.is_skipped_declaration(&cursor) {
         return CXChildVisit_Continue;
     }
 
@@ -853,15 +2859,62 @@ pub fn parse_one(ctx: &mut BindgenContext,
 fn parse(context: &mut BindgenContext) -> Result<(), ()> {
     use clang_sys::*;
 
+    let pch = context.options().precompiled_header.clone();
     let mut any_error = false;
-    for d in context.translation_unit().diags().iter() {
-        let msg = d.format();
-        let is_err = d.severity() >= CXDiagnostic_Error;
-        println!("{}, err: {}", msg, is_err);
-        any_error |= is_err;
+    let mut pch_rejected = false;
+    let clang_diagnostics = context.translation_unit().diags();
+    for d in clang_diagnostics.iter() {
+        let mut severity = match d.severity() {
+            CXDiagnostic_Ignored => continue,
+            CXDiagnostic_Note => DiagnosticSeverity::Note,
+            CXDiagnostic_Warning => DiagnosticSeverity::Warning,
+            _ => DiagnosticSeverity::Error,
+        };
+
+        // Fatal errors stop clang from parsing anything else, so there's no
+        // recovering from those.
+        if d.severity() == CXDiagnostic_Error &&
+           !context.options().fail_on_clang_error {
+            if let Some(declaration) = recoverable_declaration(context, d) {
+                context.diagnostic(DiagnosticSeverity::Warning,
+                                   format!("{}: skipping `{}`, which clang \
+                                            couldn't parse: {}",
+                                           d.location(),
+                                           declaration.spelling(),
+                                           d.spelling()));
+                context.skip_declaration(&declaration);
+                severity = DiagnosticSeverity::Warning;
+            }
+        }
+
+        if severity != DiagnosticSeverity::Error &&
+           !context.options().report_parse_warnings {
+            continue;
+        }
+
+        let message = d.format();
+        if severity == DiagnosticSeverity::Error {
+            any_error = true;
+            pch_rejected |= pch.as_ref().map_or(false, |pch| {
+                message.contains(pch.as_str()) || message.contains("PCH")
+            });
+        }
+        context.diagnostic(severity, message);
+    }
+
+    // Clang refuses to use a precompiled header built with different flags,
+    // and nothing sensible can come out of the parse after that, so don't
+    // even try to continue.
+    if pch_rejected {
+        context.diagnostic(DiagnosticSeverity::Error,
+                           format!("the precompiled header `{}` couldn't be \
+                                    used; it must be generated with exactly \
+                                    the same clang arguments as the bindings",
+                                   pch.unwrap()));
+        return Err(());
     }
 
-    if any_error {
+    if any_error && context.options().abort_on_parse_errors {
         return Err(());
     }
 
@@ -870,16 +2923,95 @@ fn parse(context: &mut BindgenContext) -> Result<(), ()> {
         cursor.visit(|cur| clang::ast_dump(&cur, 0));
     }
 
+    if !context.options().emit_ast_for.is_empty() {
+        if let Err(e) = emit_ast_for(context, &cursor) {
+            warn!("Couldn't dump the Clang AST: {}", e);
+        }
+    }
+
     let root = context.root_module();
     context.with_module(root, |context| {
-        cursor.visit(|cursor| parse_one(context, cursor, None))
+        cursor.visit(|cursor| parse_one(context, cursor, None));
+
+        // Now that every function they may forward to is known.
+        context.add_macro_fns();
     });
 
     assert!(context.current_module() == context.root_module(),
             "How did this happen?");
+
+    if context.options().inline_root_module && !context.has_namespaces() {
+        context.inline_root_module();
+    }
     Ok(())
 }
 
+/// If the given clang error only breaks the declaration it's in, and we can
+/// generate the rest of the bindings by skipping it, get that declaration.
+///
+/// For now that's only the case of the identifiers poisoned with `#pragma GCC
+/// poison`.
+fn recoverable_declaration(context: &BindgenContext,
+                           diagnostic: &clang::Diagnostic)
+                           -> Option<clang::Cursor> {
+    use clang_sys::*;
+
+    if !diagnostic.spelling().contains("poisoned identifier") {
+        return None;
+    }
+
+    let mut cursor = context.translation_unit()
+        .cursor_at(&diagnostic.location());
+    let mut declaration = None;
+    while let Some(parent) = cursor.fallible_semantic_parent() {
+        if cursor.is_declaration() {
+            declaration = Some(cursor);
+        }
+        match parent.kind() {
+            CXCursor_TranslationUnit |
+            CXCursor_Namespace |
+            CXCursor_LinkageSpec => break,
+            _ => cursor = parent,
+        }
+    }
+
+    declaration.and_then(|declaration| if declaration.spelling().is_empty() {
+        None
+    } else {
+        Some(declaration)
+    })
+}
+
+/// Dump the Clang AST of every declaration matching the `emit_ast_for`
+/// option.
+fn emit_ast_for(context: &BindgenContext,
+                cursor: &clang::Cursor)
+                -> io::Result<()> {
+    use clang_sys::{CXChildVisit_Break, CXChildVisit_Continue,
+                    CXChildVisit_Recurse};
+
+    let mut out: Box<Write> = match context.options().emit_ast_output {
+        Some(ref path) => Box::new(try!(File::create(path))),
+        None => Box::new(io::stderr()),
+    };
+
+    let mut result = Ok(());
+    cursor.visit(|cur| {
+        if !cur.is_declaration() ||
+           !context.options().emit_ast_for.matches(&cur.spelling()) {
+            return CXChildVisit_Recurse;
+        }
+
+        result = clang::dump_cursor_tree(&mut out, &cur, 0);
+        if result.is_ok() {
+            CXChildVisit_Continue
+        } else {
+            CXChildVisit_Break
+        }
+    });
+    result
+}
+
 /// Extracted Clang version data
 #[derive(Debug)]
 pub struct ClangVersion {
@@ -889,6 +3021,15 @@ pub struct ClangVersion {
     pub full: String,
 }
 
+/// Get the optional libclang APIs the loaded libclang provides.
+pub fn clang_capabilities() -> ClangCapabilities {
+    if !clang_sys::is_loaded() {
+        clang_sys::load().expect("Unable to find libclang");
+    }
+
+    clang::Capabilities::detect()
+}
+
 /// Get the major and the minor semvar numbers of Clang's version
 pub fn clang_version() -> ClangVersion {
     if !clang_sys::is_loaded() {
@@ -8,8 +8,9 @@ extern crate clang_sys;
 extern crate clap;
 extern crate rustc_serialize;
 
-use bindgen::clang_version;
+use bindgen::{clang_capabilities, clang_version};
 use std::env;
+use std::io::{self, Write};
 use std::panic;
 
 #[macro_use]
@@ -50,6 +51,13 @@ pub fn main() {
 
     match builder_from_flags(bind_args.into_iter()) {
         Ok((builder, output, verbose)) => {
+            if verbose {
+                let capabilities = clang_capabilities().active();
+                let _ = writeln!(io::stderr(),
+                                 "note: {} capabilities: {}",
+                                 version.full,
+                                 capabilities.join(", "));
+            }
 
             let builder_result = panic::catch_unwind(|| {
                 builder.generate().expect("Unable to generate bindings")
@@ -1,7 +1,30 @@
-use bindgen::{Builder, CodegenConfig, builder};
+use bindgen::{Builder, CodegenConfig, DiagnosticSeverity, builder};
 use clap::{App, Arg};
 use std::fs::File;
-use std::io::{self, Error, ErrorKind};
+use std::io::{self, Error, ErrorKind, Write};
+
+/// A file that isn't created (and thus truncated) until it's written to.
+struct LazyFile {
+    path: String,
+    file: Option<io::BufWriter<File>>,
+}
+
+impl io::Write for LazyFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.file.is_none() {
+            let file = try!(File::create(&self.path));
+            self.file = Some(io::BufWriter::new(file));
+        }
+        self.file.as_mut().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file {
+            Some(ref mut file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
 
 /// Construct a new [`Builder`](./struct.Builder.html) from command line flags.
 pub fn builder_from_flags<I>
@@ -17,6 +40,22 @@ pub fn builder_from_flags<I>
             Arg::with_name("header")
                 .help("C or C++ header file")
                 .required(true),
+            Arg::with_name("default-enum-style")
+                .long("default-enum-style")
+                .help("How to generate the enums no other option or \
+                       annotation applies to.")
+                .value_name("variation")
+                .possible_values(&["rust", "bitfield", "consts",
+                                   "moduleconsts"])
+                .takes_value(true),
+            Arg::with_name("rustified-enum")
+                .long("rustified-enum")
+                .help("Mark any enum whose name matches <regex> as a Rust \
+                       enum, whatever the default enum style is.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("bitfield-enum")
                 .long("bitfield-enum")
                 .help("Mark any enum whose name matches <regex> as a set of \
@@ -33,6 +72,30 @@ pub fn builder_from_flags<I>
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("constified-enum-module")
+                .long("constified-enum-module")
+                .help("Mark any enum whose name matches <regex> as a module \
+                       of constants instead of an enumeration.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("enums-as-underlying-type")
+                .long("enums-as-underlying-type")
+                .help("Refer to constified enums by their underlying integer \
+                       type in fields and function signatures."),
+            Arg::with_name("rustified-non-exhaustive-enum")
+                .long("rustified-non-exhaustive-enum")
+                .help("Mark any enum whose name matches <regex> as \
+                       non-exhaustive.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("non-exhaustive-attribute")
+                .long("non-exhaustive-attribute")
+                .help("Use #[non_exhaustive] for non-exhaustive enums \
+                       instead of a hidden variant."),
             Arg::with_name("blacklist-type")
                 .long("blacklist-type")
                 .help("Mark a type as hidden.")
@@ -40,6 +103,37 @@ pub fn builder_from_flags<I>
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("emit-hidden-items")
+                .long("emit-hidden-items")
+                .help("Generate the hidden types as #[doc(hidden)] instead \
+                       of leaving them out."),
+            Arg::with_name("hidden-alias-variation")
+                .long("hidden-alias-variation")
+                .help("What to do with the type aliases of blacklisted types.")
+                .value_name("variation")
+                .possible_values(&["keep", "opaque", "skip"])
+                .takes_value(true),
+            Arg::with_name("default-visibility")
+                .long("default-visibility")
+                .help("The visibility of the generated items.")
+                .value_name("visibility")
+                .possible_values(&["pub", "pub(crate)", "private"])
+                .takes_value(true),
+            Arg::with_name("c-bool-type")
+                .long("c-bool-type")
+                .help("The Rust type of C and C++ booleans.")
+                .value_name("type")
+                .possible_values(&["bool", "u8"])
+                .takes_value(true),
+            Arg::with_name("fixed-width-integers")
+                .long("fixed-width-integers")
+                .help("Translate the C integer types to the Rust primitives \
+                       of the same width on the target, instead of c_long \
+                       and friends."),
+            Arg::with_name("associated-constants")
+                .long("associated-constants")
+                .help("Generate the static const members of classes as \
+                       associated constants."),
             Arg::with_name("no-derive-debug")
                 .long("no-derive-debug")
                 .help("Avoid deriving Debug on any type."),
@@ -50,6 +144,24 @@ pub fn builder_from_flags<I>
             Arg::with_name("with-derive-default")
                 .long("with-derive-default")
                 .help("Deriving Default on any type."),
+            Arg::with_name("impl-default-via-zeroed")
+                .long("impl-default-via-zeroed")
+                .help("Implement Default with mem::zeroed() for the types \
+                       that can't derive it.")
+                .requires("with-derive-default"),
+            Arg::with_name("no-impl-default-via-zeroed")
+                .long("no-impl-default-via-zeroed")
+                .hidden(true)
+                .help("Don't implement Default with mem::zeroed() for any \
+                       type."),
+            Arg::with_name("impl-debug-for-unions")
+                .long("impl-debug-for-unions")
+                .help("Implement Debug for unions by hand, printing only \
+                       their name and size."),
+            Arg::with_name("impl-debug-for-long-arrays")
+                .long("impl-debug-for-long-arrays")
+                .help("Implement Debug by hand for structs with arrays of \
+                       more than 32 elements, printing them as slices."),
             Arg::with_name("no-doc-comments")
                 .long("no-doc-comments")
                 .help("Avoid including doc comments in the output, see: \
@@ -60,6 +172,11 @@ pub fn builder_from_flags<I>
             Arg::with_name("objc-extern-crate")
                 .long("objc-extern-crate")
                 .help("Use extern crate instead of use for objc"),
+            Arg::with_name("no-objc")
+                .long("no-objc")
+                .help("Generate opaque handle types for the Objective-C \
+                       interfaces instead of bindings through the objc \
+                       crate."),
             Arg::with_name("distrust-clang-mangling")
                 .long("distrust-clang-mangling")
                 .help("Do not trust the libclang-provided mangling"),
@@ -73,9 +190,17 @@ pub fn builder_from_flags<I>
                       ::std::os::raw.")
                 .value_name("prefix")
                 .takes_value(true),
+            Arg::with_name("use-libc")
+                .long("use-libc")
+                .help("Use the libc crate's definitions for the raw types and \
+                       for size_t, wchar_t and the like."),
             // All positional arguments after the end of options marker, `--`
             Arg::with_name("clang-args")
                 .multiple(true),
+            Arg::with_name("no-include-path-detection")
+                .long("no-include-path-detection")
+                .help("Don't pass the default include paths of the clang \
+                       binary to libclang."),
             Arg::with_name("dummy-uses")
                 .long("dummy-uses")
                 .help("For testing purposes, generate a C/C++ file containing \
@@ -84,6 +209,21 @@ pub fn builder_from_flags<I>
             Arg::with_name("emit-clang-ast")
                 .long("emit-clang-ast")
                 .help("Output the Clang AST for debugging purposes."),
+            Arg::with_name("emit-clang-ast-for")
+                .long("emit-clang-ast-for")
+                .help("Output the Clang AST of the declarations whose name \
+                       matches <regex>.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("emit-clang-ast-output")
+                .long("emit-clang-ast-output")
+                .help("Write the Clang AST output by --emit-clang-ast-for \
+                       to <path> instead of stderr.")
+                .value_name("path")
+                .takes_value(true)
+                .requires("emit-clang-ast-for"),
             Arg::with_name("emit-ir")
                 .long("emit-ir")
                 .help("Output our internal IR for debugging purposes."),
@@ -95,6 +235,27 @@ pub fn builder_from_flags<I>
             Arg::with_name("enable-cxx-namespaces")
                 .long("enable-cxx-namespaces")
                 .help("Enable support for C++ namespaces."),
+            Arg::with_name("root-module-name")
+                .long("root-module-name")
+                .help("The name of the module everything else lives in \
+                       with C++ namespaces enabled. Defaults to root.")
+                .value_name("name")
+                .takes_value(true)
+                .requires("enable-cxx-namespaces"),
+            Arg::with_name("inline-root-module")
+                .long("inline-root-module")
+                .help("Emit the contents of the root module at the top level \
+                       if the headers don't declare any namespace.")
+                .requires("enable-cxx-namespaces"),
+            Arg::with_name("emit-flat-aliases")
+                .long("emit-flat-aliases")
+                .help("Re-export every namespaced type under a flat name \
+                       at the top level.")
+                .requires("enable-cxx-namespaces"),
+            Arg::with_name("emit-source-annotations")
+                .long("emit-source-annotations")
+                .help("Annotate the generated types and functions with the \
+                       location they were declared at."),
             Arg::with_name("disable-name-namespacing")
                 .long("disable-name-namespacing")
                 .help("Disable name namespacing if namespaces are disabled."),
@@ -127,13 +288,35 @@ pub fn builder_from_flags<I>
             Arg::with_name("no-convert-floats")
                 .long("no-convert-floats")
                 .help("Don't automatically convert floats to f32/f64."),
+            Arg::with_name("no-convert-stdint-types")
+                .long("no-convert-stdint-types")
+                .help("Don't translate well-known integer typedefs like \
+                       uint32_t to Rust primitive types like u32."),
             Arg::with_name("no-prepend-enum-name")
                 .long("no-prepend-enum-name")
                 .help("Do not prepend the enum name to bitfield or constant variants"),
+            Arg::with_name("no-inline-wrappers")
+                .long("no-inline-wrappers")
+                .help("Do not mark the Rust functions forwarding to native \
+                       ones, like C++ methods, as #[inline]."),
             Arg::with_name("no-unstable-rust")
                 .long("no-unstable-rust")
                 .help("Do not generate unstable Rust code.")
                 .multiple(true), // FIXME: Pass legacy test suite
+            Arg::with_name("thread-local-statics")
+                .long("thread-local-statics")
+                .help("Generate the thread-local variables as \
+                       #[thread_local] statics, which requires nightly Rust."),
+            Arg::with_name("c-string-constants")
+                .long("c-string-constants")
+                .help("Generate safe accessors for the constant statics \
+                       holding C strings."),
+            Arg::with_name("dynamic-link-name")
+                .long("dynamic-link-name")
+                .help("Link the __declspec(dllimport) functions and \
+                       variables against the given DLL.")
+                .value_name("lib")
+                .takes_value(true),
             Arg::with_name("opaque-type")
                 .long("opaque-type")
                 .help("Mark a type as opaque.")
@@ -141,30 +324,176 @@ pub fn builder_from_flags<I>
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("enable-stl-blobs")
+                .long("enable-stl-blobs")
+                .help("Generate sized opaque blobs for the instantiations of \
+                       std::vector, std::unique_ptr and friends."),
+            Arg::with_name("const-generics")
+                .long("const-generics")
+                .help("Generate const generic parameters for the integer \
+                       template parameters used as array lengths."),
+            Arg::with_name("send-type")
+                .long("send-type")
+                .help("Implement Send for the types matching <regex>. This \
+                       asserts, unchecked, that they're safe to send across \
+                       threads.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("sync-type")
+                .long("sync-type")
+                .help("Implement Sync for the types matching <regex>. This \
+                       asserts, unchecked, that they're safe to share across \
+                       threads.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("impl-trait")
+                .long("impl-trait")
+                .help("Implement the marker trait at <path> for the types \
+                       matching <regex>.")
+                .value_names(&["regex", "path"])
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(2),
+            Arg::with_name("default-alias-style")
+                .long("default-alias-style")
+                .help("How to generate the typedefs no other option applies \
+                       to.")
+                .value_name("variation")
+                .possible_values(&["type_alias", "new_type",
+                                   "new_type_deref"])
+                .takes_value(true),
+            Arg::with_name("type-alias")
+                .long("type-alias")
+                .help("Generate the typedefs matching <regex> as type \
+                       aliases, whatever the default typedef style is.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("new-type-alias")
+                .long("new-type-alias")
+                .help("Generate the typedefs matching <regex> as new types \
+                       instead of type aliases.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("new-type-alias-deref")
+                .long("new-type-alias-deref")
+                .help("Like --new-type-alias, but also implement Deref and \
+                       DerefMut to the aliased type.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("newtype-conversions")
+                .long("newtype-conversions")
+                .help("Implement From both ways between the new types \
+                       generated for typedefs and the types they wrap."),
             Arg::with_name("output")
                 .short("o")
                 .long("output")
                 .help("Write Rust bindings to <output>.")
                 .takes_value(true),
+            Arg::with_name("hash-inputs")
+                .long("hash-inputs")
+                .help("Store a hash of the inputs next to <output>, and skip \
+                       the regeneration if they didn't change since the last \
+                       run.")
+                .requires("output"),
+            Arg::with_name("module-per-header")
+                .long("module-per-header")
+                .help("Group the top-level items in one module per header, \
+                       re-exported from the top level."),
+            Arg::with_name("split-output")
+                .long("split-output")
+                .help("Write the bindings to <dir>, with one file per \
+                       top-level module, and a mod.rs declaring them.")
+                .value_name("dir")
+                .takes_value(true)
+                .conflicts_with("output"),
+            Arg::with_name("force")
+                .long("force")
+                .help("Regenerate the bindings even if --hash-inputs says \
+                       nothing changed."),
             Arg::with_name("raw-line")
                 .long("raw-line")
                 .help("Add a raw line of Rust code at the beginning of output.")
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("wrap-in-cfg")
+                .long("wrap-in-cfg")
+                .help("Wrap the items matching <regex> in a #[cfg] attribute \
+                       with the given <predicate>.")
+                .value_names(&["regex", "predicate"])
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(2),
+            Arg::with_name("allow")
+                .long("allow")
+                .help("Allow <lint> for the whole generated file, with a \
+                       #![allow(...)] attribute at the top of the output.")
+                .value_name("lint")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("allow-triggered-lints")
+                .long("allow-triggered-lints")
+                .help("Allow the lints that the generated items trigger for \
+                       the whole generated file, unless --allow is given."),
+            Arg::with_name("allow-lints-per-item")
+                .long("allow-lints-per-item")
+                .help("Allow the naming lints only on the items whose names \
+                       would trigger them."),
             Arg::with_name("static")
                 .long("static-link")
                 .help("Link to static library.")
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("target")
+                .long("target")
+                .help("Generate the bindings for <triple>. If given multiple \
+                       times, the bindings for each target are merged, and \
+                       the items that differ are gated behind #[cfg] \
+                       attributes.")
+                .value_name("triple")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("use-core")
                 .long("use-core")
                 .help("Use types from Rust core instead of std."),
+            Arg::with_name("no-std")
+                .long("no-std")
+                .help("Generate bindings for a #![no_std] crate. Like \
+                       --use-core, but this also avoids deriving Debug and \
+                       generating layout tests, and uses ::core::ffi as the \
+                       ctypes prefix by default."),
+            Arg::with_name("no-core-ffi-c-void")
+                .long("no-core-ffi-c-void")
+                .help("Emit a c_void enum along with --no-std, instead of \
+                       using core::ffi::c_void."),
+            Arg::with_name("no-layout-tests")
+                .long("no-layout-tests")
+                .help("Avoid generating layout tests for any type."),
             Arg::with_name("conservative-inline-namespaces")
                 .long("conservative-inline-namespaces")
                 .help("Conservatively generate inline namespaces to avoid name \
                        conflicts."),
+            Arg::with_name("keep-inline-namespace")
+                .long("keep-inline-namespace")
+                .help("Generate the inline namespaces matching <regex> as \
+                       their own modules.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("use-msvc-mangling")
                 .long("use-msvc-mangling")
                 .help("MSVC C++ ABI mangling. DEPRECATED: Has no effect."),
@@ -194,9 +523,87 @@ pub fn builder_from_flags<I>
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("whitelist-file")
+                .long("whitelist-file")
+                .help("Whitelist everything declared in the headers whose \
+                       path matches <regex>.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("only-primary-header")
+                .long("only-primary-header")
+                .help("Only generate the items declared in the input headers, \
+                       and whatever they refer to."),
+            Arg::with_name("no-parse-warnings")
+                .long("no-parse-warnings")
+                .help("Don't report the warnings clang emits while parsing."),
             Arg::with_name("verbose")
                 .long("verbose")
-                .help("Print verbose error messages"),
+                .help("Print the libclang capabilities in use, and verbose \
+                       error messages."),
+            Arg::with_name("ignore-parse-errors")
+                .long("ignore-parse-errors")
+                .help("Generate the bindings even if clang fails to parse \
+                       the headers. They'll most likely be incomplete."),
+            Arg::with_name("fail-on-clang-error")
+                .long("fail-on-clang-error")
+                .help("Fail even on the clang errors we could recover from \
+                       by skipping the declarations they affect, like the \
+                       use of poisoned identifiers."),
+            Arg::with_name("strict")
+                .long("strict")
+                .help("Fail if any whitelisted type can't be fully understood \
+                       and would be approximated with an opaque blob."),
+            Arg::with_name("generate-static-fns-anyway")
+                .long("generate-static-fns-anyway")
+                .help("Generate the functions and variables with internal \
+                       linkage, like static ones, instead of skipping them."),
+            Arg::with_name("emit-operator-functions")
+                .long("emit-operator-functions")
+                .help("Generate the free C++ operator functions, with names \
+                       like op_add for operator+."),
+            Arg::with_name("no-inline-const-vars")
+                .long("no-inline-const-vars")
+                .help("Refer to the symbols of the constant variables \
+                       defined in a library instead of generating Rust \
+                       constants with their values."),
+            Arg::with_name("emit-macro-fns")
+                .long("emit-macro-fns")
+                .help("Generate inline functions for the function-like \
+                       macros that are a single call or an integer \
+                       expression over their parameters."),
+            Arg::with_name("cold-noreturn-functions")
+                .long("cold-noreturn-functions")
+                .help("Mark the functions that never return with #[cold]."),
+            Arg::with_name("use-array-pointers-in-arguments")
+                .long("use-array-pointers-in-arguments")
+                .help("Generate the array parameters with a length as \
+                       pointers to the whole array."),
+            Arg::with_name("references-are-nonnull")
+                .long("references-are-nonnull")
+                .help("Generate the struct fields of C++ reference type as \
+                       ptr::NonNull<T> instead of raw pointers."),
+            Arg::with_name("combined-constants-module")
+                .long("combined-constants-module")
+                .help("Generate a `constants` module re-exporting all the \
+                       constants."),
+            Arg::with_name("sort-semantically")
+                .long("sort-semantically")
+                .help("Sort the generated items by kind, and then by name, \
+                       instead of keeping the order of the headers."),
+            Arg::with_name("precompiled-header")
+                .long("precompiled-header")
+                .help("Use the given precompiled header when parsing. It \
+                       must be generated with the same clang arguments.")
+                .value_name("path")
+                .takes_value(true),
+            Arg::with_name("emit-precompiled-header")
+                .long("emit-precompiled-header")
+                .help("Save the parsed header as a precompiled header to \
+                       <path>.")
+                .value_name("path")
+                .takes_value(true),
         ]) // .args()
         .get_matches_from(args);
 
@@ -208,6 +615,16 @@ pub fn builder_from_flags<I>
         return Err(Error::new(ErrorKind::Other, "Header not found"));
     }
 
+    if let Some(variation) = matches.value_of("default-enum-style") {
+        builder = builder.default_enum_style(variation.parse().unwrap());
+    }
+
+    if let Some(rustified) = matches.values_of("rustified-enum") {
+        for regex in rustified {
+            builder = builder.rustified_enum(regex);
+        }
+    }
+
     if let Some(bitfields) = matches.values_of("bitfield-enum") {
         for regex in bitfields {
             builder = builder.bitfield_enum(regex);
@@ -220,12 +637,57 @@ pub fn builder_from_flags<I>
         }
     }
 
+    if let Some(constified_mods) = matches.values_of("constified-enum-module") {
+        for regex in constified_mods {
+            builder = builder.constified_enum_module(regex);
+        }
+    }
+
+    if matches.is_present("enums-as-underlying-type") {
+        builder = builder.enums_as_underlying_type(true);
+    }
+
+    if let Some(non_exhaustive) =
+        matches.values_of("rustified-non-exhaustive-enum") {
+        for regex in non_exhaustive {
+            builder = builder.rustified_non_exhaustive_enum(regex);
+        }
+    }
+
+    if matches.is_present("non-exhaustive-attribute") {
+        builder = builder.non_exhaustive_attribute(true);
+    }
+
     if let Some(hidden_types) = matches.values_of("blacklist-type") {
         for ty in hidden_types {
             builder = builder.hide_type(ty);
         }
     }
 
+    if matches.is_present("emit-hidden-items") {
+        builder = builder.emit_hidden_items(true);
+    }
+
+    if let Some(variation) = matches.value_of("hidden-alias-variation") {
+        builder = builder.hidden_alias_variation(variation.parse().unwrap());
+    }
+
+    if let Some(visibility) = matches.value_of("default-visibility") {
+        builder = builder.default_visibility(visibility.parse().unwrap());
+    }
+
+    if let Some(ty) = matches.value_of("c-bool-type") {
+        builder = builder.c_bool_type(ty.parse().unwrap());
+    }
+
+    if matches.is_present("fixed-width-integers") {
+        builder = builder.fixed_width_integers(true);
+    }
+
+    if matches.is_present("associated-constants") {
+        builder = builder.associated_constants(true);
+    }
+
     if matches.is_present("builtins") {
         builder = builder.emit_builtins();
     }
@@ -242,14 +704,38 @@ pub fn builder_from_flags<I>
         builder = builder.derive_default(false);
     }
 
+    if matches.is_present("impl-default-via-zeroed") {
+        builder = builder.impl_default_via_zeroed(true);
+    }
+
+    if matches.is_present("no-impl-default-via-zeroed") {
+        builder = builder.impl_default_via_zeroed(false);
+    }
+
+    if matches.is_present("impl-debug-for-unions") {
+        builder = builder.impl_debug_for_unions(true);
+    }
+
+    if matches.is_present("impl-debug-for-long-arrays") {
+        builder = builder.impl_debug_for_long_arrays(true);
+    }
+
     if matches.is_present("no-prepend-enum-name") {
         builder = builder.prepend_enum_name(false);
     }
 
+    if matches.is_present("no-inline-wrappers") {
+        builder = builder.inline_wrappers(false);
+    }
+
     if let Some(prefix) = matches.value_of("ctypes-prefix") {
         builder = builder.ctypes_prefix(prefix);
     }
 
+    if matches.is_present("use-libc") {
+        builder = builder.use_libc(true);
+    }
+
     if let Some(dummy) = matches.value_of("dummy-uses") {
         builder = builder.dummy_uses(dummy);
     }
@@ -281,6 +767,16 @@ pub fn builder_from_flags<I>
         builder = builder.emit_clang_ast();
     }
 
+    if let Some(regexes) = matches.values_of("emit-clang-ast-for") {
+        for regex in regexes {
+            builder = builder.emit_ast_for(regex);
+        }
+    }
+
+    if let Some(path) = matches.value_of("emit-clang-ast-output") {
+        builder = builder.emit_ast_output(path);
+    }
+
     if matches.is_present("emit-ir") {
         builder = builder.emit_ir();
     }
@@ -293,6 +789,22 @@ pub fn builder_from_flags<I>
         builder = builder.enable_cxx_namespaces();
     }
 
+    if let Some(name) = matches.value_of("root-module-name") {
+        builder = builder.root_module_name(name);
+    }
+
+    if matches.is_present("inline-root-module") {
+        builder = builder.inline_root_module();
+    }
+
+    if matches.is_present("emit-flat-aliases") {
+        builder = builder.emit_flat_aliases();
+    }
+
+    if matches.is_present("emit-source-annotations") {
+        builder = builder.emit_source_annotations(true);
+    }
+
     if matches.is_present("disable-name-namespacing") {
         builder = builder.disable_name_namespacing();
     }
@@ -315,10 +827,26 @@ pub fn builder_from_flags<I>
         builder = builder.no_unstable_rust();
     }
 
+    if matches.is_present("thread-local-statics") {
+        builder = builder.thread_local_statics(true);
+    }
+
+    if matches.is_present("c-string-constants") {
+        builder = builder.c_string_constants(true);
+    }
+
+    if let Some(lib) = matches.value_of("dynamic-link-name") {
+        builder = builder.dynamic_link_name(lib);
+    }
+
     if matches.is_present("no-convert-floats") {
         builder = builder.no_convert_floats();
     }
 
+    if matches.is_present("no-convert-stdint-types") {
+        builder = builder.no_convert_stdint_types();
+    }
+
     if matches.is_present("no-doc-comments") {
         builder = builder.generate_comments(false);
     }
@@ -327,28 +855,124 @@ pub fn builder_from_flags<I>
         builder = builder.whitelist_recursively(false);
     }
 
+    if matches.is_present("no-objc") {
+        builder = builder.enable_objc(false);
+    }
+
     if let Some(opaque_types) = matches.values_of("opaque-type") {
         for ty in opaque_types {
             builder = builder.opaque_type(ty);
         }
     }
 
+    if matches.is_present("enable-stl-blobs") {
+        builder = builder.enable_stl_blobs(true);
+    }
+
+    if matches.is_present("const-generics") {
+        builder = builder.const_generics(true);
+    }
+
+    if let Some(send_types) = matches.values_of("send-type") {
+        for regex in send_types {
+            builder = builder.send_type(regex);
+        }
+    }
+
+    if let Some(sync_types) = matches.values_of("sync-type") {
+        for regex in sync_types {
+            builder = builder.sync_type(regex);
+        }
+    }
+
+    if let Some(impls) = matches.values_of("impl-trait") {
+        let impls: Vec<_> = impls.collect();
+        for pair in impls.chunks(2) {
+            builder = builder.add_impl_for(pair[0], pair[1]);
+        }
+    }
+
+    if let Some(variation) = matches.value_of("default-alias-style") {
+        builder = builder.default_alias_style(variation.parse().unwrap());
+    }
+
+    if let Some(aliases) = matches.values_of("type-alias") {
+        for regex in aliases {
+            builder = builder.type_alias(regex);
+        }
+    }
+
+    if let Some(aliases) = matches.values_of("new-type-alias") {
+        for regex in aliases {
+            builder = builder.new_type_alias(regex);
+        }
+    }
+
+    if let Some(aliases) = matches.values_of("new-type-alias-deref") {
+        for regex in aliases {
+            builder = builder.new_type_alias_deref(regex);
+        }
+    }
+
+    if matches.is_present("newtype-conversions") {
+        builder = builder.newtype_conversions(true);
+    }
+
     if let Some(lines) = matches.values_of("raw-line") {
         for line in lines {
             builder = builder.raw_line(line);
         }
     }
 
+    if let Some(cfgs) = matches.values_of("wrap-in-cfg") {
+        let cfgs: Vec<_> = cfgs.collect();
+        for pair in cfgs.chunks(2) {
+            builder = builder.wrap_matching_items_in_cfg(pair[0], pair[1]);
+        }
+    }
+
+    if let Some(lints) = matches.values_of("allow") {
+        for lint in lints {
+            builder = builder.allow_attr(lint);
+        }
+    }
+
+    if matches.is_present("allow-triggered-lints") {
+        builder = builder.allow_triggered_lints(true);
+    }
+
+    if matches.is_present("allow-lints-per-item") {
+        builder = builder.allow_lints_per_item(true);
+    }
+
     if let Some(links) = matches.values_of("static") {
         for library in links {
             builder = builder.link_static(library);
         }
     }
 
+    if let Some(targets) = matches.values_of("target") {
+        for triple in targets {
+            builder = builder.target(triple);
+        }
+    }
+
     if matches.is_present("use-core") {
         builder = builder.use_core();
     }
 
+    if matches.is_present("no-std") {
+        builder = builder.no_std();
+    }
+
+    if matches.is_present("no-core-ffi-c-void") {
+        builder = builder.core_ffi_c_void(false);
+    }
+
+    if matches.is_present("no-layout-tests") {
+        builder = builder.layout_tests(false);
+    }
+
     if matches.is_present("distrust-clang-mangling") {
         builder = builder.trust_clang_mangling(false);
     }
@@ -357,6 +981,12 @@ pub fn builder_from_flags<I>
         builder = builder.conservative_inline_namespaces();
     }
 
+    if let Some(namespaces) = matches.values_of("keep-inline-namespace") {
+        for regex in namespaces {
+            builder = builder.keep_inline_namespace(regex);
+        }
+    }
+
     if let Some(whitelist) = matches.values_of("whitelist-function") {
         for regex in whitelist {
             builder = builder.whitelisted_function(regex);
@@ -375,15 +1005,124 @@ pub fn builder_from_flags<I>
         }
     }
 
-    if let Some(args) = matches.values_of("clang-args") {
-        for arg in args {
-            builder = builder.clang_arg(arg);
+    if let Some(whitelist) = matches.values_of("whitelist-file") {
+        for regex in whitelist {
+            builder = builder.whitelisted_file(regex);
         }
     }
 
+    if matches.is_present("only-primary-header") {
+        builder = builder.only_primary_header(true);
+    }
+
+    if matches.is_present("no-parse-warnings") {
+        builder = builder.report_parse_warnings(false);
+    }
+
+    // Don't use stdout, since that's where the bindings may go.
+    builder = builder.diagnostic_callback(Box::new(|severity, message| {
+        let severity = match severity {
+            DiagnosticSeverity::Note => "note",
+            DiagnosticSeverity::Warning => "warning",
+            DiagnosticSeverity::Error => "error",
+        };
+        let _ = writeln!(io::stderr(), "{}: {}", severity, message);
+    }));
+
+    if matches.is_present("ignore-parse-errors") {
+        builder = builder.abort_on_parse_errors(false);
+    }
+
+    if matches.is_present("fail-on-clang-error") {
+        builder = builder.fail_on_clang_error(true);
+    }
+
+    if matches.is_present("strict") {
+        builder = builder.strict(true);
+    }
+
+    if matches.is_present("generate-static-fns-anyway") {
+        builder = builder.generate_static_fns_anyway(true);
+    }
+
+    if matches.is_present("emit-operator-functions") {
+        builder = builder.emit_operator_functions(true);
+    }
+
+    if matches.is_present("no-inline-const-vars") {
+        builder = builder.generate_inline_const_vars(false);
+    }
+
+    if matches.is_present("emit-macro-fns") {
+        builder = builder.emit_macro_fns(true);
+    }
+
+    if matches.is_present("cold-noreturn-functions") {
+        builder = builder.cold_noreturn_functions(true);
+    }
+
+    if matches.is_present("use-array-pointers-in-arguments") {
+        builder = builder.array_pointers_in_arguments(true);
+    }
+
+    if matches.is_present("references-are-nonnull") {
+        builder = builder.references_are_nonnull(true);
+    }
+
+    if matches.is_present("combined-constants-module") {
+        builder = builder.combined_constants_module(true);
+    }
+
+    if matches.is_present("sort-semantically") {
+        builder = builder.sort_semantically(true);
+    }
+
+    if let Some(path) = matches.value_of("precompiled-header") {
+        builder = builder.precompiled_header(path);
+    }
+
+    if let Some(path) = matches.value_of("emit-precompiled-header") {
+        builder = builder.emit_precompiled_header(path);
+    }
+
+    if let Some(args) = matches.values_of("clang-args") {
+        builder = builder.clang_args(args);
+    }
+
+    if matches.is_present("no-include-path-detection") {
+        builder = builder.detect_include_paths(false);
+    }
+
+    if matches.is_present("module-per-header") {
+        builder = builder.module_per_header(true);
+    }
+
+    if let Some(dir) = matches.value_of("split-output") {
+        builder = builder.split_output(dir);
+    }
+
+    if matches.is_present("force") {
+        builder = builder.force_regeneration(true);
+    }
+
+    // Reject the invalid regexes before truncating the output.
+    if let Err(message) = builder.validate() {
+        return Err(Error::new(ErrorKind::InvalidInput, message));
+    }
+
     let output = if let Some(path) = matches.value_of("output") {
-        let file = try!(File::create(path));
-        Box::new(io::BufWriter::new(file)) as Box<io::Write>
+        if matches.is_present("hash-inputs") {
+            builder = builder.hash_inputs_to(path);
+            // We may need to read the previous output back, so don't truncate
+            // it until we actually write to it.
+            Box::new(LazyFile {
+                path: path.to_owned(),
+                file: None,
+            }) as Box<io::Write>
+        } else {
+            let file = try!(File::create(path));
+            Box::new(io::BufWriter::new(file)) as Box<io::Write>
+        }
     } else {
         Box::new(io::BufWriter::new(io::stdout())) as Box<io::Write>
     };
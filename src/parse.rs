@@ -86,6 +86,12 @@ pub trait ClangItemParser: Sized {
                   ctx: &mut BindgenContext)
                   -> Option<ItemId>;
 
+    /// Create a `const` generic parameter for the non-type template parameter
+    /// at the given location, if it can be represented as one.
+    fn const_template_param(location: clang::Cursor,
+                            ctx: &mut BindgenContext)
+                            -> Option<ItemId>;
+
     /// Create a builtin type.
     fn builtin_type(kind: TypeKind,
                     is_const: bool,
@@ -1,12 +1,12 @@
 //! A type that represents the union of a set of regular expressions.
 
-use regex::RegexSet as RxSet;
+use regex::{Error, RegexSet as RxSet};
 
 // Yeah, I'm aware this is sorta crappy, should be cheaper to compile a regex
 // ORing all the patterns, I guess...
 
 /// A dynamic set of regular expressions.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RegexSet {
     items: Vec<String>,
     set: Option<RxSet>,
@@ -39,15 +39,10 @@ impl RegexSet {
     /// Construct a RegexSet from the set of entries we've accumulated.
     ///
     /// Must be called before calling `matches()`, or it will always return
-    /// false.
-    pub fn build(&mut self) {
-        self.set = match RxSet::new(&self.items) {
-            Ok(x) => Some(x),
-            Err(e) => {
-                error!("Invalid regex in {:?}: {:?}", self.items, e);
-                None
-            }
-        }
+    /// false. Returns an error if any of the regexes is invalid.
+    pub fn build(&mut self) -> Result<(), Error> {
+        self.set = Some(try!(RxSet::new(&self.items)));
+        Ok(())
     }
 
     /// Does the given `string` match any of the regexes in this set?
@@ -57,6 +52,51 @@ impl RegexSet {
         let s = string.as_ref();
         self.set.as_ref().map(|set| set.is_match(s)).unwrap_or(false)
     }
+
+    /// Get the index of the last regex in this set matching `string`, if any.
+    pub fn last_match<S>(&self, string: S) -> Option<usize>
+        where S: AsRef<str>,
+    {
+        let s = string.as_ref();
+        self.set.as_ref().and_then(|set| set.matches(s).iter().last())
+    }
+}
+
+/// Pick the style of the last given regex matching any of `names`, out of the
+/// regex `sets` of each style, given the `order` in which the regexes of all
+/// the styles were given.
+///
+/// Along with the style, returns whether regexes of other styles matched too.
+pub fn last_matching_style<V, S>(sets: &[(V, &RegexSet)],
+                                 order: &[V],
+                                 names: &[S])
+                                 -> Option<(V, bool)>
+    where V: Copy + PartialEq,
+          S: AsRef<str>,
+{
+    let mut last: Option<(usize, V)> = None;
+    let mut matching_styles = 0;
+    for &(style, set) in sets {
+        let index = match names.iter()
+            .filter_map(|name| set.last_match(name))
+            .max() {
+            Some(index) => index,
+            None => continue,
+        };
+        matching_styles += 1;
+
+        // Where this regex was given among the regexes of all the styles.
+        let position = order.iter()
+            .enumerate()
+            .filter(|&(_, s)| *s == style)
+            .nth(index)
+            .map_or(index, |(position, _)| position);
+        if last.map_or(true, |(last_position, _)| position >= last_position) {
+            last = Some((position, style));
+        }
+    }
+
+    last.map(|(_, style)| (style, matching_styles > 1))
 }
 
 impl Default for RegexSet {
@@ -0,0 +1,226 @@
+//! Split the generated bindings into one file per top-level module.
+//!
+//! When C++ namespaces are enabled, the whole output lives inside a single
+//! `pub mod root { ... }`, and each module imports that root module with
+//! `use self::super::...::root;` so items can refer to each other with paths
+//! like `root::foo::Bar`.
+//!
+//! When splitting, the contents of the root module go to `mod.rs`, and every
+//! module directly inside of it goes to its own `<name>.rs` file, declared from
+//! `mod.rs` with `pub mod <name>;`. Since the root module is now the module
+//! that `mod.rs` defines, there's no `root` to import anymore, so the root
+//! imports are removed, and `root::...` paths are rewritten to the equivalent
+//! `self::super::...` path relative to the module they appear in.
+
+use std::mem;
+use syntax::ast;
+use syntax::fold::{self, Folder};
+use syntax::ptr::P;
+use syntax::util::small_vector::SmallVector;
+
+/// A source file of the split bindings.
+#[derive(Debug)]
+pub struct SplitFile {
+    /// The name of this file, relative to the output directory.
+    pub name: String,
+    /// The inner attributes of this file.
+    pub attrs: Vec<ast::Attribute>,
+    /// The items of this file.
+    pub module: ast::Mod,
+    /// The modules declared from this file, each of them living in its own
+    /// file.
+    pub submodules: Vec<String>,
+}
+
+/// Split the given generated module into a `mod.rs` file and one file per
+/// top-level module.
+///
+/// Without namespaces, the only top-level modules that get their own file are
+/// the header modules, see `Builder::module_per_header`. Their paths are
+/// already relative to the module they're in, so nothing needs rewriting.
+///
+/// The `mod.rs` file is always the first one.
+pub fn split(module: ast::Mod,
+             namespaced: bool,
+             module_per_header: bool)
+             -> Vec<SplitFile> {
+    let mut root = SplitFile {
+        name: "mod.rs".into(),
+        attrs: vec![],
+        module: ast::Mod {
+            inner: module.inner,
+            items: vec![],
+        },
+        submodules: vec![],
+    };
+
+    if !namespaced {
+        if !module_per_header {
+            root.module.items = module.items;
+            return vec![root];
+        }
+
+        let mut files = vec![root];
+        for item in module.items {
+            if !is_module(&item) {
+                files[0].module.items.push(item);
+                continue;
+            }
+
+            let item = item.unwrap();
+            let name = item.ident.name.as_str().to_string();
+            if let ast::ItemKind::Mod(module) = item.node {
+                files.push(SplitFile {
+                    name: format!("{}.rs", name),
+                    attrs: vec![],
+                    module: module,
+                    submodules: vec![],
+                });
+                files[0].submodules.push(name);
+            }
+        }
+        return files;
+    }
+
+    let mut root_name = None;
+    let mut root_items = vec![];
+    for item in module.items {
+        if root_name.is_some() || !is_module(&item) {
+            root_items.push(item);
+            continue;
+        }
+
+        let item = item.unwrap();
+        root_name = Some(item.ident.name);
+        root.attrs = item.attrs
+            .into_iter()
+            .map(|mut attr| {
+                attr.style = ast::AttrStyle::Inner;
+                attr
+            })
+            .collect();
+        if let ast::ItemKind::Mod(module) = item.node {
+            root_items.extend(module.items);
+        }
+    }
+
+    let root_name = match root_name {
+        Some(name) => name,
+        None => {
+            root.module.items = root_items;
+            return vec![root];
+        }
+    };
+
+    let mut files = vec![];
+    for item in root_items {
+        if !is_module(&item) {
+            let mut rewriter = RootPathRewriter::new(root_name, 0);
+            root.module.items.extend(rewriter.fold_item(item));
+            continue;
+        }
+
+        let item = item.unwrap();
+        let name = item.ident.name.as_str().to_string();
+        let module = match item.node {
+            ast::ItemKind::Mod(module) => module,
+            _ => unreachable!(),
+        };
+
+        let mut rewriter = RootPathRewriter::new(root_name, 1);
+        let module = ast::Mod {
+            inner: module.inner,
+            items: module.items
+                .into_iter()
+                .flat_map(|item| rewriter.fold_item(item))
+                .collect(),
+        };
+
+        files.push(SplitFile {
+            name: format!("{}.rs", name),
+            attrs: vec![],
+            module: module,
+            submodules: vec![],
+        });
+        root.submodules.push(name);
+    }
+
+    files.insert(0, root);
+    files
+}
+
+fn is_module(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Mod(..) => true,
+        _ => false,
+    }
+}
+
+/// Removes the root module imports, and rewrites the `root::...` paths to be
+/// relative to the module they're in.
+struct RootPathRewriter {
+    root: ast::Name,
+    /// How many modules below the root module we currently are.
+    depth: usize,
+}
+
+impl RootPathRewriter {
+    fn new(root: ast::Name, depth: usize) -> Self {
+        RootPathRewriter {
+            root: root,
+            depth: depth,
+        }
+    }
+
+    fn is_root_import(&self, item: &ast::Item) -> bool {
+        match item.node {
+            ast::ItemKind::Use(ref view_path) => {
+                match view_path.node {
+                    ast::ViewPathSimple(ident, _) => ident.name == self.root,
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Folder for RootPathRewriter {
+    fn fold_item(&mut self, item: P<ast::Item>) -> SmallVector<P<ast::Item>> {
+        if self.is_root_import(&item) {
+            return SmallVector::zero();
+        }
+        fold::noop_fold_item(item, self)
+    }
+
+    fn fold_mod(&mut self, module: ast::Mod) -> ast::Mod {
+        self.depth += 1;
+        let module = fold::noop_fold_mod(module, self);
+        self.depth -= 1;
+        module
+    }
+
+    fn fold_path(&mut self, path: ast::Path) -> ast::Path {
+        let mut path = fold::noop_fold_path(path, self);
+
+        let is_root_path = path.segments.len() > 1 &&
+                           path.segments[0].identifier.name == self.root;
+        if !is_root_path {
+            return path;
+        }
+
+        let rest = mem::replace(&mut path.segments, vec![]);
+        path.segments.push(ast::Ident::from_str("self").into());
+        for _ in 0..self.depth {
+            path.segments.push(ast::Ident::from_str("super").into());
+        }
+        path.segments.extend(rest.into_iter().skip(1));
+        path
+    }
+
+    fn fold_mac(&mut self, mac: ast::Mac) -> ast::Mac {
+        // The only macros we generate are the layout test assertions, which
+        // only use names local to their module.
+        mac
+    }
+}
@@ -0,0 +1,456 @@
+//! Merge the bindings generated for several target triples into a single set
+//! of bindings.
+//!
+//! Some types have different layouts on different targets (think of `long` or
+//! `wchar_t`), so a single set of bindings can't be correct everywhere. When
+//! generating for multiple targets, we generate the bindings for each of them,
+//! and then merge the results: items that are identical across all the targets
+//! are emitted once, and the rest are emitted once per variant, gated behind a
+//! `#[cfg(...)]` attribute matching the targets they were generated for.
+//!
+//! Items are matched across targets by their pretty-printed source, aligning
+//! the item lists of each target with a longest common subsequence, so the
+//! variants of an item end up next to each other. Modules are matched by name,
+//! and their contents merged recursively.
+//!
+//! The bindings of several headers parsed independently are combined in a
+//! simpler way: they have no variants to gate, so we only keep the first item
+//! with a given identity, see `dedup`.
+
+use aster;
+use std::cmp;
+use std::collections::HashSet;
+use std::env;
+use std::mem;
+use syntax::ast;
+use syntax::print::pprust;
+use syntax::ptr::P;
+
+/// An item of the merged bindings, along with the targets it was generated
+/// for.
+struct MergedItem {
+    /// What we use to match this item across targets.
+    key: String,
+    /// The variant of this item for each target in `targets`. They're all
+    /// the same, unless this is a module.
+    items: Vec<P<ast::Item>>,
+    /// The indices of the targets this item was generated for.
+    targets: Vec<usize>,
+}
+
+/// Merge the items generated for each target in `triples` into a single list
+/// of items.
+///
+/// `per_target` contains the items generated for each target, in the same
+/// order as `triples`.
+pub fn merge(triples: &[String],
+             per_target: Vec<Vec<P<ast::Item>>>)
+             -> Vec<P<ast::Item>> {
+    let cfgs: Vec<_> = triples.iter().map(|t| target_cfg(t)).collect();
+    let targets: Vec<_> = (0..per_target.len()).collect();
+    merge_items(&cfgs, &targets, per_target)
+}
+
+/// Merge the items generated for several independently parsed headers into a
+/// single list of items, keeping a single copy of the items generated for more
+/// than one of them.
+///
+/// Items are the same if they define the same name in the same namespace, or
+/// implement the same trait for the same type, wherever they appear, since a
+/// header that includes another generates the same items for it, but not
+/// necessarily in the same order. Modules with the same name are merged, and
+/// so are the `extern` blocks of each module.
+pub fn dedup(per_header: Vec<Vec<P<ast::Item>>>) -> Vec<P<ast::Item>> {
+    let mut result = vec![];
+    let mut seen = HashSet::new();
+    for items in per_header {
+        dedup_into(&mut result, &mut seen, items);
+    }
+    result
+}
+
+fn dedup_into(result: &mut Vec<P<ast::Item>>,
+              seen: &mut HashSet<String>,
+              items: Vec<P<ast::Item>>) {
+    for item in items {
+        match item.node {
+            ast::ItemKind::Mod(..) => {
+                let existing = result.iter().position(|other| {
+                    is_module(other) && other.ident == item.ident
+                });
+                let existing = match existing {
+                    Some(existing) => existing,
+                    None => {
+                        result.push(item);
+                        continue;
+                    }
+                };
+
+                let items = module_items(item);
+                let module = result.remove(existing).map(|mut module| {
+                    if let ast::ItemKind::Mod(ref mut module) = module.node {
+                        let mut seen = module.items
+                            .iter()
+                            .flat_map(|item| item_identities(item))
+                            .collect();
+                        dedup_into(&mut module.items, &mut seen, items);
+                    }
+                    module
+                });
+                result.insert(existing, module);
+            }
+            ast::ItemKind::ForeignMod(..) => {
+                let item = item.map(|mut item| {
+                    if let ast::ItemKind::ForeignMod(ref mut foreign) =
+                        item.node {
+                        let items = mem::replace(&mut foreign.items, vec![]);
+                        foreign.items = items.into_iter()
+                            .filter(|foreign_item| {
+                                seen.insert(foreign_item_identity(foreign_item))
+                            })
+                            .collect();
+                    }
+                    item
+                });
+                let is_empty = match item.node {
+                    ast::ItemKind::ForeignMod(ref foreign) => {
+                        foreign.items.is_empty()
+                    }
+                    _ => unreachable!(),
+                };
+                if !is_empty {
+                    result.push(item);
+                }
+            }
+            _ => {
+                if seen.insert(item_identity(&item)) {
+                    result.push(item);
+                }
+            }
+        }
+    }
+}
+
+fn is_module(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Mod(..) => true,
+        _ => false,
+    }
+}
+
+fn module_items(module: P<ast::Item>) -> Vec<P<ast::Item>> {
+    match module.unwrap().node {
+        ast::ItemKind::Mod(module) => module.items,
+        _ => unreachable!(),
+    }
+}
+
+/// The identities of the given item, as `dedup` sees them: its own, or the
+/// ones of the items of an `extern` block.
+fn item_identities(item: &ast::Item) -> Vec<String> {
+    match item.node {
+        ast::ItemKind::ForeignMod(ref foreign) => {
+            foreign.items.iter().map(foreign_item_identity).collect()
+        }
+        _ => vec![item_identity(item)],
+    }
+}
+
+/// What tells the given item apart from the other items of the same module.
+fn item_identity(item: &ast::Item) -> String {
+    let name = item.ident.name.as_str();
+    match item.node {
+        ast::ItemKind::Struct(..) |
+        ast::ItemKind::Union(..) |
+        ast::ItemKind::Enum(..) |
+        ast::ItemKind::Ty(..) |
+        ast::ItemKind::Trait(..) |
+        ast::ItemKind::Mod(..) => format!("type {}", name),
+        ast::ItemKind::Fn(..) |
+        ast::ItemKind::Const(..) |
+        ast::ItemKind::Static(..) => format!("value {}", name),
+        ast::ItemKind::Impl(_, _, _, ref trait_, ref ty, _) => {
+            format!("impl {} for {}",
+                    trait_.as_ref()
+                        .map_or(String::new(),
+                                |t| pprust::path_to_string(&t.path)),
+                    pprust::ty_to_string(ty))
+        }
+        // Uses and whatnot have nothing but their contents to tell them apart.
+        _ => pprust::item_to_string(item),
+    }
+}
+
+fn foreign_item_identity(item: &ast::ForeignItem) -> String {
+    format!("value {}", item.ident.name.as_str())
+}
+
+/// Merge the items of each target in `targets`, gating the ones that aren't
+/// present in all of them with the matching `cfgs`.
+fn merge_items(cfgs: &[ast::MetaItem],
+               targets: &[usize],
+               per_target: Vec<Vec<P<ast::Item>>>)
+               -> Vec<P<ast::Item>> {
+    let mut merged: Vec<MergedItem> = vec![];
+    for (target, items) in targets.iter().cloned().zip(per_target) {
+        merged = merge_target(merged, target, items);
+    }
+
+    merged.into_iter()
+        .map(|merged| {
+            let is_module = match merged.items[0].node {
+                ast::ItemKind::Mod(..) => true,
+                _ => false,
+            };
+
+            let item = if is_module {
+                merge_modules(cfgs, &merged.targets, merged.items)
+            } else {
+                merged.items.into_iter().next().unwrap()
+            };
+
+            // If the item is present in all the targets at this level, there's
+            // no need to gate it any further.
+            if merged.targets.len() == targets.len() {
+                return item;
+            }
+
+            let cfg = any_of(merged.targets
+                .iter()
+                .map(|t| cfgs[*t].clone())
+                .collect());
+            let cfg = aster::AstBuilder::new()
+                .attr()
+                .list("cfg")
+                .with_meta_item(cfg)
+                .build();
+            item.map(|mut item| {
+                item.attrs.insert(0, cfg);
+                item
+            })
+        })
+        .collect()
+}
+
+/// Merge the contents of the variants of a module for the given targets.
+fn merge_modules(cfgs: &[ast::MetaItem],
+                 targets: &[usize],
+                 variants: Vec<P<ast::Item>>)
+                 -> P<ast::Item> {
+    let mut contents = vec![];
+    let mut first = None;
+    for variant in variants {
+        let variant = variant.map(|mut item| {
+            match item.node {
+                ast::ItemKind::Mod(ref mut module) => {
+                    contents.push(mem::replace(&mut module.items, vec![]));
+                }
+                _ => unreachable!(),
+            }
+            item
+        });
+        if first.is_none() {
+            first = Some(variant);
+        }
+    }
+
+    let items = merge_items(cfgs, targets, contents);
+    first.unwrap().map(|mut item| {
+        if let ast::ItemKind::Mod(ref mut module) = item.node {
+            module.items = items;
+        }
+        item
+    })
+}
+
+/// Merge the items of another target into the already merged items, aligning
+/// both lists by their longest common subsequence.
+fn merge_target(merged: Vec<MergedItem>,
+                target: usize,
+                items: Vec<P<ast::Item>>)
+                -> Vec<MergedItem> {
+    let keys: Vec<_> = items.iter().map(|item| item_key(item)).collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of
+    // `merged[i..]` and `keys[j..]`.
+    let mut lcs = vec![vec![0usize; keys.len() + 1]; merged.len() + 1];
+    for i in (0..merged.len()).rev() {
+        for j in (0..keys.len()).rev() {
+            lcs[i][j] = if merged[i].key == keys[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(merged.len() + items.len());
+    let mut merged = merged.into_iter().peekable();
+    let mut items = keys.into_iter().zip(items).peekable();
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let matches = match (merged.peek(), items.peek()) {
+            (Some(m), Some(&(ref key, _))) => m.key == *key,
+            (None, None) => break,
+            _ => false,
+        };
+
+        if matches {
+            let mut m = merged.next().unwrap();
+            let (_, item) = items.next().unwrap();
+            m.items.push(item);
+            m.targets.push(target);
+            result.push(m);
+            i += 1;
+            j += 1;
+        } else if items.peek().is_none() ||
+                  (merged.peek().is_some() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            result.push(merged.next().unwrap());
+            i += 1;
+        } else {
+            let (key, item) = items.next().unwrap();
+            result.push(MergedItem {
+                key: key,
+                items: vec![item],
+                targets: vec![target],
+            });
+            j += 1;
+        }
+    }
+
+    result
+}
+
+fn item_key(item: &ast::Item) -> String {
+    match item.node {
+        ast::ItemKind::Mod(..) => format!("mod {}", item.ident.name.as_str()),
+        _ => pprust::item_to_string(item),
+    }
+}
+
+fn any_of(mut cfgs: Vec<ast::MetaItem>) -> ast::MetaItem {
+    if cfgs.len() == 1 {
+        return cfgs.pop().unwrap();
+    }
+
+    aster::attr::AttrListBuilder::with_callback("any",
+                                                aster::invoke::Identity)
+        .with_meta_items(cfgs)
+        .build()
+}
+
+/// Get the Rust `target_arch` of the given target triple.
+fn target_arch(triple: &str) -> &str {
+    match triple.split('-').next().unwrap_or("") {
+        "i386" | "i486" | "i586" | "i686" => "x86",
+        arch if arch.starts_with("arm") || arch.starts_with("thumb") => "arm",
+        arch => arch,
+    }
+}
+
+/// Get the Rust `target_pointer_width` of the given target triple.
+fn target_pointer_width(triple: &str) -> &'static str {
+    // The x32 ABI uses 32-bit pointers on x86_64.
+    if triple.ends_with("x32") {
+        return "32";
+    }
+
+    match target_arch(triple) {
+        "x86_64" | "aarch64" | "powerpc64" | "powerpc64le" | "mips64" |
+        "mips64el" | "s390x" | "sparc64" => "64",
+        "msp430" | "avr" => "16",
+        _ => "32",
+    }
+}
+
+/// Get the size of a pointer in bytes on the given target triple.
+pub fn pointer_size(triple: &str) -> usize {
+    match target_pointer_width(triple) {
+        "64" => 8,
+        "16" => 2,
+        _ => 4,
+    }
+}
+
+/// Whether the given target triple is an MSVC one. Clang defaults to the MSVC
+/// environment for Windows, so `x86_64-pc-win32` is one too.
+pub fn is_msvc(triple: &str) -> bool {
+    let components: Vec<_> = triple.split('-').skip(1).collect();
+    let has = |name: &str| components.iter().any(|c| c.starts_with(name));
+    has("msvc") ||
+    ((has("windows") || has("win32")) && !has("gnu") && !has("cygnus"))
+}
+
+/// Get the `cfg` predicate that restricts the layout tests to the given
+/// target triple, since the layouts may differ for other targets: something
+/// like `all(target_arch = "x86", target_pointer_width = "32")`.
+pub fn layout_test_cfg(triple: &str) -> ast::MetaItem {
+    arch_cfg(target_arch(triple), target_pointer_width(triple))
+}
+
+/// Like `layout_test_cfg`, but for the target bindgen itself runs on.
+pub fn host_layout_test_cfg() -> ast::MetaItem {
+    let pointer_width = (mem::size_of::<usize>() * 8).to_string();
+    arch_cfg(env::consts::ARCH, &pointer_width)
+}
+
+fn arch_cfg(arch: &str, pointer_width: &str) -> ast::MetaItem {
+    aster::attr::AttrListBuilder::with_callback("all",
+                                                aster::invoke::Identity)
+        .name_value("target_arch")
+        .str(arch)
+        .name_value("target_pointer_width")
+        .str(pointer_width)
+        .build()
+}
+
+/// Get the `cfg` predicate that matches the given target triple, that is,
+/// something like `all(target_arch = "x86_64", target_os = "linux")`.
+fn target_cfg(triple: &str) -> ast::MetaItem {
+    let arch = target_arch(triple);
+    let rest: Vec<_> = triple.split('-').skip(1).collect();
+    let has = |name: &str| rest.iter().any(|c| c.starts_with(name));
+
+    let os = if has("android") {
+        Some("android")
+    } else if has("linux") {
+        Some("linux")
+    } else if has("windows") {
+        Some("windows")
+    } else if has("darwin") || has("macos") {
+        Some("macos")
+    } else if has("ios") {
+        Some("ios")
+    } else if has("freebsd") {
+        Some("freebsd")
+    } else if has("netbsd") {
+        Some("netbsd")
+    } else if has("openbsd") {
+        Some("openbsd")
+    } else {
+        None
+    };
+
+    let env = if has("msvc") {
+        Some("msvc")
+    } else if has("musl") {
+        Some("musl")
+    } else if has("gnu") {
+        Some("gnu")
+    } else {
+        None
+    };
+
+    let mut builder =
+        aster::attr::AttrListBuilder::with_callback("all",
+                                                    aster::invoke::Identity)
+            .name_value("target_arch")
+            .str(arch);
+    if let Some(os) = os {
+        builder = builder.name_value("target_os").str(os);
+    }
+    if let Some(env) = env {
+        builder = builder.name_value("target_env").str(env);
+    }
+    builder.build()
+}
@@ -69,6 +69,9 @@ pub fn generate_dummy_uses<W>(ctx: &mut BindgenContext,
         try!(writeln!(dest, "/* automatically generated by rust-bindgen */"));
         try!(writeln!(dest, ""));
         try!(writeln!(dest, "#include \"{}\"", input_header));
+        for header in &ctx.options().extra_input_headers {
+            try!(writeln!(dest, "#include \"{}\"", header));
+        }
         try!(writeln!(dest, ""));
 
         let type_items = ctx.whitelisted_items()
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -95,6 +99,24 @@ fn bindgen_test_layout_rte_ipv4_tuple__bindgen_ty_1() {
 impl Clone for rte_ipv4_tuple__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_ipv4_tuple__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &rte_ipv4_tuple__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut rte_ipv4_tuple__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn sctp_tag(&self) -> &u32 { self.sctp_tag.as_ref() }
+    #[inline]
+    pub unsafe fn sctp_tag_mut(&mut self) -> &mut u32 {
+        self.sctp_tag.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_rte_ipv4_tuple() {
     assert_eq!(::std::mem::size_of::<rte_ipv4_tuple>() , 12usize , concat ! (
@@ -182,6 +204,24 @@ fn bindgen_test_layout_rte_ipv6_tuple__bindgen_ty_1() {
 impl Clone for rte_ipv6_tuple__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_ipv6_tuple__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &rte_ipv6_tuple__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut rte_ipv6_tuple__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn sctp_tag(&self) -> &u32 { self.sctp_tag.as_ref() }
+    #[inline]
+    pub unsafe fn sctp_tag_mut(&mut self) -> &mut u32 {
+        self.sctp_tag.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_rte_ipv6_tuple() {
     assert_eq!(::std::mem::size_of::<rte_ipv6_tuple>() , 36usize , concat ! (
@@ -202,7 +242,7 @@ fn bindgen_test_layout_rte_ipv6_tuple() {
 impl Clone for rte_ipv6_tuple {
     fn clone(&self) -> Self { *self }
 }
-#[repr(C)]
+#[repr(C, align(16))]
 #[derive(Copy)]
 pub struct rte_thash_tuple {
     pub v4: __BindgenUnionField<rte_ipv4_tuple>,
@@ -230,3 +270,17 @@ impl Clone for rte_thash_tuple {
 impl Default for rte_thash_tuple {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+impl rte_thash_tuple {
+    #[inline]
+    pub unsafe fn v4(&self) -> &rte_ipv4_tuple { self.v4.as_ref() }
+    #[inline]
+    pub unsafe fn v4_mut(&mut self) -> &mut rte_ipv4_tuple {
+        self.v4.as_mut()
+    }
+    #[inline]
+    pub unsafe fn v6(&self) -> &rte_ipv6_tuple { self.v6.as_ref() }
+    #[inline]
+    pub unsafe fn v6_mut(&mut self) -> &mut rte_ipv6_tuple {
+        self.v6.as_mut()
+    }
+}
@@ -0,0 +1,9 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_upper_case_globals)]
+
+
+#![allow(non_snake_case)]
+
+
+pub const some_constant: ::std::os::raw::c_int = 1;
@@ -0,0 +1,48 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Good {
+    pub foo: ::std::os::raw::c_int,
+}
+#[test]
+#[allow(non_snake_case)]
+fn bindgen_test_layout_Good() {
+    assert_eq!(::std::mem::size_of::<Good>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Good ) ));
+    assert_eq! (::std::mem::align_of::<Good>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Good ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Good ) ) . foo as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Good ) , "::" ,
+                stringify ! ( foo ) ));
+}
+impl Clone for Good {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+#[allow(non_camel_case_types, non_snake_case)]
+pub struct lower_struct {
+    pub X: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_lower_struct() {
+    assert_eq!(::std::mem::size_of::<lower_struct>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( lower_struct ) ));
+    assert_eq! (::std::mem::align_of::<lower_struct>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( lower_struct ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const lower_struct ) ) . X as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( lower_struct ) , "::" ,
+                stringify ! ( X ) ));
+}
+impl Clone for lower_struct {
+    fn clone(&self) -> Self { *self }
+}
@@ -0,0 +1,13 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+
+#![allow(non_snake_case)]
+
+
+pub type my_int = ::std::os::raw::c_int;
+pub const some_constant: ::std::os::raw::c_int = 1;
+extern "C" {
+    pub fn DoThing();
+}
@@ -14,6 +14,14 @@ pub const Test_T_NONE: Test__bindgen_ty_1 = Test__bindgen_ty_1::T_NONE;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Test__bindgen_ty_1 { T_NONE = 0, }
+impl From<Test__bindgen_ty_1> for u32 {
+    fn from(value: Test__bindgen_ty_1) -> Self { value as u32 }
+}
+impl Test__bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(Test__bindgen_ty_1::T_NONE), _ => None, }
+    }
+}
 #[test]
 fn bindgen_test_layout_Test() {
     assert_eq!(::std::mem::size_of::<Test>() , 8usize , concat ! (
@@ -37,3 +45,11 @@ impl Clone for Test {
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Baz { Foo = 0, Bar = 1, }
+impl From<Baz> for u32 {
+    fn from(value: Baz) -> Self { value as u32 }
+}
+impl Baz {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(Baz::Foo), 1 => Some(Baz::Bar), _ => None, }
+    }
+}
@@ -26,6 +26,17 @@ pub const DataType_type_: DataType__bindgen_ty_1 =
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum DataType__bindgen_ty_1 { generic_type = 0, }
+impl From<DataType__bindgen_ty_1> for i32 {
+    fn from(value: DataType__bindgen_ty_1) -> Self { value as i32 }
+}
+impl DataType__bindgen_ty_1 {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(DataType__bindgen_ty_1::generic_type),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct Foo {
@@ -36,6 +47,14 @@ pub const Foo_Baz: Foo__bindgen_ty_1 = Foo__bindgen_ty_1::Bar;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo__bindgen_ty_1 { Bar = 0, }
+impl From<Foo__bindgen_ty_1> for u32 {
+    fn from(value: Foo__bindgen_ty_1) -> Self { value as u32 }
+}
+impl Foo__bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(Foo__bindgen_ty_1::Bar), _ => None, }
+    }
+}
 #[test]
 fn bindgen_test_layout_Foo() {
     assert_eq!(::std::mem::size_of::<Foo>() , 1usize , concat ! (
@@ -9,3 +9,15 @@ pub const NODE_FLAG_BAR: _bindgen_ty_1 = _bindgen_ty_1::NODE_FLAG_BAR;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 { NODE_FLAG_FOO = 0, NODE_FLAG_BAR = 1, }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(_bindgen_ty_1::NODE_FLAG_FOO),
+            1 => Some(_bindgen_ty_1::NODE_FLAG_BAR),
+            _ => None,
+        }
+    }
+}
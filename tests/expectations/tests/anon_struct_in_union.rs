@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -76,6 +80,16 @@ fn bindgen_test_layout_s__bindgen_ty_1() {
 impl Clone for s__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl s__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn field(&self) -> &s__bindgen_ty_1_inner {
+        self.field.as_ref()
+    }
+    #[inline]
+    pub unsafe fn field_mut(&mut self) -> &mut s__bindgen_ty_1_inner {
+        self.field.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_s() {
     assert_eq!(::std::mem::size_of::<s>() , 4usize , concat ! (
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -41,6 +45,17 @@ pub const TErrorResult_UnionState_HasException: TErrorResult_UnionState =
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum TErrorResult_UnionState { HasMessage = 0, }
+impl From<TErrorResult_UnionState> for i32 {
+    fn from(value: TErrorResult_UnionState) -> Self { value as i32 }
+}
+impl TErrorResult_UnionState {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(TErrorResult_UnionState::HasMessage),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct TErrorResult_Message {
@@ -58,6 +73,26 @@ pub struct TErrorResult__bindgen_ty_1 {
     pub mDOMExceptionInfo: __BindgenUnionField<*mut TErrorResult_DOMExceptionInfo>,
     pub bindgen_union_field: u64,
 }
+impl TErrorResult__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn mMessage(&self) -> &*mut TErrorResult_Message {
+        self.mMessage.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mMessage_mut(&mut self) -> &mut *mut TErrorResult_Message {
+        self.mMessage.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mDOMExceptionInfo(&self)
+     -> &*mut TErrorResult_DOMExceptionInfo {
+        self.mDOMExceptionInfo.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mDOMExceptionInfo_mut(&mut self)
+     -> &mut *mut TErrorResult_DOMExceptionInfo {
+        self.mDOMExceptionInfo.as_mut()
+    }
+}
 impl Default for TErrorResult {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
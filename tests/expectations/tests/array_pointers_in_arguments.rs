@@ -0,0 +1,53 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    pub fn constant(name: *const [::std::os::raw::c_schar; 8usize]);
+}
+extern "C" {
+    pub fn matrix(m: *mut [[f32; 4usize]; 4usize]);
+}
+extern "C" {
+    pub fn sized(arr: *mut [::std::os::raw::c_int; 16usize]);
+}
+extern "C" {
+    pub fn unbounded(arr: *mut ::std::os::raw::c_int);
+}
+pub type handler_t =
+    unsafe extern "C" fn(buf: *mut [::std::os::raw::c_uchar; 32usize]);
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Callbacks {
+    pub on_data: ::std::option::Option<unsafe extern "C" fn(data:
+                                                                 *mut [::std::os::raw::c_int; 4usize],
+                                                             len:
+                                                                 ::std::os::raw::c_int)
+                                           -> ::std::os::raw::c_int>,
+    pub handler: ::std::option::Option<handler_t>,
+}
+#[test]
+fn bindgen_test_layout_Callbacks() {
+    assert_eq!(::std::mem::size_of::<Callbacks>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Callbacks ) ));
+    assert_eq! (::std::mem::align_of::<Callbacks>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Callbacks ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Callbacks ) ) . on_data as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Callbacks ) , "::" ,
+                stringify ! ( on_data ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Callbacks ) ) . handler as * const _ as
+                usize } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Callbacks ) , "::" ,
+                stringify ! ( handler ) ));
+}
+impl Clone for Callbacks {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Callbacks {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -4,11 +4,85 @@
 #![allow(non_snake_case)]
 
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct A {
     pub x: ::std::os::raw::c_uchar,
-    pub _bitfield_1: [u8; 2usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize], u8>,
     pub y: ::std::os::raw::c_uchar,
     pub __bindgen_align: [u32; 0usize],
 }
@@ -33,189 +107,109 @@ impl Clone for A {
 impl A {
     #[inline]
     pub fn b1(&self) -> ::std::os::raw::c_uint {
-        let mask = 1usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b1(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 1usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 1u8, val)
     }
     #[inline]
     pub fn b2(&self) -> ::std::os::raw::c_uint {
-        let mask = 2usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 1usize;
+        let val = self._bitfield_1.get(1usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b2(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 2usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 1usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(1usize, 1u8, val)
     }
     #[inline]
     pub fn b3(&self) -> ::std::os::raw::c_uint {
-        let mask = 4usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 2usize;
+        let val = self._bitfield_1.get(2usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b3(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 4usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 2usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(2usize, 1u8, val)
     }
     #[inline]
     pub fn b4(&self) -> ::std::os::raw::c_uint {
-        let mask = 8usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 3usize;
+        let val = self._bitfield_1.get(3usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b4(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 8usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 3usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(3usize, 1u8, val)
     }
     #[inline]
     pub fn b5(&self) -> ::std::os::raw::c_uint {
-        let mask = 16usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 4usize;
+        let val = self._bitfield_1.get(4usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b5(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 16usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 4usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(4usize, 1u8, val)
     }
     #[inline]
     pub fn b6(&self) -> ::std::os::raw::c_uint {
-        let mask = 32usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 5usize;
+        let val = self._bitfield_1.get(5usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b6(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 32usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 5usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(5usize, 1u8, val)
     }
     #[inline]
     pub fn b7(&self) -> ::std::os::raw::c_uint {
-        let mask = 64usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 6usize;
+        let val = self._bitfield_1.get(6usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b7(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 64usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 6usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(6usize, 1u8, val)
     }
     #[inline]
     pub fn b8(&self) -> ::std::os::raw::c_uint {
-        let mask = 128usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 7usize;
+        let val = self._bitfield_1.get(7usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b8(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 128usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 7usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(7usize, 1u8, val)
     }
     #[inline]
     pub fn b9(&self) -> ::std::os::raw::c_uint {
-        let mask = 256usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 8usize;
+        let val = self._bitfield_1.get(8usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b9(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 256usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 8usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(8usize, 1u8, val)
     }
     #[inline]
     pub fn b10(&self) -> ::std::os::raw::c_uint {
-        let mask = 512usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 9usize;
+        let val = self._bitfield_1.get(9usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b10(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 512usize as u16;
-        let val = val as u32 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 9usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(9usize, 1u8, val)
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct B {
-    pub _bitfield_1: u32,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize], u32>,
     pub __bindgen_align: [u32; 0usize],
 }
 #[test]
@@ -231,46 +225,30 @@ impl Clone for B {
 impl B {
     #[inline]
     pub fn foo(&self) -> ::std::os::raw::c_uint {
-        let mask = 2147483647usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 31u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_foo(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 2147483647usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 31u8, val)
     }
     #[inline]
     pub fn bar(&self) -> ::std::os::raw::c_uchar {
-        let mask = 2147483648usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 31usize;
+        let val = self._bitfield_1.get(31usize, 1u8);
         unsafe { ::std::mem::transmute(val as u8) }
     }
     #[inline]
     pub fn set_bar(&mut self, val: ::std::os::raw::c_uchar) {
-        let mask = 2147483648usize as u32;
-        let val = val as u8 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 31usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u8 as u64;
+        self._bitfield_1.set(31usize, 1u8, val)
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct C {
     pub x: ::std::os::raw::c_uchar,
-    pub _bitfield_1: u8,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize], u8>,
     pub baz: ::std::os::raw::c_uint,
 }
 #[test]
@@ -295,46 +273,30 @@ impl Clone for C {
 impl C {
     #[inline]
     pub fn b1(&self) -> ::std::os::raw::c_uint {
-        let mask = 1usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b1(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 1usize as u8;
-        let val = val as u32 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 1u8, val)
     }
     #[inline]
     pub fn b2(&self) -> ::std::os::raw::c_uint {
-        let mask = 2usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 1usize;
+        let val = self._bitfield_1.get(1usize, 1u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_b2(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 2usize as u8;
-        let val = val as u32 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 1usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(1usize, 1u8, val)
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct Date1 {
-    pub _bitfield_1: [u8; 2usize],
-    pub _bitfield_2: u8,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize], u8>,
+    pub _bitfield_2: __BindgenBitfieldUnit<[u8; 1usize], u8>,
     pub __bindgen_align: [u16; 0usize],
 }
 #[test]
@@ -350,82 +312,50 @@ impl Clone for Date1 {
 impl Date1 {
     #[inline]
     pub fn nWeekDay(&self) -> ::std::os::raw::c_ushort {
-        let mask = 7usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 3u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nWeekDay(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 7usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(0usize, 3u8, val)
     }
     #[inline]
     pub fn nMonthDay(&self) -> ::std::os::raw::c_ushort {
-        let mask = 504usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 3usize;
+        let val = self._bitfield_1.get(3usize, 6u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nMonthDay(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 504usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 3usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(3usize, 6u8, val)
     }
     #[inline]
     pub fn nMonth(&self) -> ::std::os::raw::c_ushort {
-        let mask = 15872usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 9usize;
+        let val = self._bitfield_1.get(9usize, 5u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nMonth(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 15872usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 9usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(9usize, 5u8, val)
     }
     #[inline]
     pub fn nYear(&self) -> ::std::os::raw::c_ushort {
-        let mask = 255usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_2) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_2.get(0usize, 8u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nYear(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 255usize as u8;
-        let val = val as u16 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_2) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_2 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_2.set(0usize, 8u8, val)
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct Date2 {
-    pub _bitfield_1: [u8; 2usize],
-    pub _bitfield_2: u8,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize], u8>,
+    pub _bitfield_2: __BindgenBitfieldUnit<[u8; 1usize], u8>,
     pub byte: ::std::os::raw::c_uchar,
     pub __bindgen_align: [u16; 0usize],
 }
@@ -447,74 +377,42 @@ impl Clone for Date2 {
 impl Date2 {
     #[inline]
     pub fn nWeekDay(&self) -> ::std::os::raw::c_ushort {
-        let mask = 7usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 3u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nWeekDay(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 7usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(0usize, 3u8, val)
     }
     #[inline]
     pub fn nMonthDay(&self) -> ::std::os::raw::c_ushort {
-        let mask = 504usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 3usize;
+        let val = self._bitfield_1.get(3usize, 6u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nMonthDay(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 504usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 3usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(3usize, 6u8, val)
     }
     #[inline]
     pub fn nMonth(&self) -> ::std::os::raw::c_ushort {
-        let mask = 15872usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 9usize;
+        let val = self._bitfield_1.get(9usize, 5u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nMonth(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 15872usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 9usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(9usize, 5u8, val)
     }
     #[inline]
     pub fn nYear(&self) -> ::std::os::raw::c_ushort {
-        let mask = 255usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_2) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_2.get(0usize, 8u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_nYear(&mut self, val: ::std::os::raw::c_ushort) {
-        let mask = 255usize as u8;
-        let val = val as u16 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_2) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_2 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_2.set(0usize, 8u8, val)
     }
 }
@@ -4,10 +4,84 @@
 #![allow(non_snake_case)]
 
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct mach_msg_type_descriptor_t {
-    pub _bitfield_1: u32,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize], u32>,
     pub __bindgen_align: [u32; 0usize],
 }
 #[test]
@@ -26,38 +100,22 @@ impl Clone for mach_msg_type_descriptor_t {
 impl mach_msg_type_descriptor_t {
     #[inline]
     pub fn pad3(&self) -> ::std::os::raw::c_uint {
-        let mask = 16777215usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 24u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_pad3(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 16777215usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 24u8, val)
     }
     #[inline]
     pub fn type_(&self) -> ::std::os::raw::c_uint {
-        let mask = 4278190080usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 24usize;
+        let val = self._bitfield_1.get(24usize, 8u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_type(&mut self, val: ::std::os::raw::c_uint) {
-        let mask = 4278190080usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 24usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(24usize, 8u8, val)
     }
 }
@@ -0,0 +1,130 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Flags {
+    pub enabled: u8,
+    pub history: [u8; 3usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize], u8>,
+}
+#[test]
+fn bindgen_test_layout_Flags() {
+    assert_eq!(::std::mem::size_of::<Flags>() , 5usize , concat ! (
+               "Size of: " , stringify ! ( Flags ) ));
+    assert_eq! (::std::mem::align_of::<Flags>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Flags ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Flags ) ) . enabled as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Flags ) , "::" ,
+                stringify ! ( enabled ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Flags ) ) . history as * const _ as usize
+                } , 1usize , concat ! (
+                "Alignment of field: " , stringify ! ( Flags ) , "::" ,
+                stringify ! ( history ) ));
+}
+impl Clone for Flags {
+    fn clone(&self) -> Self { *self }
+}
+impl Flags {
+    #[inline]
+    pub fn a(&self) -> u8 {
+        let val = self._bitfield_1.get(0usize, 1u8);
+        unsafe { ::std::mem::transmute(val as u8) }
+    }
+    #[inline]
+    pub fn set_a(&mut self, val: u8) {
+        let val = val as u8 as u64;
+        self._bitfield_1.set(0usize, 1u8, val)
+    }
+    #[inline]
+    pub fn b(&self) -> u8 {
+        let val = self._bitfield_1.get(1usize, 7u8);
+        unsafe { ::std::mem::transmute(val as u8) }
+    }
+    #[inline]
+    pub fn set_b(&mut self, val: u8) {
+        let val = val as u8 as u64;
+        self._bitfield_1.set(1usize, 7u8, val)
+    }
+}
+pub const VERBOSE: u8 = 1;
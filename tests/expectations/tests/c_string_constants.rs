@@ -0,0 +1,52 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[link_name = "version"]
+    pub static mut version: [::std::os::raw::c_schar; 0usize];
+}
+#[inline]
+pub fn version_cstr() -> &'static ::std::ffi::CStr {
+    unsafe {
+        ::std::ffi::CStr::from_ptr(version.as_ptr() as
+                                       *const ::std::os::raw::c_char)
+    }
+}
+extern "C" {
+    #[link_name = "greeting"]
+    pub static mut greeting: *const ::std::os::raw::c_schar;
+}
+extern "C" {
+    #[link_name = "motto"]
+    pub static mut motto: *const ::std::os::raw::c_schar;
+}
+#[inline]
+pub fn motto_cstr() -> Option<&'static ::std::ffi::CStr> {
+    unsafe {
+        if motto.is_null() {
+            None
+        } else {
+            Some(::std::ffi::CStr::from_ptr(motto as
+                                                *const ::std::os::raw::c_char))
+        }
+    }
+}
+extern "C" {
+    #[link_name = "names"]
+    pub static mut names: [*const ::std::os::raw::c_schar; 3usize];
+}
+#[inline]
+pub fn names_array() -> &'static [*const ::std::os::raw::c_schar; 3usize] {
+    unsafe { &names }
+}
+extern "C" {
+    #[link_name = "counter"]
+    pub static mut counter: ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "buf"]
+    pub static mut buf: [::std::os::raw::c_schar; 16usize];
+}
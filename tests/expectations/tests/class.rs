@@ -26,6 +26,14 @@ impl <T> __IncompleteArrayField<T> {
     pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
         ::std::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
     }
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        &*self.as_ptr().offset(index as isize)
+    }
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        &mut *self.as_mut_ptr().offset(index as isize)
+    }
 }
 impl <T> ::std::fmt::Debug for __IncompleteArrayField<T> {
     fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
@@ -46,6 +54,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -222,6 +234,18 @@ fn bindgen_test_layout_Union() {
 impl Clone for Union {
     fn clone(&self) -> Self { *self }
 }
+impl Union {
+    #[inline]
+    pub unsafe fn d(&self) -> &f32 { self.d.as_ref() }
+    #[inline]
+    pub unsafe fn d_mut(&mut self) -> &mut f32 { self.d.as_mut() }
+    #[inline]
+    pub unsafe fn i(&self) -> &::std::os::raw::c_int { self.i.as_ref() }
+    #[inline]
+    pub unsafe fn i_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.i.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct WithUnion {
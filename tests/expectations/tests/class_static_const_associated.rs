@@ -0,0 +1,45 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Mode { Fast = 0, Slow = 1, }
+impl From<Mode> for i32 {
+    fn from(value: Mode) -> Self { value as i32 }
+}
+impl Mode {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Limits {
+    pub _address: u8,
+}
+extern "C" {
+    #[link_name = "_ZN6Limits7unknownE"]
+    pub static mut Limits_unknown: *const ::std::os::raw::c_int;
+}
+#[test]
+fn bindgen_test_layout_Limits() {
+    assert_eq!(::std::mem::size_of::<Limits>() , 1usize , concat ! (
+               "Size of: " , stringify ! ( Limits ) ));
+    assert_eq! (::std::mem::align_of::<Limits>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Limits ) ));
+}
+impl Clone for Limits {
+    fn clone(&self) -> Self { *self }
+}
+impl Limits {
+    pub const max: ::std::os::raw::c_int = 10;
+    pub const ratio: f64 = 0.5;
+    pub const mode: Mode = Mode::Slow;
+}
@@ -0,0 +1,28 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Buffer {
+    pub len: ::std::os::raw::c_int,
+}
+pub const Buffer_kMax: ::std::os::raw::c_int = 100;
+pub const Buffer_kGrowth: f64 = 1.5;
+#[test]
+fn bindgen_test_layout_Buffer() {
+    assert_eq!(::std::mem::size_of::<Buffer>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Buffer ) ));
+    assert_eq! (::std::mem::align_of::<Buffer>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Buffer ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . len as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( len ) ));
+}
+impl Clone for Buffer {
+    fn clone(&self) -> Self { *self }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -82,6 +86,14 @@ fn bindgen_test_layout_A__bindgen_ty_1() {
 impl Clone for A__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl A__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn f(&self) -> &::std::os::raw::c_int { self.f.as_ref() }
+    #[inline]
+    pub unsafe fn f_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.f.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct A__bindgen_ty_2 {
@@ -103,6 +115,14 @@ fn bindgen_test_layout_A__bindgen_ty_2() {
 impl Clone for A__bindgen_ty_2 {
     fn clone(&self) -> Self { *self }
 }
+impl A__bindgen_ty_2 {
+    #[inline]
+    pub unsafe fn d(&self) -> &::std::os::raw::c_int { self.d.as_ref() }
+    #[inline]
+    pub unsafe fn d_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.d.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_A() {
     assert_eq!(::std::mem::size_of::<A>() , 12usize , concat ! (
@@ -175,6 +195,20 @@ pub enum StepSyntax {
     FunctionalWithStartKeyword = 2,
     FunctionalWithEndKeyword = 3,
 }
+impl From<StepSyntax> for i32 {
+    fn from(value: StepSyntax) -> Self { value as i32 }
+}
+impl StepSyntax {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(StepSyntax::Keyword),
+            1 => Some(StepSyntax::FunctionalWithoutKeyword),
+            2 => Some(StepSyntax::FunctionalWithStartKeyword),
+            3 => Some(StepSyntax::FunctionalWithEndKeyword),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct C {
@@ -282,6 +316,25 @@ fn bindgen_test_layout_C__bindgen_ty_1() {
 impl Clone for C__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl C__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn mFunc(&self) -> &C__bindgen_ty_1__bindgen_ty_1 {
+        self.mFunc.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mFunc_mut(&mut self) -> &mut C__bindgen_ty_1__bindgen_ty_1 {
+        self.mFunc.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self) -> &C__bindgen_ty_1__bindgen_ty_2 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut C__bindgen_ty_1__bindgen_ty_2 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct C_Segment {
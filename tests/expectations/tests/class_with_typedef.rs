@@ -51,10 +51,12 @@ extern "C" {
     pub fn C_method(this: *mut C, c: C_MyInt);
 }
 extern "C" {
+    /// `c`: C++ reference, never null.
     #[link_name = "_ZN1C9methodRefERi"]
     pub fn C_methodRef(this: *mut C, c: *mut C_MyInt);
 }
 extern "C" {
+    /// `c`: C++ reference, never null.
     #[link_name = "_ZN1C16complexMethodRefERPKc"]
     pub fn C_complexMethodRef(this: *mut C, c: *mut C_Lookup);
 }
@@ -0,0 +1,21 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod root {
+    pub mod net {
+        pub const VERSION: ::std::os::raw::c_int = 1;
+        pub const PORT: ::std::os::raw::c_int = 8080;
+    }
+    pub mod disk {
+        pub const VERSION: ::std::os::raw::c_int = 2;
+    }
+}
+pub mod constants {
+    pub use super::root::net::VERSION;
+    pub use super::root::net::PORT;
+    pub use super::root::disk::VERSION as VERSION1;
+}
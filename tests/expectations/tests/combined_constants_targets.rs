@@ -0,0 +1,12 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const LONG_SIZE: ::std::os::raw::c_ulong = 8;
+pub const VERSION: ::std::os::raw::c_int = 1;
+pub mod constants {
+    pub use super::LONG_SIZE;
+    pub use super::VERSION;
+}
@@ -9,6 +9,18 @@ pub const FOO_BAZ: _bindgen_ty_1 = _bindgen_ty_1::FOO_BAZ;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 { FOO_BAR = 0, FOO_BAZ = 1, }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(_bindgen_ty_1::FOO_BAR),
+            1 => Some(_bindgen_ty_1::FOO_BAZ),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct Foo {
@@ -18,6 +30,14 @@ pub const Foo_FOO_BAR: Foo__bindgen_ty_1 = Foo__bindgen_ty_1::FOO_BAR;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo__bindgen_ty_1 { FOO_BAR = 10, }
+impl From<Foo__bindgen_ty_1> for u32 {
+    fn from(value: Foo__bindgen_ty_1) -> Self { value as u32 }
+}
+impl Foo__bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 10 => Some(Foo__bindgen_ty_1::FOO_BAR), _ => None, }
+    }
+}
 #[test]
 fn bindgen_test_layout_Foo() {
     assert_eq!(::std::mem::size_of::<Foo>() , 1usize , concat ! (
@@ -0,0 +1,24 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type size_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Buf<const N: usize> {
+    pub data: [::std::os::raw::c_schar; N],
+}
+impl <const N: usize> Default for Buf<N> {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct Array<T, const N: usize> {
+    pub items: [T; N],
+    pub len: ::std::os::raw::c_uint,
+}
+impl <T, const N: usize> Default for Array<T, N> {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -0,0 +1,26 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type size_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Buf<const N: usize> {
+    pub data: [::std::os::raw::c_schar; N],
+}
+impl <const N: usize> Default for Buf<N> {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Message {
+    pub payload: Buf<16>,
+}
+impl Clone for Message {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Message {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
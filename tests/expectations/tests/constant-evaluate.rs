@@ -9,9 +9,21 @@ pub const bar: _bindgen_ty_1 = _bindgen_ty_1::bar;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 { foo = 4, bar = 8, }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            4 => Some(_bindgen_ty_1::foo),
+            8 => Some(_bindgen_ty_1::bar),
+            _ => None,
+        }
+    }
+}
 pub type EasyToOverflow = ::std::os::raw::c_ulonglong;
 pub const k: EasyToOverflow = 2147483648;
-pub const k_expr: EasyToOverflow = 0;
+pub const k_expr: EasyToOverflow = 1152921504606846976;
 pub const BAZ: ::std::os::raw::c_longlong = 24;
 pub const fuzz: f64 = 51.;
 pub const BAZZ: ::std::os::raw::c_schar = 53;
@@ -0,0 +1,22 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const kSize: ::std::os::raw::c_ulong = 4096;
+pub const Pi: f64 = 3.14159;
+pub const kNegative: ::std::os::raw::c_longlong = -42;
+pub const kMax: ::std::os::raw::c_ulonglong = 18446744073709551615;
+pub const kBig: ::std::os::raw::c_ulonglong = 1152921504606846976;
+pub const kNan: f32 = ::std::f32::NAN;
+pub const kInf: f64 = ::std::f64::INFINITY;
+pub const kNegInf: f64 = ::std::f64::NEG_INFINITY;
+extern "C" {
+    #[link_name = "mutable_global"]
+    pub static mut mutable_global: ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "external_const"]
+    pub static external_const: ::std::os::raw::c_int;
+}
@@ -0,0 +1,43 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const Color_RED: Color = 0;
+pub const Color_GREEN: Color = 1;
+pub const Color_BLUE: Color = 2;
+pub type Color = ::std::os::raw::c_uchar;
+pub const Mode_READ: Mode = 1;
+pub const Mode_WRITE: Mode = 2;
+pub const Mode_READ_WRITE: Mode = 3;
+pub type Mode = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Pixel {
+    pub color: Color,
+    pub mode: Mode,
+}
+#[test]
+fn bindgen_test_layout_Pixel() {
+    assert_eq!(::std::mem::size_of::<Pixel>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Pixel ) ));
+    assert_eq! (::std::mem::align_of::<Pixel>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Pixel ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Pixel ) ) . color as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Pixel ) , "::" ,
+                stringify ! ( color ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Pixel ) ) . mode as * const _ as usize }
+                , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Pixel ) , "::" ,
+                stringify ! ( mode ) ));
+}
+impl Clone for Pixel {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Pixel {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -0,0 +1,79 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub mod Color {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RED: Type = 0;
+    pub const GREEN: Type = 1;
+    pub const BLUE: Type = 4;
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Pixel {
+    pub color: Color::Type,
+}
+#[test]
+fn bindgen_test_layout_Pixel() {
+    assert_eq!(::std::mem::size_of::<Pixel>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Pixel ) ));
+    assert_eq! (::std::mem::align_of::<Pixel>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Pixel ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Pixel ) ) . color as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Pixel ) , "::" ,
+                stringify ! ( color ) ));
+}
+impl Clone for Pixel {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Pixel {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+/** <div rustbindgen constify-module></div> */
+pub mod Direction {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const NORTH: Type = 0;
+    pub const SOUTH: Type = 1;
+}
+extern "C" {
+    pub fn turn(to: Direction::Type);
+}
+pub mod mode_consts {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const MODE_READ: Type = 0;
+    pub const MODE_WRITE: Type = 1;
+}
+pub type mode = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct File {
+    pub how: mode_consts::Type,
+    pub flags: mode,
+}
+#[test]
+fn bindgen_test_layout_File() {
+    assert_eq!(::std::mem::size_of::<File>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( File ) ));
+    assert_eq! (::std::mem::align_of::<File>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( File ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const File ) ) . how as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( File ) , "::" ,
+                stringify ! ( how ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const File ) ) . flags as * const _ as usize }
+                , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( File ) , "::" ,
+                stringify ! ( flags ) ));
+}
+impl Clone for File {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for File {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -18,3 +18,19 @@ pub enum nsCSSPropertyID {
     eCSSPropertyAlias_bb = 3,
     eCSSProperty_COUNT_unexistingVariantValue = 4,
 }
+impl From<nsCSSPropertyID> for u32 {
+    fn from(value: nsCSSPropertyID) -> Self { value as u32 }
+}
+impl nsCSSPropertyID {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(nsCSSPropertyID::eCSSProperty_a),
+            1 => Some(nsCSSPropertyID::eCSSProperty_b),
+            2 => Some(nsCSSPropertyID::eCSSPropertyAlias_aa),
+            3 => Some(nsCSSPropertyID::eCSSPropertyAlias_bb),
+            4 =>
+            Some(nsCSSPropertyID::eCSSProperty_COUNT_unexistingVariantValue),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct handle_t(pub ::std::os::raw::c_int);
+impl Clone for handle_t {
+    fn clone(&self) -> Self { *self }
+}
+pub type raw_t = ::std::os::raw::c_int;
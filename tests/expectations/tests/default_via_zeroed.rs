@@ -0,0 +1,35 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Node {
+    pub value: ::std::os::raw::c_int,
+    pub next: *mut Node,
+}
+#[test]
+fn bindgen_test_layout_Node() {
+    assert_eq!(::std::mem::size_of::<Node>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Node ) ));
+    assert_eq! (::std::mem::align_of::<Node>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Node ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Node ) ) . value as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Node ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Node ) ) . next as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Node ) , "::" ,
+                stringify ! ( next ) ));
+}
+impl Clone for Node {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Node {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
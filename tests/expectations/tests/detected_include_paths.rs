@@ -0,0 +1,38 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Sample {
+    pub tag: u8,
+    pub value: u32,
+    pub stamp: i64,
+}
+#[test]
+fn bindgen_test_layout_Sample() {
+    assert_eq!(::std::mem::size_of::<Sample>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Sample ) ));
+    assert_eq! (::std::mem::align_of::<Sample>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Sample ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sample ) ) . tag as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sample ) , "::" ,
+                stringify ! ( tag ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sample ) ) . value as * const _ as usize
+                } , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sample ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sample ) ) . stamp as * const _ as usize
+                } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sample ) , "::" ,
+                stringify ! ( stamp ) ));
+}
+impl Clone for Sample {
+    fn clone(&self) -> Self { *self }
+}
@@ -0,0 +1,22 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[link(name = "widgets", kind = "dylib")]
+extern "C" {
+    #[link_name = "widget_version"]
+    pub static mut widget_version: ::std::os::raw::c_int;
+}
+#[link(name = "widgets", kind = "dylib")]
+extern "C" {
+    pub fn get_widget_count() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn register_widget(id: ::std::os::raw::c_int);
+}
+#[link(name = "widgets", kind = "dylib")]
+extern "C" {
+    pub fn reset_widgets();
+}
@@ -0,0 +1,46 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(u32)]
+/** The state of a connection. */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum connection_state {
+    /// Not connected yet.
+    CONNECTION_IDLE = 0,
+    /// Connected and ready.
+    CONNECTION_OPEN = 1,
+    /// Closed by either side.
+    CONNECTION_CLOSED = 2,
+}
+impl From<connection_state> for u32 {
+    fn from(value: connection_state) -> Self { value as u32 }
+}
+impl connection_state {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(connection_state::CONNECTION_IDLE),
+            1 => Some(connection_state::CONNECTION_OPEN),
+            2 => Some(connection_state::CONNECTION_CLOSED),
+            _ => None,
+        }
+    }
+}
+pub const flags_FLAG_NONE: flags = 0;
+/// Send before anything else.
+pub const flags_FLAG_URGENT: flags = 1;
+/** Flags for `send_message`. */
+pub type flags = ::std::os::raw::c_uint;
+extern "C" {
+    /** Send a message over a connection. */
+    /// # Arguments
+    ///
+    /// * `connection`: The connection to use.
+    /// * `message`: The message, NUL-terminated.
+    pub fn send_message(connection: ::std::os::raw::c_int,
+                        message: *const ::std::os::raw::c_schar,
+                        flags: flags)
+     -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,73 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Foo {
+    pub x: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Foo() {
+    assert_eq!(::std::mem::size_of::<Foo>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Foo ) ));
+    assert_eq! (::std::mem::align_of::<Foo>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Foo ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Foo ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Foo ) , "::" ,
+                stringify ! ( x ) ));
+}
+impl Clone for Foo {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Bar { A = 0, B = 1, }
+impl From<Bar> for u32 {
+    fn from(value: Bar) -> Self { value as u32 }
+}
+impl Bar {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(Bar::A), 1 => Some(Bar::B), _ => None, }
+    }
+}
+pub type ns_size = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Holder {
+    pub foo: Foo,
+    pub bar: Bar,
+    pub len: ns_size,
+}
+#[test]
+fn bindgen_test_layout_Holder() {
+    assert_eq!(::std::mem::size_of::<Holder>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Holder ) ));
+    assert_eq! (::std::mem::align_of::<Holder>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Holder ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Holder ) ) . foo as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Holder ) , "::" ,
+                stringify ! ( foo ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Holder ) ) . bar as * const _ as usize }
+                , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Holder ) , "::" ,
+                stringify ! ( bar ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Holder ) ) . len as * const _ as usize }
+                , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Holder ) , "::" ,
+                stringify ! ( len ) ));
+}
+impl Clone for Holder {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Holder {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
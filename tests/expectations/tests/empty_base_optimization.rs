@@ -0,0 +1,41 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Empty {
+    pub _address: u8,
+}
+#[test]
+fn bindgen_test_layout_Empty() {
+    assert_eq!(::std::mem::size_of::<Empty>() , 1usize , concat ! (
+               "Size of: " , stringify ! ( Empty ) ));
+    assert_eq! (::std::mem::align_of::<Empty>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Empty ) ));
+}
+impl Clone for Empty {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Derived {
+    pub value: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Derived() {
+    assert_eq!(::std::mem::size_of::<Derived>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Derived ) ));
+    assert_eq! (::std::mem::align_of::<Derived>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Derived ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Derived ) ) . value as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Derived ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for Derived {
+    fn clone(&self) -> Self { *self }
+}
@@ -7,6 +7,26 @@
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo { Bar = 0, Qux = 1, }
+impl From<Foo> for u32 {
+    fn from(value: Foo) -> Self { value as u32 }
+}
+impl Foo {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(Foo::Bar), 1 => Some(Foo::Qux), _ => None, }
+    }
+}
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Neg { MinusOne = -1, One = 1, }
+impl From<Neg> for i32 {
+    fn from(value: Neg) -> Self { value as i32 }
+}
+impl Neg {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            -1 => Some(Neg::MinusOne),
+            1 => Some(Neg::One),
+            _ => None,
+        }
+    }
+}
@@ -7,3 +7,11 @@
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Bar { VAL = 0, }
+impl From<Bar> for u8 {
+    fn from(value: Bar) -> Self { value as u8 }
+}
+impl Bar {
+    pub fn from_raw(value: u8) -> Option<Self> {
+        match value { 0 => Some(Bar::VAL), _ => None, }
+    }
+}
@@ -9,6 +9,18 @@ pub const whatever_else: _bindgen_ty_1 = _bindgen_ty_1::whatever_else;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 { match_ = 0, whatever_else = 1, }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(_bindgen_ty_1::match_),
+            1 => Some(_bindgen_ty_1::whatever_else),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Default)]
 pub struct C__bindgen_vtable {
@@ -0,0 +1,25 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const Dup_DUP_NONE: Dup = Dup::DUP_ZERO;
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Dup { DUP_ZERO = 0, DUP_ONE = 1, }
+impl From<Dup> for u32 {
+    fn from(value: Dup) -> Self { value as u32 }
+}
+impl Dup {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Dup::DUP_ZERO),
+            1 => Some(Dup::DUP_ONE),
+            _ => None,
+        }
+    }
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NoConvert { NO_CONVERT_A = 0, NO_CONVERT_B = 1, }
@@ -8,3 +8,11 @@ pub const Foo_Dupe: Foo = Foo::Bar;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo { Bar = 1, }
+impl From<Foo> for u32 {
+    fn from(value: Foo) -> Self { value as u32 }
+}
+impl Foo {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 1 => Some(Foo::Bar), _ => None, }
+    }
+}
@@ -7,18 +7,80 @@
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo { Bar = 0, Qux = 1, }
+impl From<Foo> for u8 {
+    fn from(value: Foo) -> Self { value as u8 }
+}
+impl Foo {
+    pub fn from_raw(value: u8) -> Option<Self> {
+        match value { 0 => Some(Foo::Bar), 1 => Some(Foo::Qux), _ => None, }
+    }
+}
 #[repr(i8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Neg { MinusOne = -1, One = 1, }
+impl From<Neg> for i8 {
+    fn from(value: Neg) -> Self { value as i8 }
+}
+impl Neg {
+    pub fn from_raw(value: i8) -> Option<Self> {
+        match value {
+            -1 => Some(Neg::MinusOne),
+            1 => Some(Neg::One),
+            _ => None,
+        }
+    }
+}
 #[repr(u16)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Bigger { Much = 255, Larger = 256, }
+impl From<Bigger> for u16 {
+    fn from(value: Bigger) -> Self { value as u16 }
+}
+impl Bigger {
+    pub fn from_raw(value: u16) -> Option<Self> {
+        match value {
+            255 => Some(Bigger::Much),
+            256 => Some(Bigger::Larger),
+            _ => None,
+        }
+    }
+}
 #[repr(i64)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum MuchLong { MuchLow = -4294967296, }
+impl From<MuchLong> for i64 {
+    fn from(value: MuchLong) -> Self { value as i64 }
+}
+impl MuchLong {
+    pub fn from_raw(value: i64) -> Option<Self> {
+        match value { -4294967296 => Some(MuchLong::MuchLow), _ => None, }
+    }
+}
 #[repr(i64)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum MuchLongLong { I64_MIN = -9223372036854775808, }
+impl From<MuchLongLong> for i64 {
+    fn from(value: MuchLongLong) -> Self { value as i64 }
+}
+impl MuchLongLong {
+    pub fn from_raw(value: i64) -> Option<Self> {
+        match value {
+            -9223372036854775808 => Some(MuchLongLong::I64_MIN),
+            _ => None,
+        }
+    }
+}
 #[repr(u64)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum MuchULongLong { MuchHigh = 4294967296, }
+impl From<MuchULongLong> for u64 {
+    fn from(value: MuchULongLong) -> Self { value as u64 }
+}
+impl MuchULongLong {
+    pub fn from_raw(value: u64) -> Option<Self> {
+        match value {
+            4294967296 => Some(MuchULongLong::MuchHigh),
+            _ => None,
+        }
+    }
+}
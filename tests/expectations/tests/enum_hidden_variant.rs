@@ -0,0 +1,23 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const Level_MAX: Level = Level::HIGH;
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Level { LOW = 0, MEDIUM = 1, HIGH = 2, }
+impl From<Level> for u32 {
+    fn from(value: Level) -> Self { value as u32 }
+}
+impl Level {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Level::LOW),
+            1 => Some(Level::MEDIUM),
+            2 => Some(Level::HIGH),
+            _ => None,
+        }
+    }
+}
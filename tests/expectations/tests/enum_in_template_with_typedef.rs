@@ -15,3 +15,11 @@ pub const std_fbstring_core_Category_Bar: std_fbstring_core_Category =
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum std_fbstring_core_Category { Foo = 0, }
+impl From<std_fbstring_core_Category> for u8 {
+    fn from(value: std_fbstring_core_Category) -> Self { value as u8 }
+}
+impl std_fbstring_core_Category {
+    pub fn from_raw(value: u8) -> Option<Self> {
+        match value { 0 => Some(std_fbstring_core_Category::Foo), _ => None, }
+    }
+}
@@ -7,3 +7,11 @@
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo { Bar = -2, Qux = 1, }
+impl From<Foo> for i32 {
+    fn from(value: Foo) -> Self { value as i32 }
+}
+impl Foo {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value { -2 => Some(Foo::Bar), 1 => Some(Foo::Qux), _ => None, }
+    }
+}
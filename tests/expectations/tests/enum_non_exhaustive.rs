@@ -0,0 +1,52 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Planet {
+    MERCURY = 0,
+    VENUS = 1,
+    EARTH = 2,
+
+    #[doc(hidden)]
+    __Nonexhaustive = 3,
+}
+impl From<Planet> for u32 {
+    fn from(value: Planet) -> Self { value as u32 }
+}
+impl Planet {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Planet::MERCURY),
+            1 => Some(Planet::VENUS),
+            2 => Some(Planet::EARTH),
+            3 => None,
+            _ => None,
+        }
+    }
+}
+#[repr(i8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Edge {
+    EDGE_ZERO = 0,
+    EDGE_MAX = 127,
+
+    #[doc(hidden)]
+    __Nonexhaustive = -1,
+}
+impl From<Edge> for i8 {
+    fn from(value: Edge) -> Self { value as i8 }
+}
+impl Edge {
+    pub fn from_raw(value: i8) -> Option<Self> {
+        match value {
+            0 => Some(Edge::EDGE_ZERO),
+            127 => Some(Edge::EDGE_MAX),
+            -1 => None,
+            _ => None,
+        }
+    }
+}
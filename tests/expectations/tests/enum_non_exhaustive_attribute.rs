@@ -0,0 +1,23 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Planet { MERCURY = 0, VENUS = 1, EARTH = 2, }
+impl From<Planet> for u32 {
+    fn from(value: Planet) -> Self { value as u32 }
+}
+impl Planet {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Planet::MERCURY),
+            1 => Some(Planet::VENUS),
+            2 => Some(Planet::EARTH),
+            _ => None,
+        }
+    }
+}
@@ -7,9 +7,41 @@
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Foo { Bar = 0, Qux = 1, }
+impl From<Foo> for u8 {
+    fn from(value: Foo) -> Self { value as u8 }
+}
+impl Foo {
+    pub fn from_raw(value: u8) -> Option<Self> {
+        match value { 0 => Some(Foo::Bar), 1 => Some(Foo::Qux), _ => None, }
+    }
+}
 #[repr(i8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Neg { MinusOne = -1, One = 1, }
+impl From<Neg> for i8 {
+    fn from(value: Neg) -> Self { value as i8 }
+}
+impl Neg {
+    pub fn from_raw(value: i8) -> Option<Self> {
+        match value {
+            -1 => Some(Neg::MinusOne),
+            1 => Some(Neg::One),
+            _ => None,
+        }
+    }
+}
 #[repr(u16)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Bigger { Much = 255, Larger = 256, }
+impl From<Bigger> for u16 {
+    fn from(value: Bigger) -> Self { value as u16 }
+}
+impl Bigger {
+    pub fn from_raw(value: u16) -> Option<Self> {
+        match value {
+            255 => Some(Bigger::Much),
+            256 => Some(Bigger::Larger),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+/** <div rustbindgen constify-module></div> */
+pub mod Annotated {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const ANNOTATED_A: Type = 0;
+    pub const ANNOTATED_B: Type = 1;
+}
+pub const Matched_MATCHED_A: Matched = Matched(1);
+pub const Matched_MATCHED_B: Matched = Matched(2);
+impl ::std::ops::BitOr<Matched> for Matched {
+    type
+    Output
+    =
+    Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self { Matched(self.0 | other.0) }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Matched(pub ::std::os::raw::c_uint);
+pub const Defaulted_DEFAULTED_A: Defaulted = 0;
+pub const Defaulted_DEFAULTED_B: Defaulted = 1;
+pub type Defaulted = ::std::os::raw::c_uint;
@@ -0,0 +1,64 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const flags_FLAG_READ: flags = 1;
+pub const flags_FLAG_WRITE: flags = 2;
+pub type flags = ::std::os::raw::c_uint;
+pub mod mode {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const MODE_TEXT: Type = 0;
+    pub const MODE_BINARY: Type = 1;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum color { RED = 0, GREEN = 1, }
+impl From<color> for u32 {
+    fn from(value: color) -> Self { value as u32 }
+}
+impl color {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(color::RED),
+            1 => Some(color::GREEN),
+            _ => None,
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct file {
+    pub flags: u32,
+    pub mode: u32,
+}
+#[test]
+fn bindgen_test_layout_file() {
+    assert_eq!(::std::mem::size_of::<file>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( file ) ));
+    assert_eq! (::std::mem::align_of::<file>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( file ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const file ) ) . flags as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( file ) , "::" ,
+                stringify ! ( flags ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const file ) ) . mode as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( file ) , "::" ,
+                stringify ! ( mode ) ));
+}
+impl Clone for file {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn open_file(path: *const ::std::os::raw::c_schar,
+                     flags: u32,
+                     mode: u32)
+     -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn paint(color: color);
+}
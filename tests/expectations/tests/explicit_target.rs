@@ -0,0 +1,27 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct HasLong {
+    pub l: ::std::os::raw::c_long,
+}
+#[test]
+#[cfg(all(target_arch = "x86", target_pointer_width = "32"))]
+fn bindgen_test_layout_HasLong() {
+    assert_eq!(::std::mem::size_of::<HasLong>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( HasLong ) ));
+    assert_eq! (::std::mem::align_of::<HasLong>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( HasLong ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const HasLong ) ) . l as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( HasLong ) , "::" ,
+                stringify ! ( l ) ));
+}
+impl Clone for HasLong {
+    fn clone(&self) -> Self { *self }
+}
@@ -0,0 +1,66 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct AlignedField {
+    pub tag: ::std::os::raw::c_schar,
+    pub __bindgen_padding_0: [u32; 3usize],
+    pub value: ::std::os::raw::c_int,
+    pub __bindgen_padding_1: [u32; 3usize],
+}
+#[test]
+fn bindgen_test_layout_AlignedField() {
+    assert_eq!(::std::mem::size_of::<AlignedField>() , 32usize , concat ! (
+               "Size of: " , stringify ! ( AlignedField ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const AlignedField ) ) . tag as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( AlignedField ) , "::" ,
+                stringify ! ( tag ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const AlignedField ) ) . value as * const _ as
+                usize } , 16usize , concat ! (
+                "Alignment of field: " , stringify ! ( AlignedField ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for AlignedField {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct AlignedToEight {
+    pub a: ::std::os::raw::c_schar,
+    pub __bindgen_padding_0: u32,
+    pub b: ::std::os::raw::c_int,
+    pub c: ::std::os::raw::c_schar,
+    pub __bindgen_align: [u64; 0usize],
+}
+#[test]
+fn bindgen_test_layout_AlignedToEight() {
+    assert_eq!(::std::mem::size_of::<AlignedToEight>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( AlignedToEight ) ));
+    assert_eq! (::std::mem::align_of::<AlignedToEight>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( AlignedToEight ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const AlignedToEight ) ) . a as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( AlignedToEight ) , "::"
+                , stringify ! ( a ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const AlignedToEight ) ) . b as * const _ as
+                usize } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( AlignedToEight ) , "::"
+                , stringify ! ( b ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const AlignedToEight ) ) . c as * const _ as
+                usize } , 12usize , concat ! (
+                "Alignment of field: " , stringify ! ( AlignedToEight ) , "::"
+                , stringify ! ( c ) ));
+}
+impl Clone for AlignedToEight {
+    fn clone(&self) -> Self { *self }
+}
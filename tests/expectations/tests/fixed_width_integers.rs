@@ -0,0 +1,57 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Sizes {
+    pub l: i32,
+    pub ul: u32,
+    pub ll: i64,
+    pub s: i16,
+    pub uc: u8,
+}
+#[test]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+fn bindgen_test_layout_Sizes() {
+    assert_eq!(::std::mem::size_of::<Sizes>() , 24usize , concat ! (
+               "Size of: " , stringify ! ( Sizes ) ));
+    assert_eq! (::std::mem::align_of::<Sizes>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Sizes ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sizes ) ) . l as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sizes ) , "::" ,
+                stringify ! ( l ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sizes ) ) . ul as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sizes ) , "::" ,
+                stringify ! ( ul ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sizes ) ) . ll as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sizes ) , "::" ,
+                stringify ! ( ll ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sizes ) ) . s as * const _ as usize } ,
+                16usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sizes ) , "::" ,
+                stringify ! ( s ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Sizes ) ) . uc as * const _ as usize } ,
+                18usize , concat ! (
+                "Alignment of field: " , stringify ! ( Sizes ) , "::" ,
+                stringify ! ( uc ) ));
+}
+impl Clone for Sizes {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Sizes {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+extern "C" {
+    pub fn add_longs(a: i32, b: u32) -> i32;
+}
@@ -0,0 +1,73 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod root {
+    pub mod foo {
+        #[allow(unused_imports)]
+        use self::super::super::root;
+        pub type Baz = ::std::os::raw::c_int;
+        #[repr(C)]
+        #[derive(Debug, Default, Copy)]
+        pub struct Bar {
+            pub baz: root::foo::Baz,
+        }
+        #[test]
+        fn bindgen_test_layout_Bar() {
+            assert_eq!(::std::mem::size_of::<Bar>() , 4usize , concat ! (
+                       "Size of: " , stringify ! ( Bar ) ));
+            assert_eq! (::std::mem::align_of::<Bar>() , 4usize , concat ! (
+                        "Alignment of " , stringify ! ( Bar ) ));
+            assert_eq! (unsafe {
+                        & ( * ( 0 as * const Bar ) ) . baz as * const _ as
+                        usize } , 0usize , concat ! (
+                        "Alignment of field: " , stringify ! ( Bar ) , "::" ,
+                        stringify ! ( baz ) ));
+        }
+        impl Clone for Bar {
+            fn clone(&self) -> Self { *self }
+        }
+    }
+    pub mod bar {
+        #[allow(unused_imports)]
+        use self::super::super::root;
+        #[repr(C)]
+        #[derive(Debug, Default, Copy)]
+        pub struct Bar {
+            pub foo_bar: root::foo::Bar,
+        }
+        #[test]
+        fn bindgen_test_layout_Bar() {
+            assert_eq!(::std::mem::size_of::<Bar>() , 4usize , concat ! (
+                       "Size of: " , stringify ! ( Bar ) ));
+            assert_eq! (::std::mem::align_of::<Bar>() , 4usize , concat ! (
+                        "Alignment of " , stringify ! ( Bar ) ));
+            assert_eq! (unsafe {
+                        & ( * ( 0 as * const Bar ) ) . foo_bar as * const _ as
+                        usize } , 0usize , concat ! (
+                        "Alignment of field: " , stringify ! ( Bar ) , "::" ,
+                        stringify ! ( foo_bar ) ));
+        }
+        impl Clone for Bar {
+            fn clone(&self) -> Self { *self }
+        }
+    }
+    pub mod a {
+        #[allow(unused_imports)]
+        use self::super::super::root;
+        pub type b_c = ::std::os::raw::c_int;
+    }
+    pub mod a_b {
+        #[allow(unused_imports)]
+        use self::super::super::root;
+        pub type c = ::std::os::raw::c_int;
+    }
+}
+pub use root::foo::Baz as foo_Baz;
+pub use root::foo::Bar as foo_Bar;
+pub use root::bar::Bar as bar_Bar;
+pub use root::a::b_c as a_b_c;
+pub use root::a_b::c as a_b_c1;
@@ -0,0 +1,68 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Default)]
+pub struct __IncompleteArrayField<T>(::std::marker::PhantomData<T>);
+impl <T> __IncompleteArrayField<T> {
+    #[inline]
+    pub fn new() -> Self {
+        __IncompleteArrayField(::std::marker::PhantomData)
+    }
+    #[inline]
+    pub unsafe fn as_ptr(&self) -> *const T { ::std::mem::transmute(self) }
+    #[inline]
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut T {
+        ::std::mem::transmute(self)
+    }
+    #[inline]
+    pub unsafe fn as_slice(&self, len: usize) -> &[T] {
+        ::std::slice::from_raw_parts(self.as_ptr(), len)
+    }
+    #[inline]
+    pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
+        ::std::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
+    }
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        &*self.as_ptr().offset(index as isize)
+    }
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        &mut *self.as_mut_ptr().offset(index as isize)
+    }
+}
+impl <T> ::std::fmt::Debug for __IncompleteArrayField<T> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        fmt.write_str("__IncompleteArrayField")
+    }
+}
+impl <T> ::std::clone::Clone for __IncompleteArrayField<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new() }
+}
+impl <T> ::std::marker::Copy for __IncompleteArrayField<T> { }
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct flexible_tail {
+    pub len: ::std::os::raw::c_int,
+    pub tag: ::std::os::raw::c_schar,
+    pub __bindgen_padding_0: [u8; 3usize],
+    pub data: __IncompleteArrayField<::std::os::raw::c_int>,
+}
+#[test]
+fn bindgen_test_layout_flexible_tail() {
+    assert_eq!(::std::mem::size_of::<flexible_tail>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( flexible_tail ) ));
+    assert_eq! (::std::mem::align_of::<flexible_tail>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( flexible_tail ) ));
+}
+impl Clone for flexible_tail {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for flexible_tail {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -0,0 +1,37 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type Callback =
+    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Handlers {
+    pub on_event: Callback,
+    pub table: [Callback; 4usize],
+}
+#[test]
+fn bindgen_test_layout_Handlers() {
+    assert_eq!(::std::mem::size_of::<Handlers>() , 40usize , concat ! (
+               "Size of: " , stringify ! ( Handlers ) ));
+    assert_eq! (::std::mem::align_of::<Handlers>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Handlers ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Handlers ) ) . on_event as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Handlers ) , "::" ,
+                stringify ! ( on_event ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Handlers ) ) . table as * const _ as
+                usize } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Handlers ) , "::" ,
+                stringify ! ( table ) ));
+}
+impl Clone for Handlers {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Handlers {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -5,8 +5,10 @@
 
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct Foo([u8; 0]);
+#[derive(Debug)]
+pub struct Foo {
+    _unused: [u8; 0],
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct RefPtr<T> {
@@ -16,7 +18,7 @@ impl <T> Default for RefPtr<T> {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
 #[repr(C)]
-#[derive(Debug, Copy)]
+#[derive(Debug)]
 pub struct Bar {
     pub m_member: RefPtr<Foo>,
 }
@@ -32,9 +34,6 @@ fn bindgen_test_layout_Bar() {
                 "Alignment of field: " , stringify ! ( Bar ) , "::" ,
                 stringify ! ( m_member ) ));
 }
-impl Clone for Bar {
-    fn clone(&self) -> Self { *self }
-}
 impl Default for Bar {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
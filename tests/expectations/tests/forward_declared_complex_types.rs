@@ -20,8 +20,10 @@ impl Clone for Foo_empty {
     fn clone(&self) -> Self { *self }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct Foo([u8; 0]);
+#[derive(Debug)]
+pub struct Foo {
+    _unused: [u8; 0],
+}
 #[repr(C)]
 #[derive(Debug, Copy)]
 pub struct Bar {
@@ -45,21 +47,25 @@ impl Clone for Bar {
 impl Default for Bar {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+extern "C" {
+    #[link_name = "_Z9baz_classP4Quux"]
+    pub fn baz_class(q: *mut Quux);
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct Union {
+    _unused: [u8; 0],
+}
 extern "C" {
     #[link_name = "_Z10baz_structP3Foo"]
     pub fn baz_struct(f: *mut Foo);
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct Union([u8; 0]);
+#[derive(Debug)]
+pub struct Quux {
+    _unused: [u8; 0],
+}
 extern "C" {
     #[link_name = "_Z9baz_unionP5Union"]
     pub fn baz_union(u: *mut Union);
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct Quux([u8; 0]);
-extern "C" {
-    #[link_name = "_Z9baz_classP4Quux"]
-    pub fn baz_class(q: *mut Quux);
-}
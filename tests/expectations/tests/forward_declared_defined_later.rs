@@ -0,0 +1,29 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct later {
+    pub value: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_later() {
+    assert_eq!(::std::mem::size_of::<later>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( later ) ));
+    assert_eq! (::std::mem::align_of::<later>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( later ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const later ) ) . value as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( later ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for later {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn later_value(l: *mut later) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,37 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct sqlite3 {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct handle {
+    pub db: *mut sqlite3,
+}
+#[test]
+fn bindgen_test_layout_handle() {
+    assert_eq!(::std::mem::size_of::<handle>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( handle ) ));
+    assert_eq! (::std::mem::align_of::<handle>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( handle ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const handle ) ) . db as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( handle ) , "::" ,
+                stringify ! ( db ) ));
+}
+impl Clone for handle {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for handle {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+extern "C" {
+    pub fn sqlite3_close(db: *mut sqlite3) -> ::std::os::raw::c_int;
+}
@@ -5,5 +5,4 @@
 
 
 pub type foo =
-    ::std::option::Option<unsafe extern "C" fn(bar: ::std::os::raw::c_int)
-                              -> ::std::os::raw::c_int>;
+    unsafe extern "C" fn(bar: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
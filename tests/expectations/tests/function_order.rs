@@ -0,0 +1,90 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[link_name = "_Z5alphai"]
+    pub fn alpha(arg1: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "_Z5alphaPKc"]
+    pub fn alpha1(arg1: *const ::std::os::raw::c_schar);
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Between {
+    pub x: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Between() {
+    assert_eq!(::std::mem::size_of::<Between>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Between ) ));
+    assert_eq! (::std::mem::align_of::<Between>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Between ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Between ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Between ) , "::" ,
+                stringify ! ( x ) ));
+}
+impl Clone for Between {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    #[link_name = "_Z4manys"]
+    pub fn many(arg1: ::std::os::raw::c_short);
+}
+extern "C" {
+    #[link_name = "_Z4manyi"]
+    pub fn many1(arg1: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "_Z4manyl"]
+    pub fn many2(arg1: ::std::os::raw::c_long);
+}
+extern "C" {
+    #[link_name = "_Z4manyx"]
+    pub fn many3(arg1: ::std::os::raw::c_longlong);
+}
+extern "C" {
+    #[link_name = "_Z4manyt"]
+    pub fn many4(arg1: ::std::os::raw::c_ushort);
+}
+extern "C" {
+    #[link_name = "_Z4manyj"]
+    pub fn many5(arg1: ::std::os::raw::c_uint);
+}
+extern "C" {
+    #[link_name = "_Z4manym"]
+    pub fn many6(arg1: ::std::os::raw::c_ulong);
+}
+extern "C" {
+    #[link_name = "_Z4manyy"]
+    pub fn many7(arg1: ::std::os::raw::c_ulonglong);
+}
+extern "C" {
+    #[link_name = "_Z4manyf"]
+    pub fn many8(arg1: f32);
+}
+extern "C" {
+    #[link_name = "_Z4manyd"]
+    pub fn many9(arg1: f64);
+}
+extern "C" {
+    #[link_name = "_Z4manyPi"]
+    pub fn many10(arg1: *mut ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "_Z3midv"]
+    pub fn mid();
+}
+extern "C" {
+    #[link_name = "_Z4zetav"]
+    pub fn zeta();
+}
+extern "C" {
+    #[link_name = "_Z4zetai"]
+    pub fn zeta1(arg1: ::std::os::raw::c_int);
+}
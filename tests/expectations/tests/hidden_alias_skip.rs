@@ -0,0 +1,26 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct UsesAlias {
+    pub alias: FooAlias,
+}
+#[test]
+fn bindgen_test_layout_UsesAlias() {
+    assert_eq!(::std::mem::size_of::<UsesAlias>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( UsesAlias ) ));
+    assert_eq! (::std::mem::align_of::<UsesAlias>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( UsesAlias ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const UsesAlias ) ) . alias as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( UsesAlias ) , "::" ,
+                stringify ! ( alias ) ));
+}
+impl Clone for UsesAlias {
+    fn clone(&self) -> Self { *self }
+}
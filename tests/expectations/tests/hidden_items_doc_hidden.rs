@@ -0,0 +1,68 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+#[doc(hidden)]
+pub struct Secret {
+    pub key: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Secret() {
+    assert_eq!(::std::mem::size_of::<Secret>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Secret ) ));
+    assert_eq! (::std::mem::align_of::<Secret>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Secret ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Secret ) ) . key as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Secret ) , "::" ,
+                stringify ! ( key ) ));
+}
+impl Clone for Secret {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+#[doc(hidden)]
+pub struct Internal {
+    pub state: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Internal() {
+    assert_eq!(::std::mem::size_of::<Internal>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Internal ) ));
+    assert_eq! (::std::mem::align_of::<Internal>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Internal ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Internal ) ) . state as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Internal ) , "::" ,
+                stringify ! ( state ) ));
+}
+impl Clone for Internal {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Visible {
+    pub value: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Visible() {
+    assert_eq!(::std::mem::size_of::<Visible>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Visible ) ));
+    assert_eq! (::std::mem::align_of::<Visible>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Visible ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Visible ) ) . value as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Visible ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for Visible {
+    fn clone(&self) -> Self { *self }
+}
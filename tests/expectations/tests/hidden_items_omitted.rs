@@ -0,0 +1,26 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Visible {
+    pub value: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Visible() {
+    assert_eq!(::std::mem::size_of::<Visible>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Visible ) ));
+    assert_eq! (::std::mem::align_of::<Visible>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Visible ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Visible ) ) . value as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Visible ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for Visible {
+    fn clone(&self) -> Self { *self }
+}
@@ -0,0 +1,47 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+#[doc(hidden)]
+pub struct Secret {
+    pub key: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Secret() {
+    assert_eq!(::std::mem::size_of::<Secret>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Secret ) ));
+    assert_eq! (::std::mem::align_of::<Secret>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Secret ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Secret ) ) . key as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Secret ) , "::" ,
+                stringify ! ( key ) ));
+}
+impl Clone for Secret {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Visible {
+    pub secret: Secret,
+}
+#[test]
+fn bindgen_test_layout_Visible() {
+    assert_eq!(::std::mem::size_of::<Visible>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Visible ) ));
+    assert_eq! (::std::mem::align_of::<Visible>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Visible ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Visible ) ) . secret as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Visible ) , "::" ,
+                stringify ! ( secret ) ));
+}
+impl Clone for Visible {
+    fn clone(&self) -> Self { *self }
+}
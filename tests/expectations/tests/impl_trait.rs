@@ -0,0 +1,84 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+pub mod my { pub trait Ops {} pub trait FileOps {} }
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct file_ops {
+    pub open: ::std::option::Option<unsafe extern "C" fn(path:
+                                                              *const ::std::os::raw::c_schar)
+                                        -> ::std::os::raw::c_int>,
+}
+#[test]
+fn bindgen_test_layout_file_ops() {
+    assert_eq!(::std::mem::size_of::<file_ops>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( file_ops ) ));
+    assert_eq! (::std::mem::align_of::<file_ops>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( file_ops ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const file_ops ) ) . open as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( file_ops ) , "::" ,
+                stringify ! ( open ) ));
+}
+impl Clone for file_ops {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for file_ops {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+impl ::my::Ops for file_ops { }
+impl ::my::FileOps for file_ops { }
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct net_ops {
+    pub fd: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_net_ops() {
+    assert_eq!(::std::mem::size_of::<net_ops>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( net_ops ) ));
+    assert_eq! (::std::mem::align_of::<net_ops>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( net_ops ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const net_ops ) ) . fd as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( net_ops ) , "::" ,
+                stringify ! ( fd ) ));
+}
+impl Clone for net_ops {
+    fn clone(&self) -> Self { *self }
+}
+impl ::my::Ops for net_ops { }
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct ops_table {
+    pub file: *mut file_ops,
+    pub net: *mut net_ops,
+}
+#[test]
+fn bindgen_test_layout_ops_table() {
+    assert_eq!(::std::mem::size_of::<ops_table>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( ops_table ) ));
+    assert_eq! (::std::mem::align_of::<ops_table>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( ops_table ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ops_table ) ) . file as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( ops_table ) , "::" ,
+                stringify ! ( file ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ops_table ) ) . net as * const _ as usize
+                } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( ops_table ) , "::" ,
+                stringify ! ( net ) ));
+}
+impl Clone for ops_table {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for ops_table {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
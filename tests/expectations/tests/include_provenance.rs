@@ -0,0 +1,81 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub mod guarded {
+    #[repr(C)]
+    #[derive(Debug, Default, Copy)]
+    pub struct Guarded {
+        pub a: ::std::os::raw::c_int,
+    }
+    #[test]
+    fn bindgen_test_layout_Guarded() {
+        assert_eq!(::std::mem::size_of::<Guarded>() , 4usize , concat ! (
+                   "Size of: " , stringify ! ( Guarded ) ));
+        assert_eq! (::std::mem::align_of::<Guarded>() , 4usize , concat ! (
+                    "Alignment of " , stringify ! ( Guarded ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Guarded ) ) . a as * const _ as usize
+                    } , 0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Guarded ) , "::" ,
+                    stringify ! ( a ) ));
+    }
+    impl Clone for Guarded {
+        fn clone(&self) -> Self { *self }
+    }
+}
+pub mod once {
+    #[repr(C)]
+    #[derive(Debug, Default, Copy)]
+    pub struct Once {
+        pub b: ::std::os::raw::c_schar,
+    }
+    #[test]
+    fn bindgen_test_layout_Once() {
+        assert_eq!(::std::mem::size_of::<Once>() , 1usize , concat ! (
+                   "Size of: " , stringify ! ( Once ) ));
+        assert_eq! (::std::mem::align_of::<Once>() , 1usize , concat ! (
+                    "Alignment of " , stringify ! ( Once ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Once ) ) . b as * const _ as usize }
+                    , 0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Once ) , "::" ,
+                    stringify ! ( b ) ));
+    }
+    impl Clone for Once {
+        fn clone(&self) -> Self { *self }
+    }
+}
+pub mod include_provenance {
+    #[repr(C)]
+    #[derive(Debug, Default, Copy)]
+    pub struct Uses {
+        pub g: self::super::guarded::Guarded,
+        pub o: self::super::once::Once,
+    }
+    #[test]
+    fn bindgen_test_layout_Uses() {
+        assert_eq!(::std::mem::size_of::<Uses>() , 8usize , concat ! (
+                   "Size of: " , stringify ! ( Uses ) ));
+        assert_eq! (::std::mem::align_of::<Uses>() , 4usize , concat ! (
+                    "Alignment of " , stringify ! ( Uses ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Uses ) ) . g as * const _ as usize }
+                    , 0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Uses ) , "::" ,
+                    stringify ! ( g ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Uses ) ) . o as * const _ as usize }
+                    , 4usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Uses ) , "::" ,
+                    stringify ! ( o ) ));
+    }
+    impl Clone for Uses {
+        fn clone(&self) -> Self { *self }
+    }
+}
+pub use self::guarded::*;
+pub use self::once::*;
+pub use self::include_provenance::*;
@@ -0,0 +1,9 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const VERSION: ::std::os::raw::c_int = 3;
+pub const RATIO: f64 = 1.5;
+pub const NAME: &'static [u8; 8usize] = b"bindgen\x00";
@@ -0,0 +1,10 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[link_name = "VERSION"]
+    pub static VERSION: ::std::os::raw::c_int;
+}
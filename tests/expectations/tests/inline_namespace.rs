@@ -9,8 +9,6 @@ pub mod root {
     #[allow(unused_imports)]
     use self::super::root;
     pub mod foo {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         pub type Ty = ::std::os::raw::c_int;
     }
     #[repr(C)]
@@ -9,11 +9,7 @@ pub mod root {
     #[allow(unused_imports)]
     use self::super::root;
     pub mod foo {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         pub mod bar {
-            #[allow(unused_imports)]
-            use self::super::super::super::root;
             pub type Ty = ::std::os::raw::c_int;
         }
         pub type Ty = ::std::os::raw::c_longlong;
@@ -0,0 +1,62 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod root {
+    #[allow(unused_imports)]
+    use self::super::root;
+    pub mod foo {
+        pub mod v1 {
+            #[repr(C)]
+            #[derive(Debug, Default, Copy)]
+            pub struct Widget {
+                pub id: ::std::os::raw::c_int,
+            }
+            #[test]
+            fn bindgen_test_layout_Widget() {
+                assert_eq!(::std::mem::size_of::<Widget>() , 4usize , concat !
+                           ( "Size of: " , stringify ! ( Widget ) ));
+                assert_eq! (::std::mem::align_of::<Widget>() , 4usize , concat
+                            ! ( "Alignment of " , stringify ! ( Widget ) ));
+                assert_eq! (unsafe {
+                            & ( * ( 0 as * const Widget ) ) . id as * const _
+                            as usize } , 0usize , concat ! (
+                            "Alignment of field: " , stringify ! ( Widget ) ,
+                            "::" , stringify ! ( id ) ));
+            }
+            impl Clone for Widget {
+                fn clone(&self) -> Self { *self }
+            }
+        }
+        pub type Handle = ::std::os::raw::c_int;
+    }
+    #[repr(C)]
+    #[derive(Debug, Default, Copy)]
+    pub struct Bar {
+        pub widget: root::foo::v1::Widget,
+        pub handle: root::foo::Handle,
+    }
+    #[test]
+    fn bindgen_test_layout_Bar() {
+        assert_eq!(::std::mem::size_of::<Bar>() , 8usize , concat ! (
+                   "Size of: " , stringify ! ( Bar ) ));
+        assert_eq! (::std::mem::align_of::<Bar>() , 4usize , concat ! (
+                    "Alignment of " , stringify ! ( Bar ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Bar ) ) . widget as * const _ as
+                    usize } , 0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Bar ) , "::" ,
+                    stringify ! ( widget ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Bar ) ) . handle as * const _ as
+                    usize } , 4usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Bar ) , "::" ,
+                    stringify ! ( handle ) ));
+    }
+    impl Clone for Bar {
+        fn clone(&self) -> Self { *self }
+    }
+}
@@ -0,0 +1,36 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Point() {
+    assert_eq!(::std::mem::size_of::<Point>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Point ) ));
+    assert_eq! (::std::mem::align_of::<Point>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Point ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( x ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . y as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( y ) ));
+}
+impl Clone for Point {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    #[link_name = "_Z6originv"]
+    pub fn origin() -> Point;
+}
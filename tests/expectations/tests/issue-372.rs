@@ -82,6 +82,28 @@ pub mod root {
         ah = 10,
         ai = 11,
     }
+    impl From<n> for u32 {
+        fn from(value: n) -> Self { value as u32 }
+    }
+    impl n {
+        pub fn from_raw(value: u32) -> Option<Self> {
+            match value {
+                0 => Some(n::o),
+                1 => Some(n::p),
+                2 => Some(n::q),
+                3 => Some(n::r),
+                4 => Some(n::s),
+                5 => Some(n::t),
+                6 => Some(n::b),
+                7 => Some(n::ae),
+                8 => Some(n::e),
+                9 => Some(n::ag),
+                10 => Some(n::ah),
+                11 => Some(n::ai),
+                _ => None,
+            }
+        }
+    }
     #[repr(C)]
     pub struct F {
         pub w: [u64; 33usize],
@@ -9,11 +9,7 @@ pub mod root {
     #[allow(unused_imports)]
     use self::super::root;
     pub mod mozilla {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         pub mod detail {
-            #[allow(unused_imports)]
-            use self::super::super::super::root;
             #[repr(C)]
             #[derive(Debug, Default, Copy)]
             pub struct GuardObjectNotifier {
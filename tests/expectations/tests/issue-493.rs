@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -51,6 +55,17 @@ pub const basic_string___min_cap: basic_string__bindgen_ty_1 =
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum basic_string__bindgen_ty_1 { __min_cap = 0, }
+impl From<basic_string__bindgen_ty_1> for i32 {
+    fn from(value: basic_string__bindgen_ty_1) -> Self { value as i32 }
+}
+impl basic_string__bindgen_ty_1 {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(basic_string__bindgen_ty_1::__min_cap),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct basic_string___short {
@@ -64,6 +79,24 @@ pub struct basic_string___short__bindgen_ty_1 {
     pub __lx: __BindgenUnionField<basic_string_value_type>,
     pub bindgen_union_field: u8,
 }
+impl basic_string___short__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn __size_(&self) -> &::std::os::raw::c_uchar {
+        self.__size_.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __size__mut(&mut self) -> &mut ::std::os::raw::c_uchar {
+        self.__size_.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __lx(&self) -> &basic_string_value_type {
+        self.__lx.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __lx_mut(&mut self) -> &mut basic_string_value_type {
+        self.__lx.as_mut()
+    }
+}
 impl Default for basic_string___short {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
@@ -77,11 +110,38 @@ pub struct basic_string___ulx {
 impl Default for basic_string___ulx {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+impl basic_string___ulx {
+    #[inline]
+    pub unsafe fn __lx(&self) -> &basic_string___long { self.__lx.as_ref() }
+    #[inline]
+    pub unsafe fn __lx_mut(&mut self) -> &mut basic_string___long {
+        self.__lx.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __lxx(&self) -> &basic_string___short {
+        self.__lxx.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __lxx_mut(&mut self) -> &mut basic_string___short {
+        self.__lxx.as_mut()
+    }
+}
 pub const basic_string___n_words: basic_string__bindgen_ty_2 =
     basic_string__bindgen_ty_2::__n_words;
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum basic_string__bindgen_ty_2 { __n_words = 0, }
+impl From<basic_string__bindgen_ty_2> for i32 {
+    fn from(value: basic_string__bindgen_ty_2) -> Self { value as i32 }
+}
+impl basic_string__bindgen_ty_2 {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(basic_string__bindgen_ty_2::__n_words),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct basic_string___raw {
@@ -106,6 +166,26 @@ pub struct basic_string___rep__bindgen_ty_1 {
 impl Default for basic_string___rep__bindgen_ty_1 {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+impl basic_string___rep__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn __l(&self) -> &basic_string___long { self.__l.as_ref() }
+    #[inline]
+    pub unsafe fn __l_mut(&mut self) -> &mut basic_string___long {
+        self.__l.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __s(&self) -> &basic_string___short { self.__s.as_ref() }
+    #[inline]
+    pub unsafe fn __s_mut(&mut self) -> &mut basic_string___short {
+        self.__s.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __r(&self) -> &basic_string___raw { self.__r.as_ref() }
+    #[inline]
+    pub unsafe fn __r_mut(&mut self) -> &mut basic_string___raw {
+        self.__r.as_mut()
+    }
+}
 impl Default for basic_string___rep {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
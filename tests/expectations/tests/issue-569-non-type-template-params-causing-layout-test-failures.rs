@@ -9,6 +9,18 @@ pub const ENUM_VARIANT_2: _bindgen_ty_1 = _bindgen_ty_1::ENUM_VARIANT_2;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 { ENUM_VARIANT_1 = 0, ENUM_VARIANT_2 = 1, }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(_bindgen_ty_1::ENUM_VARIANT_1),
+            1 => Some(_bindgen_ty_1::ENUM_VARIANT_2),
+            _ => None,
+        }
+    }
+}
 pub type JS_Alias = u8;
 #[repr(C)]
 pub struct JS_Base {
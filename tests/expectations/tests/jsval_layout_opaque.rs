@@ -4,6 +4,80 @@
 #![allow(non_snake_case)]
 
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
 #[repr(C)]
 pub struct __BindgenUnionField<T>(::std::marker::PhantomData<T>);
 impl <T> __BindgenUnionField<T> {
@@ -13,6 +87,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -46,6 +124,27 @@ pub enum JSValueType {
     JSVAL_TYPE_UNKNOWN = 32,
     JSVAL_TYPE_MISSING = 33,
 }
+impl From<JSValueType> for u8 {
+    fn from(value: JSValueType) -> Self { value as u8 }
+}
+impl JSValueType {
+    pub fn from_raw(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(JSValueType::JSVAL_TYPE_DOUBLE),
+            1 => Some(JSValueType::JSVAL_TYPE_INT32),
+            2 => Some(JSValueType::JSVAL_TYPE_UNDEFINED),
+            3 => Some(JSValueType::JSVAL_TYPE_BOOLEAN),
+            4 => Some(JSValueType::JSVAL_TYPE_MAGIC),
+            5 => Some(JSValueType::JSVAL_TYPE_STRING),
+            6 => Some(JSValueType::JSVAL_TYPE_SYMBOL),
+            7 => Some(JSValueType::JSVAL_TYPE_NULL),
+            8 => Some(JSValueType::JSVAL_TYPE_OBJECT),
+            32 => Some(JSValueType::JSVAL_TYPE_UNKNOWN),
+            33 => Some(JSValueType::JSVAL_TYPE_MISSING),
+            _ => None,
+        }
+    }
+}
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JSValueTag {
@@ -59,6 +158,25 @@ pub enum JSValueTag {
     JSVAL_TAG_NULL = 131063,
     JSVAL_TAG_OBJECT = 131064,
 }
+impl From<JSValueTag> for u32 {
+    fn from(value: JSValueTag) -> Self { value as u32 }
+}
+impl JSValueTag {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            131056 => Some(JSValueTag::JSVAL_TAG_MAX_DOUBLE),
+            131057 => Some(JSValueTag::JSVAL_TAG_INT32),
+            131058 => Some(JSValueTag::JSVAL_TAG_UNDEFINED),
+            131061 => Some(JSValueTag::JSVAL_TAG_STRING),
+            131062 => Some(JSValueTag::JSVAL_TAG_SYMBOL),
+            131059 => Some(JSValueTag::JSVAL_TAG_BOOLEAN),
+            131060 => Some(JSValueTag::JSVAL_TAG_MAGIC),
+            131063 => Some(JSValueTag::JSVAL_TAG_NULL),
+            131064 => Some(JSValueTag::JSVAL_TAG_OBJECT),
+            _ => None,
+        }
+    }
+}
 #[repr(u64)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JSValueShiftedTag {
@@ -72,29 +190,104 @@ pub enum JSValueShiftedTag {
     JSVAL_SHIFTED_TAG_NULL = 18445477436314353664,
     JSVAL_SHIFTED_TAG_OBJECT = 18445618173802708992,
 }
+impl From<JSValueShiftedTag> for u64 {
+    fn from(value: JSValueShiftedTag) -> Self { value as u64 }
+}
+impl JSValueShiftedTag {
+    pub fn from_raw(value: u64) -> Option<Self> {
+        match value {
+            18444492278190833663 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_MAX_DOUBLE),
+            18444633011384221696 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_INT32),
+            18444773748872577024 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_UNDEFINED),
+            18445195961337643008 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_STRING),
+            18445336698825998336 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_SYMBOL),
+            18444914486360932352 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_BOOLEAN),
+            18445055223849287680 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_MAGIC),
+            18445477436314353664 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_NULL),
+            18445618173802708992 =>
+            Some(JSValueShiftedTag::JSVAL_SHIFTED_TAG_OBJECT),
+            _ => None,
+        }
+    }
+}
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JSWhyMagic {
+    /// a hole in a native object's elements
     JS_ELEMENTS_HOLE = 0,
+    /// there is not a pending iterator value
     JS_NO_ITER_VALUE = 1,
+    /// exception value thrown when closing a generator
     JS_GENERATOR_CLOSING = 2,
+    /// compiler sentinel value
     JS_NO_CONSTANT = 3,
+    /// used in debug builds to catch tracing errors
     JS_THIS_POISON = 4,
+    /// used in debug builds to catch tracing errors
     JS_ARG_POISON = 5,
+    /// an empty subnode in the AST serializer
     JS_SERIALIZE_NO_NODE = 6,
+    /// lazy arguments value on the stack
     JS_LAZY_ARGUMENTS = 7,
+    /// optimized-away 'arguments' value
     JS_OPTIMIZED_ARGUMENTS = 8,
+    /// magic value passed to natives to indicate construction
     JS_IS_CONSTRUCTING = 9,
+    /// arguments.callee has been overwritten
     JS_OVERWRITTEN_CALLEE = 10,
+    /// value of static block object slot
     JS_BLOCK_NEEDS_CLONE = 11,
+    /// see class js::HashableValue
     JS_HASH_KEY_EMPTY = 12,
+    /// error while running Ion code
     JS_ION_ERROR = 13,
+    /// missing recover instruction result
     JS_ION_BAILOUT = 14,
+    /// optimized out slot
     JS_OPTIMIZED_OUT = 15,
+    /// uninitialized lexical bindings that produce ReferenceError on touch.
     JS_UNINITIALIZED_LEXICAL = 16,
+    /// for local use
     JS_GENERIC_MAGIC = 17,
     JS_WHY_MAGIC_COUNT = 18,
 }
+impl From<JSWhyMagic> for u32 {
+    fn from(value: JSWhyMagic) -> Self { value as u32 }
+}
+impl JSWhyMagic {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(JSWhyMagic::JS_ELEMENTS_HOLE),
+            1 => Some(JSWhyMagic::JS_NO_ITER_VALUE),
+            2 => Some(JSWhyMagic::JS_GENERATOR_CLOSING),
+            3 => Some(JSWhyMagic::JS_NO_CONSTANT),
+            4 => Some(JSWhyMagic::JS_THIS_POISON),
+            5 => Some(JSWhyMagic::JS_ARG_POISON),
+            6 => Some(JSWhyMagic::JS_SERIALIZE_NO_NODE),
+            7 => Some(JSWhyMagic::JS_LAZY_ARGUMENTS),
+            8 => Some(JSWhyMagic::JS_OPTIMIZED_ARGUMENTS),
+            9 => Some(JSWhyMagic::JS_IS_CONSTRUCTING),
+            10 => Some(JSWhyMagic::JS_OVERWRITTEN_CALLEE),
+            11 => Some(JSWhyMagic::JS_BLOCK_NEEDS_CLONE),
+            12 => Some(JSWhyMagic::JS_HASH_KEY_EMPTY),
+            13 => Some(JSWhyMagic::JS_ION_ERROR),
+            14 => Some(JSWhyMagic::JS_ION_BAILOUT),
+            15 => Some(JSWhyMagic::JS_OPTIMIZED_OUT),
+            16 => Some(JSWhyMagic::JS_UNINITIALIZED_LEXICAL),
+            17 => Some(JSWhyMagic::JS_GENERIC_MAGIC),
+            18 => Some(JSWhyMagic::JS_WHY_MAGIC_COUNT),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct jsval_layout {
@@ -110,7 +303,7 @@ pub struct jsval_layout {
 #[repr(C)]
 #[derive(Debug, Copy)]
 pub struct jsval_layout__bindgen_ty_1 {
-    pub _bitfield_1: u64,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize], u64>,
     pub __bindgen_align: [u64; 0usize],
 }
 #[test]
@@ -132,39 +325,23 @@ impl Default for jsval_layout__bindgen_ty_1 {
 impl jsval_layout__bindgen_ty_1 {
     #[inline]
     pub fn payload47(&self) -> u64 {
-        let mask = 140737488355327usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 47u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_payload47(&mut self, val: u64) {
-        let mask = 140737488355327usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(0usize, 47u8, val)
     }
     #[inline]
     pub fn tag(&self) -> JSValueTag {
-        let mask = 18446603336221196288usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 47usize;
+        let val = self._bitfield_1.get(47usize, 17u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_tag(&mut self, val: JSValueTag) {
-        let mask = 18446603336221196288usize as u64;
         let val = val as u32 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 47usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(47usize, 17u8, val)
     }
 }
 #[repr(C)]
@@ -215,6 +392,20 @@ fn bindgen_test_layout_jsval_layout__bindgen_ty_2__bindgen_ty_1() {
 impl Clone for jsval_layout__bindgen_ty_2__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl jsval_layout__bindgen_ty_2__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn i32(&self) -> &i32 { self.i32.as_ref() }
+    #[inline]
+    pub unsafe fn i32_mut(&mut self) -> &mut i32 { self.i32.as_mut() }
+    #[inline]
+    pub unsafe fn u32(&self) -> &u32 { self.u32.as_ref() }
+    #[inline]
+    pub unsafe fn u32_mut(&mut self) -> &mut u32 { self.u32.as_mut() }
+    #[inline]
+    pub unsafe fn why(&self) -> &JSWhyMagic { self.why.as_ref() }
+    #[inline]
+    pub unsafe fn why_mut(&mut self) -> &mut JSWhyMagic { self.why.as_mut() }
+}
 #[test]
 fn bindgen_test_layout_jsval_layout__bindgen_ty_2() {
     assert_eq!(::std::mem::size_of::<jsval_layout__bindgen_ty_2>() , 4usize ,
@@ -279,6 +470,51 @@ fn bindgen_test_layout_jsval_layout() {
 impl Clone for jsval_layout {
     fn clone(&self) -> Self { *self }
 }
+impl jsval_layout {
+    #[inline]
+    pub unsafe fn asBits(&self) -> &u64 { self.asBits.as_ref() }
+    #[inline]
+    pub unsafe fn asBits_mut(&mut self) -> &mut u64 { self.asBits.as_mut() }
+    #[inline]
+    pub unsafe fn debugView(&self) -> &jsval_layout__bindgen_ty_1 {
+        self.debugView.as_ref()
+    }
+    #[inline]
+    pub unsafe fn debugView_mut(&mut self)
+     -> &mut jsval_layout__bindgen_ty_1 {
+        self.debugView.as_mut()
+    }
+    #[inline]
+    pub unsafe fn s(&self) -> &jsval_layout__bindgen_ty_2 { self.s.as_ref() }
+    #[inline]
+    pub unsafe fn s_mut(&mut self) -> &mut jsval_layout__bindgen_ty_2 {
+        self.s.as_mut()
+    }
+    #[inline]
+    pub unsafe fn asDouble(&self) -> &f64 { self.asDouble.as_ref() }
+    #[inline]
+    pub unsafe fn asDouble_mut(&mut self) -> &mut f64 {
+        self.asDouble.as_mut()
+    }
+    #[inline]
+    pub unsafe fn asPtr(&self) -> &*mut ::std::os::raw::c_void {
+        self.asPtr.as_ref()
+    }
+    #[inline]
+    pub unsafe fn asPtr_mut(&mut self) -> &mut *mut ::std::os::raw::c_void {
+        self.asPtr.as_mut()
+    }
+    #[inline]
+    pub unsafe fn asWord(&self) -> &usize { self.asWord.as_ref() }
+    #[inline]
+    pub unsafe fn asWord_mut(&mut self) -> &mut usize { self.asWord.as_mut() }
+    #[inline]
+    pub unsafe fn asUIntPtr(&self) -> &usize { self.asUIntPtr.as_ref() }
+    #[inline]
+    pub unsafe fn asUIntPtr_mut(&mut self) -> &mut usize {
+        self.asUIntPtr.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct Value {
@@ -26,6 +26,14 @@ impl <T> __IncompleteArrayField<T> {
     pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
         ::std::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
     }
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        &*self.as_ptr().offset(index as isize)
+    }
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        &mut *self.as_mut_ptr().offset(index as isize)
+    }
 }
 impl <T> ::std::fmt::Debug for __IncompleteArrayField<T> {
     fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
@@ -38,7 +46,7 @@ impl <T> ::std::clone::Clone for __IncompleteArrayField<T> {
 }
 impl <T> ::std::marker::Copy for __IncompleteArrayField<T> { }
 #[repr(C, packed)]
-#[derive(Debug, Default, Copy)]
+#[derive(Default, Copy)]
 pub struct header {
     pub proto: ::std::os::raw::c_schar,
     pub size: ::std::os::raw::c_uint,
@@ -53,3 +61,12 @@ fn bindgen_test_layout_header() {
 impl Clone for header {
     fn clone(&self) -> Self { *self }
 }
+impl ::std::fmt::Debug for header {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("header");
+        builder.field("proto", &{ self.proto });
+        builder.field("size", &{ self.size });
+        builder.field("data", &{ self.data });
+        builder.finish()
+    }
+}
@@ -24,7 +24,7 @@ pub const ARP_OP_INVREPLY: ::std::os::raw::c_uint = 9;
  * See http://standards.ieee.org/regauth/groupmac/tutorial.html
  */
 #[repr(C, packed)]
-#[derive(Debug, Default, Copy)]
+#[derive(Default, Copy)]
 pub struct ether_addr {
     /**< Addr bytes in tx order */
     pub addr_bytes: [u8; 6usize],
@@ -44,11 +44,18 @@ fn bindgen_test_layout_ether_addr() {
 impl Clone for ether_addr {
     fn clone(&self) -> Self { *self }
 }
+impl ::std::fmt::Debug for ether_addr {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("ether_addr");
+        builder.field("addr_bytes", &{ self.addr_bytes });
+        builder.finish()
+    }
+}
 /**
  * ARP header IPv4 payload.
  */
 #[repr(C, packed)]
-#[derive(Debug, Default, Copy)]
+#[derive(Default, Copy)]
 pub struct arp_ipv4 {
     /**< sender hardware address */
     pub arp_sha: ether_addr,
@@ -89,11 +96,21 @@ fn bindgen_test_layout_arp_ipv4() {
 impl Clone for arp_ipv4 {
     fn clone(&self) -> Self { *self }
 }
+impl ::std::fmt::Debug for arp_ipv4 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("arp_ipv4");
+        builder.field("arp_sha", &{ self.arp_sha });
+        builder.field("arp_sip", &{ self.arp_sip });
+        builder.field("arp_tha", &{ self.arp_tha });
+        builder.field("arp_tip", &{ self.arp_tip });
+        builder.finish()
+    }
+}
 /**
  * ARP header.
  */
 #[repr(C, packed)]
-#[derive(Debug, Default, Copy)]
+#[derive(Default, Copy)]
 pub struct arp_hdr {
     pub arp_hrd: u16,
     pub arp_pro: u16,
@@ -142,3 +159,15 @@ fn bindgen_test_layout_arp_hdr() {
 impl Clone for arp_hdr {
     fn clone(&self) -> Self { *self }
 }
+impl ::std::fmt::Debug for arp_hdr {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("arp_hdr");
+        builder.field("arp_hrd", &{ self.arp_hrd });
+        builder.field("arp_pro", &{ self.arp_pro });
+        builder.field("arp_hln", &{ self.arp_hln });
+        builder.field("arp_pln", &{ self.arp_pln });
+        builder.field("arp_op", &{ self.arp_op });
+        builder.field("arp_data", &{ self.arp_data });
+        builder.finish()
+    }
+}
@@ -8,9 +8,12 @@ pub const RTE_CACHE_LINE_SIZE: ::std::os::raw::c_uint = 64;
 pub const RTE_MEMPOOL_OPS_NAMESIZE: ::std::os::raw::c_uint = 32;
 pub const RTE_MEMPOOL_MAX_OPS_IDX: ::std::os::raw::c_uint = 16;
 pub const RTE_HEAP_NUM_FREELISTS: ::std::os::raw::c_uint = 13;
+pub type size_t = ::std::os::raw::c_longlong;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct rte_mempool([u8; 0]);
+#[derive(Debug)]
+pub struct rte_mempool {
+    _unused: [u8; 0],
+}
 /**
  * Prototype for implementation specific data provisioning function.
  *
@@ -194,7 +197,7 @@ pub struct malloc_heap {
     pub lock: rte_spinlock_t,
     pub free_head: [malloc_heap__bindgen_ty_1; 13usize],
     pub alloc_count: ::std::os::raw::c_uint,
-    pub total_size: usize,
+    pub total_size: size_t,
 }
 #[repr(C)]
 #[derive(Debug, Copy)]
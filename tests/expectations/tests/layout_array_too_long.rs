@@ -13,11 +13,28 @@ pub const IP_MAX_FRAG_NUM: _bindgen_ty_1 = _bindgen_ty_1::IP_MAX_FRAG_NUM;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_1 {
+    /// index of last fragment
     IP_LAST_FRAG_IDX = 0,
+    /// index of first fragment
     IP_FIRST_FRAG_IDX = 1,
+    /// minimum number of fragments
     IP_MIN_FRAG_NUM = 2,
     IP_MAX_FRAG_NUM = 4,
 }
+impl From<_bindgen_ty_1> for u32 {
+    fn from(value: _bindgen_ty_1) -> Self { value as u32 }
+}
+impl _bindgen_ty_1 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(_bindgen_ty_1::IP_LAST_FRAG_IDX),
+            1 => Some(_bindgen_ty_1::IP_FIRST_FRAG_IDX),
+            2 => Some(_bindgen_ty_1::IP_MIN_FRAG_NUM),
+            4 => Some(_bindgen_ty_1::IP_MAX_FRAG_NUM),
+            _ => None,
+        }
+    }
+}
 /** @internal fragmented mbuf */
 #[repr(C)]
 #[derive(Debug, Copy)]
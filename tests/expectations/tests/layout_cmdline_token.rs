@@ -138,6 +138,24 @@ pub enum cmdline_numtype {
     INT32 = 6,
     INT64 = 7,
 }
+impl From<cmdline_numtype> for u32 {
+    fn from(value: cmdline_numtype) -> Self { value as u32 }
+}
+impl cmdline_numtype {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(cmdline_numtype::UINT8),
+            1 => Some(cmdline_numtype::UINT16),
+            2 => Some(cmdline_numtype::UINT32),
+            3 => Some(cmdline_numtype::UINT64),
+            4 => Some(cmdline_numtype::INT8),
+            5 => Some(cmdline_numtype::INT16),
+            6 => Some(cmdline_numtype::INT32),
+            7 => Some(cmdline_numtype::INT64),
+            _ => None,
+        }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Copy)]
 pub struct cmdline_token_num_data {
@@ -4,6 +4,80 @@
 #![allow(non_snake_case)]
 
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
 #[repr(C)]
 pub struct __BindgenUnionField<T>(::std::marker::PhantomData<T>);
 impl <T> __BindgenUnionField<T> {
@@ -13,6 +87,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -67,13 +145,21 @@ pub const RTE_ETH_FLOW_MAX: ::std::os::raw::c_uint = 22;
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_eth_rx_mq_mode {
+    /// None of DCB,RSS or VMDQ mode
     ETH_MQ_RX_NONE = 0,
+    /// For RX side, only RSS is on
     ETH_MQ_RX_RSS = 1,
+    /// For RX side,only DCB is on.
     ETH_MQ_RX_DCB = 2,
+    /// Both DCB and RSS enable
     ETH_MQ_RX_DCB_RSS = 3,
+    /// Only VMDQ, no RSS nor DCB
     ETH_MQ_RX_VMDQ_ONLY = 4,
+    /// RSS mode with VMDQ
     ETH_MQ_RX_VMDQ_RSS = 5,
+    /// Use VMDQ+DCB to route traffic to queues
     ETH_MQ_RX_VMDQ_DCB = 6,
+    /// Enable both VMDQ and DCB in VMDq
     ETH_MQ_RX_VMDQ_DCB_RSS = 7,
 }
 /**
@@ -88,7 +174,7 @@ pub struct rte_eth_rxmode {
     pub max_rx_pkt_len: u32,
     /**< hdr buf size (header_split enabled).*/
     pub split_hdr_size: u16,
-    pub _bitfield_1: [u8; 2usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize], u8>,
 }
 #[test]
 fn bindgen_test_layout_rte_eth_rxmode() {
@@ -121,165 +207,93 @@ impl Default for rte_eth_rxmode {
 impl rte_eth_rxmode {
     #[inline]
     pub fn header_split(&self) -> u16 {
-        let mask = 1usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_header_split(&mut self, val: u16) {
-        let mask = 1usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(0usize, 1u8, val)
     }
     #[inline]
     pub fn hw_ip_checksum(&self) -> u16 {
-        let mask = 2usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 1usize;
+        let val = self._bitfield_1.get(1usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_hw_ip_checksum(&mut self, val: u16) {
-        let mask = 2usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 1usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(1usize, 1u8, val)
     }
     #[inline]
     pub fn hw_vlan_filter(&self) -> u16 {
-        let mask = 4usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 2usize;
+        let val = self._bitfield_1.get(2usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_hw_vlan_filter(&mut self, val: u16) {
-        let mask = 4usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 2usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(2usize, 1u8, val)
     }
     #[inline]
     pub fn hw_vlan_strip(&self) -> u16 {
-        let mask = 8usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 3usize;
+        let val = self._bitfield_1.get(3usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_hw_vlan_strip(&mut self, val: u16) {
-        let mask = 8usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 3usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(3usize, 1u8, val)
     }
     #[inline]
     pub fn hw_vlan_extend(&self) -> u16 {
-        let mask = 16usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 4usize;
+        let val = self._bitfield_1.get(4usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_hw_vlan_extend(&mut self, val: u16) {
-        let mask = 16usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 4usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(4usize, 1u8, val)
     }
     #[inline]
     pub fn jumbo_frame(&self) -> u16 {
-        let mask = 32usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 5usize;
+        let val = self._bitfield_1.get(5usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_jumbo_frame(&mut self, val: u16) {
-        let mask = 32usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 5usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(5usize, 1u8, val)
     }
     #[inline]
     pub fn hw_strip_crc(&self) -> u16 {
-        let mask = 64usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 6usize;
+        let val = self._bitfield_1.get(6usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_hw_strip_crc(&mut self, val: u16) {
-        let mask = 64usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 6usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(6usize, 1u8, val)
     }
     #[inline]
     pub fn enable_scatter(&self) -> u16 {
-        let mask = 128usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 7usize;
+        let val = self._bitfield_1.get(7usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_enable_scatter(&mut self, val: u16) {
-        let mask = 128usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 7usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(7usize, 1u8, val)
     }
     #[inline]
     pub fn enable_lro(&self) -> u16 {
-        let mask = 256usize as u16;
-        let field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 8usize;
+        let val = self._bitfield_1.get(8usize, 1u8);
         unsafe { ::std::mem::transmute(val as u16) }
     }
     #[inline]
     pub fn set_enable_lro(&mut self, val: u16) {
-        let mask = 256usize as u16;
-        let val = val as u16 as u16;
-        let mut field_val: u16 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 8usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u16 as u64;
+        self._bitfield_1.set(8usize, 1u8, val)
     }
 }
 #[repr(u32)]
@@ -289,9 +303,13 @@ impl rte_eth_rxmode {
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_eth_tx_mq_mode {
+    /// It is in neither DCB nor VT mode.
     ETH_MQ_TX_NONE = 0,
+    /// For TX side,only DCB is on.
     ETH_MQ_TX_DCB = 1,
+    /// For TX side,both DCB and VT is on.
     ETH_MQ_TX_VMDQ_DCB = 2,
+    /// Only VT on, no DCB
     ETH_MQ_TX_VMDQ_ONLY = 3,
 }
 /**
@@ -303,7 +321,7 @@ pub struct rte_eth_txmode {
     /**< TX multi-queues mode. */
     pub mq_mode: rte_eth_tx_mq_mode,
     pub pvid: u16,
-    pub _bitfield_1: u8,
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize], u8>,
     pub __bindgen_padding_0: u8,
 }
 #[test]
@@ -332,57 +350,33 @@ impl Default for rte_eth_txmode {
 impl rte_eth_txmode {
     #[inline]
     pub fn hw_vlan_reject_tagged(&self) -> u8 {
-        let mask = 1usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 1u8);
         unsafe { ::std::mem::transmute(val as u8) }
     }
     #[inline]
     pub fn set_hw_vlan_reject_tagged(&mut self, val: u8) {
-        let mask = 1usize as u8;
-        let val = val as u8 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u8 as u64;
+        self._bitfield_1.set(0usize, 1u8, val)
     }
     #[inline]
     pub fn hw_vlan_reject_untagged(&self) -> u8 {
-        let mask = 2usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 1usize;
+        let val = self._bitfield_1.get(1usize, 1u8);
         unsafe { ::std::mem::transmute(val as u8) }
     }
     #[inline]
     pub fn set_hw_vlan_reject_untagged(&mut self, val: u8) {
-        let mask = 2usize as u8;
-        let val = val as u8 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 1usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u8 as u64;
+        self._bitfield_1.set(1usize, 1u8, val)
     }
     #[inline]
     pub fn hw_vlan_insert_pvid(&self) -> u8 {
-        let mask = 4usize as u8;
-        let field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 2usize;
+        let val = self._bitfield_1.get(2usize, 1u8);
         unsafe { ::std::mem::transmute(val as u8) }
     }
     #[inline]
     pub fn set_hw_vlan_insert_pvid(&mut self, val: u8) {
-        let mask = 4usize as u8;
-        let val = val as u8 as u8;
-        let mut field_val: u8 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 2usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u8 as u64;
+        self._bitfield_1.set(2usize, 1u8, val)
     }
 }
 /**
@@ -446,7 +440,12 @@ impl Default for rte_eth_rss_conf {
  * in DCB configratioins
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum rte_eth_nb_tcs { ETH_4_TCS = 4, ETH_8_TCS = 8, }
+pub enum rte_eth_nb_tcs {
+    /// 4 TCs with DCB.
+    ETH_4_TCS = 4,
+    /// 8 TCs with DCB.
+    ETH_8_TCS = 8,
+}
 #[repr(u32)]
 /**
  * This enum indicates the possible number of queue pools
@@ -454,9 +453,13 @@ pub enum rte_eth_nb_tcs { ETH_4_TCS = 4, ETH_8_TCS = 8, }
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_eth_nb_pools {
+    /// 8 VMDq pools.
     ETH_8_POOLS = 8,
+    /// 16 VMDq pools.
     ETH_16_POOLS = 16,
+    /// 32 VMDq pools.
     ETH_32_POOLS = 32,
+    /// 64 VMDq pools.
     ETH_64_POOLS = 64,
 }
 /**
@@ -789,10 +792,15 @@ impl Default for rte_eth_vmdq_rx_conf {
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_fdir_mode {
+    /// Disable FDIR support.
     RTE_FDIR_MODE_NONE = 0,
+    /// Enable FDIR signature filter mode.
     RTE_FDIR_MODE_SIGNATURE = 1,
+    /// Enable FDIR perfect filter mode.
     RTE_FDIR_MODE_PERFECT = 2,
+    /// Enable FDIR filter mode - MAC VLAN.
     RTE_FDIR_MODE_PERFECT_MAC_VLAN = 3,
+    /// Enable FDIR filter mode - tunnel.
     RTE_FDIR_MODE_PERFECT_TUNNEL = 4,
 }
 #[repr(u32)]
@@ -802,8 +810,11 @@ pub enum rte_fdir_mode {
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_fdir_pballoc_type {
+    /// 64k.
     RTE_FDIR_PBALLOC_64K = 0,
+    /// 128k.
     RTE_FDIR_PBALLOC_128K = 1,
+    /// 256k.
     RTE_FDIR_PBALLOC_256K = 2,
 }
 #[repr(u32)]
@@ -812,8 +823,11 @@ pub enum rte_fdir_pballoc_type {
  */
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum rte_fdir_status_mode {
+    /// Never report FDIR hash.
     RTE_FDIR_NO_REPORT_STATUS = 0,
+    /// Only report FDIR hash for matching pkts.
     RTE_FDIR_REPORT_STATUS = 1,
+    /// Always report FDIR hash.
     RTE_FDIR_REPORT_STATUS_ALWAYS = 2,
 }
 /**
@@ -1343,6 +1357,33 @@ fn bindgen_test_layout_rte_eth_conf__bindgen_ty_2() {
 impl Clone for rte_eth_conf__bindgen_ty_2 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_eth_conf__bindgen_ty_2 {
+    #[inline]
+    pub unsafe fn vmdq_dcb_tx_conf(&self) -> &rte_eth_vmdq_dcb_tx_conf {
+        self.vmdq_dcb_tx_conf.as_ref()
+    }
+    #[inline]
+    pub unsafe fn vmdq_dcb_tx_conf_mut(&mut self)
+     -> &mut rte_eth_vmdq_dcb_tx_conf {
+        self.vmdq_dcb_tx_conf.as_mut()
+    }
+    #[inline]
+    pub unsafe fn dcb_tx_conf(&self) -> &rte_eth_dcb_tx_conf {
+        self.dcb_tx_conf.as_ref()
+    }
+    #[inline]
+    pub unsafe fn dcb_tx_conf_mut(&mut self) -> &mut rte_eth_dcb_tx_conf {
+        self.dcb_tx_conf.as_mut()
+    }
+    #[inline]
+    pub unsafe fn vmdq_tx_conf(&self) -> &rte_eth_vmdq_tx_conf {
+        self.vmdq_tx_conf.as_ref()
+    }
+    #[inline]
+    pub unsafe fn vmdq_tx_conf_mut(&mut self) -> &mut rte_eth_vmdq_tx_conf {
+        self.vmdq_tx_conf.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_rte_eth_conf() {
     assert_eq!(::std::mem::size_of::<rte_eth_conf>() , 2944usize , concat ! (
@@ -4,6 +4,80 @@
 #![allow(non_snake_case)]
 
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
 #[repr(C)]
 pub struct __BindgenUnionField<T>(::std::marker::PhantomData<T>);
 impl <T> __BindgenUnionField<T> {
@@ -13,6 +87,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -147,6 +225,20 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_1() {
 impl Clone for rte_mbuf__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn refcnt_atomic(&self) -> &rte_atomic16_t {
+        self.refcnt_atomic.as_ref()
+    }
+    #[inline]
+    pub unsafe fn refcnt_atomic_mut(&mut self) -> &mut rte_atomic16_t {
+        self.refcnt_atomic.as_mut()
+    }
+    #[inline]
+    pub unsafe fn refcnt(&self) -> &u16 { self.refcnt.as_ref() }
+    #[inline]
+    pub unsafe fn refcnt_mut(&mut self) -> &mut u16 { self.refcnt.as_mut() }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_2 {
@@ -158,7 +250,7 @@ pub struct rte_mbuf__bindgen_ty_2 {
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
-    pub _bitfield_1: [u8; 4usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize], u8>,
     pub __bindgen_align: [u32; 0usize],
 }
 #[test]
@@ -178,129 +270,73 @@ impl Clone for rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
 impl rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
     #[inline]
     pub fn l2_type(&self) -> u32 {
-        let mask = 15usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_l2_type(&mut self, val: u32) {
-        let mask = 15usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 4u8, val)
     }
     #[inline]
     pub fn l3_type(&self) -> u32 {
-        let mask = 240usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 4usize;
+        let val = self._bitfield_1.get(4usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_l3_type(&mut self, val: u32) {
-        let mask = 240usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 4usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(4usize, 4u8, val)
     }
     #[inline]
     pub fn l4_type(&self) -> u32 {
-        let mask = 3840usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 8usize;
+        let val = self._bitfield_1.get(8usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_l4_type(&mut self, val: u32) {
-        let mask = 3840usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 8usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(8usize, 4u8, val)
     }
     #[inline]
     pub fn tun_type(&self) -> u32 {
-        let mask = 61440usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 12usize;
+        let val = self._bitfield_1.get(12usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_tun_type(&mut self, val: u32) {
-        let mask = 61440usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 12usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(12usize, 4u8, val)
     }
     #[inline]
     pub fn inner_l2_type(&self) -> u32 {
-        let mask = 983040usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 16usize;
+        let val = self._bitfield_1.get(16usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_inner_l2_type(&mut self, val: u32) {
-        let mask = 983040usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 16usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(16usize, 4u8, val)
     }
     #[inline]
     pub fn inner_l3_type(&self) -> u32 {
-        let mask = 15728640usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 20usize;
+        let val = self._bitfield_1.get(20usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_inner_l3_type(&mut self, val: u32) {
-        let mask = 15728640usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 20usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(20usize, 4u8, val)
     }
     #[inline]
     pub fn inner_l4_type(&self) -> u32 {
-        let mask = 251658240usize as u32;
-        let field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 24usize;
+        let val = self._bitfield_1.get(24usize, 4u8);
         unsafe { ::std::mem::transmute(val as u32) }
     }
     #[inline]
     pub fn set_inner_l4_type(&mut self, val: u32) {
-        let mask = 251658240usize as u32;
-        let val = val as u32 as u32;
-        let mut field_val: u32 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 24usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        let val = val as u32 as u64;
+        self._bitfield_1.set(24usize, 4u8, val)
     }
 }
 #[test]
@@ -320,6 +356,24 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_2() {
 impl Clone for rte_mbuf__bindgen_ty_2 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_2 {
+    #[inline]
+    pub unsafe fn packet_type(&self) -> &u32 { self.packet_type.as_ref() }
+    #[inline]
+    pub unsafe fn packet_type_mut(&mut self) -> &mut u32 {
+        self.packet_type.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_3 {
@@ -410,6 +464,22 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1() {
 impl Clone for rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn lo(&self) -> &u32 { self.lo.as_ref() }
+    #[inline]
+    pub unsafe fn lo_mut(&mut self) -> &mut u32 { self.lo.as_mut() }
+}
 #[test]
 fn bindgen_test_layout_rte_mbuf__bindgen_ty_3__bindgen_ty_1() {
     assert_eq!(::std::mem::size_of::<rte_mbuf__bindgen_ty_3__bindgen_ty_1>() ,
@@ -494,6 +564,34 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_3() {
 impl Clone for rte_mbuf__bindgen_ty_3 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_3 {
+    #[inline]
+    pub unsafe fn rss(&self) -> &u32 { self.rss.as_ref() }
+    #[inline]
+    pub unsafe fn rss_mut(&mut self) -> &mut u32 { self.rss.as_mut() }
+    #[inline]
+    pub unsafe fn fdir(&self) -> &rte_mbuf__bindgen_ty_3__bindgen_ty_1 {
+        self.fdir.as_ref()
+    }
+    #[inline]
+    pub unsafe fn fdir_mut(&mut self)
+     -> &mut rte_mbuf__bindgen_ty_3__bindgen_ty_1 {
+        self.fdir.as_mut()
+    }
+    #[inline]
+    pub unsafe fn sched(&self) -> &rte_mbuf__bindgen_ty_3__bindgen_ty_2 {
+        self.sched.as_ref()
+    }
+    #[inline]
+    pub unsafe fn sched_mut(&mut self)
+     -> &mut rte_mbuf__bindgen_ty_3__bindgen_ty_2 {
+        self.sched.as_mut()
+    }
+    #[inline]
+    pub unsafe fn usr(&self) -> &u32 { self.usr.as_ref() }
+    #[inline]
+    pub unsafe fn usr_mut(&mut self) -> &mut u32 { self.usr.as_mut() }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_4 {
@@ -525,6 +623,21 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_4() {
 impl Clone for rte_mbuf__bindgen_ty_4 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_4 {
+    #[inline]
+    pub unsafe fn userdata(&self) -> &*mut ::std::os::raw::c_void {
+        self.userdata.as_ref()
+    }
+    #[inline]
+    pub unsafe fn userdata_mut(&mut self)
+     -> &mut *mut ::std::os::raw::c_void {
+        self.userdata.as_mut()
+    }
+    #[inline]
+    pub unsafe fn udata64(&self) -> &u64 { self.udata64.as_ref() }
+    #[inline]
+    pub unsafe fn udata64_mut(&mut self) -> &mut u64 { self.udata64.as_mut() }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_5 {
@@ -536,7 +649,7 @@ pub struct rte_mbuf__bindgen_ty_5 {
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct rte_mbuf__bindgen_ty_5__bindgen_ty_1 {
-    pub _bitfield_1: [u16; 4usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize], u16>,
     pub __bindgen_align: [u64; 0usize],
 }
 #[test]
@@ -556,111 +669,63 @@ impl Clone for rte_mbuf__bindgen_ty_5__bindgen_ty_1 {
 impl rte_mbuf__bindgen_ty_5__bindgen_ty_1 {
     #[inline]
     pub fn l2_len(&self) -> u64 {
-        let mask = 127usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 0usize;
+        let val = self._bitfield_1.get(0usize, 7u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_l2_len(&mut self, val: u64) {
-        let mask = 127usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 0usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(0usize, 7u8, val)
     }
     #[inline]
     pub fn l3_len(&self) -> u64 {
-        let mask = 65408usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 7usize;
+        let val = self._bitfield_1.get(7usize, 9u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_l3_len(&mut self, val: u64) {
-        let mask = 65408usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 7usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(7usize, 9u8, val)
     }
     #[inline]
     pub fn l4_len(&self) -> u64 {
-        let mask = 16711680usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 16usize;
+        let val = self._bitfield_1.get(16usize, 8u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_l4_len(&mut self, val: u64) {
-        let mask = 16711680usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 16usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(16usize, 8u8, val)
     }
     #[inline]
     pub fn tso_segsz(&self) -> u64 {
-        let mask = 1099494850560usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 24usize;
+        let val = self._bitfield_1.get(24usize, 16u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_tso_segsz(&mut self, val: u64) {
-        let mask = 1099494850560usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 24usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(24usize, 16u8, val)
     }
     #[inline]
     pub fn outer_l3_len(&self) -> u64 {
-        let mask = 561850441793536usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 40usize;
+        let val = self._bitfield_1.get(40usize, 9u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_outer_l3_len(&mut self, val: u64) {
-        let mask = 561850441793536usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 40usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(40usize, 9u8, val)
     }
     #[inline]
     pub fn outer_l2_len(&self) -> u64 {
-        let mask = 71494644084506624usize as u64;
-        let field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        let val = (field_val & mask) >> 49usize;
+        let val = self._bitfield_1.get(49usize, 7u8);
         unsafe { ::std::mem::transmute(val as u64) }
     }
     #[inline]
     pub fn set_outer_l2_len(&mut self, val: u64) {
-        let mask = 71494644084506624usize as u64;
         let val = val as u64 as u64;
-        let mut field_val: u64 =
-            unsafe { ::std::mem::transmute(self._bitfield_1) };
-        field_val &= !mask;
-        field_val |= (val << 49usize) & mask;
-        self._bitfield_1 = unsafe { ::std::mem::transmute(field_val) };
+        self._bitfield_1.set(49usize, 7u8, val)
     }
 }
 #[test]
@@ -680,6 +745,24 @@ fn bindgen_test_layout_rte_mbuf__bindgen_ty_5() {
 impl Clone for rte_mbuf__bindgen_ty_5 {
     fn clone(&self) -> Self { *self }
 }
+impl rte_mbuf__bindgen_ty_5 {
+    #[inline]
+    pub unsafe fn tx_offload(&self) -> &u64 { self.tx_offload.as_ref() }
+    #[inline]
+    pub unsafe fn tx_offload_mut(&mut self) -> &mut u64 {
+        self.tx_offload.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &rte_mbuf__bindgen_ty_5__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut rte_mbuf__bindgen_ty_5__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_rte_mbuf() {
     assert_eq!(::std::mem::size_of::<rte_mbuf>() , 128usize , concat ! (
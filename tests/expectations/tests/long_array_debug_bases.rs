@@ -0,0 +1,168 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Header {
+    pub tag: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Header() {
+    assert_eq!(::std::mem::size_of::<Header>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Header ) ));
+    assert_eq! (::std::mem::align_of::<Header>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Header ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Header ) ) . tag as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Header ) , "::" ,
+                stringify ! ( tag ) ));
+}
+impl Clone for Header {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+pub struct Record {
+    pub _base: Header,
+    pub buf: [::std::os::raw::c_uchar; 64usize],
+}
+#[test]
+fn bindgen_test_layout_Record() {
+    assert_eq!(::std::mem::size_of::<Record>() , 68usize , concat ! (
+               "Size of: " , stringify ! ( Record ) ));
+    assert_eq! (::std::mem::align_of::<Record>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Record ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Record ) ) . buf as * const _ as usize }
+                , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Record ) , "::" ,
+                stringify ! ( buf ) ));
+}
+impl ::std::fmt::Debug for Record {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("Record");
+        builder.field("_base", &self._base);
+        builder.field("buf", &&self.buf[..]);
+        builder.finish()
+    }
+}
+impl Default for Record {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+pub struct Flagged {
+    pub buf: [::std::os::raw::c_uchar; 64usize],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize], u32>,
+}
+#[test]
+fn bindgen_test_layout_Flagged() {
+    assert_eq!(::std::mem::size_of::<Flagged>() , 68usize , concat ! (
+               "Size of: " , stringify ! ( Flagged ) ));
+    assert_eq! (::std::mem::align_of::<Flagged>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Flagged ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Flagged ) ) . buf as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Flagged ) , "::" ,
+                stringify ! ( buf ) ));
+}
+impl ::std::fmt::Debug for Flagged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("Flagged");
+        builder.field("buf", &&self.buf[..]);
+        builder.field("flags", &self.flags());
+        builder.finish()
+    }
+}
+impl Default for Flagged {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+impl Flagged {
+    #[inline]
+    pub fn flags(&self) -> ::std::os::raw::c_uint {
+        let val = self._bitfield_1.get(0usize, 32u8);
+        unsafe { ::std::mem::transmute(val as u32) }
+    }
+    #[inline]
+    pub fn set_flags(&mut self, val: ::std::os::raw::c_uint) {
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 32u8, val)
+    }
+}
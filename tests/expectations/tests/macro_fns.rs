@@ -0,0 +1,23 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[inline]
+pub unsafe fn COMBINE(a: u32, b: u32) -> u32 { (a << 8) | b }
+#[inline]
+pub unsafe fn FLAG(x: u32) -> u32 { 1 << x }
+#[inline]
+pub unsafe fn mylib_init() -> ::std::os::raw::c_int { mylib_init_ex(0) }
+extern "C" {
+    pub fn mylib_init_ex(flags: ::std::os::raw::c_int)
+     -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn mylib_set_level(level: ::std::os::raw::c_uint);
+}
+#[inline]
+pub unsafe fn mylib_verbose(lvl: ::std::os::raw::c_uint) {
+    mylib_set_level(lvl)
+}
@@ -6,8 +6,6 @@
 
 #[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
 pub mod root {
-    #[allow(unused_imports)]
-    use self::super::root;
     #[repr(C)]
     #[derive(Debug, Default, Copy)]
     pub struct Test {
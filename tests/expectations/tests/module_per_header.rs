@@ -0,0 +1,96 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub mod a {
+    #[repr(C)]
+    #[derive(Debug, Copy)]
+    pub struct A {
+        pub b: *mut self::super::b::B,
+        pub value: ::std::os::raw::c_int,
+    }
+    #[test]
+    fn bindgen_test_layout_A() {
+        assert_eq!(::std::mem::size_of::<A>() , 16usize , concat ! (
+                   "Size of: " , stringify ! ( A ) ));
+        assert_eq! (::std::mem::align_of::<A>() , 8usize , concat ! (
+                    "Alignment of " , stringify ! ( A ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const A ) ) . b as * const _ as usize } ,
+                    0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( A ) , "::" ,
+                    stringify ! ( b ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const A ) ) . value as * const _ as usize }
+                    , 8usize , concat ! (
+                    "Alignment of field: " , stringify ! ( A ) , "::" ,
+                    stringify ! ( value ) ));
+    }
+    impl Clone for A {
+        fn clone(&self) -> Self { *self }
+    }
+    impl Default for A {
+        fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+    }
+}
+pub mod b {
+    #[repr(C)]
+    #[derive(Debug, Copy)]
+    pub struct B {
+        pub a: *mut self::super::a::A,
+    }
+    #[test]
+    fn bindgen_test_layout_B() {
+        assert_eq!(::std::mem::size_of::<B>() , 8usize , concat ! (
+                   "Size of: " , stringify ! ( B ) ));
+        assert_eq! (::std::mem::align_of::<B>() , 8usize , concat ! (
+                    "Alignment of " , stringify ! ( B ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const B ) ) . a as * const _ as usize } ,
+                    0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( B ) , "::" ,
+                    stringify ! ( a ) ));
+    }
+    impl Clone for B {
+        fn clone(&self) -> Self { *self }
+    }
+    impl Default for B {
+        fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+    }
+}
+pub mod module_per_header {
+    #[repr(C)]
+    #[derive(Debug, Copy)]
+    pub struct Both {
+        pub a: self::super::a::A,
+        pub b: self::super::b::B,
+    }
+    #[test]
+    fn bindgen_test_layout_Both() {
+        assert_eq!(::std::mem::size_of::<Both>() , 24usize , concat ! (
+                   "Size of: " , stringify ! ( Both ) ));
+        assert_eq! (::std::mem::align_of::<Both>() , 8usize , concat ! (
+                    "Alignment of " , stringify ! ( Both ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Both ) ) . a as * const _ as usize }
+                    , 0usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Both ) , "::" ,
+                    stringify ! ( a ) ));
+        assert_eq! (unsafe {
+                    & ( * ( 0 as * const Both ) ) . b as * const _ as usize }
+                    , 16usize , concat ! (
+                    "Alignment of field: " , stringify ! ( Both ) , "::" ,
+                    stringify ! ( b ) ));
+    }
+    impl Clone for Both {
+        fn clone(&self) -> Self { *self }
+    }
+    impl Default for Both {
+        fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+    }
+}
+pub use self::a::*;
+pub use self::b::*;
+pub use self::module_per_header::*;
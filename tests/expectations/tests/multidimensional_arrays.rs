@@ -0,0 +1,98 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Matrix {
+    pub m: [[f64; 4usize]; 4usize],
+}
+#[test]
+fn bindgen_test_layout_Matrix() {
+    assert_eq!(::std::mem::size_of::<Matrix>() , 128usize , concat ! (
+               "Size of: " , stringify ! ( Matrix ) ));
+    assert_eq! (::std::mem::align_of::<Matrix>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Matrix ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Matrix ) ) . m as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Matrix ) , "::" ,
+                stringify ! ( m ) ));
+}
+impl Clone for Matrix {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Grid {
+    pub cells: [[::std::os::raw::c_uchar; 3usize]; 5usize],
+}
+#[test]
+fn bindgen_test_layout_Grid() {
+    assert_eq!(::std::mem::size_of::<Grid>() , 15usize , concat ! (
+               "Size of: " , stringify ! ( Grid ) ));
+    assert_eq! (::std::mem::align_of::<Grid>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Grid ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Grid ) ) . cells as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Grid ) , "::" ,
+                stringify ! ( cells ) ));
+}
+impl Clone for Grid {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+pub struct Wide {
+    pub rows: [[::std::os::raw::c_uchar; 64usize]; 2usize],
+}
+#[test]
+fn bindgen_test_layout_Wide() {
+    assert_eq!(::std::mem::size_of::<Wide>() , 128usize , concat ! (
+               "Size of: " , stringify ! ( Wide ) ));
+    assert_eq! (::std::mem::align_of::<Wide>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Wide ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Wide ) ) . rows as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Wide ) , "::" ,
+                stringify ! ( rows ) ));
+}
+impl Default for Wide {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+pub struct Long {
+    pub rows: [[::std::os::raw::c_uchar; 2usize]; 64usize],
+    pub len: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Long() {
+    assert_eq!(::std::mem::size_of::<Long>() , 132usize , concat ! (
+               "Size of: " , stringify ! ( Long ) ));
+    assert_eq! (::std::mem::align_of::<Long>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Long ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Long ) ) . rows as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Long ) , "::" ,
+                stringify ! ( rows ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Long ) ) . len as * const _ as usize } ,
+                128usize , concat ! (
+                "Alignment of field: " , stringify ! ( Long ) , "::" ,
+                stringify ! ( len ) ));
+}
+impl ::std::fmt::Debug for Long {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("Long");
+        builder.field("rows", &&self.rows[..]);
+        builder.field("len", &self.len);
+        builder.finish()
+    }
+}
+impl Default for Long {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
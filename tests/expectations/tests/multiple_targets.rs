@@ -0,0 +1,63 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct HasLong {
+    pub l: ::std::os::raw::c_long,
+}
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[test]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+fn bindgen_test_layout_HasLong() {
+    assert_eq!(::std::mem::size_of::<HasLong>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( HasLong ) ));
+    assert_eq! (::std::mem::align_of::<HasLong>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( HasLong ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const HasLong ) ) . l as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( HasLong ) , "::" ,
+                stringify ! ( l ) ));
+}
+#[cfg(all(target_arch = "x86_64", target_os = "windows"))]
+#[test]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+fn bindgen_test_layout_HasLong() {
+    assert_eq!(::std::mem::size_of::<HasLong>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( HasLong ) ));
+    assert_eq! (::std::mem::align_of::<HasLong>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( HasLong ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const HasLong ) ) . l as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( HasLong ) , "::" ,
+                stringify ! ( l ) ));
+}
+impl Clone for HasLong {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct NoLong {
+    pub i: ::std::os::raw::c_int,
+}
+#[test]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+fn bindgen_test_layout_NoLong() {
+    assert_eq!(::std::mem::size_of::<NoLong>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( NoLong ) ));
+    assert_eq! (::std::mem::align_of::<NoLong>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( NoLong ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const NoLong ) ) . i as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( NoLong ) , "::" ,
+                stringify ! ( i ) ));
+}
+impl Clone for NoLong {
+    fn clone(&self) -> Self { *self }
+}
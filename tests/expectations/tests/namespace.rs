@@ -13,8 +13,6 @@ pub mod root {
         pub fn top_level();
     }
     pub mod whatever {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         pub type whatever_int_t = ::std::os::raw::c_int;
         extern "C" {
             #[link_name = "_ZN8whatever11in_whateverEv"]
@@ -86,16 +84,16 @@ pub mod root {
             fn default() -> Self { unsafe { ::std::mem::zeroed() } }
         }
         extern "C" {
-            #[link_name = "_ZN1w3hehEv"]
-            pub fn heh() -> root::w::whatever_int_t;
+            #[link_name = "_ZN1w4barrEv"]
+            pub fn barr() -> root::C<f32>;
         }
         extern "C" {
             #[link_name = "_ZN1w3fooEv"]
             pub fn foo() -> root::C<::std::os::raw::c_int>;
         }
         extern "C" {
-            #[link_name = "_ZN1w4barrEv"]
-            pub fn barr() -> root::C<f32>;
+            #[link_name = "_ZN1w3hehEv"]
+            pub fn heh() -> root::w::whatever_int_t;
         }
     }
 }
@@ -0,0 +1,44 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct handle_t(pub ::std::os::raw::c_uint);
+impl Clone for handle_t {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct length_t(pub ::std::os::raw::c_int);
+impl Clone for length_t {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::ops::Deref for length_t {
+    type Target = ::std::os::raw::c_int;
+    #[inline]
+    fn deref(&self) -> &::std::os::raw::c_int { &self.0 }
+}
+impl ::std::ops::DerefMut for length_t {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut ::std::os::raw::c_int { &mut self.0 }
+}
+pub type inner_t = ::std::os::raw::c_int;
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct outer_t(pub inner_t);
+impl Clone for outer_t {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct int_ptr_t(pub *mut ::std::os::raw::c_int);
+impl Clone for int_ptr_t {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn open_handle(length: length_t, flags: outer_t, out: int_ptr_t)
+     -> handle_t;
+}
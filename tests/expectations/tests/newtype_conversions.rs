@@ -0,0 +1,38 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct user_id_t(pub u32);
+impl Clone for user_id_t {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::convert::From<u32> for user_id_t {
+    #[inline]
+    fn from(inner: u32) -> Self { user_id_t(inner) }
+}
+impl ::std::convert::From<user_id_t> for u32 {
+    #[inline]
+    fn from(new_type: user_id_t) -> Self { new_type.0 }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy)]
+pub struct group_id_t(pub u32);
+impl Clone for group_id_t {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::convert::From<u32> for group_id_t {
+    #[inline]
+    fn from(inner: u32) -> Self { group_id_t(inner) }
+}
+impl ::std::convert::From<group_id_t> for u32 {
+    #[inline]
+    fn from(new_type: group_id_t) -> Self { new_type.0 }
+}
+extern "C" {
+    pub fn set_owner(user: user_id_t, group: group_id_t)
+     -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,37 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+pub type Handle = ::std::os::raw::c_uint;
+pub type Kind = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Foo {
+    pub value: Handle,
+    pub kind: Kind,
+}
+#[test]
+fn bindgen_test_layout_Foo() {
+    assert_eq!(::std::mem::size_of::<Foo>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Foo ) ));
+    assert_eq! (::std::mem::align_of::<Foo>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Foo ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Foo ) ) . value as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Foo ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Foo ) ) . kind as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Foo ) , "::" ,
+                stringify ! ( kind ) ));
+}
+impl Clone for Foo {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Foo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
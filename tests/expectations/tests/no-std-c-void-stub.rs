@@ -0,0 +1,22 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(u8)]
+pub enum c_void { __variant1, __variant2, }
+#[repr(C)]
+#[derive(Copy)]
+pub struct foo {
+    pub bar: *mut c_void,
+}
+impl ::core::clone::Clone for foo {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for foo {
+    fn default() -> Self { unsafe { ::core::mem::zeroed() } }
+}
+extern "C" {
+    pub fn takes_void(ptr: *mut c_void);
+}
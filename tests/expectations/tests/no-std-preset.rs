@@ -0,0 +1,78 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+extern crate core;
+
+#[repr(C)]
+pub struct __BindgenUnionField<T>(::core::marker::PhantomData<T>);
+impl <T> __BindgenUnionField<T> {
+    #[inline]
+    pub fn new() -> Self { __BindgenUnionField(::core::marker::PhantomData) }
+    #[inline]
+    pub unsafe fn as_ref(&self) -> &T { ::core::mem::transmute(self) }
+    #[inline]
+    pub unsafe fn as_mut(&mut self) -> &mut T { ::core::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
+}
+impl <T> ::core::default::Default for __BindgenUnionField<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl <T> ::core::clone::Clone for __BindgenUnionField<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new() }
+}
+impl <T> ::core::marker::Copy for __BindgenUnionField<T> { }
+impl <T> ::core::fmt::Debug for __BindgenUnionField<T> {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        fmt.write_str("__BindgenUnionField")
+    }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct foo {
+    pub a: ::core::ffi::c_int,
+    pub b: ::core::ffi::c_int,
+    pub bar: *mut ::core::ffi::c_void,
+}
+impl ::core::clone::Clone for foo {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for foo {
+    fn default() -> Self { unsafe { ::core::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Default, Copy)]
+pub struct _bindgen_ty_1 {
+    pub bar: __BindgenUnionField<::core::ffi::c_int>,
+    pub baz: __BindgenUnionField<::core::ffi::c_long>,
+    pub bindgen_union_field: u64,
+}
+impl ::core::clone::Clone for _bindgen_ty_1 {
+    fn clone(&self) -> Self { *self }
+}
+impl _bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn bar(&self) -> &::core::ffi::c_int { self.bar.as_ref() }
+    #[inline]
+    pub unsafe fn bar_mut(&mut self) -> &mut ::core::ffi::c_int {
+        self.bar.as_mut()
+    }
+    #[inline]
+    pub unsafe fn baz(&self) -> &::core::ffi::c_long { self.baz.as_ref() }
+    #[inline]
+    pub unsafe fn baz_mut(&mut self) -> &mut ::core::ffi::c_long {
+        self.baz.as_mut()
+    }
+}
+extern "C" {
+    #[link_name = "bazz"]
+    pub static mut bazz: _bindgen_ty_1;
+}
+pub type fooFunction =
+    ::core::option::Option<unsafe extern "C" fn(bar: ::core::ffi::c_int)>;
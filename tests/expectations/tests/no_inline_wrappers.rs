@@ -0,0 +1,30 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Counter {
+    pub _address: u8,
+}
+#[test]
+fn bindgen_test_layout_Counter() {
+    assert_eq!(::std::mem::size_of::<Counter>() , 1usize , concat ! (
+               "Size of: " , stringify ! ( Counter ) ));
+    assert_eq! (::std::mem::align_of::<Counter>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( Counter ) ));
+}
+extern "C" {
+    #[link_name = "_ZN7Counter3getEv"]
+    pub fn Counter_get(this: *mut Counter) -> ::std::os::raw::c_int;
+}
+impl Clone for Counter {
+    fn clone(&self) -> Self { *self }
+}
+impl Counter {
+    pub unsafe fn get(&mut self) -> ::std::os::raw::c_int {
+        Counter_get(&mut *self)
+    }
+}
@@ -0,0 +1,37 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+/**
+ * <div rustbindgen no-layout-test></div>
+ */
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Configurable {
+    pub flags: ::std::os::raw::c_int,
+}
+impl Clone for Configurable {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Checked {
+    pub value: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Checked() {
+    assert_eq!(::std::mem::size_of::<Checked>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Checked ) ));
+    assert_eq! (::std::mem::align_of::<Checked>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Checked ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Checked ) ) . value as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Checked ) , "::" ,
+                stringify ! ( value ) ));
+}
+impl Clone for Checked {
+    fn clone(&self) -> Self { *self }
+}
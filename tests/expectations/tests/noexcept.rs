@@ -0,0 +1,24 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    /// Declared `noexcept` in C++.
+    #[link_name = "_Z7checkedi"]
+    pub fn checked(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    /// Declared `noexcept` in C++.
+    #[link_name = "_Z6legacyv"]
+    pub fn legacy();
+}
+extern "C" {
+    #[link_name = "_Z9may_throwi"]
+    pub fn may_throw(x: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "_Z5plainv"]
+    pub fn plain();
+}
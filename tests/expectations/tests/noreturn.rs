@@ -0,0 +1,23 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[cold]
+    pub fn my_abort() -> !;
+}
+extern "C" {
+    #[cold]
+    pub fn my_exit(status: ::std::os::raw::c_int) -> !;
+}
+extern "C" {
+    pub fn my_memcpy(dst: *mut ::std::os::raw::c_void,
+                     src: *const ::std::os::raw::c_void,
+                     n: ::std::os::raw::c_ulong)
+     -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn may_return(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
@@ -23,6 +23,20 @@ pub struct nsStyleAutoArray<T> {
 pub enum nsStyleAutoArray_WithSingleInitialElement {
     WITH_SINGLE_INITIAL_ELEMENT = 0,
 }
+impl From<nsStyleAutoArray_WithSingleInitialElement> for i32 {
+    fn from(value: nsStyleAutoArray_WithSingleInitialElement) -> Self {
+        value as i32
+    }
+}
+impl nsStyleAutoArray_WithSingleInitialElement {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 =>
+            Some(nsStyleAutoArray_WithSingleInitialElement::WITH_SINGLE_INITIAL_ELEMENT),
+            _ => None,
+        }
+    }
+}
 impl <T> Default for nsStyleAutoArray<T> {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
@@ -0,0 +1,17 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct Foo {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn consume_foo(foo: *mut Foo);
+}
+extern "C" {
+    pub fn make_foo() -> *mut Foo;
+}
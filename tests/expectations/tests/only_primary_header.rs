@@ -0,0 +1,85 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct ByValue {
+    pub a: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_ByValue() {
+    assert_eq!(::std::mem::size_of::<ByValue>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( ByValue ) ));
+    assert_eq! (::std::mem::align_of::<ByValue>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( ByValue ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ByValue ) ) . a as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( ByValue ) , "::" ,
+                stringify ! ( a ) ));
+}
+impl Clone for ByValue {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Pointee {
+    pub b: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Pointee() {
+    assert_eq!(::std::mem::size_of::<Pointee>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Pointee ) ));
+    assert_eq! (::std::mem::align_of::<Pointee>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Pointee ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Pointee ) ) . b as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Pointee ) , "::" ,
+                stringify ! ( b ) ));
+}
+impl Clone for Pointee {
+    fn clone(&self) -> Self { *self }
+}
+pub type lib_int = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Wrapper {
+    pub v: ByValue,
+    pub p: *mut Pointee,
+    pub n: lib_int,
+}
+#[test]
+fn bindgen_test_layout_Wrapper() {
+    assert_eq!(::std::mem::size_of::<Wrapper>() , 24usize , concat ! (
+               "Size of: " , stringify ! ( Wrapper ) ));
+    assert_eq! (::std::mem::align_of::<Wrapper>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Wrapper ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Wrapper ) ) . v as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Wrapper ) , "::" ,
+                stringify ! ( v ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Wrapper ) ) . p as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Wrapper ) , "::" ,
+                stringify ! ( p ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Wrapper ) ) . n as * const _ as usize } ,
+                16usize , concat ! (
+                "Alignment of field: " , stringify ! ( Wrapper ) , "::" ,
+                stringify ! ( n ) ));
+}
+impl Clone for Wrapper {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Wrapper {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+extern "C" {
+    pub fn wrapper_fn(w: *mut Wrapper);
+}
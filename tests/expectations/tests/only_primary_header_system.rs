@@ -0,0 +1,55 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct div_t {
+    pub quot: ::std::os::raw::c_int,
+    pub rem: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_div_t() {
+    assert_eq!(::std::mem::size_of::<div_t>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( div_t ) ));
+    assert_eq! (::std::mem::align_of::<div_t>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( div_t ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const div_t ) ) . quot as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( div_t ) , "::" ,
+                stringify ! ( quot ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const div_t ) ) . rem as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( div_t ) , "::" ,
+                stringify ! ( rem ) ));
+}
+impl Clone for div_t {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Quotient {
+    pub d: div_t,
+}
+#[test]
+fn bindgen_test_layout_Quotient() {
+    assert_eq!(::std::mem::size_of::<Quotient>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Quotient ) ));
+    assert_eq! (::std::mem::align_of::<Quotient>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Quotient ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Quotient ) ) . d as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Quotient ) , "::" ,
+                stringify ! ( d ) ));
+}
+impl Clone for Quotient {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn quotient_size(q: *mut Quotient) -> usize;
+}
@@ -0,0 +1,22 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Tag<T> {
+    pub ptr: *mut T,
+}
+impl <T> Default for Tag<T> {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Derived {
+    pub value: ::std::os::raw::c_int,
+}
+impl Clone for Derived {
+    fn clone(&self) -> Self { *self }
+}
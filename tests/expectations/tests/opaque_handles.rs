@@ -0,0 +1,32 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct sqlite3 {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct sqlite3_stmt {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn sqlite3_prepare(db: *mut sqlite3,
+                           sql: *const ::std::os::raw::c_schar,
+                           stmt: *mut *mut sqlite3_stmt)
+     -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sqlite3_step(stmt: *mut sqlite3_stmt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sqlite3_finalize(stmt: *mut sqlite3_stmt)
+     -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sqlite3_close(db: *mut sqlite3) -> ::std::os::raw::c_int;
+}
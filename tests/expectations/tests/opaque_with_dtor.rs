@@ -0,0 +1,18 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct WithDtor {
+    pub _bindgen_opaque_blob: u32,
+}
+#[test]
+fn bindgen_test_layout_WithDtor() {
+    assert_eq!(::std::mem::size_of::<WithDtor>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( WithDtor ) ));
+    assert_eq! (::std::mem::align_of::<WithDtor>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( WithDtor ) ));
+}
@@ -0,0 +1,40 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Vec2 {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Vec2() {
+    assert_eq!(::std::mem::size_of::<Vec2>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Vec2 ) ));
+    assert_eq! (::std::mem::align_of::<Vec2>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Vec2 ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Vec2 ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Vec2 ) , "::" ,
+                stringify ! ( x ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Vec2 ) ) . y as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Vec2 ) , "::" ,
+                stringify ! ( y ) ));
+}
+impl Clone for Vec2 {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    #[link_name = "_Zpl4Vec2S_"]
+    pub fn op_add(a: Vec2, b: Vec2) -> Vec2;
+}
+extern "C" {
+    #[link_name = "_Zeq4Vec2S_"]
+    pub fn op_eq(a: Vec2, b: Vec2) -> bool;
+}
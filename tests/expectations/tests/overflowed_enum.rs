@@ -11,6 +11,27 @@ pub enum Foo {
     BAP_X86 = 11960045,
     BAP_X86_64 = 3128633167,
 }
+impl From<Foo> for u32 {
+    fn from(value: Foo) -> Self { value as u32 }
+}
+impl Foo {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            9698489 => Some(Foo::BAP_ARM),
+            11960045 => Some(Foo::BAP_X86),
+            3128633167 => Some(Foo::BAP_X86_64),
+            _ => None,
+        }
+    }
+}
 #[repr(u16)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Bar { One = 1, Big = 2, }
+impl From<Bar> for u16 {
+    fn from(value: Bar) -> Self { value as u16 }
+}
+impl Bar {
+    pub fn from_raw(value: u16) -> Option<Self> {
+        match value { 1 => Some(Bar::One), 2 => Some(Bar::Big), _ => None, }
+    }
+}
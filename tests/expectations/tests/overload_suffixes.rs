@@ -0,0 +1,54 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[link_name = "_Z10overloadedPA1_c"]
+    pub fn overloaded(arg1: *mut [::std::os::raw::c_schar; 1usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA2_c"]
+    pub fn overloaded1(arg1: *mut [::std::os::raw::c_schar; 2usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA11_c"]
+    pub fn overloaded10(arg1: *mut [::std::os::raw::c_schar; 11usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA12_c"]
+    pub fn overloaded11(arg1: *mut [::std::os::raw::c_schar; 12usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA3_c"]
+    pub fn overloaded2(arg1: *mut [::std::os::raw::c_schar; 3usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA4_c"]
+    pub fn overloaded3(arg1: *mut [::std::os::raw::c_schar; 4usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA5_c"]
+    pub fn overloaded4(arg1: *mut [::std::os::raw::c_schar; 5usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA6_c"]
+    pub fn overloaded5(arg1: *mut [::std::os::raw::c_schar; 6usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA7_c"]
+    pub fn overloaded6(arg1: *mut [::std::os::raw::c_schar; 7usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA8_c"]
+    pub fn overloaded7(arg1: *mut [::std::os::raw::c_schar; 8usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA9_c"]
+    pub fn overloaded8(arg1: *mut [::std::os::raw::c_schar; 9usize]);
+}
+extern "C" {
+    #[link_name = "_Z10overloadedPA10_c"]
+    pub fn overloaded9(arg1: *mut [::std::os::raw::c_schar; 10usize]);
+}
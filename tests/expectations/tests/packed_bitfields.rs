@@ -0,0 +1,139 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+#[repr(C, packed)]
+#[derive(Default, Copy)]
+pub struct PackedBitfields {
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 5usize], u8>,
+    pub c: ::std::os::raw::c_schar,
+    pub d: ::std::os::raw::c_ushort,
+}
+#[test]
+fn bindgen_test_layout_PackedBitfields() {
+    assert_eq!(::std::mem::size_of::<PackedBitfields>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( PackedBitfields ) ));
+    assert_eq! (::std::mem::align_of::<PackedBitfields>() , 1usize , concat !
+                ( "Alignment of " , stringify ! ( PackedBitfields ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const PackedBitfields ) ) . c as * const _ as
+                usize } , 5usize , concat ! (
+                "Alignment of field: " , stringify ! ( PackedBitfields ) ,
+                "::" , stringify ! ( c ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const PackedBitfields ) ) . d as * const _ as
+                usize } , 6usize , concat ! (
+                "Alignment of field: " , stringify ! ( PackedBitfields ) ,
+                "::" , stringify ! ( d ) ));
+}
+impl Clone for PackedBitfields {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::fmt::Debug for PackedBitfields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("PackedBitfields");
+        builder.field("a", &self.a());
+        builder.field("b", &self.b());
+        builder.field("c", &{ self.c });
+        builder.field("d", &{ self.d });
+        builder.finish()
+    }
+}
+impl PackedBitfields {
+    #[inline]
+    pub fn a(&self) -> ::std::os::raw::c_uint {
+        let val = self._bitfield_1.get(0usize, 3u8);
+        unsafe { ::std::mem::transmute(val as u32) }
+    }
+    #[inline]
+    pub fn set_a(&mut self, val: ::std::os::raw::c_uint) {
+        let val = val as u32 as u64;
+        self._bitfield_1.set(0usize, 3u8, val)
+    }
+    #[inline]
+    pub fn b(&self) -> ::std::os::raw::c_uint {
+        let val = self._bitfield_1.get(3usize, 30u8);
+        unsafe { ::std::mem::transmute(val as u32) }
+    }
+    #[inline]
+    pub fn set_b(&mut self, val: ::std::os::raw::c_uint) {
+        let val = val as u32 as u64;
+        self._bitfield_1.set(3usize, 30u8, val)
+    }
+}
@@ -0,0 +1,47 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C, packed)]
+#[derive(Default, Copy)]
+pub struct PackedDebug {
+    pub tag: ::std::os::raw::c_schar,
+    pub value: ::std::os::raw::c_int,
+    pub ratio: f64,
+}
+#[test]
+fn bindgen_test_layout_PackedDebug() {
+    assert_eq!(::std::mem::size_of::<PackedDebug>() , 13usize , concat ! (
+               "Size of: " , stringify ! ( PackedDebug ) ));
+    assert_eq! (::std::mem::align_of::<PackedDebug>() , 1usize , concat ! (
+                "Alignment of " , stringify ! ( PackedDebug ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const PackedDebug ) ) . tag as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( PackedDebug ) , "::" ,
+                stringify ! ( tag ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const PackedDebug ) ) . value as * const _ as
+                usize } , 1usize , concat ! (
+                "Alignment of field: " , stringify ! ( PackedDebug ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const PackedDebug ) ) . ratio as * const _ as
+                usize } , 5usize , concat ! (
+                "Alignment of field: " , stringify ! ( PackedDebug ) , "::" ,
+                stringify ! ( ratio ) ));
+}
+impl Clone for PackedDebug {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::fmt::Debug for PackedDebug {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("PackedDebug");
+        builder.field("tag", &{ self.tag });
+        builder.field("value", &{ self.value });
+        builder.field("ratio", &{ self.ratio });
+        builder.finish()
+    }
+}
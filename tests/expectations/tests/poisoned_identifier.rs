@@ -0,0 +1,58 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Buffer {
+    pub data: *mut ::std::os::raw::c_schar,
+    pub len: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Buffer() {
+    assert_eq!(::std::mem::size_of::<Buffer>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Buffer ) ));
+    assert_eq! (::std::mem::align_of::<Buffer>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Buffer ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . data as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( data ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . len as * const _ as usize }
+                , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( len ) ));
+}
+impl Clone for Buffer {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Buffer {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct stats {
+    pub reads: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_stats() {
+    assert_eq!(::std::mem::size_of::<stats>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( stats ) ));
+    assert_eq! (::std::mem::align_of::<stats>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( stats ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const stats ) ) . reads as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( stats ) , "::" ,
+                stringify ! ( reads ) ));
+}
+impl Clone for stats {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn buffer_read(buf: *mut Buffer) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,100 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Point() {
+    assert_eq!(::std::mem::size_of::<Point>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Point ) ));
+    assert_eq! (::std::mem::align_of::<Point>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Point ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( x ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . y as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( y ) ));
+}
+impl Clone for Point {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Holder {
+    pub value: *mut ::std::os::raw::c_int,
+    pub origin: *const Point,
+}
+#[test]
+fn bindgen_test_layout_Holder() {
+    assert_eq!(::std::mem::size_of::<Holder>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Holder ) ));
+    assert_eq! (::std::mem::align_of::<Holder>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Holder ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Holder ) ) . value as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Holder ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Holder ) ) . origin as * const _ as usize
+                } , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Holder ) , "::" ,
+                stringify ! ( origin ) ));
+}
+impl Clone for Holder {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Outer {
+    pub holder: Holder,
+}
+#[test]
+fn bindgen_test_layout_Outer() {
+    assert_eq!(::std::mem::size_of::<Outer>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Outer ) ));
+    assert_eq! (::std::mem::align_of::<Outer>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Outer ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Outer ) ) . holder as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Outer ) , "::" ,
+                stringify ! ( holder ) ));
+}
+impl Clone for Outer {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    /// Returns a C++ reference, never null.
+    #[link_name = "_Z7counterv"]
+    pub fn counter() -> *mut ::std::os::raw::c_int;
+}
+extern "C" {
+    /// `point`: C++ reference, never null.
+    /// `by`: C++ reference, never null.
+    #[link_name = "_Z9translateR5PointRKS_"]
+    pub fn translate(point: *mut Point, by: *const Point);
+}
+extern "C" {
+    /// `point`: C++ rvalue reference, never null.
+    #[link_name = "_Z7consumeO5Point"]
+    pub fn consume(point: *mut Point);
+}
+extern "C" {
+    /// `point`: C++ reference, never null.
+    /// Returns a C++ rvalue reference, never null.
+    #[link_name = "_Z7forwardR5Point"]
+    pub fn forward(point: *mut Point) -> *mut Point;
+}
@@ -9,8 +9,6 @@ pub mod root {
     #[allow(unused_imports)]
     use self::super::root;
     pub mod foo {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         /// <div rustbindgen replaces="foo::Bar"></div>
         #[repr(C)]
         #[derive(Debug, Default, Copy)]
@@ -0,0 +1,38 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod root {
+    #[allow(unused_imports)]
+    use self::super::root;
+    pub mod lib {
+        pub mod detail {
+            /// <div rustbindgen replaces="detail::Storage"></div>
+            #[repr(C)]
+            #[derive(Debug, Default, Copy)]
+            pub struct Storage {
+                pub custom: ::std::os::raw::c_int,
+            }
+            #[test]
+            fn bindgen_test_layout_Storage() {
+                assert_eq!(::std::mem::size_of::<Storage>() , 4usize , concat
+                           ! ( "Size of: " , stringify ! ( Storage ) ));
+                assert_eq! (::std::mem::align_of::<Storage>() , 4usize ,
+                            concat ! (
+                            "Alignment of " , stringify ! ( Storage ) ));
+                assert_eq! (unsafe {
+                            & ( * ( 0 as * const Storage ) ) . custom as *
+                            const _ as usize } , 0usize , concat ! (
+                            "Alignment of field: " , stringify ! ( Storage ) ,
+                            "::" , stringify ! ( custom ) ));
+            }
+            impl Clone for Storage {
+                fn clone(&self) -> Self { *self }
+            }
+        }
+    }
+    pub type StorageAlias = root::lib::detail::Storage;
+}
@@ -4,7 +4,7 @@
 #![allow(non_snake_case)]
 
 
-pub type FuncType = ::std::option::Option<unsafe extern "C" fn()>;
+pub type FuncType = unsafe extern "C" fn();
 extern "C" {
     pub fn Func();
 }
@@ -5,8 +5,10 @@
 
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct JS_Zone([u8; 0]);
+#[derive(Debug)]
+pub struct JS_Zone {
+    _unused: [u8; 0],
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct JS_shadow_Zone {
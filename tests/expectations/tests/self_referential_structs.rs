@@ -0,0 +1,81 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Node {
+    pub value: ::std::os::raw::c_int,
+    pub next: *mut Node,
+}
+#[test]
+fn bindgen_test_layout_Node() {
+    assert_eq!(::std::mem::size_of::<Node>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Node ) ));
+    assert_eq! (::std::mem::align_of::<Node>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Node ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Node ) ) . value as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Node ) , "::" ,
+                stringify ! ( value ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Node ) ) . next as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Node ) , "::" ,
+                stringify ! ( next ) ));
+}
+impl Clone for Node {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Node {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Even {
+    pub next: *mut Odd,
+}
+#[test]
+fn bindgen_test_layout_Even() {
+    assert_eq!(::std::mem::size_of::<Even>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Even ) ));
+    assert_eq! (::std::mem::align_of::<Even>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Even ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Even ) ) . next as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Even ) , "::" ,
+                stringify ! ( next ) ));
+}
+impl Clone for Even {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Even {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Odd {
+    pub next: *mut Even,
+}
+#[test]
+fn bindgen_test_layout_Odd() {
+    assert_eq!(::std::mem::size_of::<Odd>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Odd ) ));
+    assert_eq! (::std::mem::align_of::<Odd>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Odd ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Odd ) ) . next as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Odd ) , "::" ,
+                stringify ! ( next ) ));
+}
+impl Clone for Odd {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Odd {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
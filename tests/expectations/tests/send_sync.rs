@@ -0,0 +1,66 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct ns_context {
+    pub data: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_ns_context() {
+    assert_eq!(::std::mem::size_of::<ns_context>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( ns_context ) ));
+    assert_eq! (::std::mem::align_of::<ns_context>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( ns_context ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ns_context ) ) . data as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( ns_context ) , "::" ,
+                stringify ! ( data ) ));
+}
+impl Clone for ns_context {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for ns_context {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+/// The user asserted that this type is `Send`, bindgen can't check it.
+unsafe impl Send for ns_context { }
+/// The user asserted that this type is `Sync`, bindgen can't check it.
+unsafe impl Sync for ns_context { }
+/** <div rustbindgen send></div> */
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct handle {
+    pub ctx: *mut ns_context,
+}
+#[test]
+fn bindgen_test_layout_handle() {
+    assert_eq!(::std::mem::size_of::<handle>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( handle ) ));
+    assert_eq! (::std::mem::align_of::<handle>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( handle ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const handle ) ) . ctx as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( handle ) , "::" ,
+                stringify ! ( ctx ) ));
+}
+impl Clone for handle {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for handle {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+/// The user asserted that this type is `Send`, bindgen can't check it.
+unsafe impl Send for handle { }
+#[repr(C)]
+#[derive(Debug)]
+pub struct db {
+    _unused: [u8; 0],
+}
+/// The user asserted that this type is `Send`, bindgen can't check it.
+unsafe impl Send for db { }
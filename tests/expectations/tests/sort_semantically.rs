@@ -0,0 +1,98 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type rect_t = Rect;
+pub type size_type = ::std::os::raw::c_int;
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Axis { AXIS_X = 0, AXIS_Y = 1, }
+impl From<Axis> for u32 {
+    fn from(value: Axis) -> Self { value as u32 }
+}
+impl Axis {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Axis::AXIS_X),
+            1 => Some(Axis::AXIS_Y),
+            _ => None,
+        }
+    }
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color { RED = 0, GREEN = 1, }
+impl From<Color> for u32 {
+    fn from(value: Color) -> Self { value as u32 }
+}
+impl Color {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Color::RED),
+            1 => Some(Color::GREEN),
+            _ => None,
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Point() {
+    assert_eq!(::std::mem::size_of::<Point>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Point ) ));
+    assert_eq! (::std::mem::align_of::<Point>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Point ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . x as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( x ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Point ) ) . y as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Point ) , "::" ,
+                stringify ! ( y ) ));
+}
+impl Clone for Point {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+#[test]
+fn bindgen_test_layout_Rect() {
+    assert_eq!(::std::mem::size_of::<Rect>() , 16usize , concat ! (
+               "Size of: " , stringify ! ( Rect ) ));
+    assert_eq! (::std::mem::align_of::<Rect>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Rect ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Rect ) ) . min as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Rect ) , "::" ,
+                stringify ! ( min ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Rect ) ) . max as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Rect ) , "::" ,
+                stringify ! ( max ) ));
+}
+impl Clone for Rect {
+    fn clone(&self) -> Self { *self }
+}
+pub const MAX_POINTS: u32 = 16;
+extern "C" {
+    pub fn alpha(p: Point) -> ::std::os::raw::c_int;
+    pub fn beta(c: Color);
+    #[link_name = "counter"]
+    pub static mut counter: ::std::os::raw::c_int;
+    pub fn zeta() -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,27 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+/// tests/headers/source_annotations.h:3
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Annotated {
+    pub x: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Annotated() {
+    assert_eq!(::std::mem::size_of::<Annotated>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Annotated ) ));
+    assert_eq! (::std::mem::align_of::<Annotated>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Annotated ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Annotated ) ) . x as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Annotated ) , "::" ,
+                stringify ! ( x ) ));
+}
+impl Clone for Annotated {
+    fn clone(&self) -> Self { *self }
+}
@@ -0,0 +1,77 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Shared {
+    pub refs: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Shared() {
+    assert_eq!(::std::mem::size_of::<Shared>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Shared ) ));
+    assert_eq! (::std::mem::align_of::<Shared>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Shared ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Shared ) ) . refs as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Shared ) , "::" ,
+                stringify ! ( refs ) ));
+}
+extern "C" {
+    #[link_name = "_ZN6SharedC1ERKS_"]
+    pub fn Shared_Shared(this: *mut Shared, other: *const Shared);
+}
+impl Clone for Shared {
+    fn clone(&self) -> Self { *self }
+}
+impl Shared {
+    #[inline]
+    pub unsafe fn new(other: *const Shared) -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        Shared_Shared(&mut __bindgen_tmp, other);
+        __bindgen_tmp
+    }
+}
+extern "C" {
+    #[link_name = "_Z5shareRK6Shared"]
+    pub fn share(__bindgen_ret: *mut Shared, from: *const Shared);
+}
+#[inline]
+pub unsafe fn share_by_value(from: *const Shared) -> Shared {
+    let mut __bindgen_ret = ::std::mem::uninitialized();
+    share(&mut __bindgen_ret, from);
+    __bindgen_ret
+}
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Handle {
+    pub fd: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Handle() {
+    assert_eq!(::std::mem::size_of::<Handle>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Handle ) ));
+    assert_eq! (::std::mem::align_of::<Handle>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Handle ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Handle ) ) . fd as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Handle ) , "::" ,
+                stringify ! ( fd ) ));
+}
+extern "C" {
+    #[link_name = "_Z11open_handlePKc"]
+    pub fn open_handle(__bindgen_ret: *mut Handle,
+                       path: *const ::std::os::raw::c_schar);
+}
+#[inline]
+pub unsafe fn open_handle_by_value(path: *const ::std::os::raw::c_schar)
+ -> Handle {
+    let mut __bindgen_ret = ::std::mem::uninitialized();
+    open_handle(&mut __bindgen_ret, path);
+    __bindgen_ret
+}
@@ -0,0 +1,13 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    /// Note: this symbol has hidden visibility, so it's most likely not exported from the library that defines it.
+    pub fn hidden_fn(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn exported_fn(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
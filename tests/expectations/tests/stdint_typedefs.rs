@@ -0,0 +1,45 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type uint16_t = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Fields {
+    pub a: u8,
+    pub b: u32,
+    pub c: i64,
+    pub d: uint16_t,
+}
+#[test]
+fn bindgen_test_layout_Fields() {
+    assert_eq!(::std::mem::size_of::<Fields>() , 24usize , concat ! (
+               "Size of: " , stringify ! ( Fields ) ));
+    assert_eq! (::std::mem::align_of::<Fields>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Fields ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Fields ) ) . a as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Fields ) , "::" ,
+                stringify ! ( a ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Fields ) ) . b as * const _ as usize } ,
+                4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Fields ) , "::" ,
+                stringify ! ( b ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Fields ) ) . c as * const _ as usize } ,
+                8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Fields ) , "::" ,
+                stringify ! ( c ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Fields ) ) . d as * const _ as usize } ,
+                16usize , concat ! (
+                "Alignment of field: " , stringify ! ( Fields ) , "::" ,
+                stringify ! ( d ) ));
+}
+impl Clone for Fields {
+    fn clone(&self) -> Self { *self }
+}
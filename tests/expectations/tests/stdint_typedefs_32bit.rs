@@ -0,0 +1,33 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Buffer {
+    pub len: usize,
+    pub pos: isize,
+}
+#[test]
+#[cfg(all(target_arch = "x86", target_pointer_width = "32"))]
+fn bindgen_test_layout_Buffer() {
+    assert_eq!(::std::mem::size_of::<Buffer>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( Buffer ) ));
+    assert_eq! (::std::mem::align_of::<Buffer>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Buffer ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . len as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( len ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . pos as * const _ as usize }
+                , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( pos ) ));
+}
+impl Clone for Buffer {
+    fn clone(&self) -> Self { *self }
+}
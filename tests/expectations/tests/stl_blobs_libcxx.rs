@@ -0,0 +1,64 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+/// Opaque blob for `std::vector<int>`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct std_vector_int {
+    pub _bindgen_opaque_blob: [u64; 3usize],
+}
+#[test]
+fn bindgen_test_layout_std_vector_int() {
+    assert_eq!(::std::mem::size_of::<std_vector_int>() , 24usize , concat ! (
+               "Size of: " , stringify ! ( std_vector_int ) ));
+    assert_eq! (::std::mem::align_of::<std_vector_int>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( std_vector_int ) ));
+}
+/// Opaque blob for `std::shared_ptr<Container>`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct std_shared_ptr_Container {
+    pub _bindgen_opaque_blob: [u64; 2usize],
+}
+#[test]
+fn bindgen_test_layout_std_shared_ptr_Container() {
+    assert_eq!(::std::mem::size_of::<std_shared_ptr_Container>() , 16usize ,
+               concat ! (
+               "Size of: " , stringify ! ( std_shared_ptr_Container ) ));
+    assert_eq! (::std::mem::align_of::<std_shared_ptr_Container>() , 8usize ,
+                concat ! (
+                "Alignment of " , stringify ! ( std_shared_ptr_Container ) ));
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Container {
+    pub items: std_vector_int,
+    pub shared: std_shared_ptr_Container,
+}
+#[test]
+fn bindgen_test_layout_Container() {
+    assert_eq!(::std::mem::size_of::<Container>() , 40usize , concat ! (
+               "Size of: " , stringify ! ( Container ) ));
+    assert_eq! (::std::mem::align_of::<Container>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Container ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Container ) ) . items as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Container ) , "::" ,
+                stringify ! ( items ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Container ) ) . shared as * const _ as
+                usize } , 24usize , concat ! (
+                "Alignment of field: " , stringify ! ( Container ) , "::" ,
+                stringify ! ( shared ) ));
+}
+impl Clone for Container {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    #[link_name = "_Z11take_vectorNSt3__16vectorIiNS_9allocatorIiEEEE"]
+    pub fn take_vector(v: std_vector_int);
+}
@@ -0,0 +1,79 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+/// Opaque blob for `std::vector<int>`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct std_vector_int {
+    pub _bindgen_opaque_blob: [u64; 3usize],
+}
+#[test]
+fn bindgen_test_layout_std_vector_int() {
+    assert_eq!(::std::mem::size_of::<std_vector_int>() , 24usize , concat ! (
+               "Size of: " , stringify ! ( std_vector_int ) ));
+    assert_eq! (::std::mem::align_of::<std_vector_int>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( std_vector_int ) ));
+}
+/// Opaque blob for `std::vector<int *>`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct std_vector_int_ptr {
+    pub _bindgen_opaque_blob: [u64; 3usize],
+}
+#[test]
+fn bindgen_test_layout_std_vector_int_ptr() {
+    assert_eq!(::std::mem::size_of::<std_vector_int_ptr>() , 24usize , concat
+               ! ( "Size of: " , stringify ! ( std_vector_int_ptr ) ));
+    assert_eq! (::std::mem::align_of::<std_vector_int_ptr>() , 8usize , concat
+                ! ( "Alignment of " , stringify ! ( std_vector_int_ptr ) ));
+}
+/// Opaque blob for `std::vector<const int *>`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct std_vector_const_int_ptr {
+    pub _bindgen_opaque_blob: [u64; 3usize],
+}
+#[test]
+fn bindgen_test_layout_std_vector_const_int_ptr() {
+    assert_eq!(::std::mem::size_of::<std_vector_const_int_ptr>() , 24usize ,
+               concat ! (
+               "Size of: " , stringify ! ( std_vector_const_int_ptr ) ));
+    assert_eq! (::std::mem::align_of::<std_vector_const_int_ptr>() , 8usize ,
+                concat ! (
+                "Alignment of " , stringify ! ( std_vector_const_int_ptr ) ));
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Lists {
+    pub values: std_vector_int,
+    pub pointers: std_vector_int_ptr,
+    pub const_pointers: std_vector_const_int_ptr,
+}
+#[test]
+fn bindgen_test_layout_Lists() {
+    assert_eq!(::std::mem::size_of::<Lists>() , 72usize , concat ! (
+               "Size of: " , stringify ! ( Lists ) ));
+    assert_eq! (::std::mem::align_of::<Lists>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Lists ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Lists ) ) . values as * const _ as usize
+                } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Lists ) , "::" ,
+                stringify ! ( values ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Lists ) ) . pointers as * const _ as
+                usize } , 24usize , concat ! (
+                "Alignment of field: " , stringify ! ( Lists ) , "::" ,
+                stringify ! ( pointers ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Lists ) ) . const_pointers as * const _
+                as usize } , 48usize , concat ! (
+                "Alignment of field: " , stringify ! ( Lists ) , "::" ,
+                stringify ! ( const_pointers ) ));
+}
+impl Clone for Lists {
+    fn clone(&self) -> Self { *self }
+}
@@ -53,9 +53,28 @@ pub type struct_ptr_ptr_t = *mut *mut _bindgen_ty_1;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum typedef_named_enum { ENUM_HAS_NAME = 1, }
+impl From<typedef_named_enum> for u32 {
+    fn from(value: typedef_named_enum) -> Self { value as u32 }
+}
+impl typedef_named_enum {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(typedef_named_enum::ENUM_HAS_NAME),
+            _ => None,
+        }
+    }
+}
 pub const ENUM_IS_ANON: _bindgen_ty_2 = _bindgen_ty_2::ENUM_IS_ANON;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum _bindgen_ty_2 { ENUM_IS_ANON = 0, }
+impl From<_bindgen_ty_2> for u32 {
+    fn from(value: _bindgen_ty_2) -> Self { value as u32 }
+}
+impl _bindgen_ty_2 {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value { 0 => Some(_bindgen_ty_2::ENUM_IS_ANON), _ => None, }
+    }
+}
 pub type enum_ptr_t = *mut _bindgen_ty_2;
 pub type enum_ptr_ptr_t = *mut *mut _bindgen_ty_2;
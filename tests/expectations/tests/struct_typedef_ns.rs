@@ -6,8 +6,6 @@
 
 #[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
 pub mod root {
-    #[allow(unused_imports)]
-    use self::super::root;
     pub mod whatever {
         #[allow(unused_imports)]
         use self::super::super::root;
@@ -39,6 +37,14 @@ pub mod root {
         #[repr(u32)]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         pub enum _bindgen_ty_2 { BAR = 1, }
+        impl From<_bindgen_ty_2> for u32 {
+            fn from(value: _bindgen_ty_2) -> Self { value as u32 }
+        }
+        impl _bindgen_ty_2 {
+            pub fn from_raw(value: u32) -> Option<Self> {
+                match value { 1 => Some(_bindgen_ty_2::BAR), _ => None, }
+            }
+        }
         pub use self::super::super::root::whatever::_bindgen_ty_2 as
                 typedef_enum;
     }
@@ -74,6 +80,14 @@ pub mod root {
         #[repr(u32)]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         pub enum _bindgen_ty_2 { BAR = 1, }
+        impl From<_bindgen_ty_2> for u32 {
+            fn from(value: _bindgen_ty_2) -> Self { value as u32 }
+        }
+        impl _bindgen_ty_2 {
+            pub fn from_raw(value: u32) -> Option<Self> {
+                match value { 1 => Some(_bindgen_ty_2::BAR), _ => None, }
+            }
+        }
         pub use self::super::super::root::_bindgen_mod_id_12::_bindgen_ty_2 as
                 typedef_enum;
     }
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -135,6 +139,34 @@ fn bindgen_test_layout_foo__bindgen_ty_1() {
 impl Clone for foo__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl foo__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn b(&self) -> &::std::os::raw::c_uint { self.b.as_ref() }
+    #[inline]
+    pub unsafe fn b_mut(&mut self) -> &mut ::std::os::raw::c_uint {
+        self.b.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self)
+     -> &foo__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut foo__bindgen_ty_1__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_2(&self)
+     -> &foo__bindgen_ty_1__bindgen_ty_2 {
+        self.__bindgen_anon_2.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_2_mut(&mut self)
+     -> &mut foo__bindgen_ty_1__bindgen_ty_2 {
+        self.__bindgen_anon_2.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_foo() {
     assert_eq!(::std::mem::size_of::<foo>() , 8usize , concat ! (
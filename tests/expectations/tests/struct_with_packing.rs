@@ -5,7 +5,7 @@
 
 
 #[repr(C, packed)]
-#[derive(Debug, Default, Copy)]
+#[derive(Default, Copy)]
 pub struct a {
     pub b: ::std::os::raw::c_schar,
     pub c: ::std::os::raw::c_short,
@@ -28,3 +28,11 @@ fn bindgen_test_layout_a() {
 impl Clone for a {
     fn clone(&self) -> Self { *self }
 }
+impl ::std::fmt::Debug for a {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let mut builder = f.debug_struct("a");
+        builder.field("b", &{ self.b });
+        builder.field("c", &{ self.c });
+        builder.finish()
+    }
+}
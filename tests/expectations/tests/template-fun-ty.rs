@@ -9,8 +9,7 @@
 pub struct Foo {
     pub _address: u8,
 }
-pub type Foo_FunctionPtr<T> =
-    ::std::option::Option<unsafe extern "C" fn() -> T>;
+pub type Foo_FunctionPtr<T> = unsafe extern "C" fn() -> T;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct RefPtr {
@@ -0,0 +1,11 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+extern "C" {
+    #[thread_local]
+    #[link_name = "counter"]
+    pub static mut counter: ::std::os::raw::c_int;
+}
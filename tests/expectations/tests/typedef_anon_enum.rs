@@ -0,0 +1,51 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub const Mode_MODE_READ: Mode = 0;
+pub const Mode_MODE_WRITE: Mode = 1;
+pub type Mode = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct File {
+    pub mode: Mode,
+}
+#[test]
+fn bindgen_test_layout_File() {
+    assert_eq!(::std::mem::size_of::<File>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( File ) ));
+    assert_eq! (::std::mem::align_of::<File>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( File ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const File ) ) . mode as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( File ) , "::" ,
+                stringify ! ( mode ) ));
+}
+impl Clone for File {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for File {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+extern "C" {
+    pub fn set_mode(file: *mut File, mode: Mode);
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Level { LEVEL_LOW = 0, LEVEL_HIGH = 1, }
+impl From<Level> for u32 {
+    fn from(value: Level) -> Self { value as u32 }
+}
+impl Level {
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Level::LEVEL_LOW),
+            1 => Some(Level::LEVEL_HIGH),
+            _ => None,
+        }
+    }
+}
+pub type Severity = Level;
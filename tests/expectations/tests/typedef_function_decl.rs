@@ -0,0 +1,36 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub type handler =
+    unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)
+        -> ::std::os::raw::c_int;
+extern "C" {
+    pub fn dispatch(data: *mut ::std::os::raw::c_void)
+     -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct handler_table {
+    pub on_event: ::std::option::Option<handler>,
+}
+#[test]
+fn bindgen_test_layout_handler_table() {
+    assert_eq!(::std::mem::size_of::<handler_table>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( handler_table ) ));
+    assert_eq! (::std::mem::align_of::<handler_table>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( handler_table ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const handler_table ) ) . on_event as * const _
+                as usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( handler_table ) , "::"
+                , stringify ! ( on_event ) ));
+}
+impl Clone for handler_table {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for handler_table {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -100,6 +104,25 @@ pub struct mozilla_StyleShapeSource__bindgen_ty_1 {
     pub mFragmentOrURL: __BindgenUnionField<*mut mozilla_FragmentOrURL>,
     pub bindgen_union_field: u64,
 }
+impl mozilla_StyleShapeSource__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn mPosition(&self) -> &*mut mozilla_Position {
+        self.mPosition.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mPosition_mut(&mut self) -> &mut *mut mozilla_Position {
+        self.mPosition.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mFragmentOrURL(&self) -> &*mut mozilla_FragmentOrURL {
+        self.mFragmentOrURL.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mFragmentOrURL_mut(&mut self)
+     -> &mut *mut mozilla_FragmentOrURL {
+        self.mFragmentOrURL.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Copy)]
 pub struct Bar {
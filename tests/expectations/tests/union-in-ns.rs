@@ -19,6 +19,10 @@ pub mod root {
         pub unsafe fn as_mut(&mut self) -> &mut T {
             ::std::mem::transmute(self)
         }
+        #[inline]
+        pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+        #[inline]
+        pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
     }
     impl <T> ::std::default::Default for __BindgenUnionField<T> {
         #[inline]
@@ -57,4 +61,14 @@ pub mod root {
     impl Clone for bar {
         fn clone(&self) -> Self { *self }
     }
+    impl bar {
+        #[inline]
+        pub unsafe fn baz(&self) -> &::std::os::raw::c_int {
+            self.baz.as_ref()
+        }
+        #[inline]
+        pub unsafe fn baz_mut(&mut self) -> &mut ::std::os::raw::c_int {
+            self.baz.as_mut()
+        }
+    }
 }
@@ -0,0 +1,161 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage, Align> where Storage: AsRef<[u8]> +
+           AsMut<[u8]>, Align: Copy {
+    storage: Storage,
+    align: [Align; 0],
+}
+impl <Storage, Align> __BindgenBitfieldUnit<Storage, Align> where
+ Storage: AsRef<[u8]> + AsMut<[u8]>, Align: Copy {
+    #[inline]
+    pub fn new(storage: Storage) -> Self {
+        __BindgenBitfieldUnit{storage: storage, align: [],}
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self . storage . as_ref (  ) . len (  ));
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index =
+            if cfg!(target_endian = "big") {
+                7 - (index % 8)
+            } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val { *byte |= mask; } else { *byte &= !mask; }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index =
+                    if cfg!(target_endian = "big") {
+                        (bit_width as usize) - 1 - i
+                    } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self . storage . as_ref (  ) . len (
+                      ));
+        debug_assert!(( bit_offset + ( bit_width as usize ) ) / 8 <= self .
+                      storage . as_ref (  ) . len (  ));
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index =
+                if cfg!(target_endian = "big") {
+                    (bit_width as usize) - 1 - i
+                } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+#[repr(C)]
+pub struct __BindgenUnionField<T>(::std::marker::PhantomData<T>);
+impl <T> __BindgenUnionField<T> {
+    #[inline]
+    pub fn new() -> Self { __BindgenUnionField(::std::marker::PhantomData) }
+    #[inline]
+    pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
+    #[inline]
+    pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
+}
+impl <T> ::std::default::Default for __BindgenUnionField<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl <T> ::std::clone::Clone for __BindgenUnionField<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new() }
+}
+impl <T> ::std::marker::Copy for __BindgenUnionField<T> { }
+impl <T> ::std::fmt::Debug for __BindgenUnionField<T> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        fmt.write_str("__BindgenUnionField")
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct Flags {
+    pub raw: __BindgenUnionField<::std::os::raw::c_uint>,
+    pub _bitfield_1: __BindgenUnionField<__BindgenBitfieldUnit<[u8; 4usize],
+                                                               u32>>,
+    pub _bitfield_2: __BindgenUnionField<__BindgenBitfieldUnit<[u8; 4usize],
+                                                               u32>>,
+    pub bindgen_union_field: u32,
+}
+#[test]
+fn bindgen_test_layout_Flags() {
+    assert_eq!(::std::mem::size_of::<Flags>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( Flags ) ));
+    assert_eq! (::std::mem::align_of::<Flags>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( Flags ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Flags ) ) . raw as * const _ as usize } ,
+                0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Flags ) , "::" ,
+                stringify ! ( raw ) ));
+}
+impl Clone for Flags {
+    fn clone(&self) -> Self { *self }
+}
+impl Flags {
+    #[inline]
+    pub unsafe fn raw(&self) -> &::std::os::raw::c_uint { self.raw.as_ref() }
+    #[inline]
+    pub unsafe fn raw_mut(&mut self) -> &mut ::std::os::raw::c_uint {
+        self.raw.as_mut()
+    }
+    #[inline]
+    pub unsafe fn kind(&self) -> ::std::os::raw::c_uint {
+        let val = self._bitfield_1.as_ref().get(0usize, 3u8);
+        ::std::mem::transmute(val as u32)
+    }
+    #[inline]
+    pub unsafe fn set_kind(&mut self, val: ::std::os::raw::c_uint) {
+        let val = val as u32 as u64;
+        self._bitfield_1.as_mut().set(0usize, 3u8, val)
+    }
+    #[inline]
+    pub unsafe fn value(&self) -> ::std::os::raw::c_uint {
+        let val = self._bitfield_2.as_ref().get(0usize, 29u8);
+        ::std::mem::transmute(val as u32)
+    }
+    #[inline]
+    pub unsafe fn set_value(&mut self, val: ::std::os::raw::c_uint) {
+        let val = val as u32 as u64;
+        self._bitfield_2.as_mut().set(0usize, 29u8, val)
+    }
+}
@@ -0,0 +1,114 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+pub struct __BindgenUnionField<T>(::std::marker::PhantomData<T>);
+impl <T> __BindgenUnionField<T> {
+    #[inline]
+    pub fn new() -> Self { __BindgenUnionField(::std::marker::PhantomData) }
+    #[inline]
+    pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
+    #[inline]
+    pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
+}
+impl <T> ::std::default::Default for __BindgenUnionField<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl <T> ::std::clone::Clone for __BindgenUnionField<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new() }
+}
+impl <T> ::std::marker::Copy for __BindgenUnionField<T> { }
+impl <T> ::std::fmt::Debug for __BindgenUnionField<T> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        fmt.write_str("__BindgenUnionField")
+    }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct WithBigArray {
+    pub a: __BindgenUnionField<::std::os::raw::c_int>,
+    pub b: __BindgenUnionField<[::std::os::raw::c_int; 33usize]>,
+    pub bindgen_union_field: [u32; 33usize],
+}
+#[test]
+fn bindgen_test_layout_WithBigArray() {
+    assert_eq!(::std::mem::size_of::<WithBigArray>() , 132usize , concat ! (
+               "Size of: " , stringify ! ( WithBigArray ) ));
+    assert_eq! (::std::mem::align_of::<WithBigArray>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( WithBigArray ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const WithBigArray ) ) . a as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( WithBigArray ) , "::" ,
+                stringify ! ( a ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const WithBigArray ) ) . b as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( WithBigArray ) , "::" ,
+                stringify ! ( b ) ));
+}
+impl Clone for WithBigArray {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::fmt::Debug for WithBigArray {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str("WithBigArray { union, size: 132 }")
+    }
+}
+impl Default for WithBigArray {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+impl WithBigArray {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_int { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn b(&self) -> &[::std::os::raw::c_int; 33usize] {
+        self.b.as_ref()
+    }
+    #[inline]
+    pub unsafe fn b_mut(&mut self) -> &mut [::std::os::raw::c_int; 33usize] {
+        self.b.as_mut()
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct ContainsBigArray {
+    pub tag: ::std::os::raw::c_int,
+    pub u: WithBigArray,
+}
+#[test]
+fn bindgen_test_layout_ContainsBigArray() {
+    assert_eq!(::std::mem::size_of::<ContainsBigArray>() , 136usize , concat !
+               ( "Size of: " , stringify ! ( ContainsBigArray ) ));
+    assert_eq! (::std::mem::align_of::<ContainsBigArray>() , 4usize , concat !
+                ( "Alignment of " , stringify ! ( ContainsBigArray ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ContainsBigArray ) ) . tag as * const _
+                as usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( ContainsBigArray ) ,
+                "::" , stringify ! ( tag ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const ContainsBigArray ) ) . u as * const _ as
+                usize } , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( ContainsBigArray ) ,
+                "::" , stringify ! ( u ) ));
+}
+impl Clone for ContainsBigArray {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for ContainsBigArray {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
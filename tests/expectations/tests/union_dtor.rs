@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -52,3 +56,19 @@ fn bindgen_test_layout_UnionWithDtor() {
                 "Alignment of field: " , stringify ! ( UnionWithDtor ) , "::"
                 , stringify ! ( mBar ) ));
 }
+impl UnionWithDtor {
+    #[inline]
+    pub unsafe fn mFoo(&self) -> &::std::os::raw::c_int { self.mFoo.as_ref() }
+    #[inline]
+    pub unsafe fn mFoo_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.mFoo.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mBar(&self) -> &*mut ::std::os::raw::c_void {
+        self.mBar.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mBar_mut(&mut self) -> &mut *mut ::std::os::raw::c_void {
+        self.mBar.as_mut()
+    }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -61,3 +65,24 @@ fn bindgen_test_layout_nsStyleUnion() {
 impl Clone for nsStyleUnion {
     fn clone(&self) -> Self { *self }
 }
+impl nsStyleUnion {
+    #[inline]
+    pub unsafe fn mInt(&self) -> &::std::os::raw::c_int { self.mInt.as_ref() }
+    #[inline]
+    pub unsafe fn mInt_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.mInt.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mFloat(&self) -> &f32 { self.mFloat.as_ref() }
+    #[inline]
+    pub unsafe fn mFloat_mut(&mut self) -> &mut f32 { self.mFloat.as_mut() }
+    #[inline]
+    pub unsafe fn mPointer(&self) -> &*mut ::std::os::raw::c_void {
+        self.mPointer.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mPointer_mut(&mut self)
+     -> &mut *mut ::std::os::raw::c_void {
+        self.mPointer.as_mut()
+    }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -42,6 +46,24 @@ pub struct NastyStruct__bindgen_ty_1 {
     pub mDummy: __BindgenUnionField<::std::os::raw::c_ulong>,
     pub bindgen_union_field: u64,
 }
+impl NastyStruct__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn mFoo(&self) -> &*mut ::std::os::raw::c_void {
+        self.mFoo.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mFoo_mut(&mut self) -> &mut *mut ::std::os::raw::c_void {
+        self.mFoo.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mDummy(&self) -> &::std::os::raw::c_ulong {
+        self.mDummy.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mDummy_mut(&mut self) -> &mut ::std::os::raw::c_ulong {
+        self.mDummy.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct NastyStruct__bindgen_ty_2 {
@@ -49,6 +71,22 @@ pub struct NastyStruct__bindgen_ty_2 {
     pub wut: __BindgenUnionField<*mut ::std::os::raw::c_int>,
     pub bindgen_union_field: u64,
 }
+impl NastyStruct__bindgen_ty_2 {
+    #[inline]
+    pub unsafe fn wat(&self) -> &::std::os::raw::c_short { self.wat.as_ref() }
+    #[inline]
+    pub unsafe fn wat_mut(&mut self) -> &mut ::std::os::raw::c_short {
+        self.wat.as_mut()
+    }
+    #[inline]
+    pub unsafe fn wut(&self) -> &*mut ::std::os::raw::c_int {
+        self.wut.as_ref()
+    }
+    #[inline]
+    pub unsafe fn wut_mut(&mut self) -> &mut *mut ::std::os::raw::c_int {
+        self.wut.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct Whatever {
@@ -56,3 +94,19 @@ pub struct Whatever {
     pub mInt: __BindgenUnionField<::std::os::raw::c_int>,
     pub bindgen_union_field: u64,
 }
+impl Whatever {
+    #[inline]
+    pub unsafe fn mTPtr(&self) -> &*mut ::std::os::raw::c_void {
+        self.mTPtr.as_ref()
+    }
+    #[inline]
+    pub unsafe fn mTPtr_mut(&mut self) -> &mut *mut ::std::os::raw::c_void {
+        self.mTPtr.as_mut()
+    }
+    #[inline]
+    pub unsafe fn mInt(&self) -> &::std::os::raw::c_int { self.mInt.as_ref() }
+    #[inline]
+    pub unsafe fn mInt_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.mInt.as_mut()
+    }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -75,3 +79,11 @@ fn bindgen_test_layout_foo() {
 impl Clone for foo {
     fn clone(&self) -> Self { *self }
 }
+impl foo {
+    #[inline]
+    pub unsafe fn bar(&self) -> &foo__bindgen_ty_1 { self.bar.as_ref() }
+    #[inline]
+    pub unsafe fn bar_mut(&mut self) -> &mut foo__bindgen_ty_1 {
+        self.bar.as_mut()
+    }
+}
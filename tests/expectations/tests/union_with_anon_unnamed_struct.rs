@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -89,3 +93,22 @@ fn bindgen_test_layout_pixel() {
 impl Clone for pixel {
     fn clone(&self) -> Self { *self }
 }
+impl pixel {
+    #[inline]
+    pub unsafe fn rgba(&self) -> &::std::os::raw::c_uint {
+        self.rgba.as_ref()
+    }
+    #[inline]
+    pub unsafe fn rgba_mut(&mut self) -> &mut ::std::os::raw::c_uint {
+        self.rgba.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self) -> &pixel__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self)
+     -> &mut pixel__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
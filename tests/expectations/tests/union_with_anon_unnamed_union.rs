@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -62,6 +66,20 @@ fn bindgen_test_layout_foo__bindgen_ty_1() {
 impl Clone for foo__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl foo__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn b(&self) -> &::std::os::raw::c_ushort { self.b.as_ref() }
+    #[inline]
+    pub unsafe fn b_mut(&mut self) -> &mut ::std::os::raw::c_ushort {
+        self.b.as_mut()
+    }
+    #[inline]
+    pub unsafe fn c(&self) -> &::std::os::raw::c_uchar { self.c.as_ref() }
+    #[inline]
+    pub unsafe fn c_mut(&mut self) -> &mut ::std::os::raw::c_uchar {
+        self.c.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_foo() {
     assert_eq!(::std::mem::size_of::<foo>() , 4usize , concat ! (
@@ -77,3 +95,19 @@ fn bindgen_test_layout_foo() {
 impl Clone for foo {
     fn clone(&self) -> Self { *self }
 }
+impl foo {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_uint { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_uint {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self) -> &foo__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self) -> &mut foo__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
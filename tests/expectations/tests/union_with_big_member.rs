@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -58,6 +62,22 @@ impl Clone for WithBigArray {
 impl Default for WithBigArray {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+impl WithBigArray {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_int { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn b(&self) -> &[::std::os::raw::c_int; 33usize] {
+        self.b.as_ref()
+    }
+    #[inline]
+    pub unsafe fn b_mut(&mut self) -> &mut [::std::os::raw::c_int; 33usize] {
+        self.b.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct WithBigArray2 {
@@ -85,6 +105,23 @@ fn bindgen_test_layout_WithBigArray2() {
 impl Clone for WithBigArray2 {
     fn clone(&self) -> Self { *self }
 }
+impl WithBigArray2 {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_int { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn b(&self) -> &[::std::os::raw::c_schar; 33usize] {
+        self.b.as_ref()
+    }
+    #[inline]
+    pub unsafe fn b_mut(&mut self)
+     -> &mut [::std::os::raw::c_schar; 33usize] {
+        self.b.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Copy)]
 pub struct WithBigMember {
@@ -115,3 +152,15 @@ impl Clone for WithBigMember {
 impl Default for WithBigMember {
     fn default() -> Self { unsafe { ::std::mem::zeroed() } }
 }
+impl WithBigMember {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_int { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn b(&self) -> &WithBigArray { self.b.as_ref() }
+    #[inline]
+    pub unsafe fn b_mut(&mut self) -> &mut WithBigArray { self.b.as_mut() }
+}
@@ -13,6 +13,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::std::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::std::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::std::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -74,6 +78,20 @@ fn bindgen_test_layout_foo__bindgen_ty_1__bindgen_ty_1() {
 impl Clone for foo__bindgen_ty_1__bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl foo__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn b1(&self) -> &::std::os::raw::c_ushort { self.b1.as_ref() }
+    #[inline]
+    pub unsafe fn b1_mut(&mut self) -> &mut ::std::os::raw::c_ushort {
+        self.b1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn b2(&self) -> &::std::os::raw::c_ushort { self.b2.as_ref() }
+    #[inline]
+    pub unsafe fn b2_mut(&mut self) -> &mut ::std::os::raw::c_ushort {
+        self.b2.as_mut()
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy)]
 pub struct foo__bindgen_ty_1__bindgen_ty_2 {
@@ -107,6 +125,20 @@ fn bindgen_test_layout_foo__bindgen_ty_1__bindgen_ty_2() {
 impl Clone for foo__bindgen_ty_1__bindgen_ty_2 {
     fn clone(&self) -> Self { *self }
 }
+impl foo__bindgen_ty_1__bindgen_ty_2 {
+    #[inline]
+    pub unsafe fn c1(&self) -> &::std::os::raw::c_ushort { self.c1.as_ref() }
+    #[inline]
+    pub unsafe fn c1_mut(&mut self) -> &mut ::std::os::raw::c_ushort {
+        self.c1.as_mut()
+    }
+    #[inline]
+    pub unsafe fn c2(&self) -> &::std::os::raw::c_ushort { self.c2.as_ref() }
+    #[inline]
+    pub unsafe fn c2_mut(&mut self) -> &mut ::std::os::raw::c_ushort {
+        self.c2.as_mut()
+    }
+}
 #[test]
 fn bindgen_test_layout_foo__bindgen_ty_1() {
     assert_eq!(::std::mem::size_of::<foo__bindgen_ty_1>() , 4usize , concat !
@@ -132,3 +164,19 @@ fn bindgen_test_layout_foo() {
 impl Clone for foo {
     fn clone(&self) -> Self { *self }
 }
+impl foo {
+    #[inline]
+    pub unsafe fn a(&self) -> &::std::os::raw::c_uint { self.a.as_ref() }
+    #[inline]
+    pub unsafe fn a_mut(&mut self) -> &mut ::std::os::raw::c_uint {
+        self.a.as_mut()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1(&self) -> &foo__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_ref()
+    }
+    #[inline]
+    pub unsafe fn __bindgen_anon_1_mut(&mut self) -> &mut foo__bindgen_ty_1 {
+        self.__bindgen_anon_1.as_mut()
+    }
+}
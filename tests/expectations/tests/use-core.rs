@@ -14,6 +14,10 @@ impl <T> __BindgenUnionField<T> {
     pub unsafe fn as_ref(&self) -> &T { ::core::mem::transmute(self) }
     #[inline]
     pub unsafe fn as_mut(&mut self) -> &mut T { ::core::mem::transmute(self) }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self as *const Self as *const T }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self as *mut Self as *mut T }
 }
 impl <T> ::core::default::Default for __BindgenUnionField<T> {
     #[inline]
@@ -91,6 +95,20 @@ fn bindgen_test_layout__bindgen_ty_1() {
 impl Clone for _bindgen_ty_1 {
     fn clone(&self) -> Self { *self }
 }
+impl _bindgen_ty_1 {
+    #[inline]
+    pub unsafe fn bar(&self) -> &::std::os::raw::c_int { self.bar.as_ref() }
+    #[inline]
+    pub unsafe fn bar_mut(&mut self) -> &mut ::std::os::raw::c_int {
+        self.bar.as_mut()
+    }
+    #[inline]
+    pub unsafe fn baz(&self) -> &::std::os::raw::c_long { self.baz.as_ref() }
+    #[inline]
+    pub unsafe fn baz_mut(&mut self) -> &mut ::std::os::raw::c_long {
+        self.baz.as_mut()
+    }
+}
 extern "C" {
     #[link_name = "bazz"]
     pub static mut bazz: _bindgen_ty_1;
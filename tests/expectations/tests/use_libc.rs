@@ -0,0 +1,57 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+extern crate libc;
+
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct Buffer {
+    pub data: *mut ::libc::c_schar,
+    pub len: ::libc::size_t,
+    pub pos: ::libc::ssize_t,
+    pub sep: ::libc::wchar_t,
+    pub user: *mut ::libc::c_void,
+}
+#[test]
+fn bindgen_test_layout_Buffer() {
+    assert_eq!(::std::mem::size_of::<Buffer>() , 40usize , concat ! (
+               "Size of: " , stringify ! ( Buffer ) ));
+    assert_eq! (::std::mem::align_of::<Buffer>() , 8usize , concat ! (
+                "Alignment of " , stringify ! ( Buffer ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . data as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( data ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . len as * const _ as usize }
+                , 8usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( len ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . pos as * const _ as usize }
+                , 16usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( pos ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . sep as * const _ as usize }
+                , 24usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( sep ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const Buffer ) ) . user as * const _ as usize }
+                , 32usize , concat ! (
+                "Alignment of field: " , stringify ! ( Buffer ) , "::" ,
+                stringify ! ( user ) ));
+}
+impl Clone for Buffer {
+    fn clone(&self) -> Self { *self }
+}
+impl Default for Buffer {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+extern "C" {
+    pub fn buffer_len(buf: *mut Buffer) -> ::libc::size_t;
+}
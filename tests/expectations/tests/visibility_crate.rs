@@ -0,0 +1,30 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+pub(crate) type visible_int = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub(crate) struct visible_info {
+    pub(crate) x: visible_int,
+}
+#[test]
+fn bindgen_test_layout_visible_info() {
+    assert_eq!(::std::mem::size_of::<visible_info>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( visible_info ) ));
+    assert_eq! (::std::mem::align_of::<visible_info>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( visible_info ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const visible_info ) ) . x as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( visible_info ) , "::" ,
+                stringify ! ( x ) ));
+}
+impl Clone for visible_info {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub(crate) fn visit_info(arg1: *mut visible_info);
+}
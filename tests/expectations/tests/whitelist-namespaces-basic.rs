@@ -6,14 +6,8 @@
 
 #[allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]
 pub mod root {
-    #[allow(unused_imports)]
-    use self::super::root;
     pub mod outer {
-        #[allow(unused_imports)]
-        use self::super::super::root;
         pub mod inner {
-            #[allow(unused_imports)]
-            use self::super::super::super::root;
             #[repr(C)]
             #[derive(Debug, Default, Copy)]
             pub struct Helper {
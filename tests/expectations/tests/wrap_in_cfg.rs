@@ -0,0 +1,63 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(non_snake_case)]
+
+
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+pub struct context {
+    pub id: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_context() {
+    assert_eq!(::std::mem::size_of::<context>() , 4usize , concat ! (
+               "Size of: " , stringify ! ( context ) ));
+    assert_eq! (::std::mem::align_of::<context>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( context ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const context ) ) . id as * const _ as usize }
+                , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( context ) , "::" ,
+                stringify ! ( id ) ));
+}
+impl Clone for context {
+    fn clone(&self) -> Self { *self }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy)]
+#[cfg(feature = "gpu")]
+pub struct launch_params {
+    pub grid: ::std::os::raw::c_uint,
+    pub block: ::std::os::raw::c_uint,
+}
+#[test]
+#[cfg(feature = "gpu")]
+fn bindgen_test_layout_launch_params() {
+    assert_eq!(::std::mem::size_of::<launch_params>() , 8usize , concat ! (
+               "Size of: " , stringify ! ( launch_params ) ));
+    assert_eq! (::std::mem::align_of::<launch_params>() , 4usize , concat ! (
+                "Alignment of " , stringify ! ( launch_params ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const launch_params ) ) . grid as * const _ as
+                usize } , 0usize , concat ! (
+                "Alignment of field: " , stringify ! ( launch_params ) , "::"
+                , stringify ! ( grid ) ));
+    assert_eq! (unsafe {
+                & ( * ( 0 as * const launch_params ) ) . block as * const _ as
+                usize } , 4usize , concat ! (
+                "Alignment of field: " , stringify ! ( launch_params ) , "::"
+                , stringify ! ( block ) ));
+}
+#[cfg(feature = "gpu")]
+impl Clone for launch_params {
+    fn clone(&self) -> Self { *self }
+}
+extern "C" {
+    pub fn cpu_run(ctx: *mut context) -> ::std::os::raw::c_int;
+}
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_launch(params: *mut launch_params, ctx: *mut context)
+     -> ::std::os::raw::c_int;
+}
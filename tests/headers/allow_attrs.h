@@ -0,0 +1,3 @@
+// bindgen-flags: --allow dead_code --allow non_upper_case_globals
+
+const int some_constant = 1;
@@ -0,0 +1,9 @@
+// bindgen-flags: --allow-lints-per-item
+
+struct Good {
+    int foo;
+};
+
+struct lower_struct {
+    int X;
+};
@@ -0,0 +1,5 @@
+// bindgen-flags: --allow-triggered-lints
+
+typedef int my_int;
+const int some_constant = 1;
+void DoThing(void);
@@ -0,0 +1,13 @@
+// bindgen-flags: --use-array-pointers-in-arguments
+
+void sized(int arr[16]);
+void unbounded(int arr[]);
+void constant(const char name[8]);
+void matrix(float m[4][4]);
+
+typedef void handler_t(unsigned char buf[32]);
+
+struct Callbacks {
+    int (*on_data)(int data[4], int len);
+    handler_t* handler;
+};
@@ -0,0 +1,9 @@
+// bindgen-flags: --no-unstable-rust
+
+struct AnonymousPadding {
+    unsigned int a : 4;
+    unsigned int : 4;
+    unsigned int b : 8;
+    unsigned int : 16;
+    unsigned int c : 3;
+};
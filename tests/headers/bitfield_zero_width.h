@@ -0,0 +1,8 @@
+// bindgen-flags: --no-unstable-rust
+
+struct ZeroWidth {
+    unsigned char a : 3;
+    unsigned int : 0;
+    unsigned char b : 2;
+    unsigned char c;
+};
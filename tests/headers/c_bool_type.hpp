@@ -0,0 +1 @@
+#include "c_bool_type/flags.hpp"
@@ -0,0 +1,9 @@
+class Flags {
+public:
+    bool enabled;
+    bool history[3];
+    bool a: 1;
+    bool b: 7;
+};
+
+const bool VERBOSE = true;
@@ -0,0 +1,3 @@
+// bindgen-flags: --c-bool-type u8
+
+#include "c_bool_type/flags.hpp"
@@ -0,0 +1,8 @@
+// bindgen-flags: --c-string-constants
+
+extern const char version[];
+extern const char* greeting;
+extern const char* const motto;
+extern const char* const names[3];
+extern int counter;
+extern char buf[16];
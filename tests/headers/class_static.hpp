@@ -1,3 +1,5 @@
+// bindgen-flags: --generate-static-fns-anyway
+
 class MyClass {
 public:
     static const int* example;
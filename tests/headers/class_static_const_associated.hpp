@@ -0,0 +1,11 @@
+// bindgen-flags: --associated-constants -- -std=c++11
+
+enum class Mode { Fast, Slow };
+
+class Limits {
+public:
+    static const int max = 10;
+    static constexpr double ratio = 0.5;
+    static const Mode mode = Mode::Slow;
+    static const int* unknown;
+};
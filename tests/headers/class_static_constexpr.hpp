@@ -0,0 +1,8 @@
+// bindgen-flags: --no-inline-const-vars -- -std=c++11
+
+class Buffer {
+public:
+    static constexpr int kMax = 100;
+    static constexpr double kGrowth = 1.5;
+    int len;
+};
@@ -0,0 +1,4 @@
+// bindgen-flags: --enable-cxx-namespaces --combined-constants-module
+
+#include "combined_constants/net.hpp"
+#include "combined_constants/disk.hpp"
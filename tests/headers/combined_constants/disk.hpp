@@ -0,0 +1,3 @@
+namespace disk {
+    const int VERSION = 2;
+}
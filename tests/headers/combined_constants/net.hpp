@@ -0,0 +1,4 @@
+namespace net {
+    const int VERSION = 1;
+    const int PORT = 8080;
+}
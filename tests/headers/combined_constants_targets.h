@@ -0,0 +1,4 @@
+// bindgen-flags: --combined-constants-module
+
+extern const unsigned long LONG_SIZE = sizeof(long);
+extern const int VERSION = 1;
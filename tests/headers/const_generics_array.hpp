@@ -0,0 +1,14 @@
+// bindgen-flags: --const-generics
+
+typedef unsigned long size_t;
+
+template<size_t N>
+struct Buf {
+    char data[N];
+};
+
+template<typename T, size_t N>
+struct Array {
+    T items[N];
+    unsigned int len;
+};
@@ -0,0 +1,12 @@
+// bindgen-flags: --const-generics --no-layout-tests
+
+typedef unsigned long size_t;
+
+template<size_t N>
+struct Buf {
+    char data[N];
+};
+
+struct Message {
+    Buf<16> payload;
+};
@@ -0,0 +1,13 @@
+// bindgen-flags: -- -std=c++11
+
+constexpr unsigned long kSize = 4096;
+const double Pi = 3.14159;
+constexpr long long kNegative = -42;
+constexpr unsigned long long kMax = 0xffffffffffffffffULL;
+constexpr unsigned long long kBig = 1ULL << 60;
+constexpr float kNan = __builtin_nanf("");
+constexpr double kInf = __builtin_inf();
+constexpr double kNegInf = -__builtin_inf();
+
+int mutable_global = 5;
+extern const int external_const;
@@ -0,0 +1,18 @@
+// bindgen-flags: --constified-enum "Color|Mode" -- -std=c++11
+
+enum Color : unsigned char {
+    RED,
+    GREEN,
+    BLUE,
+};
+
+enum Mode {
+    READ = 1,
+    WRITE = 2,
+    READ_WRITE = 3,
+};
+
+struct Pixel {
+    Color color;
+    Mode mode;
+};
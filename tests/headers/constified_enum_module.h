@@ -0,0 +1,31 @@
+// bindgen-flags: --constified-enum-module Color --constified-enum-module mode
+
+enum Color {
+    RED,
+    GREEN,
+    BLUE = 4,
+};
+
+struct Pixel {
+    enum Color color;
+};
+
+/** <div rustbindgen constify-module></div> */
+enum Direction {
+    NORTH,
+    SOUTH,
+};
+
+void turn(enum Direction to);
+
+enum mode {
+    MODE_READ,
+    MODE_WRITE,
+};
+
+typedef int mode;
+
+struct File {
+    enum mode how;
+    mode flags;
+};
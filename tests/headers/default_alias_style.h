@@ -0,0 +1,4 @@
+// bindgen-flags: --default-alias-style new_type --type-alias raw_t
+
+typedef int handle_t;
+typedef int raw_t;
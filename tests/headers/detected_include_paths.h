@@ -0,0 +1,9 @@
+// bindgen-flags: --whitelist-type Sample
+
+#include <stdint.h>
+
+struct Sample {
+    uint8_t tag;
+    uint32_t value;
+    int64_t stamp;
+};
@@ -0,0 +1,7 @@
+// bindgen-flags: --dynamic-link-name widgets -- --target=x86_64-pc-windows-msvc
+
+__declspec(dllimport) extern int widget_version;
+
+__declspec(dllimport) int get_widget_count(void);
+__declspec(dllexport) void register_widget(int id);
+__declspec(dllimport) __declspec(some_unknown_thing) void reset_widgets(void);
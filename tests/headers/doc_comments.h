@@ -0,0 +1,21 @@
+// bindgen-flags: --constified-enum "flags"
+
+/** The state of a connection. */
+enum connection_state {
+  /** Not connected yet. */
+  CONNECTION_IDLE,
+  CONNECTION_OPEN, /**< Connected and ready. */
+  /// Closed by either side.
+  CONNECTION_CLOSED,
+};
+
+/** Flags for `send_message`. */
+enum flags {
+  FLAG_NONE = 0,
+  FLAG_URGENT = 1, /**< Send before anything else. */
+};
+
+/** Send a message over a connection. */
+int send_message(int connection, /**< The connection to use. */
+                 const char* message, /**< The message, NUL-terminated. */
+                 enum flags flags);
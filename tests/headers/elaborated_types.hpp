@@ -0,0 +1,11 @@
+// bindgen-flags: --strict
+
+struct Foo { int x; };
+enum Bar { A, B };
+namespace ns { typedef unsigned long size; }
+
+struct Holder {
+    struct Foo foo;
+    enum Bar bar;
+    ns::size len;
+};
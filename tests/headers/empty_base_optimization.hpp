@@ -0,0 +1,6 @@
+struct Empty {};
+
+// The empty base shares its storage with `value`.
+struct Derived : public Empty {
+    int value;
+};
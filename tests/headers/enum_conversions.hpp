@@ -0,0 +1,11 @@
+enum Dup {
+  DUP_ZERO = 0,
+  DUP_NONE = 0,
+  DUP_ONE = 1,
+};
+
+/** <div rustbindgen noconvert></div> */
+enum NoConvert {
+  NO_CONVERT_A,
+  NO_CONVERT_B,
+};
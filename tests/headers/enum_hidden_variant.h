@@ -0,0 +1,2 @@
+// The `MAX` variant is hidden by a callback in `tests.rs`.
+enum Level { LOW, MEDIUM, HIGH, MAX = HIGH };
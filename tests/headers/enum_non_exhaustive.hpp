@@ -0,0 +1,13 @@
+// bindgen-flags: --rustified-non-exhaustive-enum Planet
+
+enum Planet {
+  MERCURY,
+  VENUS,
+  EARTH,
+};
+
+/** <div rustbindgen nonexhaustive></div> */
+enum Edge : signed char {
+  EDGE_ZERO = 0,
+  EDGE_MAX = 127,
+};
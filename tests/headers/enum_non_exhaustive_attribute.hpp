@@ -0,0 +1,7 @@
+// bindgen-flags: --rustified-non-exhaustive-enum Planet --non-exhaustive-attribute
+
+enum Planet {
+  MERCURY,
+  VENUS,
+  EARTH,
+};
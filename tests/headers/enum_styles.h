@@ -0,0 +1,20 @@
+// bindgen-flags: --default-enum-style consts --bitfield-enum "Annotated|Matched"
+
+// The annotation takes precedence over the regex, and the regex over the
+// default style.
+
+/** <div rustbindgen constify-module></div> */
+enum Annotated {
+    ANNOTATED_A,
+    ANNOTATED_B,
+};
+
+enum Matched {
+    MATCHED_A = 1,
+    MATCHED_B = 2,
+};
+
+enum Defaulted {
+    DEFAULTED_A,
+    DEFAULTED_B,
+};
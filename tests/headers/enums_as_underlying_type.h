@@ -0,0 +1,25 @@
+// bindgen-flags: --constified-enum "flags" --constified-enum-module "mode" --enums-as-underlying-type
+
+enum flags {
+  FLAG_READ = 1,
+  FLAG_WRITE = 2,
+};
+
+enum mode {
+  MODE_TEXT,
+  MODE_BINARY,
+};
+
+// Rust enums keep their own type.
+enum color {
+  RED,
+  GREEN,
+};
+
+struct file {
+  enum flags flags;
+  enum mode mode;
+};
+
+int open_file(const char* path, enum flags flags, enum mode mode);
+void paint(enum color color);
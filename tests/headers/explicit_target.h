@@ -0,0 +1,3 @@
+// bindgen-flags: -- --target=i686-unknown-linux-gnu
+
+struct HasLong { long l; };
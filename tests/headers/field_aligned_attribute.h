@@ -0,0 +1,10 @@
+struct AlignedField {
+  char tag;
+  int value __attribute__((aligned(16)));
+};
+
+struct AlignedToEight {
+  char a;
+  int b __attribute__((aligned(8)));
+  char c;
+};
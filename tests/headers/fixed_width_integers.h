@@ -0,0 +1,11 @@
+// bindgen-flags: --fixed-width-integers -- --target=x86_64-pc-windows-msvc
+
+struct Sizes {
+    long l;
+    unsigned long ul;
+    long long ll;
+    short s;
+    unsigned char uc;
+};
+
+long add_longs(long a, unsigned long b);
@@ -0,0 +1,24 @@
+// bindgen-flags: --enable-cxx-namespaces --emit-flat-aliases
+
+namespace foo {
+  typedef int Baz;
+
+  struct Bar {
+    Baz baz;
+  };
+}
+
+namespace bar {
+  struct Bar {
+    foo::Bar foo_bar;
+  };
+}
+
+// Both flatten to `a_b_c`.
+namespace a {
+  typedef int b_c;
+}
+
+namespace a_b {
+  typedef int c;
+}
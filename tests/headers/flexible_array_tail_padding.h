@@ -0,0 +1,5 @@
+struct flexible_tail {
+  int len;
+  char tag;
+  int data[];
+};
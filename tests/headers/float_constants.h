@@ -0,0 +1,5 @@
+#define PI 3.14159265358979
+#define SMALL 2.2250738585072014e-308
+
+const float THIRD = 1.0f / 3.0f;
+const double TENTH = 0.1;
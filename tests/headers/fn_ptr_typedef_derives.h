@@ -0,0 +1,6 @@
+typedef void (*Callback)(int);
+
+struct Handlers {
+    Callback on_event;
+    Callback table[4];
+};
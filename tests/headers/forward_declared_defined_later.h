@@ -0,0 +1,7 @@
+struct later;
+
+int later_value(struct later* l);
+
+struct later {
+    int value;
+};
@@ -0,0 +1,7 @@
+struct sqlite3;
+
+struct handle {
+    struct sqlite3* db;
+};
+
+int sqlite3_close(struct sqlite3* db);
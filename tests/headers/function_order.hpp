@@ -0,0 +1,18 @@
+void zeta();
+void alpha(int);
+struct Between { int x; };
+void zeta(int);
+void alpha(const char*);
+void mid();
+// More than ten overloads, so their suffixes have two digits.
+void many(short);
+void many(int);
+void many(long);
+void many(long long);
+void many(unsigned short);
+void many(unsigned int);
+void many(unsigned long);
+void many(unsigned long long);
+void many(float);
+void many(double);
+void many(int*);
@@ -0,0 +1,6 @@
+typedef int handler(void *data);
+
+struct handler_table {
+    handler on_event;
+    handler *on_close;
+};
@@ -0,0 +1,13 @@
+// bindgen-flags: --blacklist-type Foo --hidden-alias-variation keep
+
+struct Foo {
+  int a;
+  int b;
+};
+
+typedef Foo FooAlias;
+typedef FooAlias FooAliasAlias;
+
+struct UsesAlias {
+  FooAlias alias;
+};
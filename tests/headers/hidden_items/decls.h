@@ -0,0 +1,12 @@
+/** <div rustbindgen hide></div> */
+struct Secret {
+  int key;
+};
+
+struct Internal {
+  int state;
+};
+
+struct Visible {
+  int value;
+};
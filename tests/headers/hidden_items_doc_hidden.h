@@ -0,0 +1,3 @@
+// bindgen-flags: --emit-hidden-items --blacklist-type Internal
+
+#include "hidden_items/decls.h"
@@ -0,0 +1,3 @@
+// bindgen-flags: --blacklist-type Internal
+
+#include "hidden_items/decls.h"
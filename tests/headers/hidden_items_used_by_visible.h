@@ -0,0 +1,10 @@
+// bindgen-flags: --emit-hidden-items
+
+/** <div rustbindgen hide></div> */
+struct Secret {
+  int key;
+};
+
+struct Visible {
+  struct Secret secret;
+};
@@ -0,0 +1,14 @@
+// bindgen-flags: --impl-trait ".*_ops" "::my::Ops" --impl-trait "file_ops" "::my::FileOps" --impl-trait "net_ops" "::my::Ops" --raw-line "pub mod my { pub trait Ops {} pub trait FileOps {} }"
+
+struct file_ops {
+    int (*open)(const char* path);
+};
+
+struct net_ops {
+    int fd;
+};
+
+struct ops_table {
+    struct file_ops* file;
+    struct net_ops* net;
+};
@@ -0,0 +1,15 @@
+// bindgen-flags: --module-per-header -- -Itests/headers/include_provenance
+
+// The same files, reached through different spellings, and through the extra
+// include directory.
+#include "include_provenance/guarded.h"
+#include "include_provenance/../include_provenance/guarded.h"
+#include <guarded.h>
+#include "include_provenance/once.h"
+#include "./include_provenance/once.h"
+#include <once.h>
+
+struct Uses {
+    struct Guarded g;
+    struct Once o;
+};
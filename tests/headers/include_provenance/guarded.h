@@ -0,0 +1,8 @@
+#ifndef INCLUDE_PROVENANCE_GUARDED_H
+#define INCLUDE_PROVENANCE_GUARDED_H
+
+struct Guarded {
+    int a;
+};
+
+#endif
@@ -0,0 +1,7 @@
+#pragma once
+
+#include "../include_provenance/guarded.h"
+
+struct Once {
+    char b;
+};
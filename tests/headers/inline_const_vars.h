@@ -0,0 +1,3 @@
+extern const int VERSION = 3;
+extern const double RATIO = 1.5;
+extern const char* const NAME = "bindgen";
@@ -0,0 +1,3 @@
+// bindgen-flags: --no-inline-const-vars
+
+extern const int VERSION = 3;
@@ -0,0 +1,17 @@
+// bindgen-flags: --enable-cxx-namespaces --keep-inline-namespace "foo::v1" -- -std=c++11
+
+namespace foo {
+  inline namespace v1 {
+    struct Widget {
+      int id;
+    };
+  };
+  inline namespace detail {
+    using Handle = int;
+  };
+};
+
+class Bar {
+  foo::Widget widget;
+  foo::Handle handle;
+};
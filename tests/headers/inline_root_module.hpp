@@ -0,0 +1,8 @@
+// bindgen-flags: --enable-cxx-namespaces --inline-root-module
+
+struct Point {
+    int x;
+    int y;
+};
+
+Point origin();
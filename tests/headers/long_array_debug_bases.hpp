@@ -0,0 +1,16 @@
+// bindgen-flags: --impl-debug-for-long-arrays
+
+struct Header {
+    int tag;
+};
+
+/* Printed as a slice, after the base. */
+struct Record : public Header {
+    unsigned char buf[64];
+};
+
+/* Printed as a slice, along with the bitfields. */
+struct Flagged {
+    unsigned char buf[64];
+    unsigned int flags : 32;
+};
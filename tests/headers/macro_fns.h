@@ -0,0 +1,13 @@
+// bindgen-flags: --emit-macro-fns
+
+int mylib_init_ex(int flags);
+void mylib_set_level(unsigned level);
+
+#define mylib_init() mylib_init_ex(0)
+#define mylib_verbose(lvl) (mylib_set_level((lvl)))
+#define FLAG(x) (1u << (x))
+#define COMBINE(a, b) (((a) << 8) | (b))
+
+// These can't be translated, so they're skipped.
+#define MASK(n) (FLAG(n) - 1)
+#define mylib_reset() mylib_init_ex(1, 2)
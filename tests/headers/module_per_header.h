@@ -0,0 +1,9 @@
+// bindgen-flags: --module-per-header
+
+#include "module_per_header/a.h"
+#include "module_per_header/b.h"
+
+struct Both {
+    struct A a;
+    struct B b;
+};
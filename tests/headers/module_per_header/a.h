@@ -0,0 +1,6 @@
+#pragma once
+
+struct A {
+    struct B* b;
+    int value;
+};
@@ -0,0 +1,7 @@
+#pragma once
+
+#include "a.h"
+
+struct B {
+    struct A* a;
+};
@@ -0,0 +1,20 @@
+// bindgen-flags: --impl-debug-for-long-arrays
+
+struct Matrix {
+    double m[4][4];
+};
+
+struct Grid {
+    unsigned char cells[5][3];
+};
+
+/* Too long to derive anything in the inner dimension. */
+struct Wide {
+    unsigned char rows[2][64];
+};
+
+/* Too long to derive in the outer dimension, but printable as a slice. */
+struct Long {
+    unsigned char rows[64][2];
+    int len;
+};
@@ -0,0 +1,4 @@
+// bindgen-flags: --target x86_64-unknown-linux --target x86_64-pc-windows
+
+struct HasLong { long l; };
+struct NoLong { int i; };
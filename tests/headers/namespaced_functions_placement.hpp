@@ -0,0 +1,19 @@
+// bindgen-flags: --enable-cxx-namespaces
+
+namespace geometry {
+struct Point {
+    int x;
+    int y;
+};
+}
+
+namespace render {
+void draw(geometry::Point* point);
+void fill(geometry::Point* point);
+}
+
+extern "C++" {
+void clear();
+}
+
+void render::fill(geometry::Point* point) {}
@@ -0,0 +1,12 @@
+// bindgen-flags: --new-type-alias "handle_t|inner_t|outer_t|int_ptr_t" --new-type-alias-deref length_t
+
+typedef unsigned int handle_t;
+typedef int length_t;
+
+// Only the outermost alias of the chain becomes a new type.
+typedef int inner_t;
+typedef inner_t outer_t;
+
+typedef int* int_ptr_t;
+
+handle_t open_handle(length_t length, outer_t flags, int_ptr_t out);
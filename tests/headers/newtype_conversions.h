@@ -0,0 +1,8 @@
+// bindgen-flags: --new-type-alias "user_id_t|group_id_t" --newtype-conversions
+
+typedef unsigned int uint32_t;
+
+typedef uint32_t user_id_t;
+typedef uint32_t group_id_t;
+
+int set_owner(user_id_t user, group_id_t group);
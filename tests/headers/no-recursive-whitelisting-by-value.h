@@ -0,0 +1,13 @@
+// bindgen-flags: --no-recursive-whitelist --whitelist-type "Foo" --raw-line "pub type Handle = ::std::os::raw::c_uint;" --raw-line "pub type Kind = ::std::os::raw::c_uint;"
+
+typedef unsigned int Handle;
+
+enum Kind {
+  KIND_A,
+  KIND_B,
+};
+
+struct Foo {
+  Handle value;
+  enum Kind kind;
+};
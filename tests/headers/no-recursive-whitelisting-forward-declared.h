@@ -0,0 +1,9 @@
+// bindgen-flags: --no-recursive-whitelist --whitelist-type "Foo"
+
+struct Bar {
+  int qux;
+};
+
+struct Foo {
+  struct Bar* baz;
+};
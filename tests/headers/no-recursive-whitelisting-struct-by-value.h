@@ -0,0 +1,4 @@
+// bindgen-flags: --no-recursive-whitelist --whitelist-type "Foo" --whitelist-type "Bar"
+
+struct Bar { int qux; };
+struct Foo { struct Bar bar; };
@@ -0,0 +1,7 @@
+// bindgen-flags: --no-std --no-core-ffi-c-void
+
+struct foo {
+  void* bar;
+};
+
+void takes_void(void* ptr);
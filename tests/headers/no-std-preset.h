@@ -0,0 +1,13 @@
+// bindgen-flags: --no-std --raw-line "extern crate core;"
+
+struct foo {
+  int a, b;
+  void* bar;
+};
+
+union {
+  int bar;
+  long baz;
+} bazz;
+
+typedef void (*fooFunction)(int bar);
@@ -0,0 +1,7 @@
+// bindgen-flags: --no-convert-stdint-types
+
+typedef unsigned int uint32_t;
+
+struct Foo {
+    uint32_t bar;
+};
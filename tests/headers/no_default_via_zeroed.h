@@ -0,0 +1,6 @@
+// bindgen-flags: --no-impl-default-via-zeroed
+
+struct Node {
+  int value;
+  struct Node* next;
+};
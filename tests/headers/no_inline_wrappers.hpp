@@ -0,0 +1,5 @@
+// bindgen-flags: --no-inline-wrappers
+class Counter {
+public:
+  int get();
+};
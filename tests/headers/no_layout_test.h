@@ -0,0 +1,10 @@
+/**
+ * <div rustbindgen no-layout-test></div>
+ */
+struct Configurable {
+    int flags;
+};
+
+struct Checked {
+    int value;
+};
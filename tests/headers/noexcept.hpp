@@ -0,0 +1,4 @@
+int checked(int x) noexcept;
+void legacy() throw();
+void may_throw(int x) noexcept(false);
+void plain();
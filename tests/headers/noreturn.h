@@ -0,0 +1,9 @@
+// bindgen-flags: --cold-noreturn-functions
+
+void my_abort(void) __attribute__((noreturn));
+
+_Noreturn void my_exit(int status);
+
+void* my_memcpy(void* restrict dst, const void* restrict src, unsigned long n);
+
+int may_return(int x);
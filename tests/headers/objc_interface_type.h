@@ -1,4 +1,4 @@
-// bindgen-flags: --objc-extern-crate -- -x objective-c
+// bindgen-flags: --objc-extern-crate --generate-static-fns-anyway -- -x objective-c
 // bindgen-osx-only
 
 @interface Foo
@@ -0,0 +1,8 @@
+// bindgen-flags: --no-objc -- -x objective-c
+
+@interface Foo
+- (int)bar;
+@end
+
+Foo *make_foo(void);
+void consume_foo(Foo *foo);
@@ -0,0 +1,11 @@
+// bindgen-flags: --only-primary-header
+
+#include "only_primary_header/library.h"
+
+struct Wrapper {
+    struct ByValue v;
+    struct Pointee* p;
+    lib_int n;
+};
+
+void wrapper_fn(struct Wrapper* w);
@@ -0,0 +1,15 @@
+struct ByValue {
+    int a;
+};
+
+struct Pointee {
+    int b;
+};
+
+struct Unused {
+    int c;
+};
+
+typedef int lib_int;
+
+void library_fn(struct Unused u);
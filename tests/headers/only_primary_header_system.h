@@ -0,0 +1,9 @@
+// bindgen-flags: --only-primary-header
+
+#include <stdlib.h>
+
+struct Quotient {
+    div_t d;
+};
+
+size_t quotient_size(struct Quotient* q);
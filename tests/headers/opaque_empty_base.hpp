@@ -0,0 +1,7 @@
+// bindgen-flags: --no-layout-tests
+
+// We don't support partial specializations, so the base is an opaque blob we
+// can't tell is empty, but it takes no space.
+template <typename T> struct Tag { T* ptr; };
+template <typename T> struct Tag<T*> {};
+struct Derived : Tag<int*> { int value; };
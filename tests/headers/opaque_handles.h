@@ -0,0 +1,8 @@
+struct sqlite3;
+struct sqlite3_stmt;
+
+int sqlite3_prepare(struct sqlite3* db, const char* sql,
+                    struct sqlite3_stmt** stmt);
+int sqlite3_step(struct sqlite3_stmt* stmt);
+int sqlite3_finalize(struct sqlite3_stmt* stmt);
+int sqlite3_close(struct sqlite3* db);
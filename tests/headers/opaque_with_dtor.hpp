@@ -0,0 +1,7 @@
+// bindgen-flags: --opaque-type=WithDtor
+
+class WithDtor {
+  int foo;
+public:
+  ~WithDtor();
+};
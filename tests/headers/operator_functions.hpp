@@ -0,0 +1,9 @@
+// bindgen-flags: --emit-operator-functions
+
+struct Vec2 {
+  int x;
+  int y;
+};
+
+Vec2 operator+(Vec2 a, Vec2 b);
+bool operator==(Vec2 a, Vec2 b);
@@ -0,0 +1,13 @@
+// Enough overloads to need suffixes of more than one digit.
+void overloaded(char (*)[1]);
+void overloaded(char (*)[2]);
+void overloaded(char (*)[3]);
+void overloaded(char (*)[4]);
+void overloaded(char (*)[5]);
+void overloaded(char (*)[6]);
+void overloaded(char (*)[7]);
+void overloaded(char (*)[8]);
+void overloaded(char (*)[9]);
+void overloaded(char (*)[10]);
+void overloaded(char (*)[11]);
+void overloaded(char (*)[12]);
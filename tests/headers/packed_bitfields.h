@@ -0,0 +1,6 @@
+struct __attribute__((packed)) PackedBitfields {
+  unsigned int a : 3;
+  unsigned int b : 30;
+  char c;
+  unsigned short d;
+};
@@ -0,0 +1,5 @@
+struct __attribute__((packed)) PackedDebug {
+  char tag;
+  int value;
+  double ratio;
+};
@@ -0,0 +1,3 @@
+struct First {
+    int first;
+};
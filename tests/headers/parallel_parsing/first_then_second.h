@@ -0,0 +1,6 @@
+#include "first.h"
+#include "second.h"
+
+struct A {
+    struct First first;
+};
@@ -0,0 +1,3 @@
+struct Second {
+    int second;
+};
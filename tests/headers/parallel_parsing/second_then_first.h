@@ -0,0 +1,6 @@
+#include "second.h"
+#include "first.h"
+
+struct B {
+    struct Second second;
+};
@@ -0,0 +1,18 @@
+#pragma GCC poison legacy_read
+
+struct Buffer {
+    char *data;
+    int len;
+};
+
+struct stats {
+    int reads;
+};
+
+// Uses a poisoned identifier, so clang errors out on it.
+int legacy_read(struct Buffer *buf);
+
+// Same, but only this function is skipped, not the struct named like it.
+int stats(struct Buffer *buf, int legacy_read);
+
+int buffer_read(struct Buffer *buf);
@@ -0,0 +1,2 @@
+// Uses `Common` from the precompiled `common.hpp`, without including it.
+struct UsesCommon { Common common; };
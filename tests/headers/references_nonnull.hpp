@@ -0,0 +1,20 @@
+// bindgen-flags: --references-are-nonnull -- -std=c++11
+
+struct Point {
+  int x;
+  int y;
+};
+
+struct Holder {
+  int& value;
+  const Point& origin;
+};
+
+struct Outer {
+  Holder holder;
+};
+
+int& counter();
+void translate(Point& point, const Point& by);
+void consume(Point&& point);
+Point&& forward(Point& point);
@@ -0,0 +1,16 @@
+// bindgen-flags: --enable-cxx-namespaces
+
+namespace lib {
+  namespace detail {
+    struct Storage {
+      int data;
+    };
+  }
+
+  /// <div rustbindgen replaces="detail::Storage"></div>
+  struct CustomStorage {
+    int custom;
+  };
+}
+
+typedef lib::detail::Storage StorageAlias;
@@ -0,0 +1,10 @@
+// bindgen-flags: --enable-cxx-namespaces --root-module-name mylib --whitelist-type '.*'
+
+namespace outer {
+  namespace inner {
+    struct Helper {};
+  }
+  struct Test {
+    inner::Helper helper;
+  };
+}
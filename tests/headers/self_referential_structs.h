@@ -0,0 +1,12 @@
+struct Node {
+  int value;
+  struct Node* next;
+};
+
+struct Even {
+  struct Odd* next;
+};
+
+struct Odd {
+  struct Even* next;
+};
@@ -0,0 +1,14 @@
+// bindgen-flags: --send-type "ns::context|db" --sync-type "ns::context"
+
+namespace ns {
+struct context {
+    void* data;
+};
+}
+
+/** <div rustbindgen send></div> */
+struct handle {
+    ns::context* ctx;
+};
+
+struct db;
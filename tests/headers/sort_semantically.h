@@ -0,0 +1,29 @@
+// bindgen-flags: --sort-semantically
+
+int zeta(void);
+
+struct Point {
+  int x;
+  int y;
+};
+
+typedef int size_type;
+
+enum Color { RED, GREEN };
+
+#define MAX_POINTS 16
+
+int alpha(struct Point p);
+
+extern int counter;
+
+struct Rect {
+  struct Point min;
+  struct Point max;
+};
+
+typedef struct Rect rect_t;
+
+enum Axis { AXIS_X, AXIS_Y };
+
+void beta(enum Color c);
@@ -0,0 +1,3 @@
+// bindgen-flags: --emit-source-annotations
+
+struct Annotated { int x; };
@@ -0,0 +1,2 @@
+namespace foo { struct A { int x; }; }
+namespace bar { struct B { foo::A a; }; }
@@ -0,0 +1,15 @@
+class Shared {
+public:
+    Shared(const Shared& other);
+    int refs;
+};
+
+Shared share(const Shared& from);
+
+class Handle {
+public:
+    ~Handle();
+    int fd;
+};
+
+Handle open_handle(const char* path);
@@ -0,0 +1,3 @@
+static int internal_fn(int x);
+__attribute__((visibility("hidden"))) int hidden_fn(int x);
+int exported_fn(int x);
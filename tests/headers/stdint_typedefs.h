@@ -0,0 +1,12 @@
+typedef unsigned char uint8_t;
+typedef unsigned int uint32_t;
+typedef long long int64_t;
+// Not what anyone would expect, so this shouldn't become an `u16`.
+typedef unsigned int uint16_t;
+
+struct Fields {
+    uint8_t a;
+    uint32_t b;
+    int64_t c;
+    uint16_t d;
+};
@@ -0,0 +1,10 @@
+// bindgen-flags: -- --target=i686-unknown-linux-gnu
+
+// Pointer-sized on the target, even if they aren't on the host.
+typedef unsigned int size_t;
+typedef int ptrdiff_t;
+
+struct Buffer {
+    size_t len;
+    ptrdiff_t pos;
+};
@@ -0,0 +1,38 @@
+// bindgen-flags: --enable-stl-blobs --whitelist-type=Container --whitelist-function=take_vector -- -std=c++11
+
+// A trimmed down version of what libc++ looks like.
+namespace std {
+inline namespace __1 {
+template<class T>
+class allocator {};
+
+template<class T1, class T2>
+class __compressed_pair {
+  T1 __first_;
+};
+
+template<class T, class Allocator = allocator<T> >
+class vector {
+  T* __begin_;
+  T* __end_;
+  __compressed_pair<T*, Allocator> __end_cap_;
+public:
+  unsigned long size() const;
+};
+
+template<class T>
+class shared_ptr {
+  T* __ptr_;
+  void* __cntrl_;
+public:
+  T* get() const;
+};
+}
+}
+
+struct Container {
+  std::vector<int> items;
+  std::shared_ptr<Container> shared;
+};
+
+void take_vector(std::vector<int> v);
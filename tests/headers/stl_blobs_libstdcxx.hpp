@@ -0,0 +1,38 @@
+// bindgen-flags: --enable-stl-blobs --whitelist-type=Container --whitelist-function=take_vector
+
+// A trimmed down version of what libstdc++ looks like.
+namespace std {
+template<typename T>
+class allocator {};
+
+template<typename T, typename Alloc>
+struct _Vector_base {
+  struct _Vector_impl {
+    T* _M_start;
+    T* _M_finish;
+    T* _M_end_of_storage;
+  };
+
+  _Vector_impl _M_impl;
+};
+
+template<typename T, typename Alloc = allocator<T> >
+class vector : protected _Vector_base<T, Alloc> {
+public:
+  unsigned long size() const;
+};
+
+template<typename T>
+class unique_ptr {
+  T* _M_t;
+public:
+  T* get() const;
+};
+}
+
+struct Container {
+  std::vector<int> items;
+  std::unique_ptr<Container> next;
+};
+
+void take_vector(std::vector<int> v);
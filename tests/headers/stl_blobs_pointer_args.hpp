@@ -0,0 +1,30 @@
+// bindgen-flags: --enable-stl-blobs --whitelist-type=Lists
+
+// A trimmed down version of what libstdc++ looks like.
+namespace std {
+template<typename T>
+class allocator {};
+
+template<typename T, typename Alloc>
+struct _Vector_base {
+  struct _Vector_impl {
+    T* _M_start;
+    T* _M_finish;
+    T* _M_end_of_storage;
+  };
+
+  _Vector_impl _M_impl;
+};
+
+template<typename T, typename Alloc = allocator<T> >
+class vector : protected _Vector_base<T, Alloc> {
+public:
+  unsigned long size() const;
+};
+}
+
+struct Lists {
+  std::vector<int> values;
+  std::vector<int*> pointers;
+  std::vector<const int*> const_pointers;
+};
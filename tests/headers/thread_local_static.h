@@ -0,0 +1,3 @@
+// bindgen-flags: --thread-local-statics
+
+extern __thread int counter;
@@ -0,0 +1,17 @@
+// bindgen-flags: --constified-enum Mode
+
+typedef enum {
+  MODE_READ,
+  MODE_WRITE,
+} Mode;
+
+struct File {
+  Mode mode;
+};
+
+void set_mode(struct File* file, Mode mode);
+
+typedef enum {
+  LEVEL_LOW,
+  LEVEL_HIGH,
+} Level, Severity;
@@ -0,0 +1,7 @@
+typedef int handler(void *data);
+
+handler dispatch;
+
+struct handler_table {
+    handler *on_event;
+};
@@ -0,0 +1,7 @@
+// bindgen-flags: --no-unstable-rust
+
+union Flags {
+  unsigned int raw;
+  unsigned int kind : 3;
+  unsigned int value : 29;
+};
@@ -0,0 +1,11 @@
+// bindgen-flags: --impl-debug-for-unions
+
+union WithBigArray {
+  int a;
+  int b[33];
+};
+
+struct ContainsBigArray {
+  int tag;
+  union WithBigArray u;
+};
@@ -0,0 +1,15 @@
+// bindgen-flags: --use-libc --raw-line "extern crate libc;"
+
+typedef unsigned long size_t;
+typedef long ssize_t;
+typedef int wchar_t;
+
+struct Buffer {
+  char* data;
+  size_t len;
+  ssize_t pos;
+  wchar_t sep;
+  void* user;
+};
+
+size_t buffer_len(struct Buffer* buf);
@@ -0,0 +1,9 @@
+// bindgen-flags: --default-visibility pub(crate)
+
+typedef int visible_int;
+
+struct visible_info {
+    visible_int x;
+};
+
+void visit_info(struct visible_info *);
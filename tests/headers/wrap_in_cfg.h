@@ -0,0 +1,13 @@
+// bindgen-flags: --wrap-in-cfg "gpu_.*" 'feature = "gpu"'
+
+struct context {
+    int id;
+};
+
+struct launch_params {
+    unsigned grid;
+    unsigned block;
+};
+
+int gpu_launch(struct launch_params *params, struct context *ctx);
+int cpu_run(struct context *ctx);
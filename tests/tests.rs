@@ -3,10 +3,19 @@ extern crate diff;
 extern crate bindgen;
 extern crate shlex;
 
-use bindgen::Builder;
+use bindgen::{Builder, DiagnosticSeverity, EnumVariation};
+use bindgen::callbacks::{EnumVariantCustomBehavior, EnumVariantValue,
+                         ParseCallbacks};
+use std::env;
+use std::fmt;
 use std::fs;
 use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Write};
-use std::path::PathBuf;
+use std::mem;
+use std::os::raw::c_long;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
 
 #[path="../src/options.rs"]
 mod options;
@@ -105,6 +114,7 @@ fn create_bindgen_builder(header: &PathBuf) -> Result<Option<Builder>, Error> {
 
     let prepend = ["bindgen",
                    "--with-derive-default",
+                   "--impl-default-via-zeroed",
                    header_str,
                    "--raw-line",
                    "",
@@ -142,5 +152,859 @@ macro_rules! test_header {
     )
 }
 
+/// A directory for the inputs of a test, removed once the test is done.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Create a new, empty directory named after `name`.
+    ///
+    /// Creating a directory fails if it already exists, so every test gets
+    /// its own, even when they run in parallel, or in several processes.
+    fn new(name: &str) -> TempDir {
+        for i in 0.. {
+            let dir = env::temp_dir().join(format!("bindgen-{}-{}", name, i));
+            match fs::create_dir(&dir) {
+                Ok(()) => return TempDir(dir),
+                Err(ref e) if e.kind() == ErrorKind::AlreadyExists => {}
+                Err(e) => panic!("Couldn't create {}: {}", dir.display(), e),
+            }
+        }
+        unreachable!()
+    }
+
+    fn join<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.0.join(path)
+    }
+
+    /// Write a file with the given contents in this directory, and return
+    /// its path.
+    fn file(&self, name: &str, contents: &[u8]) -> PathBuf {
+        let path = self.join(name);
+        fs::File::create(&path)
+            .and_then(|mut f| f.write_all(contents))
+            .unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Counts the macros we parse, to know whether clang was invoked at all.
+struct MacroCounter(Arc<AtomicUsize>);
+
+impl fmt::Debug for MacroCounter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MacroCounter")
+    }
+}
+
+impl ParseCallbacks for MacroCounter {
+    fn parsed_macro(&self, _name: &str) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Hides the `MAX` variant of `Level`.
+#[derive(Debug)]
+struct HideMax;
+
+impl ParseCallbacks for HideMax {
+    fn enum_variant_behavior(&self,
+                             enum_name: Option<&str>,
+                             variant_name: &str,
+                             _variant_value: EnumVariantValue)
+                             -> Option<EnumVariantCustomBehavior> {
+        if enum_name == Some("Level") && variant_name == "MAX" {
+            Some(EnumVariantCustomBehavior::Hide)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn enum_variants_can_be_hidden_by_callback() {
+    let bindings = Builder::default()
+        .header("tests/headers/enum_hidden_variant.h")
+        .parse_callbacks(Box::new(HideMax))
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    assert!(bindings.contains("pub enum Level {"), "{}", bindings);
+    assert!(bindings.contains("HIGH = 2"), "{}", bindings);
+    assert!(!bindings.contains("MAX"), "{}", bindings);
+}
+
+#[test]
+fn hash_inputs_skips_regeneration() {
+    let dir = TempDir::new("hash-inputs");
+
+    let header = dir.file("input.h",
+                          b"#include \"included.h\"\n#define FOO 1\n");
+    dir.file("included.h", b"#define BAR 2\n");
+    let output = dir.join("bindings.rs");
+
+    let generate = |force: bool| {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let bindings = Builder::default()
+            .header(header.to_str().unwrap())
+            .parse_callbacks(Box::new(MacroCounter(counter.clone())))
+            .hash_inputs_to(output.to_str().unwrap())
+            .force_regeneration(force)
+            .generate()
+            .expect("Should generate the bindings");
+        bindings.write_to_file(&output).unwrap();
+        (bindings.to_string(), counter.load(Ordering::SeqCst))
+    };
+
+    // Writing the bindings somewhere else doesn't store the hash.
+    Builder::default()
+        .header(header.to_str().unwrap())
+        .hash_inputs_to(output.to_str().unwrap())
+        .generate()
+        .expect("Should generate the bindings")
+        .write_to_file(dir.join("elsewhere.rs"))
+        .unwrap();
+    assert!(!dir.join("bindings.rs.hash").exists());
+
+    let (first, parsed) = generate(false);
+    assert!(parsed > 0, "The first run should invoke clang");
+
+    let (second, parsed) = generate(false);
+    assert_eq!(parsed, 0, "Unchanged inputs shouldn't invoke clang");
+    assert_eq!(first, second);
+
+    dir.file("included.h", b"#define BAR 3\n");
+    let (_, parsed) = generate(false);
+    assert!(parsed > 0, "Changing an included header should invoke clang");
+
+    let (_, parsed) = generate(true);
+    assert!(parsed > 0, "Forcing the regeneration should invoke clang");
+}
+
+#[test]
+fn split_output_writes_one_file_per_module() {
+    let dir = TempDir::new("split-output");
+
+    let output = dir.join("bindings");
+    Builder::default()
+        .header("tests/headers/split_output/input.hpp")
+        .enable_cxx_namespaces()
+        .clang_arg("-x")
+        .clang_arg("c++")
+        .generate()
+        .expect("Should generate the bindings")
+        .write_split(&output)
+        .unwrap();
+
+    let mut files: Vec<_> = fs::read_dir(&output)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, vec!["bar.rs", "foo.rs", "mod.rs"]);
+
+    let read = |name: &str| {
+        let mut contents = String::new();
+        fs::File::open(output.join(name))
+            .and_then(|mut f| f.read_to_string(&mut contents))
+            .unwrap();
+        contents
+    };
+
+    let root = read("mod.rs");
+    assert!(root.contains("pub mod foo;"));
+    assert!(root.contains("pub mod bar;"));
+
+    let bar = read("bar.rs");
+    assert!(!bar.contains("root"), "Root paths should be rewritten");
+    assert!(bar.contains("self::super::foo::A"));
+}
+
+#[test]
+fn combined_constants_for_multiple_targets() {
+    let bindings = Builder::default()
+        .header("tests/headers/combined_constants_targets.h")
+        .combined_constants_module(true)
+        .target("x86_64-unknown-linux-gnu")
+        .target("x86_64-pc-windows-msvc")
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    // `LONG_SIZE` has a version per target, which are re-exported under the
+    // same name, gated like the constants themselves.
+    assert_eq!(bindings.matches("pub const LONG_SIZE").count(), 2);
+    assert_eq!(bindings.matches("pub use super::LONG_SIZE;").count(), 2);
+    assert!(!bindings.contains("LONG_SIZE1"), "{}", bindings);
+    assert_eq!(bindings.matches("pub use super::VERSION;").count(), 1);
+}
+
+#[test]
+fn combined_constants_module_name_clash() {
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let sink = delivered.clone();
+
+    let result = Builder::default()
+        .header("tests/headers/combined_constants.hpp")
+        .enable_cxx_namespaces()
+        .root_module_name("constants")
+        .combined_constants_module(true)
+        .diagnostic_callback(Box::new(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_owned()));
+        }))
+        .generate();
+    assert!(result.is_err(), "Two `constants` modules can't compile");
+
+    let delivered = delivered.lock().unwrap();
+    assert!(delivered.iter().any(|&(severity, ref message)| {
+        severity == DiagnosticSeverity::Error &&
+        message.contains("`constants`")
+    }));
+}
+
+#[test]
+fn fixed_width_integers_gate_layout_tests_on_the_host() {
+    // The expectation of this header covers an explicit target; without one,
+    // the widths and the layout tests are those of the host.
+    let bindings = Builder::default()
+        .header("tests/headers/fixed_width_integers.h")
+        .fixed_width_integers(true)
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    let long = format!("pub l: i{},", mem::size_of::<c_long>() * 8);
+    assert!(bindings.contains(&long), "{}", bindings);
+    let arch = format!("target_arch = \"{}\"", env::consts::ARCH);
+    assert!(bindings.contains(&arch), "{}", bindings);
+}
+
+#[test]
+fn last_given_enum_style_regex_wins() {
+    let bindings = Builder::default()
+        .header("tests/headers/enum_styles.h")
+        .default_enum_style(EnumVariation::Consts)
+        .bitfield_enum("Annotated|Matched")
+        .rustified_enum("Matched")
+        .generate()
+        .expect("Should generate the bindings");
+
+    assert!(bindings.diagnostics().iter().any(|d| {
+        d.severity == DiagnosticSeverity::Warning &&
+        d.message.contains("`Matched` matches the regexes of several enum")
+    }));
+
+    let bindings = bindings.to_string();
+    assert!(bindings.contains("pub mod Annotated {"), "{}", bindings);
+    assert!(bindings.contains("pub enum Matched {"), "{}", bindings);
+    assert!(bindings.contains("pub type Defaulted ="), "{}", bindings);
+}
+
+#[test]
+fn invalid_regex_is_an_error() {
+    let builder = Builder::default()
+        .header("tests/headers/whitelist_basic.hpp")
+        .hide_type("foo(");
+    let message = builder.validate().unwrap_err();
+    assert!(message.contains("hidden type"), "{}", message);
+    assert!(builder.generate().is_err());
+
+    let flags = ["bindgen", "tests/headers/whitelist_basic.hpp",
+                 "--blacklist-type", "foo("];
+    let flags = flags.iter().map(|flag| flag.to_string());
+    assert!(builder_from_flags(flags).is_err());
+}
+
+#[test]
+fn output_is_deterministic() {
+    // Every `HashMap` gets its own random seed, so generating the same
+    // bindings twice in the same process is enough to catch any hash map
+    // iteration order leaking into the output.
+    let generate = |header: &str| {
+        Builder::default()
+            .header(header)
+            .enable_cxx_namespaces()
+            .clang_arg("-std=c++14")
+            .generate()
+            .expect("Should generate the bindings")
+            .to_string()
+    };
+
+    for header in &["tests/headers/reparented_replacement.hpp",
+                    "tests/headers/replace_template_alias.hpp",
+                    "tests/headers/overloading.hpp",
+                    "tests/headers/namespace.hpp"] {
+        assert_eq!(generate(header),
+                   generate(header),
+                   "Generating {} twice gave different bindings",
+                   header);
+    }
+}
+
+#[test]
+fn precompiled_header() {
+    let dir = TempDir::new("precompiled-header");
+    let header = "tests/headers/precompiled_header/input.hpp";
+
+    let pch = dir.join("common.hpp.pch");
+    let pch = pch.to_str().unwrap();
+    Builder::default()
+        .header("tests/headers/precompiled_header/common.hpp")
+        .emit_precompiled_header(pch)
+        .generate()
+        .expect("Should generate the precompiled header");
+
+    let bindings = Builder::default()
+        .header(header)
+        .precompiled_header(pch)
+        .generate()
+        .expect("Should generate the bindings using the precompiled header")
+        .to_string();
+
+    assert!(bindings.contains("pub struct UsesCommon"));
+    assert!(bindings.contains("pub common: Common"));
+
+    // A precompiled header for another target is rejected by clang.
+    let result = Builder::default()
+        .header(header)
+        .precompiled_header(pch)
+        .clang_arg("--target=i686-pc-windows-msvc")
+        .generate();
+    assert!(result.is_err(),
+            "A mismatching precompiled header should fail the generation");
+}
+
+#[test]
+fn emit_clang_ast_for_a_single_item() {
+    let dir = TempDir::new("emit-clang-ast");
+
+    let dump = dir.join("ast.txt");
+    Builder::default()
+        .header("tests/headers/template.hpp")
+        .emit_ast_for("^Foo$")
+        .emit_ast_output(dump.to_str().unwrap())
+        .generate()
+        .expect("Should generate the bindings");
+
+    let mut contents = String::new();
+    fs::File::open(&dump)
+        .and_then(|mut f| f.read_to_string(&mut contents))
+        .unwrap();
+
+    assert!(contents.starts_with("ClassTemplate \"Foo\""));
+    assert!(contents.contains("TemplateTypeParameter \"T\""));
+    assert!(contents.contains("FieldDecl"));
+    // Only `Foo` is dumped, not the rest of the header.
+    assert!(!contents.contains("FunctionDecl \"bar\""));
+}
+
+#[test]
+fn emit_clang_ast_is_a_switch() {
+    // The header after the switch mustn't be taken for a regex.
+    let flags = ["bindgen", "--emit-clang-ast", "tests/headers/template.hpp"];
+    let flags = flags.iter().map(|flag| flag.to_string());
+    assert!(builder_from_flags(flags).is_ok());
+
+    let flags = ["bindgen", "--emit-clang-ast-for", "^Foo$",
+                 "tests/headers/template.hpp"];
+    let flags = flags.iter().map(|flag| flag.to_string());
+    assert!(builder_from_flags(flags).is_ok());
+}
+
+#[test]
+fn float_constants_round_trip() {
+    let bindings = Builder::default()
+        .header("tests/headers/float_constants.h")
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    let literal = |name: &str| -> String {
+        let prefix = format!("pub const {}: ", name);
+        let line = bindings.lines()
+            .find(|line| line.trim().starts_with(&prefix))
+            .expect(&format!("No constant {} in:\n{}", name, bindings));
+        let value = line.split(" = ").nth(1).unwrap();
+        value.trim_right_matches(';').to_owned()
+    };
+
+    // Compare the bits, since that's what has to round-trip.
+    let bits64 = |value: f64| -> u64 {
+        unsafe { ::std::mem::transmute(value) }
+    };
+    let bits32 = |value: f32| -> u32 {
+        unsafe { ::std::mem::transmute(value) }
+    };
+
+    let doubles = [("PI", 3.14159265358979f64),
+                   ("SMALL", 2.2250738585072014e-308f64),
+                   ("TENTH", 0.1f64)];
+    for &(name, expected) in &doubles {
+        let parsed: f64 = literal(name).parse().unwrap();
+        assert_eq!(bits64(parsed), bits64(expected), "{}", name);
+    }
+
+    let parsed: f32 = literal("THIRD").parse().unwrap();
+    assert_eq!(bits32(parsed), bits32(1.0f32 / 3.0f32));
+}
+
+/// Not really a test, but a benchmark of the hidden/opaque/whitelist regex
+/// matching on a big header. Run it with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn bench_regex_matching_on_large_header() {
+    let dir = TempDir::new("regex-bench");
+
+    let header = dir.join("input.h");
+    {
+        let mut file = fs::File::create(&header).unwrap();
+        for i in 0..5000 {
+            writeln!(file, "struct Struct{} {{ int field; }};", i).unwrap();
+            writeln!(file, "void function{}(struct Struct{}*);", i, i)
+                .unwrap();
+        }
+    }
+
+    let mut builder = Builder::default()
+        .header(header.to_str().unwrap())
+        .whitelisted_type("Struct[0-9]*")
+        .whitelisted_function("function[0-9]*");
+    for i in 0..100 {
+        builder = builder.hide_type(format!("Struct{}", i * 7))
+            .opaque_type(format!("Struct{}[0-9]", i * 3));
+    }
+
+    let start = Instant::now();
+    builder.generate().expect("Should generate the bindings");
+    let elapsed = start.elapsed();
+
+    println!("Generated the bindings in {}.{:03}s",
+             elapsed.as_secs(),
+             elapsed.subsec_nanos() / 1_000_000);
+}
+
+/// Not really a test either, but a benchmark of the whitelisting traversal
+/// on a big header with lots of edges between items. Compare the timings
+/// before and after touching the traversal code with
+/// `cargo test -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn bench_whitelisting_on_large_header() {
+    let dir = TempDir::new("whitelisting-bench");
+
+    let header = dir.join("input.hpp");
+    {
+        let mut file = fs::File::create(&header).unwrap();
+        writeln!(file, "struct Struct0 {{ int field; }};").unwrap();
+        for i in 1..10000 {
+            writeln!(file,
+                     "struct Struct{} {{ Struct{} a; Struct{}* b; }};",
+                     i,
+                     i - 1,
+                     i / 2)
+                .unwrap();
+        }
+    }
+
+    let builder = Builder::default()
+        .header(header.to_str().unwrap())
+        .whitelisted_type("Struct9999");
+
+    let start = Instant::now();
+    builder.generate().expect("Should generate the bindings");
+    let elapsed = start.elapsed();
+
+    println!("Generated the bindings in {}.{:03}s",
+             elapsed.as_secs(),
+             elapsed.subsec_nanos() / 1_000_000);
+}
+
+/// Compares parsing several headers in parallel and sequentially. Run it with
+/// `cargo test -- --ignored --nocapture` to see the timings.
+#[test]
+#[ignore]
+fn bench_parallel_parsing() {
+    let dir = TempDir::new("parallel-parsing");
+
+    dir.file("common.h", b"struct Common { int field; };\n");
+
+    let mut headers = vec![];
+    for i in 0..8 {
+        let header = dir.join(format!("header{}.h", i));
+        let mut file = fs::File::create(&header).unwrap();
+        writeln!(file, "#include \"common.h\"").unwrap();
+        for j in 0..2000 {
+            writeln!(file,
+                     "struct Header{}Struct{} {{ struct Common common; }};",
+                     i,
+                     j)
+                .unwrap();
+        }
+        headers.push(header.to_str().unwrap().to_owned());
+    }
+
+    let generate = |parallel: bool| {
+        let mut builder = Builder::default().parallel_parsing(parallel);
+        for header in &headers {
+            builder = builder.add_header(header.as_str());
+        }
+
+        let start = Instant::now();
+        let bindings = builder.generate()
+            .expect("Should generate the bindings")
+            .to_string();
+        let elapsed = start.elapsed();
+        println!("Generated the bindings {} in {}.{:03}s",
+                 if parallel { "in parallel" } else { "sequentially" },
+                 elapsed.as_secs(),
+                 elapsed.subsec_nanos() / 1_000_000);
+        bindings
+    };
+
+    let sequential = generate(false);
+    let parallel = generate(true);
+    for bindings in &[sequential, parallel] {
+        assert_eq!(bindings.matches("pub struct Common {").count(), 1);
+        assert!(bindings.contains("pub struct Header0Struct0 {"));
+        assert!(bindings.contains("pub struct Header7Struct1999 {"));
+    }
+}
+
+#[test]
+fn parallel_parsing_keeps_one_copy_of_each_item() {
+    let headers = ["tests/headers/parallel_parsing/first_then_second.h",
+                   "tests/headers/parallel_parsing/second_then_first.h"];
+    let bindings = Builder::default()
+        .header("tests/headers/does_not_exist.h")
+        .headers(headers.iter().cloned())
+        .parallel_parsing(true)
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    // Both headers include `First` and `Second`, in a different order.
+    assert_eq!(bindings.matches("pub struct First {").count(), 1);
+    assert_eq!(bindings.matches("fn bindgen_test_layout_First()").count(), 1);
+    assert_eq!(bindings.matches("pub struct Second {").count(), 1);
+    assert_eq!(bindings.matches("fn bindgen_test_layout_Second()").count(),
+               1);
+    assert!(bindings.contains("pub struct A {"));
+    assert!(bindings.contains("pub struct B {"));
+
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let sink = delivered.clone();
+    Builder::default()
+        .headers(headers.iter().cloned())
+        .parallel_parsing(true)
+        .diagnostic_callback(Box::new(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_owned()));
+        }))
+        .generate()
+        .expect("Should generate the bindings");
+
+    let delivered = delivered.lock().unwrap();
+    assert!(delivered.iter().any(|&(severity, ref message)| {
+        severity == DiagnosticSeverity::Warning &&
+        message.contains("parsing them sequentially")
+    }));
+}
+
+#[test]
+fn unresolved_include_is_reported() {
+    // Not a header test, since it doesn't generate anything by default.
+    let header = "tests/headers/unresolved_include/input.h";
+
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let sink = delivered.clone();
+
+    let result = Builder::default()
+        .header(header)
+        .diagnostic_callback(Box::new(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_owned()));
+        }))
+        .generate();
+    assert!(result.is_err(), "Parse errors should abort by default");
+
+    let delivered = delivered.lock().unwrap();
+    assert!(delivered.iter().any(|&(severity, ref message)| {
+        severity == DiagnosticSeverity::Error &&
+        message.contains("does_not_exist.h")
+    }));
+
+    let bindings = Builder::default()
+        .header(header)
+        .abort_on_parse_errors(false)
+        .generate()
+        .expect("Should generate the bindings anyway");
+
+    assert!(bindings.diagnostics().iter().any(|d| {
+        d.severity == DiagnosticSeverity::Error &&
+        d.message.contains("does_not_exist.h")
+    }));
+}
+
+#[test]
+fn poisoned_identifiers_only_skip_their_declarations() {
+    let header = "tests/headers/poisoned_identifier.h";
+
+    let result = Builder::default()
+        .header(header)
+        .fail_on_clang_error(true)
+        .generate();
+    assert!(result.is_err(), "Strict mode should abort on any clang error");
+
+    let bindings = Builder::default()
+        .header(header)
+        .generate()
+        .expect("Should skip the declaration using the poisoned identifier");
+
+    assert!(bindings.diagnostics().iter().any(|d| {
+        d.severity == DiagnosticSeverity::Warning &&
+        d.message.contains("skipping `legacy_read`")
+    }));
+    assert!(bindings.diagnostics()
+        .iter()
+        .all(|d| d.severity != DiagnosticSeverity::Error));
+
+    let bindings = bindings.to_string();
+    assert!(!bindings.contains("legacy_read"), "{}", bindings);
+    assert!(bindings.contains("pub fn buffer_read"), "{}", bindings);
+    // The skipped `stats` function doesn't take the struct with it.
+    assert!(!bindings.contains("pub fn stats"), "{}", bindings);
+    assert!(bindings.contains("pub struct stats"), "{}", bindings);
+}
+
+#[test]
+fn function_typed_fields_are_reported() {
+    // Not a header test, since clang rejects the field as well.
+    let bindings = Builder::default()
+        .header("tests/headers/function_typed_field/input.h")
+        .abort_on_parse_errors(false)
+        .generate()
+        .expect("Should generate the bindings anyway");
+
+    assert!(bindings.diagnostics().iter().any(|d| {
+        d.severity == DiagnosticSeverity::Error &&
+        d.message.contains("Field `on_event` has a function type")
+    }));
+
+    let bindings = bindings.to_string();
+    assert!(!bindings.contains("on_event"), "{}", bindings);
+    assert!(bindings.contains("pub on_close"), "{}", bindings);
+}
+
+#[test]
+fn untranslatable_macro_fns_are_reported() {
+    let bindings = Builder::default()
+        .header("tests/headers/macro_fns.h")
+        .emit_macro_fns(true)
+        .generate()
+        .expect("Should generate the bindings");
+
+    let skipped: Vec<_> = bindings.diagnostics()
+        .iter()
+        .filter(|d| d.message.contains("Skipping function-like macro"))
+        .collect();
+    assert_eq!(skipped.len(), 2, "{:?}", skipped);
+    assert!(skipped.iter()
+        .all(|d| d.severity == DiagnosticSeverity::Warning));
+    assert!(skipped.iter().any(|d| d.message.contains("`MASK`")));
+    assert!(skipped.iter().any(|d| {
+        d.message.contains("`mylib_reset`") &&
+        d.message.contains("passes 2 arguments to `mylib_init_ex`")
+    }));
+}
+
+#[test]
+fn diagnostic_callback() {
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let sink = delivered.clone();
+
+    let bindings = Builder::default()
+        .header("tests/headers/partial-specialization-and-inheritance.hpp")
+        .diagnostic_callback(Box::new(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_owned()));
+        }))
+        .generate()
+        .expect("Should generate the bindings");
+
+    let delivered = delivered.lock().unwrap();
+    assert!(delivered.iter().any(|&(severity, ref message)| {
+        severity == DiagnosticSeverity::Warning &&
+        message.contains("partial template specialization")
+    }));
+    assert_eq!(bindings.diagnostics().len(), delivered.len());
+}
+
+#[test]
+fn skipped_layout_tests_are_reported() {
+    let bindings = Builder::default()
+        .header("tests/headers/no_layout_test.h")
+        .generate()
+        .expect("Should generate the bindings");
+
+    let skipped: Vec<_> = bindings.diagnostics()
+        .iter()
+        .filter(|d| d.message.contains("Skipping the layout test"))
+        .collect();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].severity, DiagnosticSeverity::Warning);
+    assert!(skipped[0].message.contains("`Configurable`"));
+}
+
+#[test]
+fn colliding_flat_aliases_are_reported() {
+    // The expectation of this header covers the suffixed alias.
+    let bindings = Builder::default()
+        .header("tests/headers/flat_aliases.hpp")
+        .enable_cxx_namespaces()
+        .emit_flat_aliases()
+        .generate()
+        .expect("Should generate the bindings");
+
+    let collisions: Vec<_> = bindings.diagnostics()
+        .iter()
+        .filter(|d| d.message.contains("flatten to"))
+        .collect();
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].severity, DiagnosticSeverity::Warning);
+    assert!(collisions[0].message.contains("`a::b_c` and `a_b::c`"),
+            "{}",
+            collisions[0].message);
+}
+
+/// Implements a trait for the types whose name ends in `_ops`.
+#[derive(Debug)]
+struct OpsTraits;
+
+impl ParseCallbacks for OpsTraits {
+    fn implemented_traits(&self, name: &str) -> Vec<String> {
+        if name.ends_with("_ops") {
+            vec!["::my::Dynamic".into(), "::my::Ops".into()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[test]
+fn implemented_traits_callback() {
+    let bindings = Builder::default()
+        .header("tests/headers/impl_trait.h")
+        .parse_callbacks(Box::new(OpsTraits))
+        .add_impl_for("file_ops", "::my::Ops")
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    // The registered traits come first, and the duplicates are skipped.
+    let file_ops = "impl ::my::Ops for file_ops { }\n\
+                    impl ::my::Dynamic for file_ops { }\n";
+    assert!(bindings.contains(file_ops));
+    assert!(bindings.contains("impl ::my::Dynamic for net_ops { }"));
+    assert!(!bindings.contains("for ops_table { }"));
+}
+
+#[test]
+fn non_recursive_whitelist_by_value_use() {
+    // The expectation of this header covers whitelisting both types.
+    let result = Builder::default()
+        .header("tests/headers/no-recursive-whitelisting-struct-by-value.h")
+        .whitelist_recursively(false)
+        .whitelisted_type("Foo")
+        .generate();
+    assert!(result.is_err(),
+            "Using a non-whitelisted type by value should fail");
+}
+
+#[test]
+fn strict_mode() {
+    Builder::default()
+        .header("tests/headers/struct_with_struct.h")
+        .strict(true)
+        .generate()
+        .expect("A header we fully understand should pass in strict mode");
+
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let sink = delivered.clone();
+
+    let result = Builder::default()
+        .header("tests/headers/partial-specialization-and-inheritance.hpp")
+        .strict(true)
+        .diagnostic_callback(Box::new(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_owned()));
+        }))
+        .generate();
+    assert!(result.is_err(),
+            "Partial template specializations should fail in strict mode");
+
+    let delivered = delivered.lock().unwrap();
+    assert!(delivered.iter().any(|&(severity, ref message)| {
+                severity == DiagnosticSeverity::Error &&
+                message.contains("strict mode") &&
+                message.contains("Derived")
+            }),
+            "The approximated type should be named: {:?}",
+            *delivered);
+}
+
+/// Naming the overloads used to be quadratic in their number. The suffixes
+/// themselves are checked by the `overload_suffixes.hpp` expectation; this
+/// times 500 of them. Run it with `cargo test -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn bench_many_overloads() {
+    let dir = TempDir::new("many-overloads");
+
+    let header = dir.join("input.hpp");
+    {
+        let mut file = fs::File::create(&header).unwrap();
+        writeln!(file, "class Overloaded {{").unwrap();
+        writeln!(file, "public:").unwrap();
+        for i in 1..501 {
+            writeln!(file, "  void method(char (*)[{}]);", i).unwrap();
+        }
+        writeln!(file, "}};").unwrap();
+    }
+
+    let start = Instant::now();
+    let bindings = Builder::default()
+        .header(header.to_str().unwrap())
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+    let elapsed = start.elapsed();
+
+    println!("Generated 500 overloads in {}.{:03}s",
+             elapsed.as_secs(),
+             elapsed.subsec_nanos() / 1_000_000);
+    assert!(bindings.contains("pub fn Overloaded_method499(this:"));
+}
+
+#[test]
+fn bitfields_in_native_unions() {
+    let bindings = Builder::default()
+        .header("tests/headers/union_bitfield.h")
+        .generate()
+        .expect("Should generate the bindings")
+        .to_string();
+
+    assert!(bindings.contains("pub union Flags {"), "{}", bindings);
+    assert!(bindings.contains("pub _bitfield_1: \
+                               __BindgenBitfieldUnit<[u8; 4usize], u32>,"),
+            "{}",
+            bindings);
+    assert!(bindings.contains("self._bitfield_2.get(0usize, 29u8)"),
+            "{}",
+            bindings);
+    assert!(!bindings.contains("__BindgenUnionField"), "{}", bindings);
+}
+
 // This file is generated by build.rs
 include!(concat!(env!("OUT_DIR"), "/tests.rs"));